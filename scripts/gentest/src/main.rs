@@ -668,11 +668,18 @@ fn generate_node(w: &mut XmlWriter, node: &Value) {
     let overflow_y = get_str_attr(&style["overflowY"], Some("visible"));
     if overflow_x.is_some() || overflow_y.is_some() {
         maybe_write(w, "scrollbar-width", get_num_attr(&style["scrollbarWidth"], None));
+        maybe_write(w, "scrollbar-gutter", get_str_attr(&style["scrollbarGutter"], Some("auto")));
     }
 
     maybe_write(w, "contain", get_str_attr(&style["contain"], Some("none")));
+    maybe_write(w, "contain-intrinsic-width", get_str_attr(&style["containIntrinsicWidth"], Some("none")));
+    maybe_write(w, "contain-intrinsic-height", get_str_attr(&style["containIntrinsicHeight"], Some("none")));
+    maybe_write(w, "content-visibility", get_str_attr(&style["contentVisibility"], Some("visible")));
+    maybe_write(w, "container-type", get_str_attr(&style["containerType"], Some("normal")));
 
     maybe_write(w, "text-align", get_str_attr(&style["textAlign"], None));
+    maybe_write(w, "list-style-position", get_str_attr(&style["listStylePosition"], Some("outside")));
+    maybe_write(w, "ruby-align", get_str_attr(&style["rubyAlign"], Some("space-around")));
     maybe_write(w, "align-items", get_str_attr(&style["alignItems"], None));
     maybe_write(w, "align-self", get_str_attr(&style["alignSelf"], None));
     maybe_write(w, "justify-items", get_str_attr(&style["justifyItems"], None));
@@ -691,7 +698,13 @@ fn generate_node(w: &mut XmlWriter, node: &Value) {
     maybe_write(w, "max-width", get_dim_attr(&style["maxSize"]["width"], Some("auto")));
     maybe_write(w, "max-height", get_dim_attr(&style["maxSize"]["height"], Some("auto")));
 
-    maybe_write(w, "aspect-ratio", get_num_attr(&style["aspectRatio"], None));
+    maybe_write(w, "aspect-ratio", get_str_attr(&style["aspectRatio"], Some("auto")));
+    maybe_write(w, "object-fit", get_str_attr(&style["objectFit"], Some("fill")));
+    maybe_write(w, "object-position-x", get_dim_attr(&style["objectPosition"]["x"], Some("50%")));
+    maybe_write(w, "object-position-y", get_dim_attr(&style["objectPosition"]["y"], Some("50%")));
+    maybe_write(w, "transform", get_str_attr(&style["transform"], Some("none")));
+    maybe_write(w, "transform-origin-x", get_dim_attr(&style["transformOrigin"]["x"], Some("50%")));
+    maybe_write(w, "transform-origin-y", get_dim_attr(&style["transformOrigin"]["y"], Some("50%")));
 
     // TODO: null check in no gap case
     maybe_write(w, "row-gap", get_dim_attr(&style["gap"]["row"], None));
@@ -717,6 +730,10 @@ fn generate_node(w: &mut XmlWriter, node: &Value) {
     maybe_write(w, "bottom", get_dim_attr(&style["inset"]["bottom"], None));
     maybe_write(w, "right", get_dim_attr(&style["inset"]["right"], None));
 
+    maybe_write(w, "anchor-name", get_str_attr(&style["anchorName"], Some("none")));
+    maybe_write(w, "position-anchor", get_str_attr(&style["positionAnchor"], Some("auto")));
+    maybe_write(w, "position-try-fallbacks", get_str_attr(&style["positionTryFallbacks"], Some("none")));
+
     maybe_write(w, "grid-auto-flow", serialize_grid_auto_flow(&style["gridAutoFlow"]));
     maybe_write(w, "grid-template-rows", get_str_attr(&style["gridTemplateRows"], None));
     maybe_write(w, "grid-template-columns", get_str_attr(&style["gridTemplateColumns"], None));
@@ -135,18 +135,15 @@ function parseNumber(input) {
   return Number(input);
 }
 
-function parseRatio(input) {
-  if (!input) return undefined;
-
-  if (input.includes('/')) {
-    let [width, height] = input.split("/").map(part => parseFloat(part.trim()));
-    if (!width || width < 0 || !height || height <= 0) return undefined;
-    return width / height;
-  }
+function parseAspectRatio(input) {
+  if (!input || input === 'auto') return undefined;
+  return input.trim();
+}
 
-  let ratio = parseFloat(input);
-  if (!ratio || ratio < 0) return undefined;
-  return ratio;
+function parsePosition(input) {
+  // Computed positions are always lengths or percentages (with an optional third z component that is ignored)
+  const [x, y] = input.trim().split(/\s+/);
+  return { x: parseDimension(x), y: parseDimension(y) };
 }
 
 function parseEnum(input) {
@@ -225,6 +222,8 @@ function describeElement(e) {
       clear: parseEnum(e.style.clear),
 
       textAlign: parseEnum(e.style.textAlign),
+      listStylePosition: parseEnum(e.style.listStylePosition),
+      rubyAlign: parseEnum(e.style.rubyAlign),
 
       flexDirection: parseEnum(e.style.flexDirection),
       flexWrap: parseEnum(e.style.flexWrap),
@@ -232,8 +231,13 @@ function describeElement(e) {
       overflowX: parseEnum(e.style.overflowX),
       overflowY: parseEnum(e.style.overflowY),
       scrollbarWidth: getScrollBarWidth(),
+      scrollbarGutter: parseEnum(e.style.scrollbarGutter),
 
       contain: parseEnum(e.style.contain),
+      containIntrinsicWidth: parseEnum(e.style.containIntrinsicWidth),
+      containIntrinsicHeight: parseEnum(e.style.containIntrinsicHeight),
+      contentVisibility: parseEnum(e.style.contentVisibility),
+      containerType: parseEnum(e.style.containerType),
 
       alignItems: parseEnum(e.style.alignItems),
       alignSelf: parseEnum(e.style.alignSelf),
@@ -265,7 +269,12 @@ function describeElement(e) {
       size: parseSize({ width: e.style.width, height: e.style.height }),
       minSize: parseSize({ width: e.style.minWidth, height: e.style.minHeight }),
       maxSize: parseSize({ width: e.style.maxWidth, height: e.style.maxHeight }),
-      aspectRatio: parseRatio(e.style.aspectRatio),
+      aspectRatio: parseAspectRatio(e.style.aspectRatio),
+      objectFit: parseEnum(e.style.objectFit),
+      objectPosition: parsePosition(computedStyle.objectPosition),
+      // The computed transform is always `none` or a `matrix()`, and the computed origin is resolved to lengths
+      transform: parseEnum(computedStyle.transform),
+      transformOrigin: computedStyle.transform === "none" ? undefined : parsePosition(computedStyle.transformOrigin),
 
       margin: parseEdges({
         left: e.style.marginLeft,
@@ -294,6 +303,10 @@ function describeElement(e) {
         top: e.style.top,
         bottom: e.style.bottom,
       }),
+
+      anchorName: e.style.anchorName || undefined,
+      positionAnchor: e.style.positionAnchor || undefined,
+      positionTryFallbacks: e.style.positionTryFallbacks || undefined,
     },
 
     // The resolved value of the grid-template-rows/grid-template-columns properties
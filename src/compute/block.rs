@@ -1,6 +1,17 @@
-//! Computes the CSS block layout algorithm in the case that the block container being laid out contains only block-level boxes
+//! Computes the CSS block layout algorithm in the case that the block container being laid out contains only block-level
+//! boxes and atomic inline-level boxes (which are placed in line boxes)
+use crate::compute::common::alignment::resolve_self_alignment_safety;
+use crate::compute::common::anchor::PositionOptions;
+use crate::compute::common::aspect_ratio::{transfer_sizes, MaybeTransferAspectRatio, PreferredAspectRatio};
+use crate::compute::common::scrollbar::{compute_scrollbar_gutter, compute_with_auto_scrollbars, scrollbar_size};
+use crate::compute::common::size_containment::size_contained_dimensions;
 use crate::geometry::{Line, Point, Rect, Size};
-use crate::style::{AvailableSpace, CoreStyle, LengthPercentageAuto, Overflow, Position};
+use crate::style::{
+    AlignItems, AlignItemsKeyword, AlignSelf, AvailableSpace, CoreStyle, DisplayOutside, LengthPercentageAuto,
+    Overflow, Position,
+};
+#[cfg(feature = "content_size")]
+use crate::style::{LengthPercentage, Transform};
 use crate::style_helpers::TaffyMaxContent;
 use crate::tree::{Baselines, CollapsibleMarginSet, Layout, LayoutInput, LayoutOutput, RunMode, SizingMode};
 use crate::tree::{LayoutPartialTree, LayoutPartialTreeExt, NodeId};
@@ -11,7 +22,7 @@ use crate::util::MaybeMath;
 use crate::util::{MaybeResolve, ResolveOrZero};
 use crate::{
     BlockContainerStyle, BlockItemStyle, BoxGenerationMode, BoxSizing, Contain, Dimension, Direction,
-    LayoutBlockContainer, RequestedAxis, TextAlign,
+    LayoutBlockContainer, ListStylePosition, RequestedAxis, TextAlign,
 };
 
 #[cfg(feature = "float_layout")]
@@ -265,7 +276,7 @@ impl BlockContext<'_> {
 
 use super::common::alignment::{apply_alignment_fallback, compute_alignment_offset};
 #[cfg(feature = "content_size")]
-use super::common::scrollable_overflow::compute_scrollable_overflow_contribution;
+use super::common::scrollable_overflow::{compute_scrollable_overflow_contribution, resolve_transform};
 use super::common::sizing_keyword::{
     resolve_absolute_sizing_keywords, resolve_sizing_keyword, SizingKeywordResolution,
 };
@@ -289,6 +300,11 @@ struct BlockItem {
 
     /// Whether the child is a non-independent block or inline node
     is_in_same_bfc: bool,
+    /// Whether the item is an in-flow atomic inline-level box (such as an `inline-flex` box), which is placed in a line
+    /// box rather than stacked in the block axis
+    is_inline_level: bool,
+    /// Whether the item is an inline-block box, which is aligned by its last baseline within its line box
+    is_inline_block: bool,
 
     #[cfg(feature = "float_layout")]
     /// The `float` style of the node
@@ -315,8 +331,16 @@ struct BlockItem {
     overflow: Point<Overflow>,
     /// The contain style of the item
     contain: Contain,
-    /// The width of the item's scrollbars (if it has scrollbars)
-    scrollbar_width: f32,
+    #[cfg(feature = "content_size")]
+    /// The transform style of the item
+    transform: Transform,
+    #[cfg(feature = "content_size")]
+    /// The transform origin style of the item
+    transform_origin: Point<LengthPercentage>,
+
+    /// The `justify-self` of the item (falling back to the container's `justify-items`), with `self-start`
+    /// and `self-end` resolved against the container's direction. `None` represents `normal`
+    justify_self: Option<AlignSelf>,
 
     /// The position style of the item
     position: Position,
@@ -356,6 +380,9 @@ pub fn compute_block_layout(
 
     // Pull these out earlier to avoid borrowing issues
     let overflow = style.overflow();
+    let scrollbar_gutter = style.scrollbar_gutter();
+    let scrollbar_width = style.scrollbar_width();
+    let direction = style.direction();
     let is_scroll_container = overflow.x.is_scroll_container() || overflow.y.is_scroll_container();
     let contain = style.contain();
     // css-align-3 §5.1.1: a non-`normal` `align-content` makes a block container establish an
@@ -364,44 +391,51 @@ pub fn compute_block_layout(
     // <https://drafts.csswg.org/css-contain-2/#containment-layout>
     let establishes_new_bfc =
         is_scroll_container || style.align_content().is_some() || contain.establishes_independent_formatting_context();
-    let aspect_ratio = style.aspect_ratio();
     let padding = style.padding().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
     let border = style.border().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
     let padding_border_size = (padding + border).sum_axes();
     let box_sizing_adjustment =
         if style.box_sizing() == BoxSizing::ContentBox { padding_border_size } else { Size::ZERO };
+    let aspect_ratio = PreferredAspectRatio::from_style(&style, padding_border_size);
 
     let min_size = style
         .min_size()
         .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
-        .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
     let max_size = style
         .max_size()
         .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
-        .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
+    let style_size =
+        style.size().maybe_resolve(parent_size, |val, basis| tree.calc(val, basis)).maybe_add(box_sizing_adjustment);
+    let (style_size, min_size, max_size) = transfer_sizes(aspect_ratio, style_size, min_size, max_size);
     let clamped_style_size = if inputs.sizing_mode == SizingMode::InherentSize {
-        style
-            .size()
-            .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
-            .maybe_apply_aspect_ratio(aspect_ratio)
-            .maybe_add(box_sizing_adjustment)
-            .maybe_clamp(min_size, max_size)
+        style_size.maybe_clamp(min_size, max_size)
     } else {
         Size::NONE
     };
 
-    drop(style);
-
     // If both min and max in a given axis are set and max <= min then this determines the size in that axis
     let min_max_definite_size = min_size.zip_map(max_size, |min, max| match (min, max) {
         (Some(min), Some(max)) if max <= min => Some(min),
         _ => None,
     });
 
+    // A box with size containment is sized as if it were empty in the contained axes
+    let styled_based_known_dimensions = known_dimensions.or(min_max_definite_size).or(clamped_style_size);
+    let size_contained_dimensions = size_contained_dimensions(
+        &style,
+        tree.last_remembered_size(node_id),
+        styled_based_known_dimensions,
+        aspect_ratio,
+        padding_border_size,
+        min_size,
+        max_size,
+    );
+    drop(style);
+
     let styled_based_known_dimensions =
-        known_dimensions.or(min_max_definite_size).or(clamped_style_size).maybe_max(padding_border_size);
+        styled_based_known_dimensions.or(size_contained_dimensions).maybe_max(padding_border_size);
 
     // Short-circuit layout if the container's size is fully determined by the container's size and the run mode
     // is ComputeSize (and thus the container's size is all that we're interested in)
@@ -420,28 +454,30 @@ pub fn compute_block_layout(
 
     // Unwrap the block formatting context if one was passed, or else create a new one
     debug_log!("BLOCK");
+    let inputs = LayoutInput { known_dimensions: styled_based_known_dimensions, ..inputs };
     let mut output = match block_ctx {
-        Some(inherited_bfc) if !establishes_new_bfc => compute_inner(
-            tree,
-            node_id,
-            LayoutInput { known_dimensions: styled_based_known_dimensions, ..inputs },
-            inherited_bfc,
-        ),
-        _ => {
-            let mut root_bfc = BlockFormattingContext::new();
-            let mut root_ctx = root_bfc.root_block_context();
-            compute_inner(
-                tree,
-                node_id,
-                LayoutInput { known_dimensions: styled_based_known_dimensions, ..inputs },
-                &mut root_ctx,
-            )
+        Some(inherited_bfc) if !establishes_new_bfc => {
+            compute_inner(tree, node_id, inputs, inherited_bfc, Point { x: false, y: false })
         }
+        _ => compute_with_auto_scrollbars(
+            inputs,
+            overflow,
+            scrollbar_gutter,
+            scrollbar_width,
+            direction,
+            border,
+            max_size.map(|size| size.is_some()),
+            |inputs, auto_scrollbars| {
+                let mut root_bfc = BlockFormattingContext::new();
+                let mut root_ctx = root_bfc.root_block_context();
+                compute_inner(tree, node_id, inputs, &mut root_ctx, auto_scrollbars)
+            },
+        ),
     };
 
     // Layout containment suppresses the box's baseline for baseline-alignment purposes
     if contain.suppresses_baseline() {
-        output.baselines = Baselines::NONE;
+        output.baselines = Point { x: Baselines::NONE, y: Baselines::NONE };
     }
 
     output
@@ -453,6 +489,7 @@ fn compute_inner(
     node_id: NodeId,
     inputs: LayoutInput,
     #[allow(unused_mut)] mut block_ctx: &mut BlockContext<'_>,
+    auto_scrollbars: Point<bool>,
 ) -> LayoutOutput {
     let LayoutInput {
         known_dimensions, parent_size, available_space, run_mode, vertical_margins_are_collapsible, ..
@@ -462,24 +499,19 @@ fn compute_inner(
     let raw_padding = style.padding();
     let raw_border = style.border();
     let raw_margin = style.margin();
-    let aspect_ratio = style.aspect_ratio();
     let padding = raw_padding.resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
     let border = raw_border.resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
     let direction = style.direction();
 
-    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll`.
-    // However, the axis are switched (transposed) because a node that scrolls vertically needs
-    // *horizontal* space to be reserved for a scrollbar
-    let scrollbar_gutter = {
-        let offsets = style.overflow().transpose().map(|overflow| match overflow {
-            Overflow::Scroll => style.scrollbar_width(),
-            _ => 0.0,
-        });
-        match direction {
-            Direction::Ltr => Rect { top: 0.0, left: 0.0, right: offsets.x, bottom: offsets.y },
-            Direction::Rtl => Rect { top: 0.0, left: offsets.x, right: 0.0, bottom: offsets.y },
-        }
-    };
+    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll` (or to
+    // `Overflow::Auto` and the content overflows), or for any scroll container with a stable `scrollbar_gutter`
+    let scrollbar_gutter = compute_scrollbar_gutter(
+        style.overflow(),
+        style.scrollbar_gutter(),
+        style.scrollbar_width(),
+        direction,
+        auto_scrollbars,
+    );
     let padding_border = padding + border;
     let padding_border_size = padding_border.sum_axes();
     let content_box_inset = padding_border + scrollbar_gutter;
@@ -490,21 +522,18 @@ fn compute_inner(
 
     let box_sizing_adjustment =
         if style.box_sizing() == BoxSizing::ContentBox { padding_border_size } else { Size::ZERO };
-    let size = style
-        .size()
-        .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
-        .maybe_apply_aspect_ratio(aspect_ratio)
-        .maybe_add(box_sizing_adjustment);
+    let aspect_ratio = PreferredAspectRatio::from_style(&style, padding_border_size);
+    let size =
+        style.size().maybe_resolve(parent_size, |val, basis| tree.calc(val, basis)).maybe_add(box_sizing_adjustment);
     let min_size = style
         .min_size()
         .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
-        .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
     let max_size = style
         .max_size()
         .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
-        .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
+    let (size, min_size, max_size) = transfer_sizes(aspect_ratio, size, min_size, max_size);
 
     // css-sizing-4: a definite size in one axis transfers through `aspect-ratio`
     // to make the other definite. Deriving it from `known_dimensions` self-gates
@@ -514,7 +543,7 @@ fn compute_inner(
     // newly-filled axis is adopted (and clamped); an incoming known size is left
     // as the parent resolved it (re-clamping would undo padding/border overrides).
     let known_dimensions = {
-        let derived = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
+        let derived = known_dimensions.maybe_transfer_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
         Size { width: known_dimensions.width.or(derived.width), height: known_dimensions.height.or(derived.height) }
     };
     let percentage_basis_dimensions = Size {
@@ -556,16 +585,47 @@ fn compute_inner(
 
     let text_align = style.text_align();
     let align_content = style.align_content();
+    let justify_items = style.justify_items();
+    let is_list_item = style.is_list_item();
+    let list_style_position = style.list_style_position();
     drop(style);
 
     // 1. Generate items
-    let mut items = generate_item_list(tree, node_id, container_content_box_size);
+    let mut items = generate_item_list(tree, node_id, container_content_box_size, justify_items, direction);
+
+    // Measure the marker box of a list item. Outside markers do not affect the size of the list item, so they only
+    // need to be measured when performing layout.
+    let needs_marker =
+        is_list_item && (run_mode == RunMode::PerformLayout || list_style_position == ListStylePosition::Inside);
+    let marker = needs_marker
+        .then(|| {
+            tree.compute_marker_layout(
+                node_id,
+                LayoutInput {
+                    run_mode: RunMode::PerformLayout,
+                    sizing_mode: SizingMode::InherentSize,
+                    axis: RequestedAxis::Both,
+                    known_dimensions: Size::NONE,
+                    known_dimensions_are_definite: Size { width: true, height: true },
+                    parent_size: container_content_box_size,
+                    available_space: Size::MAX_CONTENT,
+                    vertical_margins_are_collapsible: Line::FALSE,
+                },
+            )
+        })
+        .flatten();
+    let (inside_marker, outside_marker) = match list_style_position {
+        ListStylePosition::Inside => (marker, None),
+        ListStylePosition::Outside => (None, marker),
+    };
 
     // 2. Compute container width
     let container_outer_width = known_dimensions.width.unwrap_or_else(|| {
         let available_width = available_space.width.maybe_sub(content_box_inset.horizontal_axis_sum());
-        let intrinsic_width = determine_content_based_container_width(tree, &items, available_width)
-            + content_box_inset.horizontal_axis_sum();
+        let inside_marker_width = inside_marker.map(|marker| marker.size.width);
+        let intrinsic_width =
+            determine_content_based_container_width(tree, &items, available_width, inside_marker_width)
+                + content_box_inset.horizontal_axis_sum();
         intrinsic_width.maybe_clamp(min_size.width, max_size.width).maybe_max(Some(padding_border_size.width))
     });
 
@@ -600,7 +660,8 @@ fn compute_inner(
         mut intrinsic_outer_height,
         first_child_top_margin_set,
         last_child_bottom_margin_set,
-        mut first_baseline,
+        mut baselines,
+        mut marker_rect,
     ) = perform_final_layout_on_in_flow_children(
         tree,
         run_mode,
@@ -613,6 +674,7 @@ fn compute_inner(
         text_align,
         direction,
         own_margins_collapse_with_children,
+        inside_marker,
         #[cfg(feature = "content_size")]
         is_scroll_container,
         block_ctx,
@@ -655,7 +717,11 @@ fn compute_inner(
         if any_in_flow {
             let keyword = apply_alignment_fallback(free_space, 1, align_content);
             let group_offset = compute_alignment_offset(free_space, 1, 0.0, keyword, false, true);
-            first_baseline = first_baseline.map(|baseline| baseline + group_offset);
+            baselines.y = baselines.y.offset_by(group_offset);
+            if let Some(rect) = marker_rect.as_mut() {
+                rect.top += group_offset;
+                rect.bottom += group_offset;
+            }
             for item in items.iter_mut() {
                 if let Some(layout) = item.final_layout.as_mut() {
                     layout.location.y += group_offset;
@@ -686,6 +752,13 @@ fn compute_inner(
                             layout.scrollable_overflow_rect,
                             item.overflow,
                             item.contain,
+                            resolve_transform(
+                                item.transform,
+                                item.transform_origin,
+                                layout.size,
+                                direction.is_rtl(),
+                                |val, basis| tree.calc(val, basis),
+                            ),
                             is_scroll_container,
                         ));
                     }
@@ -702,14 +775,32 @@ fn compute_inner(
         }
         item.position == Position::Absolute || item.can_be_collapsed_through
     });
-    let can_be_collapsed_through =
-        !has_styles_preventing_being_collapsed_through && all_in_flow_children_can_be_collapsed_through;
+    let can_be_collapsed_through = !has_styles_preventing_being_collapsed_through
+        && all_in_flow_children_can_be_collapsed_through
+        && inside_marker.is_none();
+
+    // An outside marker is placed before the start of the list item's content box, with its baseline aligned with the
+    // first baseline of the list item (or with its top at the top of the content box if there is no baseline)
+    if let Some(marker) = outside_marker {
+        let x = match direction {
+            Direction::Ltr => resolved_content_box_inset.left - marker.size.width,
+            Direction::Rtl => container_outer_width - resolved_content_box_inset.right,
+        };
+        let y = match baselines.y.first {
+            Some(baseline) => baseline - marker.baselines.y.first.unwrap_or(marker.size.height),
+            None => resolved_content_box_inset.top,
+        };
+        marker_rect = Some(Rect { left: x, right: x + marker.size.width, top: y, bottom: y + marker.size.height });
+    }
 
     let mut output = LayoutOutput {
         size: final_outer_size,
         #[cfg(feature = "content_size")]
         scrollable_overflow_rect: Rect::ZERO,
-        baselines: Baselines::from_first(first_baseline),
+        baselines,
+        scrollbar_size: scrollbar_size(scrollbar_gutter, direction),
+        replaced_content_rect: None,
+        marker_rect,
         top_margin: if own_margins_collapse_with_children.start {
             first_child_top_margin_set
         } else {
@@ -747,6 +838,7 @@ fn compute_inner(
     let absolute_position_offset = Point { x: absolute_position_inset.left, y: absolute_position_inset.top };
     let absolute_overflow_rect = perform_absolute_layout_on_absolute_children(
         tree,
+        node_id,
         &items,
         absolute_position_area,
         absolute_position_offset,
@@ -796,18 +888,35 @@ fn generate_item_list(
     tree: &impl LayoutBlockContainer,
     node: NodeId,
     node_inner_size: Size<Option<f32>>,
+    justify_items: Option<AlignItems>,
+    direction: Direction,
 ) -> Vec<BlockItem> {
     tree.child_ids(node)
         .map(|child_node_id| (child_node_id, tree.get_block_child_style(child_node_id)))
         .filter(|(_, style)| style.box_generation_mode() != BoxGenerationMode::None)
         .enumerate()
         .map(|(order, (child_node_id, child_style))| {
-            let aspect_ratio = child_style.aspect_ratio();
             let padding = child_style.padding().resolve_or_zero(node_inner_size, |val, basis| tree.calc(val, basis));
             let border = child_style.border().resolve_or_zero(node_inner_size, |val, basis| tree.calc(val, basis));
             let pb_sum = (padding + border).sum_axes();
             let box_sizing_adjustment =
                 if child_style.box_sizing() == BoxSizing::ContentBox { pb_sum } else { Size::ZERO };
+            let aspect_ratio = PreferredAspectRatio::from_style(&child_style, pb_sum);
+            let (size, min_size, max_size) = transfer_sizes(
+                aspect_ratio,
+                child_style
+                    .size()
+                    .maybe_resolve(node_inner_size, |val, basis| tree.calc(val, basis))
+                    .maybe_add(box_sizing_adjustment),
+                child_style
+                    .min_size()
+                    .maybe_resolve(node_inner_size, |val, basis| tree.calc(val, basis))
+                    .maybe_add(box_sizing_adjustment),
+                child_style
+                    .max_size()
+                    .maybe_resolve(node_inner_size, |val, basis| tree.calc(val, basis))
+                    .maybe_add(box_sizing_adjustment),
+            );
 
             let position = child_style.position();
             let overflow = child_style.overflow();
@@ -833,35 +942,37 @@ fn generate_item_list(
                 && !is_scroll_container
                 && !contain.establishes_independent_formatting_context();
 
+            // Absolutely positioned and floated boxes are always block-level
+            let is_inline_level = child_style.display_outside() == DisplayOutside::Inline
+                && position != Position::Absolute
+                && is_not_floated;
+
             BlockItem {
                 node_id: child_node_id,
                 order: order as u32,
                 is_table,
                 is_replaced,
                 is_in_same_bfc,
+                is_inline_level,
+                is_inline_block: child_style.is_inline_block(),
                 #[cfg(feature = "float_layout")]
                 float,
                 #[cfg(feature = "float_layout")]
                 clear: child_style.clear(),
                 size_style: child_style.size(),
-                size: child_style
-                    .size()
-                    .maybe_resolve(node_inner_size, |val, basis| tree.calc(val, basis))
-                    .maybe_apply_aspect_ratio(aspect_ratio)
-                    .maybe_add(box_sizing_adjustment),
-                min_size: child_style
-                    .min_size()
-                    .maybe_resolve(node_inner_size, |val, basis| tree.calc(val, basis))
-                    .maybe_apply_aspect_ratio(aspect_ratio)
-                    .maybe_add(box_sizing_adjustment),
-                max_size: child_style
-                    .max_size()
-                    .maybe_resolve(node_inner_size, |val, basis| tree.calc(val, basis))
-                    .maybe_apply_aspect_ratio(aspect_ratio)
-                    .maybe_add(box_sizing_adjustment),
+                size,
+                min_size,
+                max_size,
                 overflow,
                 contain,
-                scrollbar_width: child_style.scrollbar_width(),
+                #[cfg(feature = "content_size")]
+                transform: child_style.transform(),
+                #[cfg(feature = "content_size")]
+                transform_origin: child_style.transform_origin(),
+                justify_self: child_style
+                    .justify_self()
+                    .or(justify_items)
+                    .map(|align| align.resolve_self_relative(child_style.direction(), direction, true)),
                 position,
                 inset: child_style.inset(),
                 margin: child_style.margin(),
@@ -905,12 +1016,24 @@ fn determine_content_based_container_width(
     tree: &mut impl LayoutPartialTree,
     items: &[BlockItem],
     available_width: AvailableSpace,
+    inside_marker_width: Option<f32>,
 ) -> f32 {
     let available_space = Size { width: available_width, height: AvailableSpace::MinContent };
 
-    let mut max_child_width = 0.0;
+    let mut max_child_width = inside_marker_width.unwrap_or(0.0);
     #[cfg(feature = "float_layout")]
     let mut float_contribution = FloatIntrinsicWidthCalculator::new(available_width);
+
+    // Inline-level items are placed side by side in line boxes, and wrap onto a new line when they don't fit in the
+    // available width. Under a min-content constraint every item wraps, and under a max-content constraint none do.
+    let line_width_limit = match available_width {
+        AvailableSpace::MinContent => 0.0,
+        AvailableSpace::MaxContent => f32::INFINITY,
+        AvailableSpace::Definite(width) => width,
+    };
+    // An inside marker starts the first line
+    let mut line_width: Option<f32> = inside_marker_width;
+
     for item in items.iter().filter(|item| item.position != Position::Absolute) {
         let known_dimensions = item.size.maybe_clamp(item.min_size, item.max_size);
 
@@ -937,6 +1060,17 @@ fn determine_content_based_container_width(
 
         let width = f32_max(width, item.padding_border_sum.width) + item_x_margin_sum;
 
+        if item.is_inline_level {
+            let new_line_width = match line_width {
+                Some(line_width) if line_width + width <= line_width_limit => line_width + width,
+                _ => width,
+            };
+            line_width = Some(new_line_width);
+            max_child_width = f32_max(max_child_width, new_line_width);
+            continue;
+        }
+        line_width = None;
+
         #[cfg(feature = "float_layout")]
         if let Some(direction) = item.float.float_direction() {
             float_contribution.add_float(width, direction, item.clear);
@@ -956,7 +1090,7 @@ fn determine_content_based_container_width(
 
 /// Compute each child's final size and position
 #[inline]
-#[allow(clippy::too_many_arguments)]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 fn perform_final_layout_on_in_flow_children(
     tree: &mut impl LayoutBlockContainer,
     run_mode: RunMode,
@@ -969,9 +1103,10 @@ fn perform_final_layout_on_in_flow_children(
     text_align: TextAlign,
     direction: Direction,
     own_margins_collapse_with_children: Line<bool>,
+    mut inside_marker: Option<LayoutOutput>,
     #[cfg(feature = "content_size")] is_scroll_container: bool,
     block_ctx: &mut BlockContext<'_>,
-) -> (Rect<f32>, f32, CollapsibleMarginSet, CollapsibleMarginSet, Option<f32>) {
+) -> (Rect<f32>, f32, CollapsibleMarginSet, CollapsibleMarginSet, Point<Baselines>, Option<Rect<f32>>) {
     // Resolve container_inner_width for sizing child nodes using initial content_box_inset
     let container_inner_width = container_outer_width - resolved_content_box_inset.horizontal_axis_sum();
     let container_percentage_resolution_height =
@@ -1009,7 +1144,8 @@ fn perform_final_layout_on_in_flow_children(
     let mut first_child_top_margin_set = CollapsibleMarginSet::ZERO;
     let mut active_collapsible_margin_set = CollapsibleMarginSet::ZERO;
     let mut is_collapsing_with_first_margin_set = true;
-    let mut first_baseline: Option<f32> = None;
+    let mut baselines = Point { x: Baselines::NONE, y: Baselines::NONE };
+    let mut marker_rect = None;
     // Whether the active margin set contains the margins of a self-collapsing element with
     // clearance. Such margins collapse with the margins of following siblings but the resulting
     // margin does not collapse with the bottom margin of the parent block.
@@ -1020,7 +1156,84 @@ fn perform_final_layout_on_in_flow_children(
     #[cfg(not(feature = "float_layout"))]
     let has_active_floats = false;
 
-    for item in items.iter_mut() {
+    let mut inline_run_end = 0;
+    for item_index in 0..=items.len() {
+        // Items in a run of inline-level items have already been laid out in line boxes
+        if item_index < inline_run_end {
+            continue;
+        }
+
+        // An inside marker is laid out at the start of the first line box. If the first in-flow item is block-level (or
+        // there are no in-flow items) then the marker is placed in a line box of its own.
+        let places_marker_alone = inside_marker.is_some()
+            && items.get(item_index).map_or(true, |item| {
+                #[cfg(feature = "float_layout")]
+                if item.float.is_floated() {
+                    return false;
+                }
+                !item.is_inline_level && item.position != Position::Absolute
+            });
+        if item_index == items.len() && !places_marker_alone {
+            break;
+        }
+
+        // A run of consecutive inline-level items (and any absolutely positioned items among them) is laid out in line
+        // boxes. The line boxes are stacked in the block axis like a block-level box without margins that cannot be
+        // collapsed through.
+        if places_marker_alone || items[item_index].is_inline_level {
+            inline_run_end = match places_marker_alone {
+                true => item_index,
+                false => items[item_index..]
+                    .iter()
+                    .position(|item| !item.is_inline_level && item.position != Position::Absolute)
+                    .map_or(items.len(), |run_length| item_index + run_length),
+            };
+
+            if !is_collapsing_with_first_margin_set || !own_margins_collapse_with_children.start {
+                committed_y_offset += active_collapsible_margin_set.resolve();
+            }
+            // TODO: shorten line boxes that are beside floats rather than overlapping them
+            let (lines_height, line_baselines, lines_overflow_rect, line_marker_rect) =
+                perform_final_layout_on_inline_children(
+                    tree,
+                    run_mode,
+                    &mut items[item_index..inline_run_end],
+                    inside_marker.take(),
+                    committed_y_offset,
+                    container_outer_width,
+                    parent_size,
+                    resolved_content_box_inset,
+                    text_align,
+                    direction,
+                    #[cfg(feature = "content_size")]
+                    resolved_border,
+                    #[cfg(feature = "content_size")]
+                    is_scroll_container,
+                );
+
+            is_collapsing_with_first_margin_set = false;
+            committed_y_offset += lines_height;
+            active_collapsible_margin_set = CollapsibleMarginSet::ZERO;
+            active_margin_set_has_clearance = false;
+            y_offset_for_absolute = committed_y_offset;
+            #[cfg(feature = "float_layout")]
+            block_ctx.commit_strut();
+
+            baselines.y = Baselines {
+                first: baselines.y.first.or(line_baselines.first),
+                last: line_baselines.last.or(baselines.y.last),
+            };
+            marker_rect = marker_rect.or(line_marker_rect);
+            #[cfg(feature = "content_size")]
+            {
+                inflow_overflow_rect = inflow_overflow_rect.union(lines_overflow_rect);
+            }
+            if item_index < inline_run_end || item_index == items.len() {
+                continue;
+            }
+        }
+
+        let item = &mut items[item_index];
         if item.position == Position::Absolute {
             let x = match direction {
                 Direction::Ltr => resolved_content_box_inset.left,
@@ -1034,11 +1247,6 @@ fn perform_final_layout_on_in_flow_children(
             let item_non_auto_margin = item_margin.map(|m| m.unwrap_or(0.0));
             let item_non_auto_x_margin_sum = item_non_auto_margin.horizontal_axis_sum();
 
-            let scrollbar_size = Size {
-                width: if item.overflow.y == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
-                height: if item.overflow.x == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
-            };
-
             // Handle floated boxes
             #[cfg(feature = "float_layout")]
             if let Some(float_direction) = item.float.float_direction() {
@@ -1119,7 +1327,9 @@ fn perform_final_layout_on_in_flow_children(
                     size: item_layout.size,
                     #[cfg(feature = "content_size")]
                     scrollable_overflow_rect: item_layout.scrollable_overflow_rect,
-                    scrollbar_size,
+                    scrollbar_size: item_layout.scrollbar_size,
+                    replaced_content_rect: item_layout.replaced_content_rect,
+                    marker_rect: item_layout.marker_rect,
                     location,
                     padding: item.padding,
                     border: item.border,
@@ -1144,6 +1354,13 @@ fn perform_final_layout_on_in_flow_children(
                         item_layout.scrollable_overflow_rect,
                         item.overflow,
                         item.contain,
+                        resolve_transform(
+                            item.transform,
+                            item.transform_origin,
+                            item_layout.size,
+                            direction.is_rtl(),
+                            |val, basis| tree.calc(val, basis),
+                        ),
                         is_scroll_container,
                     ));
                 }
@@ -1251,6 +1468,26 @@ fn perform_final_layout_on_in_flow_children(
                             ),
                         });
 
+                // Block-level boxes aligned with a `justify-self` other than `normal` or `stretch` are not
+                // stretch-sized: an auto width is instead resolved as `fit-content`
+                // See <https://www.w3.org/TR/css-align-3/#justify-block>
+                let keyword_width = keyword_width.or_else(|| {
+                    let is_stretched = item
+                        .justify_self
+                        .map_or(true, |justify_self| justify_self.keyword == AlignItemsKeyword::Stretch);
+                    (item.size.width.is_none() && !is_stretched).then(|| {
+                        tree.measure_child_size(
+                            item.node_id,
+                            Size::NONE,
+                            parent_size,
+                            Size { width: AvailableSpace::Definite(stretch_width), height: AvailableSpace::MaxContent },
+                            SizingMode::InherentSize,
+                            crate::AbsoluteAxis::Horizontal,
+                            Line::TRUE,
+                        )
+                    })
+                });
+
                 let keyword_height = resolve_stretch_height(
                     item.size_style.height,
                     container_percentage_resolution_height,
@@ -1471,7 +1708,30 @@ fn perform_final_layout_on_in_flow_children(
 
             // Apply alignment
             let item_outer_width = item_layout.size.width + resolved_margin.horizontal_axis_sum();
-            if item_outer_width < container_inner_width {
+            if let Some(justify_self) = item.justify_self {
+                // `justify-self` aligns the item's margin box within the space available to it (the container's
+                // content box, or the space beside the floats it avoids). Auto margins take precedence over
+                // `justify-self` and absorb any positive free space.
+                let auto_margin_sum = resolved_margin.horizontal_axis_sum() - item_non_auto_x_margin_sum;
+                let free_x_space = stretch_width - final_size.width - auto_margin_sum;
+                let offset = match resolve_self_alignment_safety(justify_self, free_x_space < 0.0) {
+                    AlignItemsKeyword::Start
+                    | AlignItemsKeyword::FlexStart
+                    | AlignItemsKeyword::Baseline
+                    | AlignItemsKeyword::Stretch => 0.0,
+                    AlignItemsKeyword::End | AlignItemsKeyword::FlexEnd | AlignItemsKeyword::LastBaseline => {
+                        free_x_space
+                    }
+                    AlignItemsKeyword::Center => free_x_space / 2.0,
+                    // SelfStart/SelfEnd are resolved to Start/End against the item's own direction in
+                    // `generate_item_list`.
+                    AlignItemsKeyword::SelfStart | AlignItemsKeyword::SelfEnd => unreachable!(),
+                };
+                match direction {
+                    Direction::Ltr => location.x += offset,
+                    Direction::Rtl => location.x -= offset,
+                }
+            } else if item_outer_width < container_inner_width {
                 let free_x_space = container_inner_width - item_outer_width;
                 match (text_align, direction) {
                     (TextAlign::Auto, _) => {
@@ -1490,28 +1750,31 @@ fn perform_final_layout_on_in_flow_children(
                 }
             }
 
-            // A block container's first baseline is the first baseline of its first in-flow child
-            // that has one.
+            // A block container's first (last) baseline in each axis is the first (last) baseline of its
+            // first (last) in-flow child that has one.
             //
             // Scroll containers' baselines are determined from their content as if scrolled to the
             // initial position, but are additionally clamped to their border box. A scroll container
             // with no baseline synthesizes one from its border-box bottom edge.
             // See https://github.com/w3c/csswg-drafts/issues/7660
-            if first_baseline.is_none() {
-                let child_baseline = if item.overflow.y.is_scroll_container() {
-                    Some(
-                        item_layout
-                            .baselines
-                            .first
-                            .unwrap_or(item_layout.size.height)
-                            .min(item_layout.size.height)
-                            .max(0.0),
-                    )
-                } else {
-                    item_layout.baselines.first
-                };
-                first_baseline = child_baseline.map(|baseline| location.y + baseline);
-            }
+            let child_baselines_y = if item.overflow.y.is_scroll_container() {
+                let (first, last) = item_layout.baselines.y.synthesized(item_layout.size.height, true);
+                Baselines::new(first, last)
+            } else {
+                item_layout.baselines.y
+            };
+            let child_baselines =
+                Point { x: item_layout.baselines.x.offset_by(location.x), y: child_baselines_y.offset_by(location.y) };
+            baselines = Point {
+                x: Baselines {
+                    first: baselines.x.first.or(child_baselines.x.first),
+                    last: child_baselines.x.last.or(baselines.x.last),
+                },
+                y: Baselines {
+                    first: baselines.y.first.or(child_baselines.y.first),
+                    last: child_baselines.y.last.or(baselines.y.last),
+                },
+            };
 
             // Defer `set_unrounded_layout` to the post-loop pass in `compute_inner` so that
             // `align-content` can shift `location.y` before the layout is committed to the tree.
@@ -1520,7 +1783,9 @@ fn perform_final_layout_on_in_flow_children(
                 size: item_layout.size,
                 #[cfg(feature = "content_size")]
                 scrollable_overflow_rect: item_layout.scrollable_overflow_rect,
-                scrollbar_size,
+                scrollbar_size: item_layout.scrollbar_size,
+                replaced_content_rect: item_layout.replaced_content_rect,
+                marker_rect: item_layout.marker_rect,
                 location,
                 padding: item.padding,
                 border: item.border,
@@ -1543,6 +1808,13 @@ fn perform_final_layout_on_in_flow_children(
                     item_layout.scrollable_overflow_rect,
                     item.overflow,
                     item.contain,
+                    resolve_transform(
+                        item.transform,
+                        item.transform_origin,
+                        final_size,
+                        direction.is_rtl(),
+                        |val, basis| tree.calc(val, basis),
+                    ),
                     is_scroll_container,
                 ));
             }
@@ -1615,13 +1887,306 @@ fn perform_final_layout_on_in_flow_children(
 
     committed_y_offset += resolved_content_box_inset.bottom + bottom_y_margin_offset;
     let content_height = f32_max(0.0, committed_y_offset);
-    (inflow_overflow_rect, content_height, first_child_top_margin_set, last_child_bottom_margin_set, first_baseline)
+    (
+        inflow_overflow_rect,
+        content_height,
+        first_child_top_margin_set,
+        last_child_bottom_margin_set,
+        baselines,
+        marker_rect,
+    )
+}
+
+/// A line box containing inline-level items
+#[derive(Default)]
+struct LineBox {
+    /// Whether the line box contains any in-flow items
+    has_items: bool,
+    /// The sum of the widths of the margin boxes of the items in the line
+    width: f32,
+    /// The distance from the top of the line box to the baseline that its items are aligned to
+    ascent: f32,
+    /// The distance from the baseline of the line box to its bottom
+    descent: f32,
+}
+
+/// The position of an item within its line box
+struct LinePlacement {
+    /// The index of the item
+    index: usize,
+    /// The index of the item's line box
+    line: usize,
+    /// The offset of the start of the item's margin box from the start of the line box, in the inline direction
+    offset: f32,
+    /// The output of the item's layout, which is `None` for absolutely positioned items
+    output: Option<LayoutOutput>,
+    /// The item's resolved margins
+    margin: Rect<f32>,
+    /// The distance from the top of the item's margin box to its baseline
+    ascent: f32,
+}
+
+/// Lays out a run of inline-level items (and any absolutely positioned items among them) in line boxes stacked
+/// downwards from `line_top`. The items are placed side by side in the inline direction, with each line box holding as
+/// many items as fit in the container's content box, and are aligned by their baselines within their line box.
+///
+/// If a `leading_marker` (the marker box of an inside list marker) is given then it is placed at the start of the first
+/// line box, before the items.
+///
+/// Returns the total height of the line boxes, the baselines of the first and last line boxes, the scrollable overflow
+/// of the items and the position of the marker.
+#[allow(clippy::too_many_arguments)]
+fn perform_final_layout_on_inline_children(
+    tree: &mut impl LayoutBlockContainer,
+    run_mode: RunMode,
+    items: &mut [BlockItem],
+    leading_marker: Option<LayoutOutput>,
+    line_top: f32,
+    container_outer_width: f32,
+    parent_size: Size<Option<f32>>,
+    resolved_content_box_inset: Rect<f32>,
+    text_align: TextAlign,
+    direction: Direction,
+    #[cfg(feature = "content_size")] resolved_border: Rect<f32>,
+    #[cfg(feature = "content_size")] is_scroll_container: bool,
+) -> (f32, Baselines, Rect<f32>, Option<Rect<f32>>) {
+    let container_inner_width = container_outer_width - resolved_content_box_inset.horizontal_axis_sum();
+
+    // 1. Size each item and break the items into lines
+    let mut lines: Vec<LineBox> = Vec::new();
+    let mut placements: Vec<LinePlacement> = Vec::with_capacity(items.len());
+    let mut line = LineBox::default();
+
+    // The marker is aligned by its first baseline, or by its bottom edge if it has none
+    let marker_ascent = leading_marker.map(|marker| {
+        let ascent = marker.baselines.y.first.unwrap_or(marker.size.height);
+        line = LineBox { has_items: true, width: marker.size.width, ascent, descent: marker.size.height - ascent };
+        ascent
+    });
+    for (index, item) in items.iter_mut().enumerate() {
+        if item.position == Position::Absolute {
+            let placement = LinePlacement {
+                index,
+                line: lines.len(),
+                offset: line.width,
+                output: None,
+                margin: Rect::ZERO,
+                ascent: 0.0,
+            };
+            placements.push(placement);
+            continue;
+        }
+
+        // Auto margins of inline-level boxes are treated as zero
+        let margin = item.margin.resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
+        let available_width = container_inner_width - margin.horizontal_axis_sum();
+
+        // Atomic inline-level boxes are not stretch-sized: an auto width is shrink-to-fit (like `fit-content`)
+        let known_dimensions = if item.is_table || item.is_replaced {
+            Size::NONE
+        } else {
+            let width = item.size.width.unwrap_or_else(|| {
+                let item_available_width =
+                    match resolve_sizing_keyword(item.size_style.width, Some(available_width), parent_size.width) {
+                        Some(SizingKeywordResolution::Exact(width)) => return width,
+                        Some(SizingKeywordResolution::Measure(item_available_width)) => item_available_width,
+                        None => AvailableSpace::Definite(available_width),
+                    };
+                tree.measure_child_size(
+                    item.node_id,
+                    Size::NONE,
+                    parent_size,
+                    Size { width: item_available_width, height: AvailableSpace::MaxContent },
+                    SizingMode::InherentSize,
+                    crate::AbsoluteAxis::Horizontal,
+                    Line::FALSE,
+                )
+            });
+            let height = item.size.height.or_else(|| {
+                resolve_stretch_height(item.size_style.height, parent_size.height, margin.vertical_axis_sum())
+            });
+            Size { width: Some(width), height }.maybe_clamp(item.min_size, item.max_size)
+        };
+
+        let output = tree.compute_child_layout(
+            item.node_id,
+            LayoutInput {
+                run_mode,
+                sizing_mode: SizingMode::InherentSize,
+                axis: RequestedAxis::Both,
+                known_dimensions,
+                known_dimensions_are_definite: Size { width: true, height: true },
+                parent_size,
+                available_space: Size {
+                    width: AvailableSpace::Definite(available_width),
+                    height: AvailableSpace::MaxContent,
+                },
+                vertical_margins_are_collapsible: Line::FALSE,
+            },
+        );
+        item.computed_size = output.size;
+
+        // An item that doesn't fit in the remainder of a non-empty line starts a new line
+        let outer_size = output.size + margin.sum_axes();
+        if line.has_items && line.width + outer_size.width > container_inner_width + 0.001 {
+            lines.push(core::mem::take(&mut line));
+        }
+
+        // Inline-block boxes are aligned by their last baseline and other boxes by their first. Boxes without such a
+        // baseline (and inline-block boxes which are scroll containers) are aligned by their bottom margin edge.
+        let is_scroll_container = item.overflow.x.is_scroll_container() || item.overflow.y.is_scroll_container();
+        let baseline = match item.is_inline_block {
+            true => output.baselines.y.last.filter(|_| !is_scroll_container),
+            false => output.baselines.y.first,
+        };
+        let ascent = baseline.map_or(outer_size.height, |baseline| margin.top + baseline);
+        line.ascent = f32_max(line.ascent, ascent);
+        line.descent = f32_max(line.descent, outer_size.height - ascent);
+
+        placements.push(LinePlacement {
+            index,
+            line: lines.len(),
+            offset: line.width,
+            output: Some(output),
+            margin,
+            ascent,
+        });
+        line.has_items = true;
+        line.width += outer_size.width;
+    }
+    lines.push(line);
+
+    // 2. Position the line boxes and the items within them
+    let mut line_tops: Vec<f32> = Vec::with_capacity(lines.len());
+    let mut lines_height = 0.0;
+    for line in &lines {
+        line_tops.push(line_top + lines_height);
+        lines_height += line.ascent + line.descent;
+    }
+    let line_baselines = Baselines {
+        first: Some(line_tops[0] + lines[0].ascent),
+        last: Some(line_tops[lines.len() - 1] + lines[lines.len() - 1].ascent),
+    };
+
+    // Lines are aligned in the inline axis according to `text-align`
+    let align_offset = |line: &LineBox| {
+        let free_space = f32_max(0.0, container_inner_width - line.width);
+        match (text_align, direction) {
+            (TextAlign::Auto, _)
+            | (TextAlign::LegacyLeft, Direction::Ltr)
+            | (TextAlign::LegacyRight, Direction::Rtl) => 0.0,
+            (TextAlign::LegacyLeft, Direction::Rtl) | (TextAlign::LegacyRight, Direction::Ltr) => free_space,
+            (TextAlign::LegacyCenter, _) => free_space / 2.0,
+        }
+    };
+
+    let marker_rect = leading_marker.zip(marker_ascent).map(|(marker, ascent)| {
+        let x = match direction {
+            Direction::Ltr => resolved_content_box_inset.left + align_offset(&lines[0]),
+            Direction::Rtl => {
+                container_outer_width - resolved_content_box_inset.right - align_offset(&lines[0]) - marker.size.width
+            }
+        };
+        let y = line_tops[0] + lines[0].ascent - ascent;
+        Rect { left: x, right: x + marker.size.width, top: y, bottom: y + marker.size.height }
+    });
+
+    #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
+    let mut overflow_rect = Rect::ZERO;
+    for placement in placements {
+        let item = &mut items[placement.index];
+        let line = &lines[placement.line];
+        let line_top = line_tops[placement.line];
+        let inline_offset = align_offset(line) + placement.offset;
+
+        let Some(output) = placement.output else {
+            item.static_position = Point {
+                x: match direction {
+                    Direction::Ltr => resolved_content_box_inset.left + inline_offset,
+                    Direction::Rtl => container_outer_width - resolved_content_box_inset.right - inline_offset,
+                },
+                y: line_top,
+            };
+            continue;
+        };
+
+        let margin = placement.margin;
+        item.static_position = Point {
+            x: match direction {
+                Direction::Ltr => resolved_content_box_inset.left + inline_offset + margin.left,
+                Direction::Rtl => {
+                    container_outer_width
+                        - resolved_content_box_inset.right
+                        - inline_offset
+                        - margin.right
+                        - output.size.width
+                }
+            },
+            y: line_top + line.ascent - placement.ascent + margin.top,
+        };
+
+        // Relatively positioned items are offset from their position in the line
+        let inset = item.inset.zip_size(parent_size, |p, s| p.maybe_resolve(s, |val, basis| tree.calc(val, basis)));
+        let inset_offset = Point {
+            x: if direction.is_rtl() {
+                inset.right.map(|x| -x).or(inset.left).unwrap_or(0.0)
+            } else {
+                inset.left.or(inset.right.map(|x| -x)).unwrap_or(0.0)
+            },
+            y: inset.top.or(inset.bottom.map(|x| -x)).unwrap_or(0.0),
+        };
+        let location = item.static_position + inset_offset;
+
+        item.final_layout = Some(Layout {
+            order: item.order,
+            size: output.size,
+            #[cfg(feature = "content_size")]
+            scrollable_overflow_rect: output.scrollable_overflow_rect,
+            scrollbar_size: output.scrollbar_size,
+            replaced_content_rect: output.replaced_content_rect,
+            marker_rect: output.marker_rect,
+            location,
+            padding: item.padding,
+            border: item.border,
+            margin,
+        });
+
+        #[cfg(feature = "content_size")]
+        {
+            let contribution_location = if direction.is_rtl() {
+                Point {
+                    x: container_outer_width - (location.x + output.size.width) - resolved_border.right,
+                    y: location.y - resolved_border.top,
+                }
+            } else {
+                Point { x: location.x - resolved_border.left, y: location.y - resolved_border.top }
+            };
+            overflow_rect = overflow_rect.union(compute_scrollable_overflow_contribution(
+                contribution_location,
+                output.size,
+                output.scrollable_overflow_rect,
+                item.overflow,
+                item.contain,
+                resolve_transform(
+                    item.transform,
+                    item.transform_origin,
+                    output.size,
+                    direction.is_rtl(),
+                    |val, basis| tree.calc(val, basis),
+                ),
+                is_scroll_container,
+            ));
+        }
+    }
+
+    (lines_height, line_baselines, overflow_rect, marker_rect)
 }
 
 /// Perform absolute layout on all absolutely positioned children.
 #[inline]
 fn perform_absolute_layout_on_absolute_children(
     tree: &mut impl LayoutBlockContainer,
+    node_id: NodeId,
     items: &[BlockItem],
     area_size: Size<f32>,
     area_offset: Point<f32>,
@@ -1630,6 +2195,12 @@ fn perform_absolute_layout_on_absolute_children(
 ) -> Rect<f32> {
     let area_width = area_size.width;
     let area_height = area_size.height;
+    let area = Rect {
+        left: area_offset.x,
+        right: area_offset.x + area_width,
+        top: area_offset.y,
+        bottom: area_offset.y + area_height,
+    };
 
     #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
     let mut absolute_overflow_rect = Rect::ZERO;
@@ -1643,244 +2214,266 @@ fn perform_absolute_layout_on_absolute_children(
             continue;
         }
 
-        let aspect_ratio = child_style.aspect_ratio();
-        let margin =
-            child_style.margin().map(|margin| margin.resolve_to_option(area_width, |val, basis| tree.calc(val, basis)));
-        let padding = child_style.padding().resolve_or_zero(Some(area_width), |val, basis| tree.calc(val, basis));
-        let border = child_style.border().resolve_or_zero(Some(area_width), |val, basis| tree.calc(val, basis));
-        let padding_border_sum = (padding + border).sum_axes();
-        let box_sizing_adjustment =
-            if child_style.box_sizing() == BoxSizing::ContentBox { padding_border_sum } else { Size::ZERO };
-
-        // Resolve inset
-        let left = child_style.inset().left.maybe_resolve(area_width, |val, basis| tree.calc(val, basis));
-        let right = child_style.inset().right.maybe_resolve(area_width, |val, basis| tree.calc(val, basis));
-        let top = child_style.inset().top.maybe_resolve(area_height, |val, basis| tree.calc(val, basis));
-        let bottom = child_style.inset().bottom.maybe_resolve(area_height, |val, basis| tree.calc(val, basis));
-
-        // Compute known dimensions from min/max/inherent size styles
-        let size_style = child_style.size();
-        let style_size = size_style
-            .maybe_resolve(area_size, |val, basis| tree.calc(val, basis))
-            .maybe_apply_aspect_ratio(aspect_ratio)
-            .maybe_add(box_sizing_adjustment);
-        let min_size = child_style
-            .min_size()
-            .maybe_resolve(area_size, |val, basis| tree.calc(val, basis))
-            .maybe_apply_aspect_ratio(aspect_ratio)
-            .maybe_add(box_sizing_adjustment)
-            .or(padding_border_sum.map(Some))
-            .maybe_max(padding_border_sum);
-        let max_size = child_style
-            .max_size()
-            .maybe_resolve(area_size, |val, basis| tree.calc(val, basis))
-            .maybe_apply_aspect_ratio(aspect_ratio)
-            .maybe_add(box_sizing_adjustment);
-        let mut known_dimensions = style_size.maybe_clamp(min_size, max_size);
-
         drop(child_style);
 
-        // Resolve any sizing keywords (min-content, max-content, fit-content, fit-content(...),
-        // stretch) in the size styles. An explicitly sized axis takes precedence over the
-        // inset-derived size below.
-        if size_style.width.is_sizing_keyword() || size_style.height.is_sizing_keyword() {
-            resolve_absolute_sizing_keywords(
-                tree,
-                item.node_id,
-                &mut known_dimensions,
-                size_style,
-                area_size,
-                Rect { left, right, top, bottom },
-                margin,
-                SizingMode::ContentSize,
-            );
-            known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
-        }
-
-        // Fill in width from left/right and reapply aspect ratio if:
-        //   - Width is not already known
-        //   - Item has both left and right inset properties set
-        if let (None, Some(left), Some(right)) = (known_dimensions.width, left, right) {
-            let new_width_raw = area_width.maybe_sub(margin.left).maybe_sub(margin.right) - left - right;
-            known_dimensions.width = Some(f32_max(new_width_raw, 0.0));
-            known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
-        }
+        let position_options = PositionOptions::new(tree, node_id, item.node_id, area, direction);
+        for position_option in position_options.iter() {
+            let child_style = tree.get_block_child_style(item.node_id);
+            let margin = position_option
+                .margin
+                .map(|margin| margin.resolve_to_option(area_width, |val, basis| tree.calc(val, basis)));
+            let padding = child_style.padding().resolve_or_zero(Some(area_width), |val, basis| tree.calc(val, basis));
+            let border = child_style.border().resolve_or_zero(Some(area_width), |val, basis| tree.calc(val, basis));
+            let padding_border_sum = (padding + border).sum_axes();
+            let box_sizing_adjustment =
+                if child_style.box_sizing() == BoxSizing::ContentBox { padding_border_sum } else { Size::ZERO };
+            let aspect_ratio = PreferredAspectRatio::from_style(&child_style, padding_border_sum);
+
+            // Resolve inset
+            let left = position_option.inset.left.maybe_resolve(area_width, |val, basis| tree.calc(val, basis));
+            let right = position_option.inset.right.maybe_resolve(area_width, |val, basis| tree.calc(val, basis));
+            let top = position_option.inset.top.maybe_resolve(area_height, |val, basis| tree.calc(val, basis));
+            let bottom = position_option.inset.bottom.maybe_resolve(area_height, |val, basis| tree.calc(val, basis));
+
+            // Compute known dimensions from min/max/inherent size styles
+            let size_style = position_option.size;
+            let style_size = size_style
+                .maybe_resolve(area_size, |val, basis| tree.calc(val, basis))
+                .maybe_add(box_sizing_adjustment);
+            let min_size = child_style
+                .min_size()
+                .maybe_resolve(area_size, |val, basis| tree.calc(val, basis))
+                .maybe_add(box_sizing_adjustment);
+            let max_size = child_style
+                .max_size()
+                .maybe_resolve(area_size, |val, basis| tree.calc(val, basis))
+                .maybe_add(box_sizing_adjustment);
+            let (style_size, min_size, max_size) = transfer_sizes(aspect_ratio, style_size, min_size, max_size);
+            let min_size = min_size.or(padding_border_sum.map(Some)).maybe_max(padding_border_sum);
+            let mut known_dimensions = style_size.maybe_clamp(min_size, max_size);
 
-        // Fill in height from top/bottom and reapply aspect ratio if:
-        //   - Height is not already known
-        //   - Item has both top and bottom inset properties set
-        if let (None, Some(top), Some(bottom)) = (known_dimensions.height, top, bottom) {
-            let new_height_raw = area_height.maybe_sub(margin.top).maybe_sub(margin.bottom) - top - bottom;
-            known_dimensions.height = Some(f32_max(new_height_raw, 0.0));
-            known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
-        }
+            drop(child_style);
 
-        let final_size = match (known_dimensions.width, known_dimensions.height) {
-            (Some(width), Some(height)) => Size { width, height },
-            _ => {
-                let measured_size = tree.measure_child_size_both(
+            // Resolve any sizing keywords (min-content, max-content, fit-content, fit-content(...),
+            // stretch) in the size styles. An explicitly sized axis takes precedence over the
+            // inset-derived size below.
+            if size_style.width.is_sizing_keyword() || size_style.height.is_sizing_keyword() {
+                resolve_absolute_sizing_keywords(
+                    tree,
                     item.node_id,
-                    known_dimensions,
-                    area_size.map(Some),
-                    Size {
-                        width: AvailableSpace::Definite(area_width.maybe_clamp(min_size.width, max_size.width)),
-                        height: AvailableSpace::Definite(area_height.maybe_clamp(min_size.height, max_size.height)),
-                    },
+                    &mut known_dimensions,
+                    size_style,
+                    area_size,
+                    Rect { left, right, top, bottom },
+                    margin,
                     SizingMode::ContentSize,
-                    Line::FALSE,
                 );
-                known_dimensions.unwrap_or(measured_size)
+                known_dimensions =
+                    known_dimensions.maybe_transfer_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
             }
-        }
-        .maybe_clamp(min_size, max_size);
 
-        let layout_output = tree.perform_child_layout(
-            item.node_id,
-            final_size.map(Some),
-            area_size.map(Some),
-            Size {
-                width: AvailableSpace::Definite(area_width.maybe_clamp(min_size.width, max_size.width)),
-                height: AvailableSpace::Definite(area_height.maybe_clamp(min_size.height, max_size.height)),
-            },
-            SizingMode::ContentSize,
-            Line::FALSE,
-        );
+            // Fill in width from left/right and reapply aspect ratio if:
+            //   - Width is not already known
+            //   - Item has both left and right inset properties set
+            if let (None, Some(left), Some(right)) = (known_dimensions.width, left, right) {
+                let new_width_raw = area_width.maybe_sub(margin.left).maybe_sub(margin.right) - left - right;
+                known_dimensions.width = Some(f32_max(new_width_raw, 0.0));
+                known_dimensions =
+                    known_dimensions.maybe_transfer_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
+            }
 
-        let non_auto_margin = Rect {
-            left: if left.is_some() { margin.left.unwrap_or(0.0) } else { 0.0 },
-            right: if right.is_some() { margin.right.unwrap_or(0.0) } else { 0.0 },
-            top: if top.is_some() { margin.top.unwrap_or(0.0) } else { 0.0 },
-            bottom: if bottom.is_some() { margin.bottom.unwrap_or(0.0) } else { 0.0 },
-        };
+            // Fill in height from top/bottom and reapply aspect ratio if:
+            //   - Height is not already known
+            //   - Item has both top and bottom inset properties set
+            if let (None, Some(top), Some(bottom)) = (known_dimensions.height, top, bottom) {
+                let new_height_raw = area_height.maybe_sub(margin.top).maybe_sub(margin.bottom) - top - bottom;
+                known_dimensions.height = Some(f32_max(new_height_raw, 0.0));
+                known_dimensions =
+                    known_dimensions.maybe_transfer_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
+            }
 
-        // Expand auto margins to fill available space
-        // https://www.w3.org/TR/CSS21/visudet.html#abs-non-replaced-width
-        let auto_margin = {
-            // Auto margins for absolutely positioned elements in block containers only resolve
-            // if inset is set. Otherwise they resolve to 0.
-            let absolute_auto_margin_space = Point {
-                x: right.map(|right| area_size.width - right - left.unwrap_or(0.0)).unwrap_or(final_size.width),
-                y: bottom.map(|bottom| area_size.height - bottom - top.unwrap_or(0.0)).unwrap_or(final_size.height),
+            let final_size = match (known_dimensions.width, known_dimensions.height) {
+                (Some(width), Some(height)) => Size { width, height },
+                _ => {
+                    let measured_size = tree.measure_child_size_both(
+                        item.node_id,
+                        known_dimensions,
+                        area_size.map(Some),
+                        Size {
+                            width: AvailableSpace::Definite(area_width.maybe_clamp(min_size.width, max_size.width)),
+                            height: AvailableSpace::Definite(area_height.maybe_clamp(min_size.height, max_size.height)),
+                        },
+                        SizingMode::ContentSize,
+                        Line::FALSE,
+                    );
+                    known_dimensions.unwrap_or(measured_size)
+                }
+            }
+            .maybe_clamp(min_size, max_size);
+
+            let non_auto_margin = Rect {
+                left: if left.is_some() { margin.left.unwrap_or(0.0) } else { 0.0 },
+                right: if right.is_some() { margin.right.unwrap_or(0.0) } else { 0.0 },
+                top: if top.is_some() { margin.top.unwrap_or(0.0) } else { 0.0 },
+                bottom: if bottom.is_some() { margin.bottom.unwrap_or(0.0) } else { 0.0 },
             };
-            let free_space = Size {
-                width: absolute_auto_margin_space.x - final_size.width - non_auto_margin.horizontal_axis_sum(),
-                height: absolute_auto_margin_space.y - final_size.height - non_auto_margin.vertical_axis_sum(),
+
+            // Expand auto margins to fill available space
+            // https://www.w3.org/TR/CSS21/visudet.html#abs-non-replaced-width
+            let auto_margin = {
+                // Auto margins for absolutely positioned elements in block containers only resolve
+                // if inset is set. Otherwise they resolve to 0.
+                let absolute_auto_margin_space = Point {
+                    x: right.map(|right| area_size.width - right - left.unwrap_or(0.0)).unwrap_or(final_size.width),
+                    y: bottom.map(|bottom| area_size.height - bottom - top.unwrap_or(0.0)).unwrap_or(final_size.height),
+                };
+                let free_space = Size {
+                    width: absolute_auto_margin_space.x - final_size.width - non_auto_margin.horizontal_axis_sum(),
+                    height: absolute_auto_margin_space.y - final_size.height - non_auto_margin.vertical_axis_sum(),
+                };
+
+                let auto_margin_size = Size {
+                    // If all three of 'left', 'width', and 'right' are 'auto': First set any 'auto' values for 'margin-left' and 'margin-right' to 0.
+                    // Then, if the 'direction' property of the element establishing the static-position containing block is 'ltr' set 'left' to the
+                    // static position and apply rule number three below; otherwise, set 'right' to the static position and apply rule number one below.
+                    //
+                    // If none of the three is 'auto': If both 'margin-left' and 'margin-right' are 'auto', solve the equation under the extra constraint
+                    // that the two margins get equal values, unless this would make them negative, in which case when direction of the containing block is
+                    // 'ltr' ('rtl'), set 'margin-left' ('margin-right') to zero and solve for 'margin-right' ('margin-left'). If one of 'margin-left' or
+                    // 'margin-right' is 'auto', solve the equation for that value. If the values are over-constrained, ignore the value for 'left' (in case
+                    // the 'direction' property of the containing block is 'rtl') or 'right' (in case 'direction' is 'ltr') and solve for that value.
+                    width: {
+                        let auto_margin_count = margin.left.is_none() as u8 + margin.right.is_none() as u8;
+                        if auto_margin_count == 2 && free_space.width <= 0.0 {
+                            0.0
+                        } else if auto_margin_count > 0 {
+                            free_space.width / auto_margin_count as f32
+                        } else {
+                            0.0
+                        }
+                    },
+                    height: {
+                        let auto_margin_count = margin.top.is_none() as u8 + margin.bottom.is_none() as u8;
+                        if auto_margin_count == 2 && free_space.height <= 0.0 {
+                            0.0
+                        } else if auto_margin_count > 0 {
+                            free_space.height / auto_margin_count as f32
+                        } else {
+                            0.0
+                        }
+                    },
+                };
+
+                Rect {
+                    left: margin.left.map(|_| 0.0).unwrap_or(auto_margin_size.width),
+                    right: margin.right.map(|_| 0.0).unwrap_or(auto_margin_size.width),
+                    top: margin.top.map(|_| 0.0).unwrap_or(auto_margin_size.height),
+                    bottom: margin.bottom.map(|_| 0.0).unwrap_or(auto_margin_size.height),
+                }
             };
 
-            let auto_margin_size = Size {
-                // If all three of 'left', 'width', and 'right' are 'auto': First set any 'auto' values for 'margin-left' and 'margin-right' to 0.
-                // Then, if the 'direction' property of the element establishing the static-position containing block is 'ltr' set 'left' to the
-                // static position and apply rule number three below; otherwise, set 'right' to the static position and apply rule number one below.
-                //
-                // If none of the three is 'auto': If both 'margin-left' and 'margin-right' are 'auto', solve the equation under the extra constraint
-                // that the two margins get equal values, unless this would make them negative, in which case when direction of the containing block is
-                // 'ltr' ('rtl'), set 'margin-left' ('margin-right') to zero and solve for 'margin-right' ('margin-left'). If one of 'margin-left' or
-                // 'margin-right' is 'auto', solve the equation for that value. If the values are over-constrained, ignore the value for 'left' (in case
-                // the 'direction' property of the containing block is 'rtl') or 'right' (in case 'direction' is 'ltr') and solve for that value.
-                width: {
-                    let auto_margin_count = margin.left.is_none() as u8 + margin.right.is_none() as u8;
-                    if auto_margin_count == 2 && free_space.width <= 0.0 {
-                        0.0
-                    } else if auto_margin_count > 0 {
-                        free_space.width / auto_margin_count as f32
+            let resolved_margin = Rect {
+                left: margin.left.unwrap_or(auto_margin.left),
+                right: margin.right.unwrap_or(auto_margin.right),
+                top: margin.top.unwrap_or(auto_margin.top),
+                bottom: margin.bottom.unwrap_or(auto_margin.bottom),
+            };
+
+            let x_offset = match (left, right) {
+                (Some(left), Some(right)) => {
+                    if direction.is_rtl() {
+                        area_size.width - final_size.width - right - resolved_margin.right
                     } else {
-                        0.0
+                        left + resolved_margin.left
                     }
-                },
-                height: {
-                    let auto_margin_count = margin.top.is_none() as u8 + margin.bottom.is_none() as u8;
-                    if auto_margin_count == 2 && free_space.height <= 0.0 {
-                        0.0
-                    } else if auto_margin_count > 0 {
-                        free_space.height / auto_margin_count as f32
+                }
+                (Some(left), None) => left + resolved_margin.left,
+                (None, Some(right)) => area_size.width - final_size.width - right - resolved_margin.right,
+                (None, None) => {
+                    if direction.is_rtl() {
+                        item.static_position.x - final_size.width - resolved_margin.right - area_offset.x
                     } else {
-                        0.0
+                        item.static_position.x + resolved_margin.left - area_offset.x
                     }
-                },
+                }
+            };
+            let location = Point {
+                x: x_offset + area_offset.x,
+                y: top
+                    .map(|top| top + resolved_margin.top)
+                    .or(bottom.map(|bottom| area_size.height - final_size.height - bottom - resolved_margin.bottom))
+                    .maybe_add(area_offset.y)
+                    .unwrap_or(item.static_position.y + resolved_margin.top),
             };
 
-            Rect {
-                left: margin.left.map(|_| 0.0).unwrap_or(auto_margin_size.width),
-                right: margin.right.map(|_| 0.0).unwrap_or(auto_margin_size.width),
-                top: margin.top.map(|_| 0.0).unwrap_or(auto_margin_size.height),
-                bottom: margin.bottom.map(|_| 0.0).unwrap_or(auto_margin_size.height),
+            // Try the next position option if the node overflows its containing block
+            let margin_box = Rect {
+                left: location.x - resolved_margin.left,
+                right: location.x + final_size.width + resolved_margin.right,
+                top: location.y - resolved_margin.top,
+                bottom: location.y + final_size.height + resolved_margin.bottom,
+            };
+            if !position_options.accepts(&position_option, margin_box) {
+                continue;
             }
-        };
 
-        let resolved_margin = Rect {
-            left: margin.left.unwrap_or(auto_margin.left),
-            right: margin.right.unwrap_or(auto_margin.right),
-            top: margin.top.unwrap_or(auto_margin.top),
-            bottom: margin.bottom.unwrap_or(auto_margin.bottom),
-        };
+            let layout_output = tree.perform_child_layout(
+                item.node_id,
+                final_size.map(Some),
+                area_size.map(Some),
+                Size {
+                    width: AvailableSpace::Definite(area_width.maybe_clamp(min_size.width, max_size.width)),
+                    height: AvailableSpace::Definite(area_height.maybe_clamp(min_size.height, max_size.height)),
+                },
+                SizingMode::ContentSize,
+                Line::FALSE,
+            );
+            tree.set_unrounded_layout(
+                item.node_id,
+                &Layout {
+                    order: item.order,
+                    size: final_size,
+                    #[cfg(feature = "content_size")]
+                    scrollable_overflow_rect: layout_output.scrollable_overflow_rect,
+                    scrollbar_size: layout_output.scrollbar_size,
+                    replaced_content_rect: layout_output.replaced_content_rect,
+                    marker_rect: layout_output.marker_rect,
+                    location,
+                    padding,
+                    border,
+                    margin: resolved_margin,
+                },
+            );
 
-        let x_offset = match (left, right) {
-            (Some(left), Some(right)) => {
-                if direction.is_rtl() {
-                    area_size.width - final_size.width - right - resolved_margin.right
-                } else {
-                    left + resolved_margin.left
-                }
-            }
-            (Some(left), None) => left + resolved_margin.left,
-            (None, Some(right)) => area_size.width - final_size.width - right - resolved_margin.right,
-            (None, None) => {
-                if direction.is_rtl() {
-                    item.static_position.x - final_size.width - resolved_margin.right - area_offset.x
+            #[cfg(feature = "content_size")]
+            {
+                // Location is measured from the scroll origin (the inline-start edge: right side in RTL)
+                let relative_location = if direction.is_rtl() {
+                    Point {
+                        x: area_size.width - (location.x - area_offset.x) - final_size.width,
+                        y: location.y - area_offset.y,
+                    }
                 } else {
-                    item.static_position.x + resolved_margin.left - area_offset.x
-                }
+                    Point { x: location.x - area_offset.x, y: location.y - area_offset.y }
+                };
+                absolute_overflow_rect = absolute_overflow_rect.union(compute_scrollable_overflow_contribution(
+                    relative_location,
+                    final_size,
+                    layout_output.scrollable_overflow_rect,
+                    item.overflow,
+                    item.contain,
+                    resolve_transform(
+                        item.transform,
+                        item.transform_origin,
+                        final_size,
+                        direction.is_rtl(),
+                        |val, basis| tree.calc(val, basis),
+                    ),
+                    is_scroll_container,
+                ));
             }
-        };
-        let location = Point {
-            x: x_offset + area_offset.x,
-            y: top
-                .map(|top| top + resolved_margin.top)
-                .or(bottom.map(|bottom| area_size.height - final_size.height - bottom - resolved_margin.bottom))
-                .maybe_add(area_offset.y)
-                .unwrap_or(item.static_position.y + resolved_margin.top),
-        };
-        // Note: axis intentionally switched here as scrollbars take up space in the opposite axis
-        // to the axis in which scrolling is enabled.
-        let scrollbar_size = Size {
-            width: if item.overflow.y == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
-            height: if item.overflow.x == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
-        };
-
-        tree.set_unrounded_layout(
-            item.node_id,
-            &Layout {
-                order: item.order,
-                size: final_size,
-                #[cfg(feature = "content_size")]
-                scrollable_overflow_rect: layout_output.scrollable_overflow_rect,
-                scrollbar_size,
-                location,
-                padding,
-                border,
-                margin: resolved_margin,
-            },
-        );
 
-        #[cfg(feature = "content_size")]
-        {
-            // Location is measured from the scroll origin (the inline-start edge: right side in RTL)
-            let relative_location = if direction.is_rtl() {
-                Point {
-                    x: area_size.width - (location.x - area_offset.x) - final_size.width,
-                    y: location.y - area_offset.y,
-                }
-            } else {
-                Point { x: location.x - area_offset.x, y: location.y - area_offset.y }
-            };
-            absolute_overflow_rect = absolute_overflow_rect.union(compute_scrollable_overflow_contribution(
-                relative_location,
-                final_size,
-                layout_output.scrollable_overflow_rect,
-                item.overflow,
-                item.contain,
-                is_scroll_container,
-            ));
+            break;
         }
     }
 
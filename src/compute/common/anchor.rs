@@ -0,0 +1,233 @@
+//! Generic code for CSS Anchor Positioning that is shared between the absolute positioning code of all CSS algorithms.
+//!
+//! <https://drafts.csswg.org/css-anchor-position-1/>
+use core::mem::swap;
+
+use crate::geometry::{AbsoluteAxis, Rect, Size};
+use crate::style::{
+    AnchorSide, AnchorSize, BoxSizing, CoreStyle, Dimension, Direction, ExpandedDimension,
+    ExpandedLengthPercentageAuto, LengthPercentageAuto, PositionTryFallback, PositionTryFallbacks,
+};
+use crate::tree::{LayoutPartialTree, LayoutPartialTreeExt, NodeId};
+use crate::util::sys::f32_max;
+use crate::util::ResolveOrZero;
+
+/// The styles of an absolutely positioned node that differ between its position options, with any `anchor()` and
+/// `anchor-size()` values resolved to lengths (or to `auto` if they could not be resolved)
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct PositionOption {
+    /// The resolved inset styles
+    pub inset: Rect<LengthPercentageAuto>,
+    /// The margin styles (swapped if the option flips the node)
+    pub margin: Rect<LengthPercentageAuto>,
+    /// The resolved size styles
+    pub size: Size<Dimension>,
+    /// Whether this is the last option that is tried, which is used whether or not it overflows
+    is_final: bool,
+}
+
+/// The position options of an absolutely positioned node: its base position followed by its
+/// [`PositionTryFallbacks`](crate::style::PositionTryFallbacks)
+pub(crate) struct PositionOptions {
+    /// The inset, margin and size styles of the node, before any fallback or anchor resolution is applied
+    base: PositionOption,
+    /// The fallbacks that are tried if the node's base position overflows its containing block
+    fallbacks: PositionTryFallbacks,
+    /// The border box of the node's anchor (if it has one)
+    anchor: Option<Rect<f32>>,
+    /// The amount by which the node's size styles are smaller than its border box (its padding and border if it uses
+    /// `BoxSizing::ContentBox`, and otherwise zero)
+    box_sizing_adjustment: Size<f32>,
+    /// The edges of the node's containing block
+    area: Rect<f32>,
+    /// The direction of the node's containing block
+    direction: Direction,
+}
+
+impl PositionOptions {
+    /// Collect the position options of the absolutely positioned child `node` of `containing_block`.
+    ///
+    /// `area` contains the positions of the edges of the node's containing block (the rect that its insets are
+    /// resolved against) relative to the border box of `containing_block`.
+    pub fn new(
+        tree: &impl LayoutPartialTree,
+        containing_block: NodeId,
+        node: NodeId,
+        area: Rect<f32>,
+        direction: Direction,
+    ) -> Self {
+        let style = tree.get_core_container_style(node);
+        let inset = style.inset();
+        let size = style.size();
+        let uses_anchor = inset.left.0.is_anchor()
+            || inset.right.0.is_anchor()
+            || inset.top.0.is_anchor()
+            || inset.bottom.0.is_anchor()
+            || size.width.0.is_anchor_size()
+            || size.height.0.is_anchor_size();
+        let anchor = match style.position_anchor() {
+            Some(anchor_name) if uses_anchor => tree.resolve_anchor(containing_block, node, anchor_name),
+            _ => None,
+        };
+        let fallbacks = style.position_try_fallbacks();
+
+        // `anchor-size()` measures the anchor's border box, whereas a content-box size style excludes the node's own
+        // padding and border. These are resolved against the containing block's width, as for absolutely positioned
+        // nodes in each layout algorithm.
+        let box_sizing_adjustment = if anchor.is_some() && style.box_sizing() == BoxSizing::ContentBox {
+            let area_width = Some(area.right - area.left);
+            let padding = style.padding().resolve_or_zero(area_width, |val, basis| tree.calc(val, basis));
+            let border = style.border().resolve_or_zero(area_width, |val, basis| tree.calc(val, basis));
+            (padding + border).sum_axes()
+        } else {
+            Size::ZERO
+        };
+
+        Self {
+            base: PositionOption { inset, margin: style.margin(), size, is_final: false },
+            fallbacks,
+            anchor,
+            box_sizing_adjustment,
+            area,
+            direction,
+        }
+    }
+
+    /// The options to try, in order. If the base position and all of the fallbacks overflow the containing block
+    /// then the base position is used, so in that case it is tried again as the final option.
+    pub fn iter(&self) -> impl Iterator<Item = PositionOption> + '_ {
+        let fallbacks = self.fallbacks.iter().map(|fallback| self.resolve(Some(fallback), false));
+        let final_base = (!self.fallbacks.is_empty()).then(|| self.resolve(None, true));
+        core::iter::once(self.resolve(None, self.fallbacks.is_empty())).chain(fallbacks).chain(final_base)
+    }
+
+    /// Whether the node should be placed using `option`, given the resulting position of its margin box (relative to
+    /// the border box of the containing block). Options that overflow the containing block are skipped unless they
+    /// are the final option.
+    pub fn accepts(&self, option: &PositionOption, margin_box: Rect<f32>) -> bool {
+        option.is_final
+            || (margin_box.left >= self.area.left
+                && margin_box.right <= self.area.right
+                && margin_box.top >= self.area.top
+                && margin_box.bottom <= self.area.bottom)
+    }
+
+    /// Apply `fallback` to the base styles and resolve any anchor-dependent values
+    fn resolve(&self, fallback: Option<PositionTryFallback>, is_final: bool) -> PositionOption {
+        let PositionOption { mut inset, mut margin, size, .. } = self.base;
+
+        if fallback.is_some_and(PositionTryFallback::flips_horizontal) {
+            swap(&mut inset.left, &mut inset.right);
+            swap(&mut margin.left, &mut margin.right);
+            inset.left = flip_anchor_side(inset.left);
+            inset.right = flip_anchor_side(inset.right);
+        }
+        if fallback.is_some_and(PositionTryFallback::flips_vertical) {
+            swap(&mut inset.top, &mut inset.bottom);
+            swap(&mut margin.top, &mut margin.bottom);
+            inset.top = flip_anchor_side(inset.top);
+            inset.bottom = flip_anchor_side(inset.bottom);
+        }
+
+        PositionOption {
+            inset: Rect {
+                left: self.resolve_anchor_inset(inset.left, AbsoluteAxis::Horizontal, false),
+                right: self.resolve_anchor_inset(inset.right, AbsoluteAxis::Horizontal, true),
+                top: self.resolve_anchor_inset(inset.top, AbsoluteAxis::Vertical, false),
+                bottom: self.resolve_anchor_inset(inset.bottom, AbsoluteAxis::Vertical, true),
+            },
+            margin,
+            size: Size {
+                width: self.resolve_anchor_size(size.width, AbsoluteAxis::Horizontal),
+                height: self.resolve_anchor_size(size.height, AbsoluteAxis::Vertical),
+            },
+            is_final,
+        }
+    }
+
+    /// Resolve an `anchor()` value in the inset on the start (left/top) or end (right/bottom) side of `axis` to a length
+    fn resolve_anchor_inset(
+        &self,
+        inset: LengthPercentageAuto,
+        axis: AbsoluteAxis,
+        is_end: bool,
+    ) -> LengthPercentageAuto {
+        let ExpandedLengthPercentageAuto::Anchor(side) = inset.expand() else {
+            return inset;
+        };
+        let Some(anchor) = self.anchor else {
+            return LengthPercentageAuto::auto();
+        };
+
+        // Logical sides (and percentages) run from right to left in the horizontal axis of an RTL containing block
+        let is_horizontal = axis == AbsoluteAxis::Horizontal;
+        let logical = |fraction: f32| if is_horizontal && self.direction.is_rtl() { 1.0 - fraction } else { fraction };
+        let fraction = match side {
+            AnchorSide::Inside => {
+                if is_end {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            AnchorSide::Outside => {
+                if is_end {
+                    0.0
+                } else {
+                    1.0
+                }
+            }
+            AnchorSide::Left if is_horizontal => 0.0,
+            AnchorSide::Right if is_horizontal => 1.0,
+            AnchorSide::Top if !is_horizontal => 0.0,
+            AnchorSide::Bottom if !is_horizontal => 1.0,
+            // A physical side in the other axis is invalid
+            AnchorSide::Left | AnchorSide::Right | AnchorSide::Top | AnchorSide::Bottom => {
+                return LengthPercentageAuto::auto()
+            }
+            AnchorSide::Start => logical(0.0),
+            AnchorSide::End => logical(1.0),
+            AnchorSide::Center => 0.5,
+            AnchorSide::Percent(percent) => logical(percent),
+        };
+
+        let (anchor_start, anchor_end, area_start, area_end) = match axis {
+            AbsoluteAxis::Horizontal => (anchor.left, anchor.right, self.area.left, self.area.right),
+            AbsoluteAxis::Vertical => (anchor.top, anchor.bottom, self.area.top, self.area.bottom),
+        };
+        let position = anchor_start + (anchor_end - anchor_start) * fraction;
+        LengthPercentageAuto::length(if is_end { area_end - position } else { position - area_start })
+    }
+
+    /// Resolve an `anchor-size()` value in the size of `axis` to a length
+    fn resolve_anchor_size(&self, size: Dimension, axis: AbsoluteAxis) -> Dimension {
+        let ExpandedDimension::AnchorSize(anchor_size) = size.expand() else {
+            return size;
+        };
+        let Some(anchor) = self.anchor else {
+            return Dimension::auto();
+        };
+        let anchor_axis = match anchor_size {
+            AnchorSize::SameAxis => axis,
+            AnchorSize::Width | AnchorSize::Inline => AbsoluteAxis::Horizontal,
+            AnchorSize::Height | AnchorSize::Block => AbsoluteAxis::Vertical,
+        };
+        let anchor_size = match anchor_axis {
+            AbsoluteAxis::Horizontal => anchor.right - anchor.left,
+            AbsoluteAxis::Vertical => anchor.bottom - anchor.top,
+        };
+        let box_sizing_adjustment = match axis {
+            AbsoluteAxis::Horizontal => self.box_sizing_adjustment.width,
+            AbsoluteAxis::Vertical => self.box_sizing_adjustment.height,
+        };
+        Dimension::length(f32_max(anchor_size - box_sizing_adjustment, 0.0))
+    }
+}
+
+/// Update an `anchor()` value for an inset that has been moved to the opposite side by a [`PositionTryFallback`]
+fn flip_anchor_side(inset: LengthPercentageAuto) -> LengthPercentageAuto {
+    match inset.expand() {
+        ExpandedLengthPercentageAuto::Anchor(side) => LengthPercentageAuto::anchor(side.flipped()),
+        _ => inset,
+    }
+}
@@ -0,0 +1,126 @@
+//! Transfers sizes between axes through a node's preferred aspect ratio
+//!
+//! <https://drafts.csswg.org/css-sizing-4/#aspect-ratio-size-transfers>
+use crate::geometry::Size;
+use crate::style::{AspectRatio, BoxSizing, CoreStyle};
+use crate::util::sys::f32_max;
+use crate::util::MaybeMath;
+
+/// A node's preferred aspect ratio, resolved so that it can be applied to the node's border-box sizes
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub(crate) struct PreferredAspectRatio {
+    /// The ratio of width to height of the box that the ratio applies to
+    ratio: f32,
+    /// The size of the padding and border that lie outside of the box that the ratio applies to
+    inset: Size<f32>,
+}
+
+impl PreferredAspectRatio {
+    /// Resolves a node's `aspect-ratio` style, given the natural aspect ratio of its content (if any) and the sum of
+    /// its padding and border in each axis
+    pub(crate) fn resolve(
+        aspect_ratio: AspectRatio,
+        natural_ratio: Option<f32>,
+        box_sizing: BoxSizing,
+        padding_border: Size<f32>,
+    ) -> Option<Self> {
+        let (ratio, applies_to_content_box) = aspect_ratio.resolve(natural_ratio)?;
+        let inset =
+            if applies_to_content_box || box_sizing == BoxSizing::ContentBox { padding_border } else { Size::ZERO };
+        Some(Self { ratio, inset })
+    }
+
+    /// Resolves the preferred aspect ratio of a node as seen by its parent, which cannot know the natural aspect ratio
+    /// of the node's content. Replaced nodes that may use their natural aspect ratio are left to apply it themselves.
+    pub(crate) fn from_style(style: &impl CoreStyle, padding_border: Size<f32>) -> Option<Self> {
+        Self::from_parent(style.aspect_ratio(), style.is_compressible_replaced(), style.box_sizing(), padding_border)
+    }
+
+    /// The same as [`PreferredAspectRatio::from_style`], for a node whose style values have been copied out
+    pub(crate) fn from_parent(
+        aspect_ratio: AspectRatio,
+        is_compressible_replaced: bool,
+        box_sizing: BoxSizing,
+        padding_border: Size<f32>,
+    ) -> Option<Self> {
+        if aspect_ratio.auto && is_compressible_replaced {
+            return None;
+        }
+        Self::resolve(aspect_ratio, None, box_sizing, padding_border)
+    }
+
+    /// The border-box height that corresponds to the given border-box width
+    pub(crate) fn height_from_width(self, width: f32) -> f32 {
+        f32_max(width - self.inset.width, 0.0) / self.ratio + self.inset.height
+    }
+
+    /// The border-box width that corresponds to the given border-box height
+    pub(crate) fn width_from_height(self, height: f32) -> f32 {
+        f32_max(height - self.inset.height, 0.0) * self.ratio + self.inset.width
+    }
+
+    /// Computes the size in an axis that is `None` from the size in the other axis, if exactly one is `Some`
+    pub(crate) fn transfer(self, size: Size<Option<f32>>) -> Size<Option<f32>> {
+        match (size.width, size.height) {
+            (Some(width), None) => Size { width: Some(width), height: Some(self.height_from_width(width)) },
+            (None, Some(height)) => Size { width: Some(self.width_from_height(height)), height: Some(height) },
+            _ => size,
+        }
+    }
+}
+
+/// A border-box size that may be unknown in either axis
+type MaybeSize = Size<Option<f32>>;
+
+/// Transfers a node's border-box preferred, minimum and maximum sizes between axes through its preferred aspect ratio
+/// (if it has one):
+///   - The preferred size in the ratio-dependent axis is computed from the preferred size in the ratio-determining
+///     axis, after it has been clamped by the minimum and maximum sizes of that axis
+///   - The minimum and maximum sizes of an axis without a preferred size are computed from those of the other axis,
+///     where they are not set explicitly
+pub(crate) fn transfer_sizes(
+    aspect_ratio: Option<PreferredAspectRatio>,
+    size: MaybeSize,
+    min_size: MaybeSize,
+    max_size: MaybeSize,
+) -> (MaybeSize, MaybeSize, MaybeSize) {
+    let Some(aspect_ratio) = aspect_ratio else {
+        return (size, min_size, max_size);
+    };
+
+    let transferred_size = match (size.width, size.height) {
+        (Some(width), None) => Size {
+            width: Some(width),
+            height: Some(aspect_ratio.height_from_width(width.maybe_clamp(min_size.width, max_size.width))),
+        },
+        (None, Some(height)) => Size {
+            width: Some(aspect_ratio.width_from_height(height.maybe_clamp(min_size.height, max_size.height))),
+            height: Some(height),
+        },
+        _ => size,
+    };
+    let transferred_limit = |limit: Size<Option<f32>>| {
+        let transferred = aspect_ratio.transfer(limit);
+        Size {
+            width: if size.width.is_none() { transferred.width } else { limit.width },
+            height: if size.height.is_none() { transferred.height } else { limit.height },
+        }
+    };
+    (transferred_size, transferred_limit(min_size), transferred_limit(max_size))
+}
+
+/// Extension trait for applying an optional [`PreferredAspectRatio`] to a [`Size`]
+pub(crate) trait MaybeTransferAspectRatio {
+    /// Computes the size in an axis that is `None` from the size in the other axis, if there is a preferred aspect
+    /// ratio and exactly one axis is `Some`
+    fn maybe_transfer_aspect_ratio(self, aspect_ratio: Option<PreferredAspectRatio>) -> Self;
+}
+
+impl MaybeTransferAspectRatio for Size<Option<f32>> {
+    fn maybe_transfer_aspect_ratio(self, aspect_ratio: Option<PreferredAspectRatio>) -> Self {
+        match aspect_ratio {
+            Some(aspect_ratio) => aspect_ratio.transfer(self),
+            None => self,
+        }
+    }
+}
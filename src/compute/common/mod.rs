@@ -1,6 +1,10 @@
 //! Generic code that is shared between multiple layout algorithms
 pub(crate) mod alignment;
+pub(crate) mod anchor;
+pub(crate) mod aspect_ratio;
+pub(crate) mod scrollbar;
+pub(crate) mod size_containment;
 pub(crate) mod sizing_keyword;
 
-#[cfg(feature = "content_size")]
+#[cfg(any(feature = "content_size", feature = "taffy_tree"))]
 pub(crate) mod scrollable_overflow;
@@ -1,8 +1,15 @@
 //! Generic CSS scrollable overflow code that is shared between all CSS algorithms.
-use crate::geometry::{Point, Rect, Size};
-use crate::style::{Contain, Overflow};
-use crate::util::sys::{f32_max, f32_min};
+use crate::geometry::{Point, Size};
+use crate::style::{LengthPercentage, Transform};
+use crate::util::ResolveOrZero;
+#[cfg(feature = "content_size")]
+use crate::{
+    geometry::Rect,
+    style::{Contain, Overflow},
+    util::sys::{f32_max, f32_min},
+};
 
+#[cfg(feature = "content_size")]
 #[inline(always)]
 /// Determine the rectangle that a given node contributes to its parent's scrollable overflow
 /// rectangle.
@@ -22,12 +29,16 @@ use crate::util::sys::{f32_max, f32_min};
 ///
 /// A box whose containment contains its scrollable overflow (layout or paint containment)
 /// contributes only its border box, regardless of its overflow style.
+///
+/// The node's border box and overflow are moved by its `transform` (see [`resolve_transform`])
+/// before they contribute, and the contribution is the bounding box of the result.
 pub(crate) fn compute_scrollable_overflow_contribution(
     location: Point<f32>,
     size: Size<f32>,
     scrollable_overflow_rect: Rect<f32>,
     overflow: Point<Overflow>,
     contain: Contain,
+    transform: Transform,
     parent_is_scroll_container: bool,
 ) -> Rect<f32> {
     let is_scroll_container = overflow.x.is_scroll_container() || overflow.y.is_scroll_container();
@@ -47,11 +58,17 @@ pub(crate) fn compute_scrollable_overflow_contribution(
         x: if propagates.x { f32_min(0.0, scrollable_overflow_rect.left) } else { 0.0 },
         y: if propagates.y { f32_min(0.0, scrollable_overflow_rect.top) } else { 0.0 },
     };
+    let local_contribution = transform.transform_rect(Rect {
+        left: start_extent.x,
+        right: end_extent.width,
+        top: start_extent.y,
+        bottom: end_extent.height,
+    });
     let contribution = Rect {
-        left: location.x + start_extent.x,
-        right: location.x + end_extent.width,
-        top: location.y + start_extent.y,
-        bottom: location.y + end_extent.height,
+        left: location.x + local_contribution.left,
+        right: location.x + local_contribution.right,
+        top: location.y + local_contribution.top,
+        bottom: location.y + local_contribution.bottom,
     };
     let is_wholly_unreachable = contribution.right <= 0.0 || contribution.bottom <= 0.0;
     if parent_is_scroll_container && is_wholly_unreachable {
@@ -60,3 +77,31 @@ pub(crate) fn compute_scrollable_overflow_contribution(
         contribution
     }
 }
+
+/// Resolves a node's `transform` (applied about its `transform_origin`) into a transform of the
+/// coordinates of its border box, as used by `compute_scrollable_overflow_contribution`.
+///
+/// Contributions to the scrollable overflow of a right-to-left parent are measured from the
+/// parent's right edge, so in that case the transform is mirrored horizontally to match.
+pub(crate) fn resolve_transform(
+    transform: Transform,
+    transform_origin: Point<LengthPercentage>,
+    size: Size<f32>,
+    parent_is_rtl: bool,
+    resolve_calc_value: impl Fn(*const (), f32) -> f32,
+) -> Transform {
+    if transform.is_identity() {
+        return transform;
+    }
+    let origin = Point {
+        x: transform_origin.x.resolve_or_zero(Some(size.width), &resolve_calc_value),
+        y: transform_origin.y.resolve_or_zero(Some(size.height), &resolve_calc_value),
+    };
+    let transform = transform.about(origin);
+    if parent_is_rtl {
+        let mirror = Transform { a: -1.0, e: size.width, ..Transform::IDENTITY };
+        mirror.then(transform).then(mirror)
+    } else {
+        transform
+    }
+}
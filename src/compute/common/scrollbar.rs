@@ -0,0 +1,165 @@
+//! Generic code for reserving space for scrollbars that is shared between all CSS algorithms.
+use crate::geometry::{Point, Rect, Size};
+use crate::style::{Direction, Overflow, ScrollbarGutter};
+use crate::tree::{LayoutInput, LayoutOutput, RequestedAxis, RunMode};
+
+/// Determine the space reserved for a node's scrollbars (its scrollbar gutters) on each of its edges.
+///
+/// A node that scrolls vertically needs *horizontal* space to be reserved for its scrollbar: `overflow.y` determines
+/// the gutter on the inline-end edge (the right edge in LTR, and the left edge in RTL), and `overflow.x` determines the
+/// gutter on the bottom edge. The top edge never has a gutter.
+///
+/// Space is always reserved for `Overflow::Scroll` axes, whereas `Overflow::Auto` axes only reserve space if the
+/// corresponding component of `auto_scrollbars` indicates that the scrollbar is shown. A stable `scrollbar_gutter`
+/// reserves the inline-end gutter (and with `ScrollbarGutter::StableBothEdges` an inline-start gutter of the same
+/// width) for any node that is a scroll container in the `y` axis.
+#[inline(always)]
+pub(crate) fn compute_scrollbar_gutter(
+    overflow: Point<Overflow>,
+    scrollbar_gutter: ScrollbarGutter,
+    scrollbar_width: f32,
+    direction: Direction,
+    auto_scrollbars: Point<bool>,
+) -> Rect<f32> {
+    let shows_scrollbar = |overflow: Overflow, auto_scrollbar: bool| match overflow {
+        Overflow::Scroll => true,
+        Overflow::Auto => auto_scrollbar,
+        _ => false,
+    };
+    let has_stable_gutter = scrollbar_gutter.is_stable() && overflow.y.is_scroll_container();
+
+    let inline_end =
+        if has_stable_gutter || shows_scrollbar(overflow.y, auto_scrollbars.y) { scrollbar_width } else { 0.0 };
+    let inline_start =
+        if has_stable_gutter && scrollbar_gutter == ScrollbarGutter::StableBothEdges { scrollbar_width } else { 0.0 };
+    let bottom = if shows_scrollbar(overflow.x, auto_scrollbars.x) { scrollbar_width } else { 0.0 };
+
+    match direction {
+        Direction::Ltr => Rect { top: 0.0, left: inline_start, right: inline_end, bottom },
+        Direction::Rtl => Rect { top: 0.0, left: inline_end, right: inline_start, bottom },
+    }
+}
+
+/// The size of a node's scrollbars given its scrollbar gutters: the width of the vertical scrollbar (the gutter on its
+/// inline-end edge) and the height of the horizontal scrollbar (the gutter on its bottom edge).
+///
+/// An inline-start gutter reserved by `ScrollbarGutter::StableBothEdges` is not included, as the scrollbar is only
+/// ever placed in the inline-end gutter.
+#[inline(always)]
+pub(crate) fn scrollbar_size(gutter: Rect<f32>, direction: Direction) -> Size<f32> {
+    let width = match direction {
+        Direction::Ltr => gutter.right,
+        Direction::Rtl => gutter.left,
+    };
+    Size { width, height: gutter.bottom }
+}
+
+/// Lay out a node whose scrollbars may depend on whether its content overflows (`Overflow::Auto`).
+///
+/// `compute` lays out the node, reserving space for the scrollbars of any `Overflow::Auto` axes in which
+/// `auto_scrollbars` is true. The node is first laid out without any such scrollbars and, if its content overflows
+/// its scrollport in an `Overflow::Auto` axis, it is laid out again with a scrollbar for that axis. As adding one
+/// scrollbar reduces the space available to the content (which may then overflow in the other axis), this repeats
+/// until no more scrollbars are required. Scrollbars are never removed once added.
+///
+/// `is_size_constrained` indicates the axes in which the node's size is limited by its `size` or `max_size` styles.
+/// When only the node's size is requested, its content is only laid out to detect overflow if the node's size in an
+/// `Overflow::Auto` axis is limited (by these styles or by its known dimensions), as its content can't otherwise
+/// overflow in that axis.
+///
+/// Overflow is detected using the node's scrollable overflow rectangle, so `Overflow::Auto` never reserves space
+/// for a scrollbar if the `content_size` feature is disabled. The returned output's `scrollbar_size` is set to
+/// the space reserved for the node's scrollbars.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn compute_with_auto_scrollbars(
+    inputs: LayoutInput,
+    overflow: Point<Overflow>,
+    scrollbar_gutter: ScrollbarGutter,
+    scrollbar_width: f32,
+    direction: Direction,
+    #[cfg_attr(not(feature = "content_size"), allow(unused_variables))] border: Rect<f32>,
+    #[cfg_attr(not(feature = "content_size"), allow(unused_variables))] is_size_constrained: Size<bool>,
+    mut compute: impl FnMut(LayoutInput, Point<bool>) -> LayoutOutput,
+) -> LayoutOutput {
+    let gutter = |auto_scrollbars| {
+        compute_scrollbar_gutter(overflow, scrollbar_gutter, scrollbar_width, direction, auto_scrollbars)
+    };
+    let with_scrollbar_size = |mut output: LayoutOutput, auto_scrollbars: Point<bool>| {
+        output.scrollbar_size = scrollbar_size(gutter(auto_scrollbars), direction);
+        output
+    };
+    let no_auto_scrollbars = Point { x: false, y: false };
+
+    #[cfg(feature = "content_size")]
+    {
+        let has_auto_overflow = overflow.x == Overflow::Auto || overflow.y == Overflow::Auto;
+
+        // Scrollbars do not affect the size of a node whose size is already known, or whose content can't overflow
+        // it in any `Overflow::Auto` axis, so there is no need to detect overflow when only the node's size has been
+        // requested
+        let size_is_known = match inputs.axis {
+            RequestedAxis::Horizontal => inputs.known_dimensions.width.is_some(),
+            RequestedAxis::Vertical => inputs.known_dimensions.height.is_some(),
+            RequestedAxis::Both => inputs.known_dimensions.width.is_some() && inputs.known_dimensions.height.is_some(),
+        };
+        let is_size_limited =
+            inputs.known_dimensions.map(|size| size.is_some()).zip_map(is_size_constrained, |a, b| a || b);
+        let could_show_scrollbar = (overflow.x == Overflow::Auto && is_size_limited.width)
+            || (overflow.y == Overflow::Auto && is_size_limited.height);
+        let only_size_is_required = inputs.run_mode == RunMode::ComputeSize && (size_is_known || !could_show_scrollbar);
+
+        if has_auto_overflow && scrollbar_width > 0.0 && !only_size_is_required {
+            return compute_until_scrollbars_are_stable(inputs, overflow, direction, border, gutter, compute);
+        }
+    }
+
+    with_scrollbar_size(compute(inputs, no_auto_scrollbars), no_auto_scrollbars)
+}
+
+/// Lay out a node repeatedly, adding `Overflow::Auto` scrollbars until its content no longer overflows in an axis
+/// without a scrollbar (see [`compute_with_auto_scrollbars`])
+#[cfg(feature = "content_size")]
+fn compute_until_scrollbars_are_stable(
+    inputs: LayoutInput,
+    overflow: Point<Overflow>,
+    direction: Direction,
+    border: Rect<f32>,
+    gutter: impl Fn(Point<bool>) -> Rect<f32>,
+    mut compute: impl FnMut(LayoutInput, Point<bool>) -> LayoutOutput,
+) -> LayoutOutput {
+    // The node's content must be laid out to determine whether it overflows
+    let inputs = LayoutInput { run_mode: RunMode::PerformLayout, axis: RequestedAxis::Both, ..inputs };
+    let mut auto_scrollbars = Point { x: false, y: false };
+    loop {
+        let mut output = compute(inputs, auto_scrollbars);
+
+        // The scrollable overflow rect is measured from the inline-start edge of the padding box (which includes
+        // any inline-start gutter), so the content overflows if it extends past the inline-end gutter
+        let scrollbars = scrollbar_size(gutter(auto_scrollbars), direction);
+        let scrollport_end = Size {
+            width: output.size.width - border.horizontal_axis_sum() - scrollbars.width,
+            height: output.size.height - border.vertical_axis_sum() - scrollbars.height,
+        };
+        // Allow for small floating point errors in the positions of the node's content
+        const EPSILON: f32 = 0.001;
+        let overflows = Point {
+            x: output.scrollable_overflow_rect.right > scrollport_end.width + EPSILON,
+            y: output.scrollable_overflow_rect.bottom > scrollport_end.height + EPSILON,
+        };
+        let required_scrollbars = Point {
+            x: auto_scrollbars.x || (overflow.x == Overflow::Auto && overflows.x),
+            y: auto_scrollbars.y || (overflow.y == Overflow::Auto && overflows.y),
+        };
+        if required_scrollbars != auto_scrollbars {
+            // The node only needs to be laid out again if showing the scrollbars changes the space reserved for
+            // them (which it does not if a stable gutter was already reserved)
+            let gutter_changed = gutter(required_scrollbars) != gutter(auto_scrollbars);
+            auto_scrollbars = required_scrollbars;
+            if gutter_changed {
+                continue;
+            }
+        }
+        output.scrollbar_size = scrollbar_size(gutter(auto_scrollbars), direction);
+        return output;
+    }
+}
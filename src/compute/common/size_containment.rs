@@ -0,0 +1,51 @@
+//! Sizes boxes with size containment as if they were empty
+//!
+//! <https://drafts.csswg.org/css-contain-2/#containment-size>
+use crate::compute::common::aspect_ratio::PreferredAspectRatio;
+use crate::geometry::Size;
+use crate::style::CoreStyle;
+use crate::util::sys::f32_max;
+use crate::util::MaybeMath;
+
+/// Determines the border-box size of a node in each axis in which it has size containment and whose size is not
+/// already known (`size` is the size known so far). In such an axis the node is sized as if it were empty, with a
+/// content box of the size given by its `contain_intrinsic_size` style, unless its preferred aspect ratio determines
+/// the size from the other axis.
+///
+/// Returns `None` for every other axis, so that the result can be combined with the known size using `Size::or`.
+pub(crate) fn size_contained_dimensions(
+    style: &impl CoreStyle,
+    last_remembered_size: Size<Option<f32>>,
+    size: Size<Option<f32>>,
+    aspect_ratio: Option<PreferredAspectRatio>,
+    padding_border_size: Size<f32>,
+    min_size: Size<Option<f32>>,
+    max_size: Size<Option<f32>>,
+) -> Size<Option<f32>> {
+    let contained_axes = style.contain().size_contained_axes();
+    if !contained_axes.width && !contained_axes.height {
+        return Size::NONE;
+    }
+
+    let contain_intrinsic_size = style.contain_intrinsic_size();
+    let empty_size = Size {
+        width: contain_intrinsic_size.width.resolve(last_remembered_size.width) + padding_border_size.width,
+        height: contain_intrinsic_size.height.resolve(last_remembered_size.height) + padding_border_size.height,
+    };
+
+    let width = (contained_axes.width && size.width.is_none()).then(|| {
+        let width = match (aspect_ratio, size.height) {
+            (Some(ratio), Some(height)) => ratio.width_from_height(height),
+            _ => empty_size.width,
+        };
+        f32_max(width.maybe_clamp(min_size.width, max_size.width), padding_border_size.width)
+    });
+    let height = (contained_axes.height && size.height.is_none()).then(|| {
+        let height = match (aspect_ratio, size.width.or(width)) {
+            (Some(ratio), Some(width)) => ratio.height_from_width(width),
+            _ => empty_size.height,
+        };
+        f32_max(height.maybe_clamp(min_size.height, max_size.height), padding_border_size.height)
+    });
+    Size { width, height }
+}
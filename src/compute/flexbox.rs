@@ -1,11 +1,16 @@
 //! Computes the [flexbox](https://css-tricks.com/snippets/css/a-guide-to-flexbox/) layout algorithm on [`TaffyTree`](crate::TaffyTree) according to the [spec](https://www.w3.org/TR/css-flexbox-1/)
 use crate::compute::common::alignment::{compute_alignment_offset, resolve_self_alignment_safety};
+use crate::compute::common::aspect_ratio::{transfer_sizes, MaybeTransferAspectRatio, PreferredAspectRatio};
+use crate::compute::common::scrollbar::{compute_scrollbar_gutter, compute_with_auto_scrollbars};
+use crate::compute::common::size_containment::size_contained_dimensions;
 use crate::geometry::{Line, Point, Rect, Size};
 use crate::style::{
     AlignContent, AlignContentKeyword, AlignItems, AlignItemsKeyword, AlignSelf, AvailableSpace, Contain,
     JustifyContent, LengthPercentageAuto, Overflow, Position,
 };
 use crate::style::{CoreStyle, FlexDirection, FlexboxContainerStyle, FlexboxItemStyle};
+#[cfg(feature = "content_size")]
+use crate::style::{LengthPercentage, Transform};
 use crate::style_helpers::{TaffyMaxContent, TaffyMinContent};
 use crate::tree::{Baselines, Layout, LayoutInput, LayoutOutput, RunMode, SizingMode};
 use crate::tree::{LayoutFlexboxContainer, LayoutPartialTreeExt, NodeId};
@@ -16,8 +21,9 @@ use crate::util::{MaybeResolve, ResolveOrZero};
 use crate::{BoxGenerationMode, BoxSizing, Dimension, Direction, RequestedAxis};
 
 use super::common::alignment::apply_alignment_fallback;
+use super::common::anchor::PositionOptions;
 #[cfg(feature = "content_size")]
-use super::common::scrollable_overflow::compute_scrollable_overflow_contribution;
+use super::common::scrollable_overflow::{compute_scrollable_overflow_contribution, resolve_transform};
 use super::common::sizing_keyword::{
     resolve_absolute_sizing_keywords, resolve_sizing_keyword, SizingKeywordResolution,
 };
@@ -40,7 +46,7 @@ struct FlexItem {
     /// The maximum allowable size of this item
     max_size: Size<Option<f32>>,
     /// The aspect ratio of this item
-    aspect_ratio: Option<f32>,
+    aspect_ratio: Option<PreferredAspectRatio>,
     /// The cross-alignment of this item
     align_self: AlignSelf,
 
@@ -48,8 +54,12 @@ struct FlexItem {
     overflow: Point<Overflow>,
     /// The contain style of the item
     contain: Contain,
-    /// The width of the scrollbars (if it has any)
-    scrollbar_width: f32,
+    #[cfg(feature = "content_size")]
+    /// The transform style of the item
+    transform: Transform,
+    #[cfg(feature = "content_size")]
+    /// The transform origin style of the item
+    transform_origin: Point<LengthPercentage>,
     /// The flex shrink style of the item
     flex_shrink: f32,
     /// The flex grow style of the item
@@ -94,8 +104,12 @@ struct FlexItem {
     /// The size that this item wants to be, plus any padding and border
     outer_target_size: Size<f32>,
 
-    /// The position of the bottom edge of this item
+    /// The distance from the item's outer cross-start edge to the baseline that it is aligned by (its last baseline
+    /// if it has `align-self: last baseline`, and its first baseline otherwise)
     baseline: f32,
+    /// The item's first and last baselines in each axis relative to the container's border box, once the item has
+    /// been positioned. Used to determine the container's own baselines.
+    final_baselines: Point<Baselines>,
 
     /// A temporary value for the main offset
     ///
@@ -116,13 +130,52 @@ impl FlexItem {
     }
 
     /// Returns true if the item participates in baseline alignment: it has `align-self: baseline`
-    /// and neither of its cross-axis margins are `auto`.
+    /// or `align-self: last baseline` and neither of its cross-axis margins are `auto`.
     /// See <https://www.w3.org/TR/css-flexbox-1/#baseline-participation>
     fn participates_in_baseline_alignment(&self, dir: FlexDirection) -> bool {
-        self.align_self == AlignSelf::BASELINE
+        matches!(self.align_self.keyword, AlignItemsKeyword::Baseline | AlignItemsKeyword::LastBaseline)
             && !self.margin_is_auto.cross_start(dir)
             && !self.margin_is_auto.cross_end(dir)
     }
+
+    /// Returns true if the item participates in baseline alignment (see
+    /// [`FlexItem::participates_in_baseline_alignment`]) using its last baseline. Such items form a separate
+    /// baseline-sharing group, which is aligned to the cross-end edge of the line.
+    fn participates_in_last_baseline_alignment(&self, dir: FlexDirection) -> bool {
+        self.align_self.keyword == AlignItemsKeyword::LastBaseline && self.participates_in_baseline_alignment(dir)
+    }
+}
+
+/// The extent of the items in one of a flex line's baseline-sharing groups (the items aligned by their first
+/// baselines, or the items aligned by their last baselines) around their shared baseline
+#[derive(Debug, Clone, Copy, Default)]
+struct BaselineGroupExtent {
+    /// The largest distance from the shared baseline to an item's outer cross-start edge
+    above: f32,
+    /// The largest distance from the shared baseline to an item's outer cross-end edge
+    below: f32,
+}
+
+impl BaselineGroupExtent {
+    /// Computes the extent of the baseline-sharing group of the line's items that are (or are not) aligned by their
+    /// last baselines, using the items' outer cross sizes as returned by `outer_cross_size`
+    fn of_line(
+        line: &FlexLine,
+        last_baseline: bool,
+        constants: &AlgoConstants,
+        outer_cross_size: impl Fn(&FlexItem) -> f32,
+    ) -> Self {
+        line.items
+            .iter()
+            .filter(|child| {
+                child.participates_in_baseline_alignment(constants.dir)
+                    && child.participates_in_last_baseline_alignment(constants.dir) == last_baseline
+            })
+            .fold(Self::default(), |extent, child| Self {
+                above: extent.above.max(child.baseline),
+                below: extent.below.max(outer_cross_size(child) - child.baseline),
+            })
+    }
 }
 
 /// A line of [`FlexItem`] used for intermediate computation
@@ -168,8 +221,8 @@ struct AlgoConstants {
     /// The space between the content box and the border box.
     /// This consists of padding + border + scrollbar_gutter.
     content_box_inset: Rect<f32>,
-    /// The size reserved for scrollbar gutters in each axis
-    scrollbar_gutter: Point<f32>,
+    /// The space reserved for scrollbar gutters on each edge
+    scrollbar_gutter: Rect<f32>,
     /// Whether the node being laid out is a scroll container
     #[cfg(feature = "content_size")]
     is_scroll_container: bool,
@@ -237,30 +290,30 @@ pub fn compute_flexbox_layout(
 
     // Pull these out earlier to avoid borrowing issues
     let contain = style.contain();
-    let aspect_ratio = style.aspect_ratio();
+    let overflow = style.overflow();
+    let scrollbar_gutter = style.scrollbar_gutter();
+    let scrollbar_width = style.scrollbar_width();
+    let direction = style.direction();
     let padding = style.padding().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
     let border = style.border().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
     let padding_border_sum = padding.sum_axes() + border.sum_axes();
     let box_sizing_adjustment =
         if style.box_sizing() == BoxSizing::ContentBox { padding_border_sum } else { Size::ZERO };
+    let aspect_ratio = PreferredAspectRatio::from_style(&style, padding_border_sum);
 
     let min_size = style
         .min_size()
         .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
-        .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
     let max_size = style
         .max_size()
         .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
-        .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
+    let style_size =
+        style.size().maybe_resolve(parent_size, |val, basis| tree.calc(val, basis)).maybe_add(box_sizing_adjustment);
+    let (style_size, min_size, max_size) = transfer_sizes(aspect_ratio, style_size, min_size, max_size);
     let clamped_style_size = if inputs.sizing_mode == SizingMode::InherentSize {
-        style
-            .size()
-            .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
-            .maybe_apply_aspect_ratio(aspect_ratio)
-            .maybe_add(box_sizing_adjustment)
-            .maybe_clamp(min_size, max_size)
+        style_size.maybe_clamp(min_size, max_size)
     } else {
         Size::NONE
     };
@@ -271,9 +324,20 @@ pub fn compute_flexbox_layout(
         _ => None,
     });
 
+    // A box with size containment is sized as if it were empty in the contained axes
+    let size_contained_dimensions = size_contained_dimensions(
+        &style,
+        tree.last_remembered_size(node),
+        known_dimensions.or(min_max_definite_size).or(clamped_style_size),
+        aspect_ratio,
+        padding_border_sum,
+        min_size,
+        max_size,
+    );
+
     // The size of the container should be floored by the padding and border
-    let styled_based_known_dimensions =
-        known_dimensions.or(min_max_definite_size.or(clamped_style_size).maybe_max(padding_border_sum));
+    let styled_based_known_dimensions = known_dimensions
+        .or(min_max_definite_size.or(clamped_style_size).or(size_contained_dimensions).maybe_max(padding_border_sum));
 
     // Short-circuit layout if the container's size is fully determined by the container's size and the run mode
     // is ComputeSize (and thus the container's size is all that we're interested in)
@@ -307,22 +371,32 @@ pub fn compute_flexbox_layout(
         .known_dimensions_are_definite
         .zip_map(known_dimensions, |is_definite, known_dimension| is_definite || known_dimension.is_none());
 
-    let mut output = compute_preliminary(
-        tree,
-        node,
+    let mut output = compute_with_auto_scrollbars(
         LayoutInput { known_dimensions: styled_based_known_dimensions, known_dimensions_are_definite, ..inputs },
+        overflow,
+        scrollbar_gutter,
+        scrollbar_width,
+        direction,
+        border,
+        max_size.map(|size| size.is_some()),
+        |inputs, auto_scrollbars| compute_preliminary(tree, node, inputs, auto_scrollbars),
     );
 
     // Layout containment suppresses the box's baseline for baseline-alignment purposes
     if contain.suppresses_baseline() {
-        output.baselines = Baselines::NONE;
+        output.baselines = Point { x: Baselines::NONE, y: Baselines::NONE };
     }
 
     output
 }
 
 /// Compute a preliminary size for an item
-fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inputs: LayoutInput) -> LayoutOutput {
+fn compute_preliminary(
+    tree: &mut impl LayoutFlexboxContainer,
+    node: NodeId,
+    inputs: LayoutInput,
+    auto_scrollbars: Point<bool>,
+) -> LayoutOutput {
     let LayoutInput { known_dimensions, parent_size, available_space, run_mode, .. } = inputs;
 
     // Define some general constants we will need for the remainder of the algorithm.
@@ -333,6 +407,7 @@ fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inp
         inputs.known_dimensions_are_definite,
         parent_size,
         available_space,
+        auto_scrollbars,
     );
 
     // 9. Flex Layout Algorithm
@@ -369,14 +444,7 @@ fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inp
 
     // 5. Collect flex items into flex lines.
     debug_log!("collect_flex_lines");
-    #[cfg(feature = "flexbox_balance")]
-    let mut flex_lines = if constants.is_balance {
-        collect_balanced_flex_lines(&constants, available_space, &mut flex_items)
-    } else {
-        collect_flex_lines(&constants, available_space, &mut flex_items)
-    };
-    #[cfg(not(feature = "flexbox_balance"))]
-    let mut flex_lines = collect_flex_lines(&constants, available_space, &mut flex_items);
+    let mut flex_lines = collect_lines(&constants, available_space, &mut flex_items);
 
     // If container size is undefined, determine the container's main size
     // and then re-resolve gaps based on newly determined size
@@ -394,7 +462,8 @@ fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inp
         debug_log!("constants.node_outer_size", dbg:constants.node_outer_size);
         debug_log!("constants.node_inner_size", dbg:constants.node_inner_size);
 
-        // Re-resolve percentage gaps
+        // Re-resolve percentage gaps. These contribute zero to the container's intrinsic main size, but are
+        // resolved against the main size that has now been determined for laying out the items
         let style = tree.get_flexbox_container_style(node);
         let inner_container_size = constants.inner_container_size.main(constants.dir);
         let new_gap = style
@@ -402,7 +471,19 @@ fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inp
             .main(constants.dir)
             .maybe_resolve(inner_container_size, |val, basis| tree.calc(val, basis))
             .unwrap_or(0.0);
+        let gap_changed = new_gap != constants.gap.main(constants.dir);
         constants.gap.set_main(constants.dir, new_gap);
+
+        // The items of a multi-line container were collected into lines without the gap, so they are collected
+        // again against the determined main size now that the gap between them is known
+        if gap_changed && constants.is_wrap {
+            debug_log!("collect_flex_lines (resolved gap)");
+            let available_space =
+                available_space.with_main(constants.dir, AvailableSpace::Definite(inner_container_size));
+            // The previous lines borrow the items, so they must be dropped first
+            drop(flex_lines);
+            flex_lines = collect_lines(&constants, available_space, &mut flex_items);
+        }
     }
 
     // 6. Resolve the flexible lengths of all the flex items to find their used main size.
@@ -502,31 +583,62 @@ fn compute_preliminary(tree: &mut impl LayoutFlexboxContainer, node: NodeId, inp
         }
     }
 
-    // 8.5. Flex Container Baselines: calculate the flex container's first baseline
+    // 8.5. Flex Container Baselines: calculate the flex container's first and last baselines
     // See https://www.w3.org/TR/css-flexbox-1/#flex-baselines
-    // The baselines are generated from the startmost flex line, where "startmost" refers to the
-    // line's visual position: for wrap-reverse containers the cross axis is flipped, so the
-    // startmost line is the last line in flex-line order rather than the first.
-    let first_line = if constants.is_wrap_reverse { flex_lines.last() } else { flex_lines.first() };
-    let first_vertical_baseline = first_line.and_then(|line| {
-        if constants.is_column {
-            // For column containers the baseline is generated from the startmost item in the line,
-            // which for reverse-direction containers is the last item in flex order.
-            let item = if constants.dir.is_reverse() { line.items.last() } else { line.items.first() };
-            item.map(|child| child.baseline)
-        } else {
-            line.items
-                .iter()
-                .find(|item| item.participates_in_baseline_alignment(constants.dir))
-                .or_else(|| line.items.iter().next())
-                .map(|child| child.baseline)
+    // The first (last) baselines are generated from the startmost (endmost) flex line, where
+    // "startmost" refers to the line's visual position: for wrap-reverse containers the cross axis
+    // is flipped, so the startmost line is the last line in flex-line order rather than the first.
+    let (first_line, last_line) = if constants.is_wrap_reverse {
+        (flex_lines.last(), flex_lines.first())
+    } else {
+        (flex_lines.first(), flex_lines.last())
+    };
+    // The startmost (endmost) item in a line is the first (last) item in flex order, or the last
+    // (first) item for reverse-direction containers.
+    let is_reverse = constants.dir.is_reverse();
+    let startmost_item = first_line.and_then(|line| if is_reverse { line.items.last() } else { line.items.first() });
+    let endmost_item = last_line.and_then(|line| if is_reverse { line.items.first() } else { line.items.last() });
+    let horizontal_baselines = if constants.is_column {
+        // For column containers the horizontal baselines are generated from the startmost (endmost) item
+        Baselines {
+            first: startmost_item.and_then(|child| child.final_baselines.y.first),
+            last: endmost_item.and_then(|child| child.final_baselines.y.last),
         }
-    });
+    } else {
+        // For row containers they are generated from the items participating in first (last) baseline
+        // alignment, or from the first (last) item if there are none
+        let participates_in_first_baseline_alignment = |item: &&FlexItem| {
+            item.participates_in_baseline_alignment(constants.dir)
+                && !item.participates_in_last_baseline_alignment(constants.dir)
+        };
+        Baselines {
+            first: first_line
+                .and_then(|line| {
+                    line.items.iter().find(participates_in_first_baseline_alignment).or_else(|| line.items.first())
+                })
+                .and_then(|child| child.final_baselines.y.first),
+            last: last_line
+                .and_then(|line| {
+                    line.items
+                        .iter()
+                        .rev()
+                        .find(|item| item.participates_in_last_baseline_alignment(constants.dir))
+                        .or_else(|| line.items.last())
+                })
+                .and_then(|child| child.final_baselines.y.last),
+        }
+    };
+    // Vertical baselines (which only items containing vertical text have) are always generated
+    // from the startmost (endmost) item
+    let vertical_baselines = Baselines {
+        first: startmost_item.and_then(|child| child.final_baselines.x.first),
+        last: endmost_item.and_then(|child| child.final_baselines.x.last),
+    };
 
     LayoutOutput::from_sizes_and_baselines(
         constants.container_size,
         inflow_overflow_rect.union(absolute_overflow_rect),
-        Baselines::from_first(first_vertical_baseline),
+        Point { x: vertical_baselines, y: horizontal_baselines },
     )
 }
 
@@ -539,6 +651,7 @@ fn compute_constants(
     known_dimensions_are_definite: Size<bool>,
     parent_size: Size<Option<f32>>,
     available_space: Size<AvailableSpace>,
+    auto_scrollbars: Point<bool>,
 ) -> AlgoConstants {
     let dir = style.flex_direction();
     let is_row = dir.is_row();
@@ -551,38 +664,45 @@ fn compute_constants(
     #[cfg(feature = "flexbox_balance")]
     let line_count = if is_wrap { Some(style.flex_line_count().max(1)) } else { None };
 
-    let aspect_ratio = style.aspect_ratio();
     let margin = style.margin().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
     let padding = style.padding().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
     let border = style.border().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
     let padding_border_sum = padding.sum_axes() + border.sum_axes();
     let box_sizing_adjustment =
         if style.box_sizing() == BoxSizing::ContentBox { padding_border_sum } else { Size::ZERO };
+    let (_, min_size, max_size) = transfer_sizes(
+        PreferredAspectRatio::from_style(&style, padding_border_sum),
+        style.size().maybe_resolve(parent_size, |val, basis| tree.calc(val, basis)).maybe_add(box_sizing_adjustment),
+        style
+            .min_size()
+            .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+            .maybe_add(box_sizing_adjustment),
+        style
+            .max_size()
+            .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+            .maybe_add(box_sizing_adjustment),
+    );
 
     let align_items = style.align_items().unwrap_or(AlignItems::STRETCH);
     let align_content = style.align_content().unwrap_or(AlignContent::STRETCH);
     let justify_content = style.justify_content();
     let layout_direction = style.direction();
 
-    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll`.
-    // However, the axis are switched (transposed) because a node that scrolls vertically needs
-    // *horizontal* space to be reserved for a scrollbar
-    let scrollbar_gutter = style.overflow().transpose().map(|overflow| match overflow {
-        Overflow::Scroll => style.scrollbar_width(),
-        _ => 0.0,
-    });
+    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll` (or to
+    // `Overflow::Auto` and the content overflows), or for any scroll container with a stable `scrollbar_gutter`
+    let scrollbar_gutter = compute_scrollbar_gutter(
+        style.overflow(),
+        style.scrollbar_gutter(),
+        style.scrollbar_width(),
+        layout_direction,
+        auto_scrollbars,
+    );
     #[cfg(feature = "content_size")]
     let is_scroll_container = {
         let overflow = style.overflow();
         overflow.x.is_scroll_container() || overflow.y.is_scroll_container()
     };
-    let mut content_box_inset = padding + border;
-    content_box_inset.bottom += scrollbar_gutter.y;
-
-    match layout_direction {
-        Direction::Ltr => content_box_inset.right += scrollbar_gutter.x,
-        Direction::Rtl => content_box_inset.left += scrollbar_gutter.x,
-    };
+    let content_box_inset = padding + border + scrollbar_gutter;
 
     let node_outer_size = known_dimensions;
     let node_inner_size = node_outer_size.maybe_sub(content_box_inset.sum_axes());
@@ -607,16 +727,8 @@ fn compute_constants(
         is_balance,
         #[cfg(feature = "flexbox_balance")]
         line_count,
-        min_size: style
-            .min_size()
-            .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
-            .maybe_apply_aspect_ratio(aspect_ratio)
-            .maybe_add(box_sizing_adjustment),
-        max_size: style
-            .max_size()
-            .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
-            .maybe_apply_aspect_ratio(aspect_ratio)
-            .maybe_add(box_sizing_adjustment),
+        min_size,
+        max_size,
         margin,
         border,
         gap,
@@ -664,7 +776,6 @@ fn generate_anonymous_flex_items(
         .filter(|(_, _, style)| style.position() != Position::Absolute)
         .filter(|(_, _, style)| style.box_generation_mode() != BoxGenerationMode::None)
         .map(|(index, child, child_style)| {
-            let aspect_ratio = child_style.aspect_ratio();
             let padding = child_style
                 .padding()
                 .resolve_or_zero(constants.node_inner_size.width, |val, basis| tree.calc(val, basis));
@@ -674,23 +785,33 @@ fn generate_anonymous_flex_items(
             let pb_sum = (padding + border).sum_axes();
             let box_sizing_adjustment =
                 if child_style.box_sizing() == BoxSizing::ContentBox { pb_sum } else { Size::ZERO };
-            FlexItem {
-                node: child,
-                order: index as u32,
-                size: child_style
+            let aspect_ratio = PreferredAspectRatio::from_style(&child_style, pb_sum);
+            let min_size = child_style
+                .min_size()
+                .maybe_resolve(percent_resolution_size, |val, basis| tree.calc(val, basis))
+                .maybe_add(box_sizing_adjustment);
+            let max_size = child_style
+                .max_size()
+                .maybe_resolve(percent_resolution_size, |val, basis| tree.calc(val, basis))
+                .maybe_add(box_sizing_adjustment);
+            // The min and max sizes are transferred through the aspect ratio where they are used, when resolving the
+            // automatic minimum size and the stretched cross size
+            let (size, _, _) = transfer_sizes(
+                aspect_ratio,
+                child_style
                     .size()
                     .maybe_resolve(percent_resolution_size, |val, basis| tree.calc(val, basis))
-                    .maybe_apply_aspect_ratio(aspect_ratio)
                     .maybe_add(box_sizing_adjustment),
+                min_size,
+                max_size,
+            );
+            FlexItem {
+                node: child,
+                order: index as u32,
+                size,
                 size_style: child_style.size(),
-                min_size: child_style
-                    .min_size()
-                    .maybe_resolve(percent_resolution_size, |val, basis| tree.calc(val, basis))
-                    .maybe_add(box_sizing_adjustment),
-                max_size: child_style
-                    .max_size()
-                    .maybe_resolve(percent_resolution_size, |val, basis| tree.calc(val, basis))
-                    .maybe_add(box_sizing_adjustment),
+                min_size,
+                max_size,
                 aspect_ratio,
 
                 inset: child_style
@@ -713,7 +834,10 @@ fn generate_anonymous_flex_items(
                 ),
                 overflow: child_style.overflow(),
                 contain: child_style.contain(),
-                scrollbar_width: child_style.scrollbar_width(),
+                #[cfg(feature = "content_size")]
+                transform: child_style.transform(),
+                #[cfg(feature = "content_size")]
+                transform_origin: child_style.transform_origin(),
                 flex_grow: child_style.flex_grow(),
                 flex_shrink: child_style.flex_shrink(),
                 flex_basis_is_definite: false,
@@ -730,6 +854,7 @@ fn generate_anonymous_flex_items(
                 content_flex_fraction: 0.0,
 
                 baseline: 0.0,
+                final_baselines: Point { x: Baselines::NONE, y: Baselines::NONE },
 
                 offset_main: 0.0,
                 offset_cross: 0.0,
@@ -822,8 +947,8 @@ fn determine_flex_base_size(
         // Min/max sizes transferred through the aspect ratio are taken into account here
         // https://github.com/w3c/csswg-drafts/issues/10997
         let cross_axis_margin_sum = constants.margin.cross_axis_sum(dir);
-        let transferred_min_size = child.min_size.maybe_apply_aspect_ratio(child.aspect_ratio);
-        let transferred_max_size = child.max_size.maybe_apply_aspect_ratio(child.aspect_ratio);
+        let transferred_min_size = child.min_size.maybe_transfer_aspect_ratio(child.aspect_ratio);
+        let transferred_max_size = child.max_size.maybe_transfer_aspect_ratio(child.aspect_ratio);
         let child_min_cross = transferred_min_size.cross(dir).maybe_add(cross_axis_margin_sum);
         let child_max_cross = transferred_max_size.cross(dir).maybe_add(cross_axis_margin_sum);
 
@@ -1073,6 +1198,20 @@ fn determine_flex_base_size(
     }
 }
 
+/// Collect the flex items into flex lines, balancing them across lines if the container has `flex-wrap: balance`
+#[inline]
+fn collect_lines<'a>(
+    constants: &AlgoConstants,
+    available_space: Size<AvailableSpace>,
+    flex_items: &'a mut Vec<FlexItem>,
+) -> Vec<FlexLine<'a>> {
+    #[cfg(feature = "flexbox_balance")]
+    if constants.is_balance {
+        return collect_balanced_flex_lines(constants, available_space, flex_items);
+    }
+    collect_flex_lines(constants, available_space, flex_items)
+}
+
 /// Collect flex items into flex lines.
 ///
 /// # [9.3. Main Size Determination](https://www.w3.org/TR/css-flexbox-1/#main-sizing)
@@ -1524,7 +1663,7 @@ fn determine_container_main_size(
 
     let outer_main_size = outer_main_size
         .maybe_clamp(constants.min_size.main(constants.dir), constants.max_size.main(constants.dir))
-        .max(main_content_box_inset - constants.scrollbar_gutter.main(constants.dir));
+        .max(main_content_box_inset - constants.scrollbar_gutter.main_axis_sum(constants.dir));
 
     // let outer_main_size = inner_main_size + constants.padding_border.main_axis_sum(constants.dir);
     let inner_main_size = f32_max(outer_main_size - main_content_box_inset, 0.0);
@@ -1747,8 +1886,8 @@ fn determine_hypothetical_cross_size(
 
         // Sizes transferred through the aspect ratio clamp the hypothetical cross size
         // https://github.com/w3c/csswg-drafts/issues/10997
-        let transferred_min_cross = child.min_size.maybe_apply_aspect_ratio(child.aspect_ratio).cross(constants.dir);
-        let transferred_max_cross = child.max_size.maybe_apply_aspect_ratio(child.aspect_ratio).cross(constants.dir);
+        let transferred_min_cross = child.min_size.maybe_transfer_aspect_ratio(child.aspect_ratio).cross(constants.dir);
+        let transferred_max_cross = child.max_size.maybe_transfer_aspect_ratio(child.aspect_ratio).cross(constants.dir);
 
         let child_cross = child
             .size
@@ -1829,10 +1968,15 @@ fn calculate_children_base_lines(
     }
 
     for line in flex_lines {
-        // If a flex line has one or zero items participating in baseline alignment then baseline alignment is a no-op so we skip
-        let line_baseline_child_count =
-            line.items.iter().filter(|child| child.participates_in_baseline_alignment(constants.dir)).count();
-        if line_baseline_child_count <= 1 {
+        // If one of a flex line's baseline-sharing groups (the items aligned by their first baselines, and the items
+        // aligned by their last baselines) has one or zero items then baseline alignment is a no-op for that group so
+        // we skip it
+        let last_baseline_child_count =
+            line.items.iter().filter(|child| child.participates_in_last_baseline_alignment(constants.dir)).count();
+        let first_baseline_child_count =
+            line.items.iter().filter(|child| child.participates_in_baseline_alignment(constants.dir)).count()
+                - last_baseline_child_count;
+        if first_baseline_child_count <= 1 && last_baseline_child_count <= 1 {
             continue;
         }
 
@@ -1841,6 +1985,12 @@ fn calculate_children_base_lines(
             if !child.participates_in_baseline_alignment(constants.dir) {
                 continue;
             }
+            let uses_last_baseline = child.participates_in_last_baseline_alignment(constants.dir);
+            let group_child_count =
+                if uses_last_baseline { last_baseline_child_count } else { first_baseline_child_count };
+            if group_child_count <= 1 {
+                continue;
+            }
 
             let measured_size_and_baselines = tree.compute_child_layout(
                 child.node,
@@ -1878,17 +2028,11 @@ fn calculate_children_base_lines(
                 },
             );
 
-            let baseline = measured_size_and_baselines.baselines.first;
-            let height = measured_size_and_baselines.size.height;
-
-            // Scroll containers' baselines are determined from their content as if scrolled to the
-            // initial position, but are additionally clamped to their border box.
-            // See https://github.com/w3c/csswg-drafts/issues/7660
-            let baseline = if child.overflow.y.is_scroll_container() {
-                baseline.unwrap_or(height).min(height).max(0.0)
-            } else {
-                baseline.unwrap_or(height)
-            };
+            let (first_baseline, last_baseline) = measured_size_and_baselines
+                .baselines
+                .y
+                .synthesized(measured_size_and_baselines.size.height, child.overflow.y.is_scroll_container());
+            let baseline = if uses_last_baseline { last_baseline } else { first_baseline };
 
             child.baseline = baseline + child.margin.top;
         }
@@ -1928,16 +2072,24 @@ fn calculate_cross_size(flex_lines: &mut [FlexLine], node_size: Size<Option<f32>
 
         //    3. The used cross-size of the flex line is the largest of the numbers found in the
         //       previous two steps and zero.
+        //    Items aligned by their last baselines form a separate baseline-sharing group, for which the
+        //    same sum is computed.
         for line in flex_lines.iter_mut() {
-            let max_baseline: f32 = line.items.iter().map(|child| child.baseline).fold(0.0, |acc, x| acc.max(x));
+            let hypothetical_outer_cross_size = |child: &FlexItem| child.hypothetical_outer_size.cross(constants.dir);
+            let first_baseline_group =
+                BaselineGroupExtent::of_line(line, false, constants, hypothetical_outer_cross_size);
+            let last_baseline_group =
+                BaselineGroupExtent::of_line(line, true, constants, hypothetical_outer_cross_size);
             line.cross_size = line
                 .items
                 .iter()
                 .map(|child| {
-                    if child.participates_in_baseline_alignment(constants.dir) {
-                        max_baseline - child.baseline + child.hypothetical_outer_size.cross(constants.dir)
+                    if child.participates_in_last_baseline_alignment(constants.dir) {
+                        child.baseline + last_baseline_group.below
+                    } else if child.participates_in_baseline_alignment(constants.dir) {
+                        first_baseline_group.above - child.baseline + hypothetical_outer_cross_size(child)
                     } else {
-                        child.hypothetical_outer_size.cross(constants.dir)
+                        hypothetical_outer_cross_size(child)
                     }
                 })
                 .fold(0.0, |acc, x| acc.max(x));
@@ -2144,13 +2296,9 @@ fn distribute_remaining_free_space(flex_lines: &mut [FlexLine], constants: &Algo
 fn resolve_cross_axis_auto_margins(flex_lines: &mut [FlexLine], constants: &AlgoConstants) {
     for line in flex_lines {
         let line_cross_size = line.cross_size;
-        let max_baseline: f32 = line.items.iter_mut().map(|child| child.baseline).fold(0.0, |acc, x| acc.max(x));
-        let max_baseline_to_bottom_distance: f32 = line
-            .items
-            .iter_mut()
-            .filter(|child| child.participates_in_baseline_alignment(constants.dir))
-            .map(|child| child.outer_target_size.cross(constants.dir) - child.baseline)
-            .fold(0.0, |acc, x| acc.max(x));
+        let outer_target_cross_size = |child: &FlexItem| child.outer_target_size.cross(constants.dir);
+        let first_baseline_group = BaselineGroupExtent::of_line(line, false, constants, outer_target_cross_size);
+        let last_baseline_group = BaselineGroupExtent::of_line(line, true, constants, outer_target_cross_size);
 
         for child in line.items.iter_mut() {
             let free_space = line_cross_size - child.outer_target_size.cross(constants.dir);
@@ -2180,8 +2328,8 @@ fn resolve_cross_axis_auto_margins(flex_lines: &mut [FlexLine], constants: &Algo
                 child.offset_cross = align_flex_items_along_cross_axis(
                     child,
                     free_space,
-                    max_baseline,
-                    max_baseline_to_bottom_distance,
+                    first_baseline_group,
+                    last_baseline_group,
                     constants,
                 );
             }
@@ -2199,8 +2347,8 @@ fn resolve_cross_axis_auto_margins(flex_lines: &mut [FlexLine], constants: &Algo
 fn align_flex_items_along_cross_axis(
     child: &FlexItem,
     free_space: f32,
-    max_baseline: f32,
-    max_baseline_to_bottom_distance: f32,
+    first_baseline_group: BaselineGroupExtent,
+    last_baseline_group: BaselineGroupExtent,
     constants: &AlgoConstants,
 ) -> f32 {
     let cross_axis_should_reverse = constants.is_column && matches!(constants.layout_direction, Direction::Rtl);
@@ -2251,9 +2399,9 @@ fn align_flex_items_along_cross_axis(
                     // In a wrap-reverse container the cross axis is flipped, so the baseline-aligned
                     // group of items is aligned to the cross-start edge, which is the bottom of the line.
                     let line_cross_size = free_space + child.outer_target_size.cross(constants.dir);
-                    line_cross_size - max_baseline_to_bottom_distance - child.baseline
+                    line_cross_size - first_baseline_group.below - child.baseline
                 } else {
-                    max_baseline - child.baseline
+                    first_baseline_group.above - child.baseline
                 }
             } else {
                 // Until we support vertical writing modes, baseline alignment only makes sense if
@@ -2266,6 +2414,27 @@ fn align_flex_items_along_cross_axis(
                 }
             }
         }
+        AlignItemsKeyword::LastBaseline => {
+            if constants.is_row {
+                if constants.is_wrap_reverse {
+                    // In a wrap-reverse container the cross axis is flipped, so the group of items
+                    // aligned by their last baselines is aligned to the top of the line.
+                    last_baseline_group.above - child.baseline
+                } else {
+                    let line_cross_size = free_space + child.outer_target_size.cross(constants.dir);
+                    line_cross_size - last_baseline_group.below - child.baseline
+                }
+            } else {
+                // As with `baseline`, columns fall back to the alignment that `last baseline` is
+                // otherwise equivalent to, which is flex-end.
+                let baseline_column_should_reverse = cross_axis_should_reverse && !constants.is_wrap;
+                if constants.is_wrap_reverse ^ baseline_column_should_reverse {
+                    0.0
+                } else {
+                    free_space
+                }
+            }
+        }
         AlignItemsKeyword::Stretch => {
             if constants.is_wrap_reverse ^ cross_axis_should_reverse {
                 free_space
@@ -2299,7 +2468,7 @@ fn determine_container_cross_size(
     let total_line_cross_size: f32 = flex_lines.iter().map(|line| line.cross_size).sum::<f32>();
 
     let padding_border_sum = constants.content_box_inset.cross_axis_sum(constants.dir);
-    let cross_scrollbar_gutter = constants.scrollbar_gutter.cross(constants.dir);
+    let cross_scrollbar_gutter = constants.scrollbar_gutter.cross_axis_sum(constants.dir);
     let min_cross_size = constants.min_size.cross(constants.dir);
     let max_cross_size = constants.max_size.cross(constants.dir);
     let outer_container_size = node_size
@@ -2404,36 +2573,33 @@ fn calculate_flex_item(
         + item.margin.cross_start(direction)
         + cross_relative_inset;
 
-    if direction.is_row() {
+    // The item's baselines are positioned relative to the container using the item's position without
+    // any relative positioning offsets applied
+    let (baseline_offset_x, baseline_offset_y) = if direction.is_row() {
+        let baseline_offset_main =
+            if is_rtl_row { offset_main + main_relative_inset } else { offset_main - main_relative_inset };
         let baseline_offset_cross =
             total_offset_cross + item.offset_cross + effective_line_offset_cross + item.margin.cross_start(direction);
-        // Scroll containers' baselines are determined from their content as if scrolled to the initial
-        // position, but are additionally clamped to their border box.
-        // See https://github.com/w3c/csswg-drafts/issues/7660
-        let inner_baseline = {
-            let baseline = layout_output.baselines.first.unwrap_or(size.height);
-            if item.overflow.y.is_scroll_container() {
-                baseline.min(size.height).max(0.0)
-            } else {
-                baseline
-            }
-        };
-        item.baseline = baseline_offset_cross + inner_baseline;
+        (baseline_offset_main, baseline_offset_cross)
     } else {
         let baseline_offset_main = *total_offset_main + item.offset_main + item.margin.main_start(direction);
-        let inner_baseline = layout_output.baselines.first.unwrap_or(size.height);
-        item.baseline = baseline_offset_main + inner_baseline;
-    }
+        (offset_cross - cross_relative_inset, baseline_offset_main)
+    };
+    // Horizontal baselines that the item doesn't have are synthesized from its border box. Scroll containers'
+    // baselines are determined from their content as if scrolled to the initial position, but are additionally
+    // clamped to their border box. See https://github.com/w3c/csswg-drafts/issues/7660
+    let (first_baseline, last_baseline) =
+        layout_output.baselines.y.synthesized(size.height, item.overflow.y.is_scroll_container());
+    item.final_baselines = Point {
+        x: layout_output.baselines.x.offset_by(baseline_offset_x),
+        y: Baselines::new(first_baseline, last_baseline).offset_by(baseline_offset_y),
+    };
 
     let location = if direction.is_row() {
         Point { x: offset_main, y: offset_cross }
     } else {
         Point { x: offset_cross, y: offset_main }
     };
-    let scrollbar_size = Size {
-        width: if item.overflow.y == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
-        height: if item.overflow.x == Overflow::Scroll { item.scrollbar_width } else { 0.0 },
-    };
 
     tree.set_unrounded_layout(
         item.node,
@@ -2442,7 +2608,9 @@ fn calculate_flex_item(
             size,
             #[cfg(feature = "content_size")]
             scrollable_overflow_rect,
-            scrollbar_size,
+            scrollbar_size: layout_output.scrollbar_size,
+            replaced_content_rect: layout_output.replaced_content_rect,
+            marker_rect: layout_output.marker_rect,
             location,
             padding: item.padding,
             border: item.border,
@@ -2469,6 +2637,9 @@ fn calculate_flex_item(
             scrollable_overflow_rect,
             item.overflow,
             item.contain,
+            resolve_transform(item.transform, item.transform_origin, size, layout_direction.is_rtl(), |val, basis| {
+                tree.calc(val, basis)
+            }),
             constants.is_scroll_container,
         ));
     }
@@ -2587,12 +2758,12 @@ fn final_layout_pass(
     #[cfg(feature = "content_size")]
     if constants.is_scroll_container {
         overflow_rect.right += if constants.layout_direction.is_rtl() {
-            constants.content_box_inset.left - constants.border.left - constants.scrollbar_gutter.x
+            constants.content_box_inset.left - constants.border.left - constants.scrollbar_gutter.left
         } else {
-            constants.content_box_inset.right - constants.border.right - constants.scrollbar_gutter.x
+            constants.content_box_inset.right - constants.border.right - constants.scrollbar_gutter.right
         };
         overflow_rect.bottom +=
-            constants.content_box_inset.bottom - constants.border.bottom - constants.scrollbar_gutter.y;
+            constants.content_box_inset.bottom - constants.border.bottom - constants.scrollbar_gutter.bottom;
     }
 
     overflow_rect
@@ -2608,7 +2779,13 @@ fn perform_absolute_layout_on_absolute_children(
     let container_width = constants.container_size.width;
     let container_height = constants.container_size.height;
     let inset_relative_size =
-        constants.container_size - constants.border.sum_axes() - constants.scrollbar_gutter.into();
+        constants.container_size - constants.border.sum_axes() - constants.scrollbar_gutter.sum_axes();
+    let absolute_position_area = Rect {
+        left: constants.border.left + constants.scrollbar_gutter.left,
+        right: container_width - constants.border.right - constants.scrollbar_gutter.right,
+        top: constants.border.top + constants.scrollbar_gutter.top,
+        bottom: container_height - constants.border.bottom - constants.scrollbar_gutter.bottom,
+    };
 
     #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
     let mut overflow_rect = Rect::ZERO;
@@ -2622,390 +2799,426 @@ fn perform_absolute_layout_on_absolute_children(
         {
             continue;
         }
-
-        let overflow = child_style.overflow();
-        let contain = child_style.contain();
-        let scrollbar_width = child_style.scrollbar_width();
-        let aspect_ratio = child_style.aspect_ratio();
-        let align_self = child_style.align_self().unwrap_or(constants.align_items).resolve_self_relative(
-            child_style.direction(),
-            constants.layout_direction,
-            constants.is_column,
-        );
-        let margin = child_style
-            .margin()
-            .map(|margin| margin.resolve_to_option(inset_relative_size.width, |val, basis| tree.calc(val, basis)));
-        let padding =
-            child_style.padding().resolve_or_zero(Some(inset_relative_size.width), |val, basis| tree.calc(val, basis));
-        let border =
-            child_style.border().resolve_or_zero(Some(inset_relative_size.width), |val, basis| tree.calc(val, basis));
-        let padding_border_sum = (padding + border).sum_axes();
-        let box_sizing_adjustment =
-            if child_style.box_sizing() == BoxSizing::ContentBox { padding_border_sum } else { Size::ZERO };
-
-        // Resolve inset
-        // Insets are resolved against the container size minus border
-        let left =
-            child_style.inset().left.maybe_resolve(inset_relative_size.width, |val, basis| tree.calc(val, basis));
-        let right =
-            child_style.inset().right.maybe_resolve(inset_relative_size.width, |val, basis| tree.calc(val, basis));
-        let top = child_style.inset().top.maybe_resolve(inset_relative_size.height, |val, basis| tree.calc(val, basis));
-        let bottom =
-            child_style.inset().bottom.maybe_resolve(inset_relative_size.height, |val, basis| tree.calc(val, basis));
-
-        // Compute known dimensions from min/max/inherent size styles
-        let size_style = child_style.size();
-        let style_size = size_style
-            .maybe_resolve(inset_relative_size, |val, basis| tree.calc(val, basis))
-            .maybe_apply_aspect_ratio(aspect_ratio)
-            .maybe_add(box_sizing_adjustment);
-        let min_size = child_style
-            .min_size()
-            .maybe_resolve(inset_relative_size, |val, basis| tree.calc(val, basis))
-            .maybe_apply_aspect_ratio(aspect_ratio)
-            .maybe_add(box_sizing_adjustment)
-            .or(padding_border_sum.map(Some))
-            .maybe_max(padding_border_sum);
-        let max_size = child_style
-            .max_size()
-            .maybe_resolve(inset_relative_size, |val, basis| tree.calc(val, basis))
-            .maybe_apply_aspect_ratio(aspect_ratio)
-            .maybe_add(box_sizing_adjustment);
-        let mut known_dimensions = style_size.maybe_clamp(min_size, max_size);
-
         drop(child_style);
 
-        // Resolve any sizing keywords (min-content, max-content, fit-content, fit-content(...),
-        // stretch) in the size styles. An explicitly sized axis takes precedence over the
-        // inset-derived size below.
-        if size_style.width.is_sizing_keyword() || size_style.height.is_sizing_keyword() {
-            resolve_absolute_sizing_keywords(
-                tree,
-                child,
-                &mut known_dimensions,
-                size_style,
-                inset_relative_size,
-                Rect { left, right, top, bottom },
-                margin,
-                SizingMode::InherentSize,
+        let position_options =
+            PositionOptions::new(tree, node, child, absolute_position_area, constants.layout_direction);
+        for position_option in position_options.iter() {
+            let child_style = tree.get_flexbox_child_style(child);
+            let overflow = child_style.overflow();
+            let contain = child_style.contain();
+            #[cfg(feature = "content_size")]
+            let (transform, transform_origin) = (child_style.transform(), child_style.transform_origin());
+            let align_self = child_style.align_self().unwrap_or(constants.align_items).resolve_self_relative(
+                child_style.direction(),
+                constants.layout_direction,
+                constants.is_column,
             );
-            known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
-        }
-
-        // Fill in width from left/right and reapply aspect ratio if:
-        //   - Width is not already known
-        //   - Item has both left and right inset properties set
-        if let (None, Some(left), Some(right)) = (known_dimensions.width, left, right) {
-            let new_width_raw = inset_relative_size.width.maybe_sub(margin.left).maybe_sub(margin.right) - left - right;
-            known_dimensions.width = Some(f32_max(new_width_raw, 0.0));
-            known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
-        }
-
-        // Fill in height from top/bottom and reapply aspect ratio if:
-        //   - Height is not already known
-        //   - Item has both top and bottom inset properties set
-        if let (None, Some(top), Some(bottom)) = (known_dimensions.height, top, bottom) {
-            let new_height_raw =
-                inset_relative_size.height.maybe_sub(margin.top).maybe_sub(margin.bottom) - top - bottom;
-            known_dimensions.height = Some(f32_max(new_height_raw, 0.0));
-            known_dimensions = known_dimensions.maybe_apply_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
-        }
-        let final_size = match (known_dimensions.width, known_dimensions.height) {
-            (Some(width), Some(height)) => Size { width, height },
-            _ => {
-                let measured_size = tree.measure_child_size_both(
+            let margin = position_option
+                .margin
+                .map(|margin| margin.resolve_to_option(inset_relative_size.width, |val, basis| tree.calc(val, basis)));
+            let padding = child_style
+                .padding()
+                .resolve_or_zero(Some(inset_relative_size.width), |val, basis| tree.calc(val, basis));
+            let border = child_style
+                .border()
+                .resolve_or_zero(Some(inset_relative_size.width), |val, basis| tree.calc(val, basis));
+            let padding_border_sum = (padding + border).sum_axes();
+            let box_sizing_adjustment =
+                if child_style.box_sizing() == BoxSizing::ContentBox { padding_border_sum } else { Size::ZERO };
+            let aspect_ratio = PreferredAspectRatio::from_style(&child_style, padding_border_sum);
+
+            // Resolve inset
+            // Insets are resolved against the container size minus border
+            let left =
+                position_option.inset.left.maybe_resolve(inset_relative_size.width, |val, basis| tree.calc(val, basis));
+            let right = position_option
+                .inset
+                .right
+                .maybe_resolve(inset_relative_size.width, |val, basis| tree.calc(val, basis));
+            let top =
+                position_option.inset.top.maybe_resolve(inset_relative_size.height, |val, basis| tree.calc(val, basis));
+            let bottom = position_option
+                .inset
+                .bottom
+                .maybe_resolve(inset_relative_size.height, |val, basis| tree.calc(val, basis));
+
+            // Compute known dimensions from min/max/inherent size styles
+            let size_style = position_option.size;
+            let style_size = size_style
+                .maybe_resolve(inset_relative_size, |val, basis| tree.calc(val, basis))
+                .maybe_add(box_sizing_adjustment);
+            let min_size = child_style
+                .min_size()
+                .maybe_resolve(inset_relative_size, |val, basis| tree.calc(val, basis))
+                .maybe_add(box_sizing_adjustment);
+            let max_size = child_style
+                .max_size()
+                .maybe_resolve(inset_relative_size, |val, basis| tree.calc(val, basis))
+                .maybe_add(box_sizing_adjustment);
+            let (style_size, min_size, max_size) = transfer_sizes(aspect_ratio, style_size, min_size, max_size);
+            let min_size = min_size.or(padding_border_sum.map(Some)).maybe_max(padding_border_sum);
+            let mut known_dimensions = style_size.maybe_clamp(min_size, max_size);
+
+            drop(child_style);
+
+            // Resolve any sizing keywords (min-content, max-content, fit-content, fit-content(...),
+            // stretch) in the size styles. An explicitly sized axis takes precedence over the
+            // inset-derived size below.
+            if size_style.width.is_sizing_keyword() || size_style.height.is_sizing_keyword() {
+                resolve_absolute_sizing_keywords(
+                    tree,
                     child,
-                    known_dimensions,
-                    constants.node_inner_size,
-                    Size {
-                        width: AvailableSpace::Definite(container_width.maybe_clamp(min_size.width, max_size.width)),
-                        height: AvailableSpace::Definite(
-                            container_height.maybe_clamp(min_size.height, max_size.height),
-                        ),
-                    },
+                    &mut known_dimensions,
+                    size_style,
+                    inset_relative_size,
+                    Rect { left, right, top, bottom },
+                    margin,
                     SizingMode::InherentSize,
-                    Line::FALSE,
                 );
-                known_dimensions.unwrap_or(measured_size)
+                known_dimensions =
+                    known_dimensions.maybe_transfer_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
             }
-        }
-        .maybe_clamp(min_size, max_size);
-
-        let layout_output = tree.perform_child_layout(
-            child,
-            final_size.map(Some),
-            constants.node_inner_size,
-            Size {
-                width: AvailableSpace::Definite(container_width.maybe_clamp(min_size.width, max_size.width)),
-                height: AvailableSpace::Definite(container_height.maybe_clamp(min_size.height, max_size.height)),
-            },
-            SizingMode::InherentSize,
-            Line::FALSE,
-        );
-
-        let non_auto_margin = margin.map(|m| m.unwrap_or(0.0));
 
-        let free_space = Size {
-            width: constants.container_size.width - final_size.width - non_auto_margin.horizontal_axis_sum(),
-            height: constants.container_size.height - final_size.height - non_auto_margin.vertical_axis_sum(),
-        }
-        .f32_max(Size::ZERO);
-
-        // Expand auto margins to fill available space. Auto margins only absorb free space
-        // when the box is inset-constrained in that axis (both insets set); otherwise they
-        // resolve to zero and the box is statically positioned (CSS2 §10.3.7 / §10.6.4).
-        let resolved_margin = {
-            let auto_margin_size = Size {
-                width: {
-                    let auto_margin_count = margin.left.is_none() as u8 + margin.right.is_none() as u8;
-                    if auto_margin_count > 0 && left.is_some() && right.is_some() {
-                        free_space.width / auto_margin_count as f32
-                    } else {
-                        0.0
-                    }
-                },
-                height: {
-                    let auto_margin_count = margin.top.is_none() as u8 + margin.bottom.is_none() as u8;
-                    if auto_margin_count > 0 && top.is_some() && bottom.is_some() {
-                        free_space.height / auto_margin_count as f32
-                    } else {
-                        0.0
-                    }
-                },
-            };
+            // Fill in width from left/right and reapply aspect ratio if:
+            //   - Width is not already known
+            //   - Item has both left and right inset properties set
+            if let (None, Some(left), Some(right)) = (known_dimensions.width, left, right) {
+                let new_width_raw =
+                    inset_relative_size.width.maybe_sub(margin.left).maybe_sub(margin.right) - left - right;
+                known_dimensions.width = Some(f32_max(new_width_raw, 0.0));
+                known_dimensions =
+                    known_dimensions.maybe_transfer_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
+            }
 
-            Rect {
-                left: margin.left.unwrap_or(auto_margin_size.width),
-                right: margin.right.unwrap_or(auto_margin_size.width),
-                top: margin.top.unwrap_or(auto_margin_size.height),
-                bottom: margin.bottom.unwrap_or(auto_margin_size.height),
+            // Fill in height from top/bottom and reapply aspect ratio if:
+            //   - Height is not already known
+            //   - Item has both top and bottom inset properties set
+            if let (None, Some(top), Some(bottom)) = (known_dimensions.height, top, bottom) {
+                let new_height_raw =
+                    inset_relative_size.height.maybe_sub(margin.top).maybe_sub(margin.bottom) - top - bottom;
+                known_dimensions.height = Some(f32_max(new_height_raw, 0.0));
+                known_dimensions =
+                    known_dimensions.maybe_transfer_aspect_ratio(aspect_ratio).maybe_clamp(min_size, max_size);
             }
-        };
+            let final_size = match (known_dimensions.width, known_dimensions.height) {
+                (Some(width), Some(height)) => Size { width, height },
+                _ => {
+                    let measured_size = tree.measure_child_size_both(
+                        child,
+                        known_dimensions,
+                        constants.node_inner_size,
+                        Size {
+                            width: AvailableSpace::Definite(
+                                container_width.maybe_clamp(min_size.width, max_size.width),
+                            ),
+                            height: AvailableSpace::Definite(
+                                container_height.maybe_clamp(min_size.height, max_size.height),
+                            ),
+                        },
+                        SizingMode::InherentSize,
+                        Line::FALSE,
+                    );
+                    known_dimensions.unwrap_or(measured_size)
+                }
+            }
+            .maybe_clamp(min_size, max_size);
 
-        // Determine flex-relative insets
-        let (start_main, end_main) = if constants.is_row { (left, right) } else { (top, bottom) };
-        let (start_cross, end_cross) = if constants.is_row { (top, bottom) } else { (left, right) };
-        let main_axis_is_horizontal = constants.is_row;
-        let cross_axis_is_horizontal = !constants.is_row;
-        let main_is_rtl = main_axis_is_horizontal && constants.layout_direction.is_rtl();
-        let cross_is_rtl = cross_axis_is_horizontal && constants.layout_direction.is_rtl();
-        let main_axis_flex_start_reversed = constants.dir.is_reverse() ^ main_is_rtl;
-        let cross_axis_flex_start_reversed = constants.is_wrap_reverse ^ cross_is_rtl;
-        let main_start_scrollbar_offset =
-            if main_is_rtl { constants.scrollbar_gutter.main(constants.dir) } else { 0.0 };
-        let cross_start_scrollbar_offset =
-            if cross_is_rtl { constants.scrollbar_gutter.cross(constants.dir) } else { 0.0 };
-        let main_end_scrollbar_offset = if main_is_rtl { 0.0 } else { constants.scrollbar_gutter.main(constants.dir) };
-        let cross_end_scrollbar_offset =
-            if cross_is_rtl { 0.0 } else { constants.scrollbar_gutter.cross(constants.dir) };
-
-        // Apply main-axis alignment
-        // let free_main_space = free_space.main(constants.dir) - resolved_margin.main_axis_sum(constants.dir);
-        let offset_main = if start_main.is_some() || end_main.is_some() {
-            if main_is_rtl && end_main.is_some() {
-                constants.container_size.main(constants.dir)
-                    - constants.border.main_end(constants.dir)
-                    - main_end_scrollbar_offset
-                    - final_size.main(constants.dir)
-                    - end_main.unwrap_or(0.0)
-                    - resolved_margin.main_end(constants.dir)
-            } else if let Some(start) = start_main {
-                start
-                    + constants.border.main_start(constants.dir)
-                    + main_start_scrollbar_offset
-                    + resolved_margin.main_start(constants.dir)
-            } else {
-                constants.container_size.main(constants.dir)
-                    - constants.border.main_end(constants.dir)
-                    - main_end_scrollbar_offset
-                    - final_size.main(constants.dir)
-                    - end_main.unwrap_or(0.0)
-                    - resolved_margin.main_end(constants.dir)
+            let non_auto_margin = margin.map(|m| m.unwrap_or(0.0));
+
+            let free_space = Size {
+                width: constants.container_size.width - final_size.width - non_auto_margin.horizontal_axis_sum(),
+                height: constants.container_size.height - final_size.height - non_auto_margin.vertical_axis_sum(),
             }
-        } else {
-            // Stretch is an invalid value for justify_content in the flexbox algorithm, so we
-            // treat it as if it wasn't set (and thus we default to FlexStart behaviour).
-            //
-            // The `safe` overflow-position keyword is intentionally NOT applied here, even when
-            // the abs-positioned item would overflow the main axis: Chrome does not apply safe
-            // fallback to `justify-content` on absolutely-positioned flex items (only the
-            // cross-axis `align-self` does so). Matching the layout authority over a strict
-            // spec read keeps gentest fixtures green; reconsider if Chromium changes behavior.
-            // `start`/`end` are writing-mode relative (they flip for RTL but not for
-            // reversed flex-directions), whereas `flex-start`/`flex-end` and the
-            // distributed keywords' fallbacks are flex-relative.
-            let start_position = match constants.justify_content.unwrap_or(JustifyContent::FLEX_START).keyword() {
-                AlignContentKeyword::Start => !main_is_rtl,
-                AlignContentKeyword::End => main_is_rtl,
-                _ => true,
+            .f32_max(Size::ZERO);
+
+            // Expand auto margins to fill available space. Auto margins only absorb free space
+            // when the box is inset-constrained in that axis (both insets set); otherwise they
+            // resolve to zero and the box is statically positioned (CSS2 §10.3.7 / §10.6.4).
+            let resolved_margin = {
+                let auto_margin_size = Size {
+                    width: {
+                        let auto_margin_count = margin.left.is_none() as u8 + margin.right.is_none() as u8;
+                        if auto_margin_count > 0 && left.is_some() && right.is_some() {
+                            free_space.width / auto_margin_count as f32
+                        } else {
+                            0.0
+                        }
+                    },
+                    height: {
+                        let auto_margin_count = margin.top.is_none() as u8 + margin.bottom.is_none() as u8;
+                        if auto_margin_count > 0 && top.is_some() && bottom.is_some() {
+                            free_space.height / auto_margin_count as f32
+                        } else {
+                            0.0
+                        }
+                    },
+                };
+
+                Rect {
+                    left: margin.left.unwrap_or(auto_margin_size.width),
+                    right: margin.right.unwrap_or(auto_margin_size.width),
+                    top: margin.top.unwrap_or(auto_margin_size.height),
+                    bottom: margin.bottom.unwrap_or(auto_margin_size.height),
+                }
             };
-            match (
-                constants.justify_content.unwrap_or(JustifyContent::FLEX_START).keyword(),
-                main_axis_flex_start_reversed,
-            ) {
-                (AlignContentKeyword::SpaceBetween, false)
-                | (AlignContentKeyword::Stretch, false)
-                | (AlignContentKeyword::FlexStart, false)
-                | (AlignContentKeyword::FlexEnd, true) => {
-                    constants.content_box_inset.main_start(constants.dir) + resolved_margin.main_start(constants.dir)
+
+            // Determine flex-relative insets
+            let (start_main, end_main) = if constants.is_row { (left, right) } else { (top, bottom) };
+            let (start_cross, end_cross) = if constants.is_row { (top, bottom) } else { (left, right) };
+            let main_axis_is_horizontal = constants.is_row;
+            let cross_axis_is_horizontal = !constants.is_row;
+            let main_is_rtl = main_axis_is_horizontal && constants.layout_direction.is_rtl();
+            let cross_is_rtl = cross_axis_is_horizontal && constants.layout_direction.is_rtl();
+            let main_axis_flex_start_reversed = constants.dir.is_reverse() ^ main_is_rtl;
+            let cross_axis_flex_start_reversed = constants.is_wrap_reverse ^ cross_is_rtl;
+            let main_start_scrollbar_offset = constants.scrollbar_gutter.main_start(constants.dir);
+            let cross_start_scrollbar_offset = constants.scrollbar_gutter.cross_start(constants.dir);
+            let main_end_scrollbar_offset = constants.scrollbar_gutter.main_end(constants.dir);
+            let cross_end_scrollbar_offset = constants.scrollbar_gutter.cross_end(constants.dir);
+
+            // Apply main-axis alignment
+            // let free_main_space = free_space.main(constants.dir) - resolved_margin.main_axis_sum(constants.dir);
+            let offset_main = if start_main.is_some() || end_main.is_some() {
+                if main_is_rtl && end_main.is_some() {
+                    constants.container_size.main(constants.dir)
+                        - constants.border.main_end(constants.dir)
+                        - main_end_scrollbar_offset
+                        - final_size.main(constants.dir)
+                        - end_main.unwrap_or(0.0)
+                        - resolved_margin.main_end(constants.dir)
+                } else if let Some(start) = start_main {
+                    start
+                        + constants.border.main_start(constants.dir)
+                        + main_start_scrollbar_offset
+                        + resolved_margin.main_start(constants.dir)
+                } else {
+                    constants.container_size.main(constants.dir)
+                        - constants.border.main_end(constants.dir)
+                        - main_end_scrollbar_offset
+                        - final_size.main(constants.dir)
+                        - end_main.unwrap_or(0.0)
+                        - resolved_margin.main_end(constants.dir)
                 }
-                (AlignContentKeyword::Start | AlignContentKeyword::End, _) => {
-                    if start_position {
+            } else {
+                // Stretch is an invalid value for justify_content in the flexbox algorithm, so we
+                // treat it as if it wasn't set (and thus we default to FlexStart behaviour).
+                //
+                // The `safe` overflow-position keyword is intentionally NOT applied here, even when
+                // the abs-positioned item would overflow the main axis: Chrome does not apply safe
+                // fallback to `justify-content` on absolutely-positioned flex items (only the
+                // cross-axis `align-self` does so). Matching the layout authority over a strict
+                // spec read keeps gentest fixtures green; reconsider if Chromium changes behavior.
+                // `start`/`end` are writing-mode relative (they flip for RTL but not for
+                // reversed flex-directions), whereas `flex-start`/`flex-end` and the
+                // distributed keywords' fallbacks are flex-relative.
+                let start_position = match constants.justify_content.unwrap_or(JustifyContent::FLEX_START).keyword() {
+                    AlignContentKeyword::Start => !main_is_rtl,
+                    AlignContentKeyword::End => main_is_rtl,
+                    _ => true,
+                };
+                match (
+                    constants.justify_content.unwrap_or(JustifyContent::FLEX_START).keyword(),
+                    main_axis_flex_start_reversed,
+                ) {
+                    (AlignContentKeyword::SpaceBetween, false)
+                    | (AlignContentKeyword::Stretch, false)
+                    | (AlignContentKeyword::FlexStart, false)
+                    | (AlignContentKeyword::FlexEnd, true) => {
                         constants.content_box_inset.main_start(constants.dir)
                             + resolved_margin.main_start(constants.dir)
-                    } else {
+                    }
+                    (AlignContentKeyword::Start | AlignContentKeyword::End, _) => {
+                        if start_position {
+                            constants.content_box_inset.main_start(constants.dir)
+                                + resolved_margin.main_start(constants.dir)
+                        } else {
+                            constants.container_size.main(constants.dir)
+                                - constants.content_box_inset.main_end(constants.dir)
+                                - final_size.main(constants.dir)
+                                - resolved_margin.main_end(constants.dir)
+                        }
+                    }
+                    (AlignContentKeyword::FlexEnd, false)
+                    | (AlignContentKeyword::FlexStart, true)
+                    | (AlignContentKeyword::Stretch, true)
+                    | (AlignContentKeyword::SpaceBetween, true) => {
                         constants.container_size.main(constants.dir)
                             - constants.content_box_inset.main_end(constants.dir)
                             - final_size.main(constants.dir)
                             - resolved_margin.main_end(constants.dir)
                     }
+                    (AlignContentKeyword::SpaceEvenly, _)
+                    | (AlignContentKeyword::SpaceAround, _)
+                    | (AlignContentKeyword::Center, _) => {
+                        (constants.container_size.main(constants.dir)
+                            + constants.content_box_inset.main_start(constants.dir)
+                            - constants.content_box_inset.main_end(constants.dir)
+                            - final_size.main(constants.dir)
+                            + resolved_margin.main_start(constants.dir)
+                            - resolved_margin.main_end(constants.dir))
+                            / 2.0
+                    }
                 }
-                (AlignContentKeyword::FlexEnd, false)
-                | (AlignContentKeyword::FlexStart, true)
-                | (AlignContentKeyword::Stretch, true)
-                | (AlignContentKeyword::SpaceBetween, true) => {
-                    constants.container_size.main(constants.dir)
-                        - constants.content_box_inset.main_end(constants.dir)
-                        - final_size.main(constants.dir)
-                        - resolved_margin.main_end(constants.dir)
-                }
-                (AlignContentKeyword::SpaceEvenly, _)
-                | (AlignContentKeyword::SpaceAround, _)
-                | (AlignContentKeyword::Center, _) => {
-                    (constants.container_size.main(constants.dir)
-                        + constants.content_box_inset.main_start(constants.dir)
-                        - constants.content_box_inset.main_end(constants.dir)
-                        - final_size.main(constants.dir)
-                        + resolved_margin.main_start(constants.dir)
-                        - resolved_margin.main_end(constants.dir))
-                        / 2.0
-                }
-            }
-        };
+            };
 
-        // Apply cross-axis alignment
-        // let free_cross_space = free_space.cross(constants.dir) - resolved_margin.cross_axis_sum(constants.dir);
-        let offset_cross = if start_cross.is_some() || end_cross.is_some() {
-            if cross_is_rtl && end_cross.is_some() {
-                constants.container_size.cross(constants.dir)
-                    - constants.border.cross_end(constants.dir)
-                    - cross_end_scrollbar_offset
-                    - final_size.cross(constants.dir)
-                    - end_cross.unwrap_or(0.0)
-                    - resolved_margin.cross_end(constants.dir)
-            } else if let Some(start) = start_cross {
-                start
-                    + constants.border.cross_start(constants.dir)
-                    + cross_start_scrollbar_offset
-                    + resolved_margin.cross_start(constants.dir)
+            // Apply cross-axis alignment
+            // let free_cross_space = free_space.cross(constants.dir) - resolved_margin.cross_axis_sum(constants.dir);
+            let offset_cross = if start_cross.is_some() || end_cross.is_some() {
+                if cross_is_rtl && end_cross.is_some() {
+                    constants.container_size.cross(constants.dir)
+                        - constants.border.cross_end(constants.dir)
+                        - cross_end_scrollbar_offset
+                        - final_size.cross(constants.dir)
+                        - end_cross.unwrap_or(0.0)
+                        - resolved_margin.cross_end(constants.dir)
+                } else if let Some(start) = start_cross {
+                    start
+                        + constants.border.cross_start(constants.dir)
+                        + cross_start_scrollbar_offset
+                        + resolved_margin.cross_start(constants.dir)
+                } else {
+                    constants.container_size.cross(constants.dir)
+                        - constants.border.cross_end(constants.dir)
+                        - cross_end_scrollbar_offset
+                        - final_size.cross(constants.dir)
+                        - end_cross.unwrap_or(0.0)
+                        - resolved_margin.cross_end(constants.dir)
+                }
             } else {
-                constants.container_size.cross(constants.dir)
-                    - constants.border.cross_end(constants.dir)
-                    - cross_end_scrollbar_offset
-                    - final_size.cross(constants.dir)
-                    - end_cross.unwrap_or(0.0)
-                    - resolved_margin.cross_end(constants.dir)
-            }
-        } else {
-            let cross_overflows = final_size.cross(constants.dir) + resolved_margin.cross_axis_sum(constants.dir)
-                > constants.container_size.cross(constants.dir)
-                    - constants.content_box_inset.cross_axis_sum(constants.dir);
-            let cross_keyword = resolve_self_alignment_safety(align_self, cross_overflows);
-            // `start`/`end` (and `baseline`, whose static-position fallback is `start`) are
-            // writing-mode relative: they flip for RTL but not for `wrap-reverse`.
-            // `flex-start`/`flex-end` and the `stretch` fallback are flex-relative.
-            let start_position = match cross_keyword {
-                AlignItemsKeyword::Start | AlignItemsKeyword::Baseline => !cross_is_rtl,
-                AlignItemsKeyword::End => cross_is_rtl,
-                _ => true,
-            };
-            match (cross_keyword, cross_axis_flex_start_reversed) {
-                // Stretch alignment does not apply to absolutely positioned items
-                // See "Example 3" at https://www.w3.org/TR/css-flexbox-1/#abspos-items
-                // Note: Stretch should be FlexStart not Start when we support both
-                (AlignItemsKeyword::Start | AlignItemsKeyword::End | AlignItemsKeyword::Baseline, _) => {
-                    if start_position {
+                let cross_overflows = final_size.cross(constants.dir) + resolved_margin.cross_axis_sum(constants.dir)
+                    > constants.container_size.cross(constants.dir)
+                        - constants.content_box_inset.cross_axis_sum(constants.dir);
+                let cross_keyword = resolve_self_alignment_safety(align_self, cross_overflows);
+                // `start`/`end` (and `baseline`/`last baseline`, whose static-position fallbacks are
+                // `start`/`end`) are writing-mode relative: they flip for RTL but not for `wrap-reverse`.
+                // `flex-start`/`flex-end` and the `stretch` fallback are flex-relative.
+                let start_position = match cross_keyword {
+                    AlignItemsKeyword::Start | AlignItemsKeyword::Baseline => !cross_is_rtl,
+                    AlignItemsKeyword::End | AlignItemsKeyword::LastBaseline => cross_is_rtl,
+                    _ => true,
+                };
+                match (cross_keyword, cross_axis_flex_start_reversed) {
+                    // Stretch alignment does not apply to absolutely positioned items
+                    // See "Example 3" at https://www.w3.org/TR/css-flexbox-1/#abspos-items
+                    // Note: Stretch should be FlexStart not Start when we support both
+                    (
+                        AlignItemsKeyword::Start
+                        | AlignItemsKeyword::End
+                        | AlignItemsKeyword::Baseline
+                        | AlignItemsKeyword::LastBaseline,
+                        _,
+                    ) => {
+                        if start_position {
+                            constants.content_box_inset.cross_start(constants.dir)
+                                + resolved_margin.cross_start(constants.dir)
+                        } else {
+                            constants.container_size.cross(constants.dir)
+                                - constants.content_box_inset.cross_end(constants.dir)
+                                - final_size.cross(constants.dir)
+                                - resolved_margin.cross_end(constants.dir)
+                        }
+                    }
+                    (AlignItemsKeyword::Stretch | AlignItemsKeyword::FlexStart, false)
+                    | (AlignItemsKeyword::FlexEnd, true) => {
                         constants.content_box_inset.cross_start(constants.dir)
                             + resolved_margin.cross_start(constants.dir)
-                    } else {
+                    }
+                    (AlignItemsKeyword::Stretch | AlignItemsKeyword::FlexStart, true)
+                    | (AlignItemsKeyword::FlexEnd, false) => {
                         constants.container_size.cross(constants.dir)
                             - constants.content_box_inset.cross_end(constants.dir)
                             - final_size.cross(constants.dir)
                             - resolved_margin.cross_end(constants.dir)
                     }
+                    (AlignItemsKeyword::Center, _) => {
+                        (constants.container_size.cross(constants.dir)
+                            + constants.content_box_inset.cross_start(constants.dir)
+                            - constants.content_box_inset.cross_end(constants.dir)
+                            - final_size.cross(constants.dir)
+                            + resolved_margin.cross_start(constants.dir)
+                            - resolved_margin.cross_end(constants.dir))
+                            / 2.0
+                    }
+                    // SelfStart/SelfEnd are resolved to Start/End against the item's own direction
+                    // where `align_self` is read above.
+                    (AlignItemsKeyword::SelfStart | AlignItemsKeyword::SelfEnd, _) => unreachable!(),
                 }
-                (AlignItemsKeyword::Stretch | AlignItemsKeyword::FlexStart, false)
-                | (AlignItemsKeyword::FlexEnd, true) => {
-                    constants.content_box_inset.cross_start(constants.dir) + resolved_margin.cross_start(constants.dir)
-                }
-                (AlignItemsKeyword::Stretch | AlignItemsKeyword::FlexStart, true)
-                | (AlignItemsKeyword::FlexEnd, false) => {
-                    constants.container_size.cross(constants.dir)
-                        - constants.content_box_inset.cross_end(constants.dir)
-                        - final_size.cross(constants.dir)
-                        - resolved_margin.cross_end(constants.dir)
-                }
-                (AlignItemsKeyword::Center, _) => {
-                    (constants.container_size.cross(constants.dir)
-                        + constants.content_box_inset.cross_start(constants.dir)
-                        - constants.content_box_inset.cross_end(constants.dir)
-                        - final_size.cross(constants.dir)
-                        + resolved_margin.cross_start(constants.dir)
-                        - resolved_margin.cross_end(constants.dir))
-                        / 2.0
-                }
-                // SelfStart/SelfEnd are resolved to Start/End against the item's own direction
-                // where `align_self` is read above.
-                (AlignItemsKeyword::SelfStart | AlignItemsKeyword::SelfEnd, _) => unreachable!(),
-            }
-        };
-
-        let location = match constants.is_row {
-            true => Point { x: offset_main, y: offset_cross },
-            false => Point { x: offset_cross, y: offset_main },
-        };
-        let scrollbar_size = Size {
-            width: if overflow.y == Overflow::Scroll { scrollbar_width } else { 0.0 },
-            height: if overflow.x == Overflow::Scroll { scrollbar_width } else { 0.0 },
-        };
-        tree.set_unrounded_layout(
-            child,
-            &Layout {
-                order: order as u32,
-                size: final_size,
-                #[cfg(feature = "content_size")]
-                scrollable_overflow_rect: layout_output.scrollable_overflow_rect,
-                scrollbar_size,
-                location,
-                padding,
-                border,
-                margin: resolved_margin,
-            },
-        );
+            };
 
-        #[cfg(feature = "content_size")]
-        {
-            // Location is measured from the scroll origin (the inline-start edge: right side in RTL)
-            let absolute_area_offset = Point {
-                x: constants.border.left
-                    + if constants.layout_direction.is_rtl() { constants.scrollbar_gutter.x } else { 0.0 },
-                y: constants.border.top,
+            let location = match constants.is_row {
+                true => Point { x: offset_main, y: offset_cross },
+                false => Point { x: offset_cross, y: offset_main },
             };
-            let relative_location =
-                Point { x: location.x - absolute_area_offset.x, y: location.y - absolute_area_offset.y };
-            let contribution_location = if constants.layout_direction.is_rtl() {
-                Point { x: inset_relative_size.width - relative_location.x - final_size.width, y: relative_location.y }
-            } else {
-                relative_location
+
+            // Try the next position option if the node overflows its containing block
+            let margin_box = Rect {
+                left: location.x - resolved_margin.left,
+                right: location.x + final_size.width + resolved_margin.right,
+                top: location.y - resolved_margin.top,
+                bottom: location.y + final_size.height + resolved_margin.bottom,
             };
-            overflow_rect = overflow_rect.union(compute_scrollable_overflow_contribution(
-                contribution_location,
-                final_size,
-                layout_output.scrollable_overflow_rect,
-                overflow,
-                contain,
-                constants.is_scroll_container,
-            ));
+            if !position_options.accepts(&position_option, margin_box) {
+                continue;
+            }
+
+            let layout_output = tree.perform_child_layout(
+                child,
+                final_size.map(Some),
+                constants.node_inner_size,
+                Size {
+                    width: AvailableSpace::Definite(container_width.maybe_clamp(min_size.width, max_size.width)),
+                    height: AvailableSpace::Definite(container_height.maybe_clamp(min_size.height, max_size.height)),
+                },
+                SizingMode::InherentSize,
+                Line::FALSE,
+            );
+            tree.set_unrounded_layout(
+                child,
+                &Layout {
+                    order: order as u32,
+                    size: final_size,
+                    #[cfg(feature = "content_size")]
+                    scrollable_overflow_rect: layout_output.scrollable_overflow_rect,
+                    scrollbar_size: layout_output.scrollbar_size,
+                    replaced_content_rect: layout_output.replaced_content_rect,
+                    marker_rect: layout_output.marker_rect,
+                    location,
+                    padding,
+                    border,
+                    margin: resolved_margin,
+                },
+            );
+
+            #[cfg(feature = "content_size")]
+            {
+                // Location is measured from the scroll origin (the inline-start edge of the padding box: right side in RTL)
+                let contribution_location = if constants.layout_direction.is_rtl() {
+                    Point {
+                        x: constants.container_size.width - (location.x + final_size.width) - constants.border.right,
+                        y: location.y - constants.border.top,
+                    }
+                } else {
+                    Point { x: location.x - constants.border.left, y: location.y - constants.border.top }
+                };
+                overflow_rect = overflow_rect.union(compute_scrollable_overflow_contribution(
+                    contribution_location,
+                    final_size,
+                    layout_output.scrollable_overflow_rect,
+                    overflow,
+                    contain,
+                    resolve_transform(
+                        transform,
+                        transform_origin,
+                        final_size,
+                        constants.layout_direction.is_rtl(),
+                        |val, basis| tree.calc(val, basis),
+                    ),
+                    constants.is_scroll_container,
+                ));
+            }
+
+            break;
         }
     }
 
@@ -3,17 +3,18 @@ use super::types::GridTrack;
 use crate::compute::common::alignment::{
     apply_alignment_fallback, compute_alignment_offset, resolve_self_alignment_safety,
 };
+use crate::compute::common::anchor::PositionOption;
+use crate::compute::common::aspect_ratio::{transfer_sizes, MaybeTransferAspectRatio, PreferredAspectRatio};
 use crate::geometry::{InBothAbsAxis, Line, Point, Rect, Size};
 use crate::style::{
-    AlignContent, AlignItems, AlignItemsKeyword, AlignSelf, AvailableSpace, CoreStyle, GridItemStyle, Overflow,
-    Position,
+    AlignContent, AlignItems, AlignItemsKeyword, AlignSelf, AvailableSpace, CoreStyle, GridItemStyle, Position,
 };
-use crate::tree::{Layout, LayoutPartialTreeExt, NodeId, SizingMode};
+use crate::tree::{Baselines, Layout, LayoutPartialTreeExt, NodeId, SizingMode};
 use crate::util::sys::f32_max;
 use crate::util::{MaybeMath, MaybeResolve, ResolveOrZero};
 
 #[cfg(feature = "content_size")]
-use crate::compute::common::scrollable_overflow::compute_scrollable_overflow_contribution;
+use crate::compute::common::scrollable_overflow::{compute_scrollable_overflow_contribution, resolve_transform};
 use crate::compute::common::sizing_keyword::{resolve_sizing_keyword, SizingKeywordResolution};
 use crate::{AbsoluteAxis, BoxSizing, Direction, LayoutGridContainer};
 
@@ -83,6 +84,9 @@ pub(super) fn align_tracks(
 }
 
 /// Align and size a grid item into it's final position
+///
+/// For an absolutely positioned item, `position_option` overrides its inset, margin and size styles. Returns the item's
+/// scrollable overflow contribution, its baselines and its margin box (relative to the container's border box).
 #[allow(clippy::too_many_arguments)]
 pub(super) fn align_and_position_item(
     tree: &mut impl LayoutGridContainer,
@@ -90,12 +94,13 @@ pub(super) fn align_and_position_item(
     order: u32,
     grid_area: Rect<f32>,
     container_alignment_styles: InBothAbsAxis<Option<AlignItems>>,
-    baseline_shim: f32,
+    baseline_shims: Line<f32>,
     direction: Direction,
     container_border_box_width: f32,
     container_border: Rect<f32>,
     #[cfg(feature = "content_size")] container_is_scroll_container: bool,
-) -> (Rect<f32>, f32, f32) {
+    position_option: Option<&PositionOption>,
+) -> (Rect<f32>, Point<Baselines>, Rect<f32>) {
     let grid_area_size = Size { width: grid_area.right - grid_area.left, height: grid_area.bottom - grid_area.top };
 
     let style = tree.get_grid_child_style(node);
@@ -103,8 +108,8 @@ pub(super) fn align_and_position_item(
     let overflow = style.overflow();
     #[cfg(feature = "content_size")]
     let contain = style.contain();
-    let scrollbar_width = style.scrollbar_width();
-    let aspect_ratio = style.aspect_ratio();
+    #[cfg(feature = "content_size")]
+    let (transform, transform_origin) = (style.transform(), style.transform_origin());
     // Resolve writing-mode-relative self-start/self-end keywords against the item's own
     // direction. The horizontal axis is the inline axis (Taffy only supports horizontal-tb);
     // the vertical (block) axis resolves them to plain start/end.
@@ -121,12 +126,11 @@ pub(super) fn align_and_position_item(
     };
 
     let position = style.position();
-    let inset_horizontal = style
-        .inset()
+    let inset = position_option.map(|option| option.inset).unwrap_or_else(|| style.inset());
+    let inset_horizontal = inset
         .horizontal_components()
         .map(|size| size.resolve_to_option(grid_area_size.width, |val, basis| tree.calc(val, basis)));
-    let inset_vertical = style
-        .inset()
+    let inset_vertical = inset
         .vertical_components()
         .map(|size| size.resolve_to_option(grid_area_size.height, |val, basis| tree.calc(val, basis)));
     let padding =
@@ -137,24 +141,21 @@ pub(super) fn align_and_position_item(
 
     let box_sizing_adjustment =
         if style.box_sizing() == BoxSizing::ContentBox { padding_border_size } else { Size::ZERO };
+    let aspect_ratio = PreferredAspectRatio::from_style(&style, padding_border_size);
 
-    let size_style = style.size();
-    let inherent_size = size_style
-        .maybe_resolve(grid_area_size, |val, basis| tree.calc(val, basis))
-        .maybe_apply_aspect_ratio(aspect_ratio)
-        .maybe_add(box_sizing_adjustment);
+    let size_style = position_option.map(|option| option.size).unwrap_or_else(|| style.size());
+    let inherent_size =
+        size_style.maybe_resolve(grid_area_size, |val, basis| tree.calc(val, basis)).maybe_add(box_sizing_adjustment);
     let min_size = style
         .min_size()
         .maybe_resolve(grid_area_size, |val, basis| tree.calc(val, basis))
-        .maybe_add(box_sizing_adjustment)
-        .or(padding_border_size.map(Some))
-        .maybe_max(padding_border_size)
-        .maybe_apply_aspect_ratio(aspect_ratio);
+        .maybe_add(box_sizing_adjustment);
     let max_size = style
         .max_size()
         .maybe_resolve(grid_area_size, |val, basis| tree.calc(val, basis))
-        .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
+    let (inherent_size, min_size, max_size) = transfer_sizes(aspect_ratio, inherent_size, min_size, max_size);
+    let min_size = min_size.or(padding_border_size.map(Some)).maybe_max(padding_border_size);
 
     // Resolve default alignment styles if they are set on neither the parent or the node itself
     // Note: if the child has a preferred aspect ratio but neither width or height are set, then the width is stretched
@@ -179,14 +180,16 @@ pub(super) fn align_and_position_item(
 
     // Note: This is not a bug. It is part of the CSS spec that both horizontal and vertical margins
     // resolve against the WIDTH of the grid area.
-    let margin =
-        style.margin().map(|margin| margin.resolve_to_option(grid_area_size.width, |val, basis| tree.calc(val, basis)));
+    let margin = position_option
+        .map(|option| option.margin)
+        .unwrap_or_else(|| style.margin())
+        .map(|margin| margin.resolve_to_option(grid_area_size.width, |val, basis| tree.calc(val, basis)));
 
     drop(style);
 
     let grid_area_minus_item_margins_size = Size {
         width: grid_area_size.width.maybe_sub(margin.left).maybe_sub(margin.right),
-        height: grid_area_size.height.maybe_sub(margin.top).maybe_sub(margin.bottom) - baseline_shim,
+        height: grid_area_size.height.maybe_sub(margin.top).maybe_sub(margin.bottom) - baseline_shims.sum(),
     };
 
     // A size that is a sizing keyword (min-content, max-content, fit-content,
@@ -272,7 +275,7 @@ pub(super) fn align_and_position_item(
     });
 
     // Reapply aspect ratio after stretch and absolute position width adjustments
-    let Size { width, height } = Size { width, height: inherent_size.height }.maybe_apply_aspect_ratio(aspect_ratio);
+    let Size { width, height } = Size { width, height: inherent_size.height }.maybe_transfer_aspect_ratio(aspect_ratio);
 
     let height = height.or_else(|| {
         if position == Position::Absolute {
@@ -320,7 +323,7 @@ pub(super) fn align_and_position_item(
         None
     });
     // Reapply aspect ratio after stretch and absolute position height adjustments
-    let Size { width, height } = Size { width, height }.maybe_apply_aspect_ratio(aspect_ratio);
+    let Size { width, height } = Size { width, height }.maybe_transfer_aspect_ratio(aspect_ratio);
 
     // Clamp size by min and max width/height
     let Size { width, height } = Size { width, height }.maybe_clamp(min_size, max_size);
@@ -359,7 +362,7 @@ pub(super) fn align_and_position_item(
         position,
         inset_horizontal,
         margin.horizontal_components(),
-        0.0,
+        Line { start: 0.0, end: 0.0 },
         direction,
     );
     let (y, y_margin) = align_item_within_area(
@@ -369,15 +372,10 @@ pub(super) fn align_and_position_item(
         position,
         inset_vertical,
         margin.vertical_components(),
-        baseline_shim,
+        baseline_shims,
         Direction::Ltr,
     );
 
-    let scrollbar_size = Size {
-        width: if overflow.y == Overflow::Scroll { scrollbar_width } else { 0.0 },
-        height: if overflow.x == Overflow::Scroll { scrollbar_width } else { 0.0 },
-    };
-
     let resolved_margin = Rect { left: x_margin.start, right: x_margin.end, top: y_margin.start, bottom: y_margin.end };
 
     tree.set_unrounded_layout(
@@ -388,7 +386,9 @@ pub(super) fn align_and_position_item(
             size: Size { width, height },
             #[cfg(feature = "content_size")]
             scrollable_overflow_rect: layout_output.scrollable_overflow_rect,
-            scrollbar_size,
+            scrollbar_size: layout_output.scrollbar_size,
+            replaced_content_rect: layout_output.replaced_content_rect,
+            marker_rect: layout_output.marker_rect,
             padding,
             border,
             margin: resolved_margin,
@@ -410,13 +410,30 @@ pub(super) fn align_and_position_item(
             layout_output.scrollable_overflow_rect,
             overflow,
             contain,
+            resolve_transform(transform, transform_origin, Size { width, height }, direction.is_rtl(), |val, basis| {
+                tree.calc(val, basis)
+            }),
             container_is_scroll_container,
         )
     };
     #[cfg(not(feature = "content_size"))]
     let contribution = Rect::ZERO;
 
-    (contribution, y, height)
+    // The item's baselines relative to the container. Only horizontal baselines are synthesized, as vertical
+    // baselines are never used for alignment within the grid.
+    let (first_baseline, last_baseline) =
+        layout_output.baselines.y.synthesized(height, overflow.y.is_scroll_container());
+    let baselines =
+        Point { x: layout_output.baselines.x.offset_by(x), y: Baselines::new(y + first_baseline, y + last_baseline) };
+
+    let margin_box = Rect {
+        left: x - resolved_margin.left,
+        right: x + width + resolved_margin.right,
+        top: y - resolved_margin.top,
+        bottom: y + height + resolved_margin.bottom,
+    };
+
+    (contribution, baselines, margin_box)
 }
 
 /// Align and size a grid item along a single axis
@@ -428,11 +445,14 @@ pub(super) fn align_item_within_area(
     position: Position,
     inset: Line<Option<f32>>,
     margin: Line<Option<f32>>,
-    baseline_shim: f32,
+    baseline_shims: Line<f32>,
     direction: Direction,
 ) -> (f32, Line<f32>) {
     // Calculate grid area dimension in the axis
-    let non_auto_margin = Line { start: margin.start.unwrap_or(0.0) + baseline_shim, end: margin.end.unwrap_or(0.0) };
+    let non_auto_margin = Line {
+        start: margin.start.unwrap_or(0.0) + baseline_shims.start,
+        end: margin.end.unwrap_or(0.0) + baseline_shims.end,
+    };
     let grid_area_size = f32_max(grid_area.end - grid_area.start, 0.0);
     let free_space = f32_max(grid_area_size - resolved_size - non_auto_margin.sum(), 0.0);
 
@@ -440,8 +460,8 @@ pub(super) fn align_item_within_area(
     let auto_margin_count = margin.start.is_none() as u8 + margin.end.is_none() as u8;
     let auto_margin_size = if auto_margin_count > 0 { free_space / auto_margin_count as f32 } else { 0.0 };
     let resolved_margin = Line {
-        start: margin.start.unwrap_or(auto_margin_size) + baseline_shim,
-        end: margin.end.unwrap_or(auto_margin_size),
+        start: margin.start.unwrap_or(auto_margin_size) + baseline_shims.start,
+        end: margin.end.unwrap_or(auto_margin_size) + baseline_shims.end,
     };
 
     let overflows = resolved_size + non_auto_margin.sum() > grid_area_size;
@@ -449,7 +469,8 @@ pub(super) fn align_item_within_area(
 
     // Compute offset in the axis
     let alignment_based_offset = match alignment_keyword {
-        // TODO: Add support for baseline alignment. For now we treat it as "start".
+        // Baseline alignment is achieved by the baseline shim (which acts like an extra start margin) so baseline
+        // aligned items are otherwise aligned to the start of the grid area
         AlignItemsKeyword::Start
         | AlignItemsKeyword::FlexStart
         | AlignItemsKeyword::Baseline
@@ -460,7 +481,8 @@ pub(super) fn align_item_within_area(
                 resolved_margin.start
             }
         }
-        AlignItemsKeyword::End | AlignItemsKeyword::FlexEnd => {
+        // Likewise, last baseline aligned items are end aligned with a shim acting like an extra end margin
+        AlignItemsKeyword::End | AlignItemsKeyword::FlexEnd | AlignItemsKeyword::LastBaseline => {
             if direction.is_rtl() {
                 resolved_margin.start
             } else {
@@ -72,15 +72,18 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
         .min(MAX_GRID_TRACKS as u32) as u16;
 
     let auto_repetition_count: u16 = template.clone().filter(|track_def| track_def.is_auto_repetition()).count() as u16;
-    let all_track_defs_have_fixed_component = template.clone().all(|track_def| match track_def {
+    // The tracks of the auto-repetition itself may be intrinsically sized (in which case it is repeated once),
+    // but the tracks outside of it must each have a fixed min or max track sizing function
+    let all_non_auto_repeated_track_defs_have_fixed_component = template.clone().all(|track_def| match track_def {
         GenericGridTemplateComponent::Single(sizing_function) => sizing_function.has_fixed_component(),
         GenericGridTemplateComponent::Repeat(repeat) => {
-            repeat.tracks().all(|sizing_function| sizing_function.has_fixed_component())
+            matches!(repeat.count(), RepetitionCount::AutoFit | RepetitionCount::AutoFill)
+                || repeat.tracks().all(|sizing_function| sizing_function.has_fixed_component())
         }
     });
 
-    let template_is_valid =
-        auto_repetition_count == 0 || (auto_repetition_count == 1 && all_track_defs_have_fixed_component);
+    let template_is_valid = auto_repetition_count == 0
+        || (auto_repetition_count == 1 && all_non_auto_repeated_track_defs_have_fixed_component);
 
     // If the template is invalid because it contains multiple auto-repetition definitions or it combines an auto-repetition
     // definition with non-fixed-size track sizing functions, then disregard it entirely and default to zero explicit tracks
@@ -122,22 +125,23 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
             let parent_size = Some(inner_container_size);
 
             /// ...treating each track as its max track sizing function if that is definite or as its minimum track sizing function
-            /// otherwise, flooring the max track sizing function by the min track sizing function if both are definite
+            /// otherwise, flooring the max track sizing function by the min track sizing function if both are definite.
+            /// Returns `None` if neither are definite.
             fn track_definite_value(
                 sizing_function: TrackSizingFunction,
                 parent_size: Option<f32>,
                 calc_resolver: impl Fn(*const (), f32) -> f32,
-            ) -> f32 {
+            ) -> Option<f32> {
                 let max_size = sizing_function.max.definite_value(parent_size, &calc_resolver);
                 let min_size = sizing_function.min.definite_value(parent_size, &calc_resolver);
-                max_size.map(|max| max.maybe_max(min_size)).or(min_size).unwrap()
+                max_size.map(|max| max.maybe_max(min_size)).or(min_size)
             }
 
             let non_repeating_track_used_space: f32 = template
                 .clone()
                 .map(|track_def| match track_def {
                     GenericGridTemplateComponent::Single(sizing_function) => {
-                        track_definite_value(sizing_function, parent_size, &resolve_calc_value)
+                        track_definite_value(sizing_function, parent_size, &resolve_calc_value).unwrap_or(0.0)
                     }
                     GenericGridTemplateComponent::Repeat(repeat) => match repeat.count() {
                         RepetitionCount::Count(count) => {
@@ -145,6 +149,7 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
                                 .tracks()
                                 .map(|sizing_function| {
                                     track_definite_value(sizing_function, parent_size, &resolve_calc_value)
+                                        .unwrap_or(0.0)
                                 })
                                 .sum::<f32>();
                             sum * (count as f32)
@@ -155,43 +160,51 @@ pub(crate) fn compute_explicit_grid_size_in_axis(
                 .sum();
             let gap_size = style.gap().get_abs(axis).resolve_or_zero(Some(inner_container_size), &resolve_calc_value);
 
-            // Compute the amount of space that a single repetition of the repeated track list takes
-            let per_repetition_track_used_space: f32 = repetition_definition_iter
+            // Compute the amount of space that a single repetition of the repeated track list takes.
+            // If any of the repeated tracks has neither a definite min nor max track sizing function, then
+            // the number of repetitions is one.
+            let per_repetition_track_used_space: Option<f32> = repetition_definition_iter
                 .map(|sizing_function| track_definite_value(sizing_function, parent_size, &resolve_calc_value))
-                .sum::<f32>();
-
-            // We special case the first repetition here because the number of gaps in the first repetition
-            // depends on the number of non-repeating tracks in the template
-            let first_repetition_and_non_repeating_tracks_used_space = non_repeating_track_used_space
-                + per_repetition_track_used_space
-                + ((non_auto_repeating_track_count as u32 + repetition_track_count as u32).saturating_sub(1) as f32
-                    * gap_size);
-
-            // If a single repetition already overflows the container then we return 1 as the repetition count
-            // (the number of repetitions is floored at 1)
-            if first_repetition_and_non_repeating_tracks_used_space > inner_container_size {
-                1u32
-            } else {
-                let per_repetition_gap_used_space = (repetition_track_count as f32) * gap_size;
-                let per_repetition_used_space = per_repetition_track_used_space + per_repetition_gap_used_space;
-                let num_repetition_that_fit = (inner_container_size
-                    - first_repetition_and_non_repeating_tracks_used_space)
-                    / per_repetition_used_space;
-
-                // If the container size is a preferred or maximum size:
-                //   Then we return the maximum number of repetitions that fit into the container without overflowing.
-                // If the container size is a minimum size:
-                //   - Then we return the minimum number of repetitions required to overflow the size.
-                //
-                // In all cases we add the additional repetition that was already accounted for in the special-case computation above
-                // Note: as-casts from f32 to u32 saturate, so a huge or infinite number of repetitions
-                // (e.g. due to zero-sized tracks) resolves to u32::MAX and is clamped below
-                match auto_fit_strategy {
-                    AutoRepeatStrategy::MaxRepetitionsThatDoNotOverflow => {
-                        (floor(num_repetition_that_fit) as u32).saturating_add(1)
-                    }
-                    AutoRepeatStrategy::MinRepetitionsThatDoOverflow => {
-                        (ceil(num_repetition_that_fit) as u32).saturating_add(1)
+                .sum::<Option<f32>>();
+
+            match per_repetition_track_used_space {
+                None => 1u32,
+                Some(per_repetition_track_used_space) => {
+                    // We special case the first repetition here because the number of gaps in the first repetition
+                    // depends on the number of non-repeating tracks in the template
+                    let first_repetition_and_non_repeating_tracks_used_space = non_repeating_track_used_space
+                        + per_repetition_track_used_space
+                        + ((non_auto_repeating_track_count as u32 + repetition_track_count as u32).saturating_sub(1)
+                            as f32
+                            * gap_size);
+
+                    // If a single repetition already overflows the container then we return 1 as the repetition count
+                    // (the number of repetitions is floored at 1)
+                    if first_repetition_and_non_repeating_tracks_used_space > inner_container_size {
+                        1u32
+                    } else {
+                        let per_repetition_gap_used_space = (repetition_track_count as f32) * gap_size;
+                        let per_repetition_used_space = per_repetition_track_used_space + per_repetition_gap_used_space;
+                        let num_repetition_that_fit = (inner_container_size
+                            - first_repetition_and_non_repeating_tracks_used_space)
+                            / per_repetition_used_space;
+
+                        // If the container size is a preferred or maximum size:
+                        //   Then we return the maximum number of repetitions that fit into the container without overflowing.
+                        // If the container size is a minimum size:
+                        //   - Then we return the minimum number of repetitions required to overflow the size.
+                        //
+                        // In all cases we add the additional repetition that was already accounted for in the special-case computation above
+                        // Note: as-casts from f32 to u32 saturate, so a huge or infinite number of repetitions
+                        // (e.g. due to zero-sized tracks) resolves to u32::MAX and is clamped below
+                        match auto_fit_strategy {
+                            AutoRepeatStrategy::MaxRepetitionsThatDoNotOverflow => {
+                                (floor(num_repetition_that_fit) as u32).saturating_add(1)
+                            }
+                            AutoRepeatStrategy::MinRepetitionsThatDoOverflow => {
+                                (ceil(num_repetition_that_fit) as u32).saturating_add(1)
+                            }
+                        }
                     }
                 }
             }
@@ -699,6 +712,30 @@ mod test {
         assert_eq!(auto_row_reps, 4);
     }
 
+    #[test]
+    fn explicit_grid_sizing_auto_fill_intrinsic_tracks() {
+        use RepetitionCount::AutoFill;
+        let size = |grid_template_columns| {
+            let grid_style: Style<DefaultCheapStr> =
+                Style { display: Display::Grid, grid_template_columns, ..Default::default() };
+            compute_explicit_grid_size_in_axis(
+                &grid_style,
+                Some(100.0),
+                AutoRepeatStrategy::MaxRepetitionsThatDoNotOverflow,
+                |_, _| 42.42,
+                AbsoluteAxis::Horizontal,
+            )
+        };
+
+        // A repeated track with a definite max track sizing function is treated as that size
+        assert_eq!(size(vec![repeat(AutoFill, vec![minmax(min_content(), length(30.0))])]), (3, 3));
+        // A repeated track with neither a definite min nor max track sizing function is repeated once
+        assert_eq!(size(vec![repeat(AutoFill, vec![minmax(min_content(), fr(1.0))])]), (1, 1));
+        assert_eq!(size(vec![length(20.0), repeat(AutoFill, vec![auto(), length(10.0)])]), (1, 3));
+        // The tracks outside of the repetition must still have a fixed min or max track sizing function
+        assert_eq!(size(vec![auto(), repeat(AutoFill, vec![length(10.0)])]), (0, 0));
+    }
+
     #[test]
     fn auto_repeat_clamping_preserves_template_source_order() {
         use RepetitionCount::{AutoFill, Count};
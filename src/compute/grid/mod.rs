@@ -1,8 +1,12 @@
 //! This module is a partial implementation of the CSS Grid Level 1 specification
 //! <https://www.w3.org/TR/css-grid-1>
+use crate::compute::common::anchor::PositionOptions;
+use crate::compute::common::aspect_ratio::{transfer_sizes, PreferredAspectRatio};
+use crate::compute::common::scrollbar::{compute_scrollbar_gutter, compute_with_auto_scrollbars};
+use crate::compute::common::size_containment::size_contained_dimensions;
 use crate::geometry::{AbsoluteAxis, AbstractAxis, InBothAbsAxis};
 use crate::geometry::{Line, Point, Rect, Size};
-use crate::style::{AlignItems, AvailableSpace, Overflow, Position};
+use crate::style::{AlignItems, AvailableSpace, Position};
 use crate::tree::{Baselines, Layout, LayoutInput, LayoutOutput, LayoutPartialTreeExt, NodeId, RunMode, SizingMode};
 use crate::util::debug::debug_log;
 use crate::util::sys::{f32_max, f32_min, GridTrackVec, Vec};
@@ -19,14 +23,14 @@ use placement::place_grid_items;
 use track_sizing::{
     determine_if_item_crosses_flexible_or_intrinsic_tracks, resolve_item_track_indexes, track_sizing_algorithm,
 };
-use types::{CellOccupancyMatrix, GridTrack, NamedLineResolver};
+use types::{CellOccupancyMatrix, GridTrack, GridTrackKind, NamedLineResolver};
 
 #[cfg(feature = "detailed_layout_info")]
 use crate::sys::{DefaultCheapStr, String};
 #[cfg(feature = "detailed_layout_info")]
 use crate::{CheapCloneStr, GridPlacement, OriginZeroGridPlacement};
 #[cfg(feature = "detailed_layout_info")]
-use types::{GridItem, GridTrackKind, TrackCounts};
+use types::{GridItem, TrackCounts};
 
 pub(crate) use types::{GridCoordinate, GridLine, OriginZeroLine, MAX_GRID_TRACKS, MAX_OZ_LINE, MIN_OZ_LINE};
 
@@ -51,6 +55,40 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     tree: &mut Tree,
     node: NodeId,
     inputs: LayoutInput,
+) -> LayoutOutput {
+    let style = tree.get_grid_container_style(node);
+    let overflow = style.overflow();
+    let scrollbar_gutter = style.scrollbar_gutter();
+    let scrollbar_width = style.scrollbar_width();
+    let direction = style.direction();
+    let border = style.border().resolve_or_zero(inputs.parent_size.width, |val, basis| tree.calc(val, basis));
+    // Only whether the size is limited matters here, so box sizing doesn't need to be taken into account
+    let is_size_constrained =
+        style.size().maybe_resolve(inputs.parent_size, |val, basis| tree.calc(val, basis)).zip_map(
+            style.max_size().maybe_resolve(inputs.parent_size, |val, basis| tree.calc(val, basis)),
+            |size, max| size.is_some() || max.is_some(),
+        );
+    drop(style);
+
+    compute_with_auto_scrollbars(
+        inputs,
+        overflow,
+        scrollbar_gutter,
+        scrollbar_width,
+        direction,
+        border,
+        is_size_constrained,
+        |inputs, auto_scrollbars| compute_grid_layout_inner(tree, node, inputs, auto_scrollbars),
+    )
+}
+
+/// Computes the layout of a grid container, reserving space for the scrollbars of any `Overflow::Auto`
+/// axes in which `auto_scrollbars` is true
+fn compute_grid_layout_inner<Tree: LayoutGridContainer>(
+    tree: &mut Tree,
+    node: NodeId,
+    inputs: LayoutInput,
+    auto_scrollbars: Point<bool>,
 ) -> LayoutOutput {
     let LayoutInput { known_dimensions, parent_size, available_space, run_mode, .. } = inputs;
 
@@ -60,53 +98,53 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
 
     // 1. Compute "available grid space"
     // https://www.w3.org/TR/css-grid-1/#available-grid-space
-    let aspect_ratio = style.aspect_ratio();
     let padding = style.padding().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
     let border = style.border().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
     let padding_border = padding + border;
     let padding_border_size = padding_border.sum_axes();
     let box_sizing_adjustment =
         if style.box_sizing() == BoxSizing::ContentBox { padding_border_size } else { Size::ZERO };
+    let aspect_ratio = PreferredAspectRatio::from_style(&style, padding_border_size);
 
     let min_size = style
         .min_size()
         .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
-        .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
     let max_size = style
         .max_size()
         .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
-        .maybe_apply_aspect_ratio(aspect_ratio)
         .maybe_add(box_sizing_adjustment);
-    let preferred_size = if inputs.sizing_mode == SizingMode::InherentSize {
-        style
-            .size()
-            .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
-            .maybe_apply_aspect_ratio(style.aspect_ratio())
-            .maybe_add(box_sizing_adjustment)
-    } else {
-        Size::NONE
-    };
+    let style_size =
+        style.size().maybe_resolve(parent_size, |val, basis| tree.calc(val, basis)).maybe_add(box_sizing_adjustment);
+    let (style_size, min_size, max_size) = transfer_sizes(aspect_ratio, style_size, min_size, max_size);
+    let preferred_size = if inputs.sizing_mode == SizingMode::InherentSize { style_size } else { Size::NONE };
 
-    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll`.
-    // However, the axis are switched (transposed) because a node that scrolls vertically needs
-    // *horizontal* space to be reserved for a scrollbar
-    let scrollbar_gutter = style.overflow().transpose().map(|overflow| match overflow {
-        Overflow::Scroll => style.scrollbar_width(),
-        _ => 0.0,
-    });
+    // A box with size containment is sized as if it were empty in the contained axes
+    let known_dimensions = known_dimensions.or(size_contained_dimensions(
+        &style,
+        tree.last_remembered_size(node),
+        known_dimensions.or(preferred_size.maybe_clamp(min_size, max_size)),
+        aspect_ratio,
+        padding_border_size,
+        min_size,
+        max_size,
+    ));
+
+    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll` (or to
+    // `Overflow::Auto` and the content overflows)
+    let scrollbar_gutter = compute_scrollbar_gutter(
+        style.overflow(),
+        style.scrollbar_gutter(),
+        style.scrollbar_width(),
+        direction,
+        auto_scrollbars,
+    );
     #[cfg(feature = "content_size")]
     let is_scroll_container = {
         let overflow = style.overflow();
         overflow.x.is_scroll_container() || overflow.y.is_scroll_container()
     };
-    let mut content_box_inset = padding_border;
-    content_box_inset.bottom += scrollbar_gutter.y;
-
-    match direction {
-        Direction::Ltr => content_box_inset.right += scrollbar_gutter.x,
-        Direction::Rtl => content_box_inset.left += scrollbar_gutter.x,
-    };
+    let content_box_inset = padding_border + scrollbar_gutter;
 
     let align_content = style.align_content().unwrap_or(AlignContent::STRETCH);
     let justify_content = style.justify_content().unwrap_or(JustifyContent::STRETCH);
@@ -302,8 +340,17 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     // Record this as a boolean (per-axis) on each item for later use in the track-sizing algorithm
     determine_if_item_crosses_flexible_or_intrinsic_tracks(&mut items, &columns, &rows);
 
+    // Percentage gaps in an axis in which the container's size is indefinite contribute zero to its intrinsic size,
+    // and are resolved against its final size once that has been determined
+    let has_percentage_column_gap = inner_node_size.width.is_none()
+        && columns.iter().any(|track| track.kind == GridTrackKind::Gutter && track.uses_percentage());
+    let has_percentage_row_gap = inner_node_size.height.is_none()
+        && rows.iter().any(|track| track.kind == GridTrackKind::Gutter && track.uses_percentage());
+
     // Determine if the grid has any baseline aligned items
-    let has_baseline_aligned_item = items.iter().any(|item| item.participates_in_baseline_alignment());
+    let has_baseline_aligned_item = items
+        .iter()
+        .any(|item| item.participates_in_baseline_alignment() || item.participates_in_last_baseline_alignment());
 
     // Run track sizing algorithm for Inline axis
     track_sizing_algorithm(
@@ -378,9 +425,13 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
 
     // 7. Resolve percentage track base sizes
     // In the case of an indefinitely sized container these resolve to zero during the "Initialise Tracks" step
-    // and therefore need to be re-resolved here based on the content-sized content box of the container
-    if !available_grid_space.width.is_definite() {
-        for column in &mut columns {
+    // and therefore need to be re-resolved here based on the content-sized content box of the container.
+    // Percentage gaps are always re-resolved if the container's size was indefinite, even if the available space
+    // is definite.
+    let resolve_percentage_columns = !available_grid_space.width.is_definite();
+    if resolve_percentage_columns || has_percentage_column_gap {
+        let is_resolved = |track: &&mut GridTrack| resolve_percentage_columns || track.kind == GridTrackKind::Gutter;
+        for column in columns.iter_mut().filter(is_resolved) {
             let min: Option<f32> = column
                 .min_track_sizing_function
                 .resolved_percentage_size(container_content_box.width, |val, basis| tree.calc(val, basis));
@@ -390,8 +441,10 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
             column.base_size = column.base_size.maybe_clamp(min, max);
         }
     }
-    if !available_grid_space.height.is_definite() {
-        for row in &mut rows {
+    let resolve_percentage_rows = !available_grid_space.height.is_definite();
+    if resolve_percentage_rows || has_percentage_row_gap {
+        let is_resolved = |track: &&mut GridTrack| resolve_percentage_rows || track.kind == GridTrackKind::Gutter;
+        for row in rows.iter_mut().filter(is_resolved) {
             let min: Option<f32> = row
                 .min_track_sizing_function
                 .resolved_percentage_size(container_content_box.height, |val, basis| tree.calc(val, basis));
@@ -404,6 +457,7 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
 
     // Column sizing must be re-run (once) if:
     //   - The grid container's width was initially indefinite and there are any columns with percentage track sizing functions
+    //   - The grid container's width was initially indefinite and the column gap is a percentage
     //   - Any grid item crossing an intrinsically sized track's min content contribution width has changed
     // TODO: Only rerun sizing for tracks that actually require it rather than for all tracks if any need it.
     let mut rerun_column_sizing;
@@ -412,7 +466,10 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     let has_percentage_column = columns.iter().any(|track| track.uses_percentage());
     let has_percentage_row = rows.iter().any(|track| track.uses_percentage());
     let parent_width_indefinite = !available_space.width.is_definite();
-    rerun_column_sizing = parent_width_indefinite && has_percentage_column;
+    rerun_column_sizing = (parent_width_indefinite && has_percentage_column) || has_percentage_column_gap;
+    if has_percentage_column_gap {
+        inner_node_size.width = Some(container_content_box.width);
+    }
 
     if !rerun_column_sizing {
         intrinsic_column_contribution_changed =
@@ -471,12 +528,16 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
 
         // Row sizing must be re-run (once) if:
         //   - The grid container's height was initially indefinite and there are any rows with percentage track sizing functions
+        //   - The grid container's height was initially indefinite and the row gap is a percentage
         //   - Any grid item crossing an intrinsically sized track's min content contribution height has changed
         // TODO: Only rerun sizing for tracks that actually require it rather than for all tracks if any need it.
         let mut rerun_row_sizing;
 
         let parent_height_indefinite = !available_space.height.is_definite();
-        rerun_row_sizing = parent_height_indefinite && has_percentage_row;
+        rerun_row_sizing = (parent_height_indefinite && has_percentage_row) || has_percentage_row_gap;
+        if has_percentage_row_gap {
+            inner_node_size.height = Some(container_content_box.height);
+        }
 
         if !rerun_row_sizing {
             intrinsic_row_contribution_changed =
@@ -569,12 +630,14 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
 
     // Align columns
     let inline_size_without_scrollbar = f32_max(container_border_box.width - padding_border_size.width, 0.0);
-    let inline_scrollbar_gutter_for_alignment = f32_min(scrollbar_gutter.x, inline_size_without_scrollbar);
+    let left_scrollbar_gutter_for_alignment = f32_min(scrollbar_gutter.left, inline_size_without_scrollbar);
+    let right_scrollbar_gutter_for_alignment =
+        f32_min(scrollbar_gutter.right, inline_size_without_scrollbar - left_scrollbar_gutter_for_alignment);
     align_tracks(
         container_content_box.get(AbstractAxis::Inline),
         Line {
-            start: padding.left + if direction.is_rtl() { inline_scrollbar_gutter_for_alignment } else { 0.0 },
-            end: padding.right + if direction.is_rtl() { 0.0 } else { inline_scrollbar_gutter_for_alignment },
+            start: padding.left + left_scrollbar_gutter_for_alignment,
+            end: padding.right + right_scrollbar_gutter_for_alignment,
         },
         Line { start: border.left, end: border.right },
         &mut columns,
@@ -623,21 +686,21 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
             },
         };
         #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
-        let (overflow_contribution, y_position, height) = align_and_position_item(
+        let (overflow_contribution, final_baselines, _) = align_and_position_item(
             tree,
             item.node,
             index as u32,
             grid_area,
             container_alignment_styles,
-            item.baseline_shim,
+            Line { start: item.baseline_shim, end: item.last_baseline_shim },
             direction,
             container_border_box.width,
             border,
             #[cfg(feature = "content_size")]
             is_scroll_container,
+            None,
         );
-        item.y_position = y_position;
-        item.height = height;
+        item.final_baselines = final_baselines;
 
         #[cfg(feature = "content_size")]
         {
@@ -740,19 +803,22 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
                     maybe_col_indexes
                         .end
                         .map(|index| rtl_line_as_end_edge(&columns, index))
-                        .unwrap_or(border.left + scrollbar_gutter.x),
+                        .unwrap_or(border.left + scrollbar_gutter.left),
                     maybe_col_indexes
                         .start
                         .map(|index| rtl_line_as_start_edge(&columns, index))
-                        .unwrap_or(container_border_box.width - border.right),
+                        .unwrap_or(container_border_box.width - border.right - scrollbar_gutter.right),
                 )
             } else {
                 (
-                    maybe_col_indexes.start.map(|index| line_as_start_edge(&columns, index)).unwrap_or(border.left),
+                    maybe_col_indexes
+                        .start
+                        .map(|index| line_as_start_edge(&columns, index))
+                        .unwrap_or(border.left + scrollbar_gutter.left),
                     maybe_col_indexes
                         .end
                         .map(|index| line_as_end_edge(&columns, index))
-                        .unwrap_or(container_border_box.width - border.right - scrollbar_gutter.x),
+                        .unwrap_or(container_border_box.width - border.right - scrollbar_gutter.right),
                 )
             };
 
@@ -761,30 +827,42 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
                 bottom: maybe_row_indexes
                     .end
                     .map(|index| line_as_end_edge(&rows, index))
-                    .unwrap_or(container_border_box.height - border.bottom - scrollbar_gutter.y),
+                    .unwrap_or(container_border_box.height - border.bottom - scrollbar_gutter.bottom),
                 left: grid_area_left,
                 right: grid_area_right,
             };
             drop(child_style);
 
-            // TODO: Baseline alignment support for absolutely positioned items (should check if is actually specified)
-            #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
-            let (overflow_contribution, _, _) = align_and_position_item(
-                tree,
-                child,
-                order,
-                grid_area,
-                container_alignment_styles,
-                0.0,
-                direction,
-                container_border_box.width,
-                border,
+            let position_options = PositionOptions::new(tree, node, child, grid_area, direction);
+            for position_option in position_options.iter() {
+                // TODO: Baseline alignment support for absolutely positioned items (should check if is actually specified)
+                #[cfg_attr(not(feature = "content_size"), allow(unused_variables))]
+                let (overflow_contribution, _, margin_box) = align_and_position_item(
+                    tree,
+                    child,
+                    order,
+                    grid_area,
+                    container_alignment_styles,
+                    Line { start: 0.0, end: 0.0 },
+                    direction,
+                    container_border_box.width,
+                    border,
+                    #[cfg(feature = "content_size")]
+                    is_scroll_container,
+                    Some(&position_option),
+                );
+
+                // Try the next position option if the item overflows its grid area. The layout set by a
+                // rejected option is overwritten by the next one.
+                if !position_options.accepts(&position_option, margin_box) {
+                    continue;
+                }
+
                 #[cfg(feature = "content_size")]
-                is_scroll_container,
-            );
-            #[cfg(feature = "content_size")]
-            {
-                absolute_overflow_rect = absolute_overflow_rect.union(overflow_contribution);
+                {
+                    absolute_overflow_rect = absolute_overflow_rect.union(overflow_contribution);
+                }
+                break;
             }
 
             order += 1;
@@ -803,11 +881,13 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
                 final_row_counts,
                 rows,
                 detailed_row_line_names,
+                row_auto_repetition_count,
             ),
             columns: DetailedGridTracksInfo::from_grid_tracks_and_track_count(
                 final_col_counts,
                 columns,
                 detailed_column_line_names,
+                col_auto_repetition_count,
             ),
             items: items.iter().map(DetailedGridItemsInfo::from_grid_item).collect(),
         },
@@ -829,28 +909,43 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
         return LayoutOutput::from_outer_size(container_border_box);
     }
 
-    // Determine the grid container baseline(s) (currently we only compute the first baseline)
+    // Determine the grid container's first and last baselines in each axis
     // Layout containment suppresses the box's baseline for baseline-alignment purposes
-    let grid_container_baseline: Option<f32> = if contain.suppresses_baseline() {
-        None
+    let grid_container_baselines = if contain.suppresses_baseline() {
+        Point { x: Baselines::NONE, y: Baselines::NONE }
     } else {
-        // Sort items by row start position so that we can iterate items in groups which are in the same row
-        items.sort_by_key(|item| item.row_indexes.start);
-
-        // Get the row index of the first row containing items
-        let first_row = items[0].row_indexes.start;
-
-        // Create a slice of all of the items start in this row (taking advantage of the fact that we have just sorted the array)
-        let first_row_items = &items[0..].split(|item| item.row_indexes.start != first_row).next().unwrap();
-
-        // Check if any items in *this row* participate in baseline alignment
+        // The first (horizontal) baseline is taken from the first row containing items: from the first item in that
+        // row participating in baseline alignment, or else from the first item in the row.
         // (items with an auto block-axis margin do not participate: https://www.w3.org/TR/css-align-3/#baseline-align-self)
-        let item = first_row_items
-            .iter()
+        let first_row = items.iter().map(|item| item.row_indexes.start).min().unwrap();
+        let first_row_items = || items.iter().filter(|item| item.row_indexes.start == first_row);
+        let first_row_item = first_row_items()
             .find(|item| item.participates_in_baseline_alignment())
-            .unwrap_or(&first_row_items[0]);
-
-        Some(item.y_position + item.baseline.unwrap_or(item.height))
+            .or_else(|| first_row_items().next())
+            .unwrap();
+
+        // Likewise, the last baseline is taken from the last row containing items: from the last item in that row
+        // participating in last baseline alignment, or else from the last item in the row.
+        let last_row = items.iter().map(|item| item.row_indexes.end).max().unwrap();
+        let last_row_items = || items.iter().rev().filter(|item| item.row_indexes.end == last_row);
+        let last_row_item = last_row_items()
+            .find(|item| item.participates_in_last_baseline_alignment())
+            .or_else(|| last_row_items().next())
+            .unwrap();
+
+        // Vertical baselines are taken from the first item in the first column and the last item in the last column
+        let first_column = items.iter().map(|item| item.column_indexes.start).min().unwrap();
+        let first_column_item = items.iter().find(|item| item.column_indexes.start == first_column).unwrap();
+        let last_column = items.iter().map(|item| item.column_indexes.end).max().unwrap();
+        let last_column_item = items.iter().rev().find(|item| item.column_indexes.end == last_column).unwrap();
+
+        Point {
+            x: Baselines {
+                first: first_column_item.final_baselines.x.first,
+                last: last_column_item.final_baselines.x.last,
+            },
+            y: Baselines { first: first_row_item.final_baselines.y.first, last: last_row_item.final_baselines.y.last },
+        }
     };
 
     // A scroll container's own padding at the end of the content is part of its scrollable
@@ -868,11 +963,7 @@ pub fn compute_grid_layout<Tree: LayoutGridContainer>(
     #[cfg(not(feature = "content_size"))]
     let scrollable_overflow_rect = item_overflow_rect;
 
-    LayoutOutput::from_sizes_and_baselines(
-        container_border_box,
-        scrollable_overflow_rect,
-        Baselines::from_first(grid_container_baseline),
-    )
+    LayoutOutput::from_sizes_and_baselines(container_border_box, scrollable_overflow_rect, grid_container_baselines)
 }
 
 /// Information from the computation of grid
@@ -1004,6 +1095,70 @@ impl<S: CheapCloneStr> DetailedGridInfo<S> {
         let bottom = self.rows.positions[item.row_end as usize - 2].end;
         Some((Point { x: left, y: top }, Size { width: right - left, height: bottom - top }))
     }
+
+    /// The physical extent of the grid line with the passed 0-indexed line index in the passed
+    /// axis (see [`DetailedGridTracksInfo::names_for_line`]), relative to the grid container's
+    /// border box. The extent covers the gutter and any content-alignment spacing between the
+    /// tracks on either side of the line. `direction` only affects the columns.
+    ///
+    /// Returns `None` if the index is out of range.
+    pub fn line_extent(&self, axis: AbsoluteAxis, line_index: usize, direction: Direction) -> Option<Line<f32>> {
+        match axis {
+            AbsoluteAxis::Horizontal => self.columns.line_extent(line_index, direction.is_rtl()),
+            AbsoluteAxis::Vertical => self.rows.line_extent(line_index, false),
+        }
+    }
+
+    /// The physical extent (see [`DetailedGridInfo::line_extent`]) of the first grid line with the
+    /// passed name in the passed axis. Lines implicitly named by `grid-template-areas` (such as
+    /// `sidebar-start`) can be looked up as well as explicitly named lines.
+    ///
+    /// Returns `None` if no line in the axis has the name.
+    pub fn named_line_extent(&self, axis: AbsoluteAxis, name: &str, direction: Direction) -> Option<Line<f32>> {
+        let line_index = match axis {
+            AbsoluteAxis::Horizontal => self.columns.lines_named(name).next(),
+            AbsoluteAxis::Vertical => self.rows.lines_named(name).next(),
+        }?;
+        self.line_extent(axis, line_index, direction)
+    }
+
+    /// Compute the physical rect of the named grid area, relative to the grid container's border box.
+    ///
+    /// Like `grid-area: <name>`, the area spans from the first `<name>-start` line to the first
+    /// `<name>-end` line in each axis, so areas defined by `grid-template-areas` and areas implied by
+    /// explicitly named lines are both found. As with [`DetailedGridInfo::item_grid_area`], the rect
+    /// excludes any gutter or content-alignment spacing around the area.
+    ///
+    /// Returns `None` if either axis lacks the start or end line, or if an end line doesn't follow
+    /// its start line.
+    pub fn named_area(&self, name: &str) -> Option<Rect<f32>> {
+        /// Find the tracks spanned by the area in one axis
+        fn area_tracks<'a, S: CheapCloneStr>(
+            tracks: &'a DetailedGridTracksInfo<S>,
+            name: &str,
+        ) -> Option<(&'a Line<f32>, &'a Line<f32>)> {
+            let first_line_named = |suffix: &str| {
+                tracks.iter_line_names().position(|names| {
+                    names.iter().any(|line_name| line_name.as_ref().strip_suffix(suffix) == Some(name))
+                })
+            };
+            let start = first_line_named("-start")?;
+            let end = first_line_named("-end")?;
+            if end <= start {
+                return None;
+            }
+            Some((tracks.positions.get(start)?, tracks.positions.get(end - 1)?))
+        }
+
+        let (start_col, end_col) = area_tracks(&self.columns, name)?;
+        let (start_row, end_row) = area_tracks(&self.rows, name)?;
+        Some(Rect {
+            left: f32_min(start_col.start, end_col.start),
+            right: f32_max(start_col.end, end_col.end),
+            top: start_row.start,
+            bottom: end_row.end,
+        })
+    }
 }
 
 /// Information from the computation of grids tracks
@@ -1027,6 +1182,15 @@ pub struct DetailedGridTracksInfo<S: CheapCloneStr = DefaultCheapStr> {
     /// [`DetailedGridTracksInfo::iter_line_names`] for indices relative to the full grid
     /// (including implicit tracks). Empty if the grid has no named lines.
     pub line_names: GridLineNames<S>,
+
+    /// The number of times that the `auto-fill` or `auto-fit` repetition in the track list was repeated.
+    /// Zero if the track list doesn't contain such a repetition (or is invalid and was ignored).
+    pub auto_repetitions: u16,
+
+    /// Whether each track (index-aligned with `positions`) was collapsed because it was generated by an
+    /// `auto-fit` repetition and contains no items. Collapsed tracks have zero size, and the gutters on
+    /// either side of them are collapsed too.
+    pub collapsed_tracks: Vec<bool>,
 }
 
 #[cfg(feature = "detailed_layout_info")]
@@ -1045,6 +1209,7 @@ impl<S: CheapCloneStr> DetailedGridTracksInfo<S> {
         track_count: TrackCounts,
         grid_tracks: Vec<GridTrack>,
         line_names: GridLineNames<S>,
+        auto_repetitions: u16,
     ) -> Self {
         DetailedGridTracksInfo {
             negative_implicit_tracks: track_count.negative_implicit,
@@ -1052,6 +1217,12 @@ impl<S: CheapCloneStr> DetailedGridTracksInfo<S> {
             positive_implicit_tracks: track_count.positive_implicit,
             positions: DetailedGridTracksInfo::<S>::positions_from_grid_track_layout(&grid_tracks),
             line_names,
+            auto_repetitions,
+            collapsed_tracks: grid_tracks
+                .iter()
+                .filter(|track| track.kind == GridTrackKind::Track)
+                .map(|track| track.is_collapsed)
+                .collect(),
         }
     }
 
@@ -1078,6 +1249,41 @@ impl<S: CheapCloneStr> DetailedGridTracksInfo<S> {
         self.line_names.iter_padded(leading_empty, trailing_empty)
     }
 
+    /// Iterate over the 0-indexed line indices (as used by [`DetailedGridTracksInfo::names_for_line`])
+    /// of the grid lines with the passed name, in line order. This includes the `<area>-start` and
+    /// `<area>-end` lines implicitly named by `grid-template-areas`.
+    pub fn lines_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = usize> + 'a {
+        self.iter_line_names()
+            .enumerate()
+            .filter(move |(_, names)| names.iter().any(|line_name| line_name.as_ref() == name))
+            .map(|(line_index, _)| line_index)
+    }
+
+    /// The physical extent of the grid line with the passed 0-indexed line index, relative to the
+    /// grid container's border box. This is the gutter between the tracks on either side of the line
+    /// (including any content-alignment spacing), so it has zero size if there is no such spacing.
+    /// The first and last lines lie on the outer edges of the first and last tracks.
+    ///
+    /// Returns `None` if the index is out of range.
+    fn line_extent(&self, line_index: usize, is_reversed: bool) -> Option<Line<f32>> {
+        let preceding_track = line_index.checked_sub(1).and_then(|index| self.positions.get(index));
+        let following_track = self.positions.get(line_index);
+        let (start, end) = match (preceding_track, following_track) {
+            (Some(preceding), Some(following)) if is_reversed => (following.end, preceding.start),
+            (Some(preceding), Some(following)) => (preceding.end, following.start),
+            (None, Some(following)) => {
+                let edge = if is_reversed { following.end } else { following.start };
+                (edge, edge)
+            }
+            (Some(preceding), None) => {
+                let edge = if is_reversed { preceding.start } else { preceding.end };
+                (edge, edge)
+            }
+            (None, None) => return None,
+        };
+        Some(Line { start: f32_min(start, end), end: f32_max(start, end) })
+    }
+
     /// Write the used track sizes and line names of this axis to the passed writer in the
     /// resolved value format of the `grid-template-rows`/`grid-template-columns` properties
     /// (see <https://www.w3.org/TR/css-grid-1/#resolved-track-list>)
@@ -461,20 +461,50 @@ fn resolve_item_baselines(
     items: &mut [GridItem],
     inner_node_size: Size<Option<f32>>,
 ) {
-    // Sort items by track in the other axis (row) start position so that we can iterate items in groups which
-    // are in the same track in the other axis (row)
+    // Items aligned to their first baseline share a baseline with other items that start in the same row, whereas
+    // items aligned to their last baseline share a baseline with other items that end in the same row.
+    resolve_baseline_sharing_groups(tree, axis, items, inner_node_size, false);
+    resolve_baseline_sharing_groups(tree, axis, items, inner_node_size, true);
+}
+
+/// Compute the baseline shims of items participating in either first (`last_baseline == false`) or last
+/// (`last_baseline == true`) baseline alignment.
+fn resolve_baseline_sharing_groups(
+    tree: &mut impl LayoutPartialTree,
+    axis: AbstractAxis,
+    items: &mut [GridItem],
+    inner_node_size: Size<Option<f32>>,
+    last_baseline: bool,
+) {
+    let participates = |item: &GridItem| {
+        if last_baseline {
+            item.participates_in_last_baseline_alignment()
+        } else {
+            item.participates_in_baseline_alignment()
+        }
+    };
+
+    // Sort items by the track in the other axis (row) that they start (or end) in so that we can iterate items in
+    // groups which share a baseline
     let other_axis = axis.other();
-    items.sort_by_key(|item| item.placement(other_axis).start);
+    let group_key = |item: &GridItem| {
+        let placement = item.placement(other_axis);
+        if last_baseline {
+            placement.end
+        } else {
+            placement.start
+        }
+    };
+    items.sort_by_key(group_key);
 
     // Iterate over grid rows
     let mut remaining_items = &mut items[0..];
     while !remaining_items.is_empty() {
         // Get the row index of the current row
-        let current_row = remaining_items[0].placement(other_axis).start;
+        let current_row = group_key(&remaining_items[0]);
 
         // Find the item index of the first item that is in a different row (or None if we've reached the end of the list)
-        let next_row_first_item =
-            remaining_items.iter().position(|item| item.placement(other_axis).start != current_row);
+        let next_row_first_item = remaining_items.iter().position(|item| group_key(item) != current_row);
 
         // Use this index to split the `remaining_items` slice in two slices:
         //    - A `row_items` slice containing the items (that start) in the current row
@@ -493,14 +523,14 @@ fn resolve_item_baselines(
         // Count how many items in *this row* are baseline aligned
         // If a row has one or zero items participating in baseline alignment then baseline alignment is a no-op
         // for those items and we skip further computations for that row
-        let row_baseline_item_count = row_items.iter().filter(|item| item.participates_in_baseline_alignment()).count();
+        let row_baseline_item_count = row_items.iter().filter(|item| participates(item)).count();
         if row_baseline_item_count <= 1 {
             continue;
         }
 
         // Compute the baselines of all items in the row participating in baseline alignment
         for item in row_items.iter_mut() {
-            if !item.participates_in_baseline_alignment() {
+            if !participates(item) {
                 continue;
             }
 
@@ -513,35 +543,44 @@ fn resolve_item_baselines(
                 Line::FALSE,
             );
 
-            let baseline = measured_size_and_baselines.baselines.first;
             let height = measured_size_and_baselines.size.height;
 
             // Scroll containers' baselines are determined from their content as if scrolled to the
             // initial position, but are additionally clamped to their border box.
             // See https://github.com/w3c/csswg-drafts/issues/7660
-            let baseline = if item.overflow.y.is_scroll_container() {
-                baseline.unwrap_or(height).min(height).max(0.0)
+            let (first, last) =
+                measured_size_and_baselines.baselines.y.synthesized(height, item.overflow.y.is_scroll_container());
+
+            if last_baseline {
+                // Last baselines are measured from the item's outer block-end edge
+                let margin_bottom =
+                    item.margin.bottom.resolve_or_zero(inner_node_size.width, |val, basis| tree.calc(val, basis));
+                item.last_baseline = Some(height - last + margin_bottom);
             } else {
-                baseline.unwrap_or(height)
-            };
-
-            item.baseline = Some(
-                baseline + item.margin.top.resolve_or_zero(inner_node_size.width, |val, basis| tree.calc(val, basis)),
-            );
+                let margin_top =
+                    item.margin.top.resolve_or_zero(inner_node_size.width, |val, basis| tree.calc(val, basis));
+                item.baseline = Some(first + margin_top);
+            }
         }
 
         // Compute the max baseline of all items in the row participating in baseline alignment
+        let item_baseline = |item: &GridItem| if last_baseline { item.last_baseline } else { item.baseline };
         let row_max_baseline = row_items
             .iter()
-            .filter(|item| item.participates_in_baseline_alignment())
-            .map(|item| item.baseline.unwrap_or(0.0))
+            .filter(|item| participates(item))
+            .map(|item| item_baseline(item).unwrap_or(0.0))
             .max_by(|a, b| a.total_cmp(b))
             .unwrap();
 
         // Compute the baseline shim for each item in the row participating in baseline alignment
         for item in row_items.iter_mut() {
-            if item.participates_in_baseline_alignment() {
-                item.baseline_shim = row_max_baseline - item.baseline.unwrap_or(0.0);
+            if participates(item) {
+                let shim = row_max_baseline - item_baseline(item).unwrap_or(0.0);
+                if last_baseline {
+                    item.last_baseline_shim = shim;
+                } else {
+                    item.baseline_shim = shim;
+                }
             }
         }
     }
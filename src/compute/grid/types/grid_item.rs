@@ -1,11 +1,12 @@
 //! Contains GridItem used to represent a single grid item during layout
 use super::GridTrack;
+use crate::compute::common::aspect_ratio::{transfer_sizes, MaybeTransferAspectRatio, PreferredAspectRatio};
 use crate::compute::common::sizing_keyword::{resolve_sizing_keyword, SizingKeywordResolution};
 use crate::compute::grid::OriginZeroLine;
 use crate::geometry::AbstractAxis;
 use crate::geometry::{Line, Point, Rect, Size};
-use crate::style::{AlignItems, AlignSelf, AvailableSpace, Dimension, LengthPercentageAuto, Overflow};
-use crate::tree::{LayoutPartialTree, LayoutPartialTreeExt, NodeId, SizingMode};
+use crate::style::{AlignItems, AlignSelf, AspectRatio, AvailableSpace, Dimension, LengthPercentageAuto, Overflow};
+use crate::tree::{Baselines, LayoutPartialTree, LayoutPartialTreeExt, NodeId, SizingMode};
 use crate::util::{MaybeMath, MaybeResolve, ResolveOrZero};
 use crate::{AlignItemsKeyword, BoxSizing, GridItemStyle, LengthPercentage};
 use core::ops::Range;
@@ -43,7 +44,7 @@ pub(in super::super) struct GridItem {
     /// The item's max_size style
     pub max_size: Size<LengthPercentageAuto>,
     /// The item's aspect_ratio style
-    pub aspect_ratio: Option<f32>,
+    pub aspect_ratio: AspectRatio,
     /// The item's padding style
     pub padding: Rect<LengthPercentage>,
     /// The item's border style
@@ -54,11 +55,16 @@ pub(in super::super) struct GridItem {
     pub align_self: AlignSelf,
     /// The item's justify_self property, or the parent's justify_items property is not set
     pub justify_self: AlignSelf,
-    /// The items first baseline (horizontal)
+    /// The distance from the item's outer block-start edge to its first (horizontal) baseline. Only computed for
+    /// items participating in first baseline alignment.
     pub baseline: Option<f32>,
     /// Shim for baseline alignment that acts like an extra top margin
-    /// TODO: Support last baseline and vertical text baselines
     pub baseline_shim: f32,
+    /// The distance from the item's last (horizontal) baseline to its outer block-end edge. Only computed for items
+    /// participating in last baseline alignment.
+    pub last_baseline: Option<f32>,
+    /// Shim for last baseline alignment that acts like an extra bottom margin
+    pub last_baseline_shim: f32,
 
     /// The item's definite row-start and row-end (same as `row` field, except in a different coordinate system)
     /// (as indexes into the Vec<GridTrack> stored in a grid's AbstractAxisTracks)
@@ -86,10 +92,9 @@ pub(in super::super) struct GridItem {
     /// Cache for the max-content size
     pub max_content_contribution_cache: Size<Option<f32>>,
 
-    /// Final y position. Used to compute baseline alignment for the container.
-    pub y_position: f32,
-    /// Final height. Used to compute baseline alignment for the container.
-    pub height: f32,
+    /// The item's final first and last baselines in each axis, relative to the container's border box. Used to
+    /// compute the container's own baselines.
+    pub final_baselines: Point<Baselines>,
 }
 
 impl GridItem {
@@ -122,6 +127,8 @@ impl GridItem {
             justify_self: style.justify_self().unwrap_or(parent_justify_items),
             baseline: None,
             baseline_shim: 0.0,
+            last_baseline: None,
+            last_baseline_shim: 0.0,
             row_indexes: Line { start: 0, end: 0 }, // Properly initialised later
             column_indexes: Line { start: 0, end: 0 }, // Properly initialised later
             crosses_flexible_row: false,            // Properly initialised later
@@ -132,8 +139,7 @@ impl GridItem {
             min_content_contribution_cache: Size::NONE,
             max_content_contribution_cache: Size::NONE,
             minimum_contribution_cache: Size::NONE,
-            y_position: 0.0,
-            height: 0.0,
+            final_baselines: Point { x: Baselines::NONE, y: Baselines::NONE },
         }
     }
 
@@ -157,9 +163,20 @@ impl GridItem {
     /// See <https://www.w3.org/TR/css-align-3/#baseline-align-self>
     #[inline(always)]
     pub fn participates_in_baseline_alignment(&self) -> bool {
-        self.align_self.keyword == AlignItemsKeyword::Baseline
-            && !self.has_auto_block_margin()
-            && !self.has_cyclic_block_size_dependency()
+        self.align_self.keyword == AlignItemsKeyword::Baseline && self.can_participate_in_baseline_alignment()
+    }
+
+    /// Returns true if the item participates in last baseline alignment: it has `align-self: last baseline`
+    /// and neither of its block-axis margins are `auto`.
+    #[inline(always)]
+    pub fn participates_in_last_baseline_alignment(&self) -> bool {
+        self.align_self.keyword == AlignItemsKeyword::LastBaseline && self.can_participate_in_baseline_alignment()
+    }
+
+    /// Whether the item would participate in baseline alignment if it was baseline aligned
+    #[inline(always)]
+    fn can_participate_in_baseline_alignment(&self) -> bool {
+        !self.has_auto_block_margin() && !self.has_cyclic_block_size_dependency()
     }
 
     /// This item's placement in the specified axis in OriginZero coordinates
@@ -264,6 +281,16 @@ impl GridItem {
         }
     }
 
+    /// The item's preferred aspect ratio, given the sum of its resolved padding and border in each axis
+    pub fn preferred_aspect_ratio(&self, padding_border_size: Size<f32>) -> Option<PreferredAspectRatio> {
+        PreferredAspectRatio::from_parent(
+            self.aspect_ratio,
+            self.is_compressible_replaced,
+            self.box_sizing,
+            padding_border_size,
+        )
+    }
+
     /// Compute the known_dimensions to be passed to the child sizing functions
     /// The key thing that is being done here is applying stretch alignment, which is necessary to
     /// allow percentage sizes further down the tree to resolve properly in some cases
@@ -274,7 +301,6 @@ impl GridItem {
     ) -> Size<Option<f32>> {
         let margins = self.margins_axis_sums_with_baseline_shims(grid_area_size.width, tree);
 
-        let aspect_ratio = self.aspect_ratio;
         // CSS resolves percentage padding and border against the inline size of the containing
         // block. For a grid item under intrinsic measurement, that inline-size basis is the grid
         // area's width when it is definite.
@@ -286,21 +312,20 @@ impl GridItem {
         let padding_border_size = (padding + border).sum_axes();
         let box_sizing_adjustment =
             if self.box_sizing == BoxSizing::ContentBox { padding_border_size } else { Size::ZERO };
+        let aspect_ratio = self.preferred_aspect_ratio(padding_border_size);
         let inherent_size = self
             .size
             .maybe_resolve(grid_area_size, |val, basis| tree.calc(val, basis))
-            .maybe_apply_aspect_ratio(aspect_ratio)
             .maybe_add(box_sizing_adjustment);
         let min_size = self
             .min_size
             .maybe_resolve(grid_area_size, |val, basis| tree.calc(val, basis))
-            .maybe_apply_aspect_ratio(aspect_ratio)
             .maybe_add(box_sizing_adjustment);
         let max_size = self
             .max_size
             .maybe_resolve(grid_area_size, |val, basis| tree.calc(val, basis))
-            .maybe_apply_aspect_ratio(aspect_ratio)
             .maybe_add(box_sizing_adjustment);
+        let (inherent_size, min_size, max_size) = transfer_sizes(aspect_ratio, inherent_size, min_size, max_size);
 
         let grid_area_minus_item_margins_size = grid_area_size.maybe_sub(margins);
 
@@ -332,7 +357,7 @@ impl GridItem {
         });
         // Reapply aspect ratio after stretch and absolute position width adjustments
         let Size { width, height } =
-            Size { width, height: inherent_size.height }.maybe_apply_aspect_ratio(aspect_ratio);
+            Size { width, height: inherent_size.height }.maybe_transfer_aspect_ratio(aspect_ratio);
 
         let height = height.or_else(|| {
             // A height that is a sizing keyword is not auto, so it does not stretch. The stretch
@@ -359,7 +384,7 @@ impl GridItem {
             None
         });
         // Reapply aspect ratio after stretch and absolute position height adjustments
-        let Size { width, height } = Size { width, height }.maybe_apply_aspect_ratio(aspect_ratio);
+        let Size { width, height } = Size { width, height }.maybe_transfer_aspect_ratio(aspect_ratio);
 
         // Clamp size by min and max width/height
         let Size { width, height } = Size { width, height }.maybe_clamp(min_size, max_size);
@@ -463,7 +488,8 @@ impl GridItem {
             right: self.margin.right.resolve_or_zero(Some(0.0), |val, basis| tree.calc(val, basis)),
             top: self.margin.top.resolve_or_zero(inner_node_width, |val, basis| tree.calc(val, basis))
                 + self.baseline_shim,
-            bottom: self.margin.bottom.resolve_or_zero(inner_node_width, |val, basis| tree.calc(val, basis)),
+            bottom: self.margin.bottom.resolve_or_zero(inner_node_width, |val, basis| tree.calc(val, basis))
+                + self.last_baseline_shim,
         }
         .sum_axes()
     }
@@ -612,18 +638,20 @@ impl GridItem {
         let padding_border_size = (padding + border).sum_axes();
         let box_sizing_adjustment =
             if self.box_sizing == BoxSizing::ContentBox { padding_border_size } else { Size::ZERO };
-        self.size
-            .maybe_resolve(grid_area_size, |val, basis| tree.calc(val, basis))
-            .maybe_apply_aspect_ratio(self.aspect_ratio)
-            .maybe_add(box_sizing_adjustment)
-            .get(axis)
-            .or_else(|| {
-                self.min_size
-                    .maybe_resolve(grid_area_size, |val, basis| tree.calc(val, basis))
-                    .maybe_apply_aspect_ratio(self.aspect_ratio)
-                    .maybe_add(box_sizing_adjustment)
-                    .get(axis)
-            })
+        let (size, min_size, _) = transfer_sizes(
+            self.preferred_aspect_ratio(padding_border_size),
+            self.size
+                .maybe_resolve(grid_area_size, |val, basis| tree.calc(val, basis))
+                .maybe_add(box_sizing_adjustment),
+            self.min_size
+                .maybe_resolve(grid_area_size, |val, basis| tree.calc(val, basis))
+                .maybe_add(box_sizing_adjustment),
+            self.max_size
+                .maybe_resolve(grid_area_size, |val, basis| tree.calc(val, basis))
+                .maybe_add(box_sizing_adjustment),
+        );
+        size.get(axis)
+            .or(min_size.get(axis))
             .or_else(|| self.overflow.get(axis).maybe_into_automatic_min_size())
             .unwrap_or_else(|| {
                 // Automatic minimum size. See https://www.w3.org/TR/css-grid-1/#min-size-auto
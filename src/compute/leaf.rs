@@ -1,13 +1,14 @@
 //! Computes size using styles and measure functions
 
-#[cfg(feature = "content_size")]
-use crate::geometry::Rect;
-use crate::geometry::Size;
-use crate::style::{AvailableSpace, Overflow, Position};
-use crate::tree::{Baselines, CollapsibleMarginSet, RunMode};
+use crate::compute::common::aspect_ratio::{transfer_sizes, PreferredAspectRatio};
+use crate::compute::common::scrollbar::{compute_scrollbar_gutter, scrollbar_size};
+use crate::compute::common::size_containment::size_contained_dimensions;
+use crate::geometry::{Point, Rect, Size};
+use crate::style::{AvailableSpace, ObjectFit, Position};
+use crate::tree::{Baselines, CollapsibleMarginSet, ReplacedContent, RunMode};
 use crate::tree::{LayoutInput, LayoutOutput, SizingMode};
 use crate::util::debug::debug_log;
-use crate::util::sys::f32_max;
+use crate::util::sys::{f32_max, f32_min};
 use crate::util::MaybeMath;
 use crate::util::{MaybeResolve, ResolveOrZero};
 use crate::{BoxSizing, CoreStyle};
@@ -22,6 +23,47 @@ pub fn compute_leaf_layout<MeasureFunction>(
 ) -> LayoutOutput
 where
     MeasureFunction: FnOnce(Size<Option<f32>>, Size<AvailableSpace>) -> Size<f32>,
+{
+    compute_leaf_layout_with_baselines(inputs, style, resolve_calc_value, |known_dimensions, available_space| {
+        (measure_function(known_dimensions, available_space), Point { x: Baselines::NONE, y: Baselines::NONE })
+    })
+}
+
+/// Compute the size and baselines of a leaf node (node with no children)
+///
+/// This is the same as [`compute_leaf_layout`], except that the measure function also returns the baselines of the
+/// node's content (such as the first and last lines of a text node) in each axis, measured from the top-left corner
+/// of the node's content box. These are converted to be relative to the node's border box and returned in the
+/// [`LayoutOutput`] so that the node can participate in baseline alignment.
+pub fn compute_leaf_layout_with_baselines<MeasureFunction>(
+    inputs: LayoutInput,
+    style: &impl CoreStyle,
+    resolve_calc_value: impl Fn(*const (), f32) -> f32,
+    measure_function: MeasureFunction,
+) -> LayoutOutput
+where
+    MeasureFunction: FnOnce(Size<Option<f32>>, Size<AvailableSpace>) -> (Size<f32>, Point<Baselines>),
+{
+    compute_leaf_layout_with_natural_aspect_ratio(inputs, style, None, resolve_calc_value, measure_function)
+}
+
+/// Compute the size and baselines of a leaf node (node with no children) whose content has a natural aspect ratio
+///
+/// This is the same as [`compute_leaf_layout_with_baselines`], except that it is also passed the natural aspect ratio
+/// (width divided by height) of the node's content, such as the aspect ratio of an image. This is used in preference
+/// to the ratio given in the node's `aspect_ratio` style if that style is [`AspectRatio::AUTO`] or
+/// `AspectRatio { auto: true, .. }`.
+///
+/// [`AspectRatio::AUTO`]: crate::AspectRatio::AUTO
+pub fn compute_leaf_layout_with_natural_aspect_ratio<MeasureFunction>(
+    inputs: LayoutInput,
+    style: &impl CoreStyle,
+    natural_aspect_ratio: Option<f32>,
+    resolve_calc_value: impl Fn(*const (), f32) -> f32,
+    measure_function: MeasureFunction,
+) -> LayoutOutput
+where
+    MeasureFunction: FnOnce(Size<Option<f32>>, Size<AvailableSpace>) -> (Size<f32>, Point<Baselines>),
 {
     let LayoutInput { known_dimensions, parent_size, available_space, sizing_mode, run_mode, .. } = inputs;
 
@@ -44,36 +86,52 @@ where
             (node_size, node_min_size, node_max_size, None)
         }
         SizingMode::InherentSize => {
-            let aspect_ratio = style.aspect_ratio();
-            let style_size = style
-                .size()
-                .maybe_resolve(parent_size, &resolve_calc_value)
-                .maybe_apply_aspect_ratio(aspect_ratio)
-                .maybe_add(box_sizing_adjustment);
-            let style_min_size = style
-                .min_size()
-                .maybe_resolve(parent_size, &resolve_calc_value)
-                .maybe_apply_aspect_ratio(aspect_ratio)
-                .maybe_add(box_sizing_adjustment);
+            // The natural aspect ratio of the content is ignored if the node has size containment
+            let contained_axes = style.contain().size_contained_axes();
+            let natural_aspect_ratio = natural_aspect_ratio.filter(|_| !contained_axes.width && !contained_axes.height);
+            let aspect_ratio =
+                PreferredAspectRatio::resolve(style.aspect_ratio(), natural_aspect_ratio, style.box_sizing(), pb_sum);
+            let style_size =
+                style.size().maybe_resolve(parent_size, &resolve_calc_value).maybe_add(box_sizing_adjustment);
+            let style_min_size =
+                style.min_size().maybe_resolve(parent_size, &resolve_calc_value).maybe_add(box_sizing_adjustment);
             let style_max_size =
                 style.max_size().maybe_resolve(parent_size, &resolve_calc_value).maybe_add(box_sizing_adjustment);
+            // A transferred maximum size does not apply to the node's content, whose size acts as the automatic
+            // minimum size in the ratio-dependent axis
+            let (style_size, style_min_size, _) =
+                transfer_sizes(aspect_ratio, style_size, style_min_size, style_max_size);
 
             let node_size = known_dimensions.or(style_size);
             (node_size, style_min_size, style_max_size, aspect_ratio)
         }
     };
 
-    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll`.
-    // However, the axis are switched (transposed) because a node that scrolls vertically needs
-    // *horizontal* space to be reserved for a scrollbar
-    let scrollbar_gutter = style.overflow().transpose().map(|overflow| match overflow {
-        Overflow::Scroll => style.scrollbar_width(),
-        _ => 0.0,
-    });
-    // TODO: make side configurable based on the `direction` property
-    let mut content_box_inset = padding_border;
-    content_box_inset.right += scrollbar_gutter.x;
-    content_box_inset.bottom += scrollbar_gutter.y;
+    // A node with size containment is sized as if it had no content in the contained axes. Leaf layout has no access
+    // to the node's last remembered size, so trees that support `contain_intrinsic_size: auto` resolve it beforehand.
+    let node_size = node_size.or(size_contained_dimensions(
+        style,
+        Size::NONE,
+        node_size,
+        aspect_ratio,
+        pb_sum,
+        node_min_size,
+        node_max_size,
+    ));
+
+    // Scrollbar gutters are reserved when the `overflow` property is set to `Overflow::Scroll` (or when the
+    // `scrollbar_gutter` is stable). Leaf nodes have no laid out content to overflow, so `Overflow::Auto` never
+    // reserves space for a scrollbar.
+    let direction = style.direction();
+    let scrollbar_gutter = compute_scrollbar_gutter(
+        style.overflow(),
+        style.scrollbar_gutter(),
+        style.scrollbar_width(),
+        direction,
+        Point { x: false, y: false },
+    );
+    let scrollbar_size = scrollbar_size(scrollbar_gutter, direction);
+    let content_box_inset = padding_border + scrollbar_gutter;
 
     let has_styles_preventing_being_collapsed_through = !style.is_block()
         || style.overflow().x.is_scroll_container()
@@ -102,7 +160,10 @@ where
                 size,
                 #[cfg(feature = "content_size")]
                 scrollable_overflow_rect: Rect::ZERO,
-                baselines: Baselines::NONE,
+                baselines: Point { x: Baselines::NONE, y: Baselines::NONE },
+                scrollbar_size,
+                replaced_content_rect: None,
+                marker_rect: None,
                 top_margin: CollapsibleMarginSet::ZERO,
                 bottom_margin: CollapsibleMarginSet::ZERO,
                 margins_can_collapse_through: false,
@@ -135,7 +196,7 @@ where
     };
 
     // Measure node
-    let measured_size = measure_function(
+    let (measured_size, content_baselines) = measure_function(
         match run_mode {
             RunMode::ComputeSize => known_dimensions,
             RunMode::PerformLayout => Size::NONE,
@@ -149,7 +210,10 @@ where
         .maybe_clamp(node_min_size, node_max_size);
     let size = Size {
         width: clamped_size.width,
-        height: f32_max(clamped_size.height, aspect_ratio.map(|ratio| clamped_size.width / ratio).unwrap_or(0.0)),
+        height: f32_max(
+            clamped_size.height,
+            aspect_ratio.map(|ratio| ratio.height_from_width(clamped_size.width)).unwrap_or(0.0),
+        ),
     };
     let size = size.maybe_max(padding_border.sum_axes().map(Some));
 
@@ -159,22 +223,35 @@ where
     #[cfg(feature = "content_size")]
     let scrollable_overflow_rect = {
         let is_scroll_container = style.overflow().x.is_scroll_container() || style.overflow().y.is_scroll_container();
-        let is_rtl = style.direction().is_rtl();
+        let is_rtl = direction.is_rtl();
         let start_padding = if is_rtl { padding.right } else { padding.left };
         let end_padding = if is_rtl { padding.left } else { padding.right };
+        let start_gutter = if is_rtl { scrollbar_gutter.right } else { scrollbar_gutter.left };
         Rect {
             left: 0.0,
-            right: start_padding + measured_size.width + if is_scroll_container { end_padding } else { 0.0 },
+            right: start_padding
+                + start_gutter
+                + measured_size.width
+                + if is_scroll_container { end_padding } else { 0.0 },
             top: 0.0,
             bottom: padding.top + measured_size.height + if is_scroll_container { padding.bottom } else { 0.0 },
         }
     };
 
+    // The content box is inset from the border box by the node's padding, border and scrollbar gutters
+    let baselines = Point {
+        x: content_baselines.x.offset_by(content_box_inset.left),
+        y: content_baselines.y.offset_by(content_box_inset.top),
+    };
+
     LayoutOutput {
         size,
         #[cfg(feature = "content_size")]
         scrollable_overflow_rect,
-        baselines: Baselines::NONE,
+        baselines,
+        scrollbar_size,
+        replaced_content_rect: None,
+        marker_rect: None,
         top_margin: CollapsibleMarginSet::ZERO,
         bottom_margin: CollapsibleMarginSet::ZERO,
         margins_can_collapse_through: !has_styles_preventing_being_collapsed_through
@@ -182,3 +259,248 @@ where
             && measured_size.height == 0.0,
     }
 }
+
+/// The width of a replaced node's content box when neither its styles nor its content determine a width
+const FALLBACK_REPLACED_WIDTH: f32 = 300.0;
+/// The height of a replaced node's content box when neither its styles nor its content determine a height
+const FALLBACK_REPLACED_HEIGHT: f32 = 150.0;
+
+/// Compute the size of a replaced leaf node (such as an image or a video) from the natural dimensions of its content
+///
+/// This is an alternative to [`compute_leaf_layout`] for nodes whose content has a natural size rather than being
+/// measured. It implements the CSS sizing algorithm for replaced elements:
+///   - An `auto` width or height is taken from the size in the other axis through the preferred aspect ratio (which is
+///     the natural aspect ratio of the content unless overridden by the `aspect_ratio` style), or else from the
+///     natural size of the content, or else defaults to 300x150
+///   - If both the width and height are `auto` and the node has a preferred aspect ratio, then the min and max sizes
+///     are resolved in a way that preserves the ratio where possible, as described in
+///     <https://www.w3.org/TR/CSS22/visudet.html#min-max-widths>
+///
+/// The rectangle in which the content is painted (according to the `object_fit` and `object_position` styles) is
+/// returned as [`LayoutOutput::replaced_content_rect`].
+///
+/// See <https://www.w3.org/TR/CSS22/visudet.html#inline-replaced-width> and
+/// <https://www.w3.org/TR/CSS22/visudet.html#inline-replaced-height>
+pub fn compute_replaced_leaf_layout(
+    inputs: LayoutInput,
+    style: &impl CoreStyle,
+    resolve_calc_value: impl Fn(*const (), f32) -> f32,
+    content: ReplacedContent,
+) -> LayoutOutput {
+    let LayoutInput { known_dimensions, parent_size, available_space, sizing_mode, .. } = inputs;
+
+    let margin = style.margin().resolve_or_zero(parent_size.width, &resolve_calc_value);
+    let padding = style.padding().resolve_or_zero(parent_size.width, &resolve_calc_value);
+    let border = style.border().resolve_or_zero(parent_size.width, &resolve_calc_value);
+    let pb_sum = (padding + border).sum_axes();
+    let box_sizing_adjustment = if style.box_sizing() == BoxSizing::ContentBox { pb_sum } else { Size::ZERO };
+
+    // Scrollbar gutters are reserved in the same way as for other leaf nodes (see `compute_leaf_layout`)
+    let direction = style.direction();
+    let scrollbar_gutter = compute_scrollbar_gutter(
+        style.overflow(),
+        style.scrollbar_gutter(),
+        style.scrollbar_width(),
+        direction,
+        Point { x: false, y: false },
+    );
+    let scrollbar_size = scrollbar_size(scrollbar_gutter, direction);
+    let content_box_inset = padding + border + scrollbar_gutter;
+    let content_box_inset_sum = content_box_inset.sum_axes();
+
+    // A node with size containment is sized as if its content had no natural aspect ratio, and had the natural size
+    // given by its `contain_intrinsic_size` style in the contained axes
+    let contained_axes = style.contain().size_contained_axes();
+    let contain_intrinsic_size = style.contain_intrinsic_size();
+    let is_size_contained = contained_axes.width || contained_axes.height;
+    let natural_ratio = if is_size_contained { None } else { content.resolved_natural_ratio() };
+    let natural_size = Size {
+        width: if contained_axes.width {
+            Some(contain_intrinsic_size.width.resolve(None))
+        } else {
+            content.natural_size.width
+        },
+        height: if contained_axes.height {
+            Some(contain_intrinsic_size.height.resolve(None))
+        } else {
+            content.natural_size.height
+        },
+    };
+
+    // The content is painted inside of the scrollbar gutters, so its natural ratio applies to the box inside of them
+    let aspect_ratio =
+        PreferredAspectRatio::resolve(style.aspect_ratio(), natural_ratio, style.box_sizing(), content_box_inset_sum);
+
+    // For ContentSize mode, we pretend that the node has no size styles as these should be ignored.
+    let (style_size, min_size, max_size) = match sizing_mode {
+        SizingMode::ContentSize => (Size::NONE, Size::NONE, Size::NONE),
+        SizingMode::InherentSize => (
+            style.size().maybe_resolve(parent_size, &resolve_calc_value).maybe_add(box_sizing_adjustment),
+            style.min_size().maybe_resolve(parent_size, &resolve_calc_value).maybe_add(box_sizing_adjustment),
+            style.max_size().maybe_resolve(parent_size, &resolve_calc_value).maybe_add(box_sizing_adjustment),
+        ),
+    };
+
+    // The border box can not be smaller than the padding and border, and a max size smaller than the corresponding
+    // min size is treated as being equal to it
+    let min_size = min_size.unwrap_or(Size::ZERO).zip_map(pb_sum, f32_max);
+    let max_size = max_size.unwrap_or(Size { width: f32::INFINITY, height: f32::INFINITY }).zip_map(min_size, f32_max);
+    let clamp = |size: Size<f32>| size.zip_map(max_size, f32_min).zip_map(min_size, f32_max);
+
+    // Natural and fallback sizes are sizes of the content box
+    let natural_size = natural_size.maybe_add(content_box_inset_sum);
+    let fallback_size =
+        Size { width: FALLBACK_REPLACED_WIDTH, height: FALLBACK_REPLACED_HEIGHT } + content_box_inset_sum;
+
+    let size = match (known_dimensions.or(style_size), aspect_ratio) {
+        (Size { width: Some(width), height: Some(height) }, _) => clamp(Size { width, height }),
+        (Size { width: Some(width), height: None }, _) => {
+            let width = f32_min(f32_max(width, min_size.width), max_size.width);
+            let height = match aspect_ratio {
+                Some(aspect_ratio) => aspect_ratio.height_from_width(width),
+                None => natural_size.height.unwrap_or(fallback_size.height),
+            };
+            clamp(Size { width, height })
+        }
+        (Size { width: None, height: Some(height) }, _) => {
+            let height = f32_min(f32_max(height, min_size.height), max_size.height);
+            let width = match aspect_ratio {
+                Some(aspect_ratio) => aspect_ratio.width_from_height(height),
+                None => natural_size.width.unwrap_or(fallback_size.width),
+            };
+            clamp(Size { width, height })
+        }
+        (Size { width: None, height: None }, Some(aspect_ratio)) => {
+            let size = match natural_size {
+                Size { width: Some(width), .. } => Size { width, height: aspect_ratio.height_from_width(width) },
+                Size { width: None, height: Some(height) } => {
+                    Size { width: aspect_ratio.width_from_height(height), height }
+                }
+                // Content with only a natural aspect ratio stretches to fill the available width
+                Size { width: None, height: None } => {
+                    let width = available_space
+                        .width
+                        .into_option()
+                        .map(|width| f32_max(width - margin.horizontal_axis_sum(), 0.0))
+                        .unwrap_or(fallback_size.width);
+                    Size { width, height: aspect_ratio.height_from_width(width) }
+                }
+            };
+            clamp_preserving_aspect_ratio(size, min_size, max_size, aspect_ratio)
+        }
+        (Size { width: None, height: None }, None) => clamp(natural_size.unwrap_or(fallback_size)),
+    };
+
+    let content_box_size = (size - content_box_inset_sum).map(|value| f32_max(value, 0.0));
+    let object_size = concrete_object_size(style.object_fit(), content, content_box_size);
+    let object_position = style.object_position();
+    let object_offset = Point {
+        x: object_position.x.resolve_or_zero(Some(content_box_size.width - object_size.width), &resolve_calc_value),
+        y: object_position.y.resolve_or_zero(Some(content_box_size.height - object_size.height), &resolve_calc_value),
+    };
+    let replaced_content_rect = Rect {
+        left: content_box_inset.left + object_offset.x,
+        right: content_box_inset.left + object_offset.x + object_size.width,
+        top: content_box_inset.top + object_offset.y,
+        bottom: content_box_inset.top + object_offset.y + object_size.height,
+    };
+
+    debug_log!("REPLACED LEAF");
+    debug_log!("size", dbg:size);
+    debug_log!("replaced_content_rect", dbg:replaced_content_rect);
+
+    LayoutOutput {
+        scrollbar_size,
+        replaced_content_rect: Some(replaced_content_rect),
+        ..LayoutOutput::from_outer_size(size)
+    }
+}
+
+/// Computes the size at which the content of a replaced node is painted within the node's content box, according to
+/// its `object_fit` style
+///
+/// See <https://www.w3.org/TR/css-images-3/#the-object-fit>
+fn concrete_object_size(object_fit: ObjectFit, content: ReplacedContent, content_box_size: Size<f32>) -> Size<f32> {
+    let natural_ratio = content.resolved_natural_ratio().filter(|ratio| ratio.is_finite() && *ratio > 0.0);
+
+    // The largest size with the natural aspect ratio that fits within the content box, or the smallest size that
+    // covers it
+    let fit_to_content_box = |cover: bool| match natural_ratio {
+        Some(ratio) => {
+            let is_wider_than_content = content_box_size.width > content_box_size.height * ratio;
+            if is_wider_than_content == cover {
+                Size { width: content_box_size.width, height: content_box_size.width / ratio }
+            } else {
+                Size { width: content_box_size.height * ratio, height: content_box_size.height }
+            }
+        }
+        None => content_box_size,
+    };
+    // The natural size of the content, with any missing dimensions taken from the natural aspect ratio or the
+    // content box
+    let natural_size = || match (content.natural_size, natural_ratio) {
+        (Size { width: Some(width), height: Some(height) }, _) => Size { width, height },
+        (Size { width: Some(width), height: None }, Some(ratio)) => Size { width, height: width / ratio },
+        (Size { width: None, height: Some(height) }, Some(ratio)) => Size { width: height * ratio, height },
+        (Size { width: None, height: None }, Some(_)) => fit_to_content_box(false),
+        (natural_size, None) => natural_size.unwrap_or(content_box_size),
+    };
+
+    match object_fit {
+        ObjectFit::Fill => content_box_size,
+        ObjectFit::Contain => fit_to_content_box(false),
+        ObjectFit::Cover => fit_to_content_box(true),
+        ObjectFit::None => natural_size(),
+        ObjectFit::ScaleDown => {
+            let natural_size = natural_size();
+            let contained_size = fit_to_content_box(false);
+            if natural_size.width <= contained_size.width && natural_size.height <= contained_size.height {
+                natural_size
+            } else {
+                contained_size
+            }
+        }
+    }
+}
+
+/// Clamps the size of a replaced node whose width and height are both `auto` by its min and max sizes, while
+/// preserving its aspect ratio where possible
+///
+/// See the table in <https://www.w3.org/TR/CSS22/visudet.html#min-max-widths>
+fn clamp_preserving_aspect_ratio(
+    size: Size<f32>,
+    min_size: Size<f32>,
+    max_size: Size<f32>,
+    aspect_ratio: PreferredAspectRatio,
+) -> Size<f32> {
+    let Size { width, height } = size;
+    let too_wide = width > max_size.width;
+    let too_narrow = width < min_size.width;
+    let too_tall = height > max_size.height;
+    let too_short = height < min_size.height;
+
+    let (width, height) = match (too_wide, too_narrow, too_tall, too_short) {
+        // Whichever axis must shrink the most determines the size
+        (true, _, true, _) if max_size.width * height <= max_size.height * width => {
+            (max_size.width, f32_max(min_size.height, aspect_ratio.height_from_width(max_size.width)))
+        }
+        (true, _, true, _) => {
+            (f32_max(min_size.width, aspect_ratio.width_from_height(max_size.height)), max_size.height)
+        }
+        // Whichever axis must grow the most determines the size
+        (_, true, _, true) if min_size.width * height <= min_size.height * width => {
+            (f32_min(max_size.width, aspect_ratio.width_from_height(min_size.height)), min_size.height)
+        }
+        (_, true, _, true) => {
+            (min_size.width, f32_min(max_size.height, aspect_ratio.height_from_width(min_size.width)))
+        }
+        (_, true, true, _) => (min_size.width, max_size.height),
+        (true, _, _, true) => (max_size.width, min_size.height),
+        (true, _, _, _) => (max_size.width, f32_max(aspect_ratio.height_from_width(max_size.width), min_size.height)),
+        (_, true, _, _) => (min_size.width, f32_min(aspect_ratio.height_from_width(min_size.width), max_size.height)),
+        (_, _, true, _) => (f32_max(aspect_ratio.width_from_height(max_size.height), min_size.width), max_size.height),
+        (_, _, _, true) => (f32_min(aspect_ratio.width_from_height(min_size.height), max_size.width), min_size.height),
+        _ => (width, height),
+    };
+    Size { width, height }
+}
@@ -9,8 +9,13 @@
 //! | [`compute_flexbox_layout`]        | Layout a Flexbox container and it's direct children                                                                                                                                                |
 //! | [`compute_grid_layout`]           | Layout a CSS Grid container and it's direct children                                                                                                                                               |
 //! | [`compute_block_layout`]          | Layout a Block container and it's direct children                                                                                                                                                  |
+//! | [`compute_ruby_layout`]           | Layout a Ruby container and it's direct children (ruby bases and their annotations)                                                                                                                |
 //! | [`compute_leaf_layout`]           | Applies common properties like padding/border/aspect-ratio to a node before deferring to a passed closure to determine it's size. Can be applied to nodes like text or image nodes.                |
+//! | [`compute_leaf_layout_with_baselines`] | Like [`compute_leaf_layout`], but the passed closure also determines the baselines of the node's content (e.g. the first and last lines of text).                                            |
+//! | [`compute_leaf_layout_with_natural_aspect_ratio`] | Like [`compute_leaf_layout_with_baselines`], but is also passed the natural aspect ratio of the node's content (e.g. an image), which may be used in place of its `aspect_ratio` style. |
+//! | [`compute_replaced_leaf_layout`]  | Applies the CSS sizing rules for replaced elements to a node, given the natural size and aspect ratio of its content (e.g. an image or video) rather than a closure to measure it. |
 //! | [`compute_root_layout`]           | Layout the root node of a tree (regardless of it's layout mode). This function is typically called once to begin a layout run.                                                                     |                                                                      |
+//! | [`compute_intrinsic_sizes`]       | Compute the min-content, max-content and fit-content contributions of a node (regardless of it's layout mode) without laying it out.                                                              |
 //! | [`compute_hidden_layout`]         | Mark a node as hidden during layout (like `Display::None`)                                                                                                                                         |
 //! | [`compute_cached_layout`]         | Attempts to find a cached layout for the specified node and layout inputs. Uses the provided closure to compute the layout (and then stores the result in the cache) if no cached layout is found. |
 //!
@@ -27,6 +32,9 @@ pub(crate) mod leaf;
 #[cfg(feature = "block_layout")]
 pub(crate) mod block;
 
+#[cfg(feature = "block_layout")]
+pub(crate) mod ruby;
+
 #[cfg(feature = "float_layout")]
 pub(crate) mod float;
 
@@ -36,11 +44,17 @@ pub(crate) mod flexbox;
 #[cfg(feature = "grid")]
 pub(crate) mod grid;
 
-pub use leaf::compute_leaf_layout;
+pub use leaf::{
+    compute_leaf_layout, compute_leaf_layout_with_baselines, compute_leaf_layout_with_natural_aspect_ratio,
+    compute_replaced_leaf_layout,
+};
 
 #[cfg(feature = "block_layout")]
 pub use self::block::{compute_block_layout, BlockContext, BlockFormattingContext};
 
+#[cfg(feature = "block_layout")]
+pub use self::ruby::compute_ruby_layout;
+
 #[cfg(feature = "flexbox")]
 pub use self::flexbox::compute_flexbox_layout;
 
@@ -50,13 +64,14 @@ pub use self::grid::compute_grid_layout;
 #[cfg(feature = "float_layout")]
 pub use self::float::{BfcSlot, ContentSlot, FloatContext, FloatIntrinsicWidthCalculator};
 
-use crate::geometry::{Line, Point, Size};
-use crate::style::{AvailableSpace, CoreStyle, Overflow};
+use crate::geometry::{AbsoluteAxis, Line, Point, Rect, Size};
+use crate::style::{AvailableSpace, CoreStyle};
 use crate::tree::{
-    Layout, LayoutInput, LayoutOutput, LayoutPartialTree, LayoutPartialTreeExt, NodeId, RoundTree, SizingMode,
+    IntrinsicSizes, Layout, LayoutInput, LayoutOutput, LayoutPartialTree, LayoutPartialTreeExt, NodeId, RequestedAxis,
+    RoundTree, SizingMode,
 };
 use crate::util::debug::{debug_log, debug_log_node, debug_pop_node, debug_push_node};
-use crate::util::sys::round;
+use crate::util::sys::{f32_max, f32_min, round};
 use crate::util::ResolveOrZero;
 use crate::{CacheTree, MaybeMath, MaybeResolve};
 
@@ -66,6 +81,7 @@ pub fn compute_root_layout(tree: &mut impl LayoutPartialTree, root: NodeId, avai
 
     #[cfg(feature = "block_layout")]
     {
+        use crate::compute::common::aspect_ratio::{transfer_sizes, PreferredAspectRatio};
         use crate::BoxSizing;
 
         let parent_size = available_space.into_options();
@@ -73,30 +89,28 @@ pub fn compute_root_layout(tree: &mut impl LayoutPartialTree, root: NodeId, avai
 
         if style.is_block() {
             // Pull these out earlier to avoid borrowing issues
-            let aspect_ratio = style.aspect_ratio();
             let margin = style.margin().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
             let padding = style.padding().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
             let border = style.border().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
             let padding_border_size = (padding + border).sum_axes();
             let box_sizing_adjustment =
                 if style.box_sizing() == BoxSizing::ContentBox { padding_border_size } else { Size::ZERO };
+            let aspect_ratio = PreferredAspectRatio::from_style(&style, padding_border_size);
 
             let min_size = style
                 .min_size()
                 .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
-                .maybe_apply_aspect_ratio(aspect_ratio)
                 .maybe_add(box_sizing_adjustment);
             let max_size = style
                 .max_size()
                 .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
-                .maybe_apply_aspect_ratio(aspect_ratio)
                 .maybe_add(box_sizing_adjustment);
-            let clamped_style_size = style
+            let style_size = style
                 .size()
                 .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
-                .maybe_apply_aspect_ratio(aspect_ratio)
-                .maybe_add(box_sizing_adjustment)
-                .maybe_clamp(min_size, max_size);
+                .maybe_add(box_sizing_adjustment);
+            let (style_size, min_size, max_size) = transfer_sizes(aspect_ratio, style_size, min_size, max_size);
+            let clamped_style_size = style_size.maybe_clamp(min_size, max_size);
 
             // If both min and max in a given axis are set and max <= min then this determines the size in that axis
             let min_max_definite_size = min_size.zip_map(max_size, |min, max| match (min, max) {
@@ -136,10 +150,6 @@ pub fn compute_root_layout(tree: &mut impl LayoutPartialTree, root: NodeId, avai
         style.border().resolve_or_zero(available_space.width.into_option(), |val, basis| tree.calc(val, basis));
     let margin =
         style.margin().resolve_or_zero(available_space.width.into_option(), |val, basis| tree.calc(val, basis));
-    let scrollbar_size = Size {
-        width: if style.overflow().y == Overflow::Scroll { style.scrollbar_width() } else { 0.0 },
-        height: if style.overflow().x == Overflow::Scroll { style.scrollbar_width() } else { 0.0 },
-    };
     let location = Point {
         x: if style.direction().is_rtl() {
             available_space.width.into_option().map_or(0.0, |available_width| available_width - output.size.width)
@@ -158,7 +168,9 @@ pub fn compute_root_layout(tree: &mut impl LayoutPartialTree, root: NodeId, avai
             size: output.size,
             #[cfg(feature = "content_size")]
             scrollable_overflow_rect: output.scrollable_overflow_rect,
-            scrollbar_size,
+            scrollbar_size: output.scrollbar_size,
+            replaced_content_rect: output.replaced_content_rect,
+            marker_rect: output.marker_rect,
             padding,
             border,
             // TODO: support auto margins for root node?
@@ -167,6 +179,102 @@ pub fn compute_root_layout(tree: &mut impl LayoutPartialTree, root: NodeId, avai
     );
 }
 
+/// Compute the intrinsic size contributions of a node in the requested axis (or both axes), given the space available
+/// to it
+///
+/// Percentages (including percentage margins) are resolved against the available space where it is definite. The
+/// width contributions are computed with `available_space.height` available in the vertical axis, and the height
+/// contributions are computed with the node's width set to its fit-content width. Only the measurements needed for
+/// the requested axis are made: the contributions in an axis that was not requested are zero.
+///
+/// The node's sizes are computed using [`RunMode::ComputeSize`](crate::RunMode::ComputeSize), so its cache is used
+/// and no layouts are set. This can be called at any time: it doesn't require the tree to have been laid out.
+pub fn compute_intrinsic_sizes(
+    tree: &mut impl LayoutPartialTree,
+    node: NodeId,
+    available_space: Size<AvailableSpace>,
+    axis: RequestedAxis,
+) -> Size<IntrinsicSizes> {
+    const NOT_REQUESTED: IntrinsicSizes = IntrinsicSizes { min_content: 0.0, max_content: 0.0, fit_content: 0.0 };
+
+    let parent_size = available_space.into_options();
+    let style = tree.get_core_container_style(node);
+    let margin = style.margin().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis)).sum_axes();
+    drop(style);
+
+    let mut measure = |known_dimensions: Size<Option<f32>>, available_space: Size<AvailableSpace>, axis| {
+        tree.measure_child_size(
+            node,
+            known_dimensions,
+            parent_size,
+            available_space,
+            SizingMode::InherentSize,
+            axis,
+            Line::FALSE,
+        )
+    };
+
+    // The height contributions only depend on the fit-content width, which only depends on one of the min-content
+    // and max-content widths unless the available width is definite
+    let width_is_requested = axis != RequestedAxis::Vertical;
+    let min_content_width = if width_is_requested || available_space.width != AvailableSpace::MaxContent {
+        measure(
+            Size::NONE,
+            Size { width: AvailableSpace::MinContent, height: available_space.height },
+            AbsoluteAxis::Horizontal,
+        ) + margin.width
+    } else {
+        0.0
+    };
+    let max_content_width = if width_is_requested || available_space.width != AvailableSpace::MinContent {
+        measure(
+            Size::NONE,
+            Size { width: AvailableSpace::MaxContent, height: available_space.height },
+            AbsoluteAxis::Horizontal,
+        ) + margin.width
+    } else {
+        0.0
+    };
+    let width = IntrinsicSizes {
+        min_content: min_content_width,
+        max_content: max_content_width,
+        fit_content: fit_content_contribution(min_content_width, max_content_width, available_space.width),
+    };
+    if axis == RequestedAxis::Horizontal {
+        return Size { width, height: NOT_REQUESTED };
+    }
+
+    let known_width = f32_max(width.fit_content - margin.width, 0.0);
+    let known_dimensions = Size { width: Some(known_width), height: None };
+    let min_content_height = measure(
+        known_dimensions,
+        Size { width: AvailableSpace::Definite(known_width), height: AvailableSpace::MinContent },
+        AbsoluteAxis::Vertical,
+    ) + margin.height;
+    let max_content_height = measure(
+        known_dimensions,
+        Size { width: AvailableSpace::Definite(known_width), height: AvailableSpace::MaxContent },
+        AbsoluteAxis::Vertical,
+    ) + margin.height;
+    let height = IntrinsicSizes {
+        min_content: min_content_height,
+        max_content: max_content_height,
+        fit_content: fit_content_contribution(min_content_height, max_content_height, available_space.height),
+    };
+
+    Size { width: if width_is_requested { width } else { NOT_REQUESTED }, height }
+}
+
+/// The fit-content contribution of a node with the given min-content and max-content contributions
+#[inline]
+fn fit_content_contribution(min_content: f32, max_content: f32, available_space: AvailableSpace) -> f32 {
+    match available_space {
+        AvailableSpace::Definite(available_space) => f32_max(min_content, f32_min(available_space, max_content)),
+        AvailableSpace::MinContent => min_content,
+        AvailableSpace::MaxContent => max_content,
+    }
+}
+
 /// Attempts to find a cached layout for the specified node and layout inputs.
 ///
 /// Uses the provided closure to compute the layout (and then stores the result in the cache) if no cached layout is found.
@@ -217,7 +325,13 @@ where
 /// In order to prevent innacuracies caused by rounding already-rounded values, we read from `unrounded_layout`
 /// and write to `final_layout`.
 pub fn round_layout(tree: &mut impl RoundTree, node_id: NodeId) {
-    return round_layout_inner(tree, node_id, 0.0, 0.0);
+    round_subtree_layout(tree, node_id, Point::ZERO)
+}
+
+/// Rounds the calculated layout of a subtree whose root's parent has its (unrounded) border box at `parent_location`
+/// relative to the root of the tree. See [`round_layout`]
+pub(crate) fn round_subtree_layout(tree: &mut impl RoundTree, node_id: NodeId, parent_location: Point<f32>) {
+    return round_layout_inner(tree, node_id, parent_location.x, parent_location.y);
 
     /// Recursive function to apply rounding to all descendents
     fn round_layout_inner(tree: &mut impl RoundTree, node_id: NodeId, cumulative_x: f32, cumulative_y: f32) {
@@ -246,6 +360,19 @@ pub fn round_layout(tree: &mut impl RoundTree, node_id: NodeId) {
         layout.padding.bottom = round(cumulative_y + unrounded_layout.size.height)
             - round(cumulative_y + unrounded_layout.size.height - unrounded_layout.padding.bottom);
 
+        layout.replaced_content_rect = unrounded_layout.replaced_content_rect.map(|rect| Rect {
+            left: round(cumulative_x + rect.left) - round(cumulative_x),
+            right: round(cumulative_x + rect.right) - round(cumulative_x),
+            top: round(cumulative_y + rect.top) - round(cumulative_y),
+            bottom: round(cumulative_y + rect.bottom) - round(cumulative_y),
+        });
+        layout.marker_rect = unrounded_layout.marker_rect.map(|rect| Rect {
+            left: round(cumulative_x + rect.left) - round(cumulative_x),
+            right: round(cumulative_x + rect.right) - round(cumulative_x),
+            top: round(cumulative_y + rect.top) - round(cumulative_y),
+            bottom: round(cumulative_y + rect.bottom) - round(cumulative_y),
+        });
+
         #[cfg(feature = "content_size")]
         round_scrollable_overflow_rect(
             &mut layout,
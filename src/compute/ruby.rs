@@ -0,0 +1,390 @@
+//! Computes the layout of ruby containers, which place ruby annotations (such as furigana) above the ruby bases that
+//! they annotate.
+//!
+//! <https://www.w3.org/TR/css-ruby-1/>
+//!
+//! Taffy only supports horizontal writing modes, so annotations are always placed above their bases. Each pair of a base
+//! and its annotation is laid out as an unbreakable unit, and the pairs of a ruby container are never broken across
+//! lines.
+use crate::compute::common::aspect_ratio::{transfer_sizes, PreferredAspectRatio};
+#[cfg(feature = "content_size")]
+use crate::compute::common::scrollable_overflow::{compute_scrollable_overflow_contribution, resolve_transform};
+use crate::geometry::{AbsoluteAxis, Line, Point, Rect, Size};
+#[cfg(feature = "content_size")]
+use crate::style::{Contain, LengthPercentage, Overflow, Transform};
+use crate::style::{CoreStyle, LengthPercentageAuto};
+use crate::style_helpers::TaffyMaxContent;
+use crate::tree::{Baselines, Layout, LayoutInput, LayoutOutput, RequestedAxis, RunMode, SizingMode};
+use crate::tree::{LayoutPartialTreeExt, NodeId};
+use crate::util::debug::debug_log;
+use crate::util::sys::{f32_max, Vec};
+use crate::util::MaybeMath;
+use crate::util::{MaybeResolve, ResolveOrZero};
+use crate::{
+    BlockContainerStyle, BlockItemStyle, BoxGenerationMode, BoxSizing, Direction, LayoutBlockContainer, RubyAlign,
+};
+
+/// A ruby base or ruby annotation
+struct RubyBox {
+    /// The id of the node
+    node_id: NodeId,
+    /// The order of the node relative to its siblings
+    order: u32,
+    /// The resolved margins of the node. Auto margins are treated as zero.
+    margin: Rect<f32>,
+    /// The resolved padding of the node
+    padding: Rect<f32>,
+    /// The resolved border of the node
+    border: Rect<f32>,
+    /// The node's inset style, which offsets relatively positioned boxes from their position in the pair
+    inset: Rect<LengthPercentageAuto>,
+    /// Whether the node has an auto width, which allows it to be stretched to the width of its pair
+    has_auto_width: bool,
+    /// The node's resolved min width
+    min_width: Option<f32>,
+    /// The node's resolved max width
+    max_width: Option<f32>,
+    /// The overflow style of the node
+    #[cfg(feature = "content_size")]
+    overflow: Point<Overflow>,
+    /// The containment style of the node
+    #[cfg(feature = "content_size")]
+    contain: Contain,
+    /// The transform style of the node
+    #[cfg(feature = "content_size")]
+    transform: Transform,
+    /// The transform origin style of the node
+    #[cfg(feature = "content_size")]
+    transform_origin: Point<LengthPercentage>,
+
+    /// The width of the node when sized to its content
+    content_width: f32,
+    /// The output of the node's layout
+    output: LayoutOutput,
+}
+
+impl RubyBox {
+    /// The size of the node's margin box
+    fn outer_size(&self) -> Size<f32> {
+        self.output.size + self.margin.sum_axes()
+    }
+
+    /// The distance from the top of the node's margin box to its first baseline (or to the bottom of its margin box if
+    /// it has no baseline)
+    fn ascent(&self) -> f32 {
+        self.output.baselines.y.first.map_or(self.outer_size().height, |baseline| self.margin.top + baseline)
+    }
+}
+
+/// A ruby base and the annotation that annotates it
+#[derive(Default)]
+struct RubyPair {
+    /// The ruby base. This is `None` for an annotation that doesn't follow a base.
+    base: Option<RubyBox>,
+    /// The ruby annotation, if the base is annotated
+    annotation: Option<RubyBox>,
+    /// The width of the pair: the width of the wider of the base and the annotation
+    width: f32,
+}
+
+/// The size of a row of ruby bases or ruby annotations, in which the boxes are aligned by their baselines
+#[derive(Default, Clone, Copy)]
+struct RowMetrics {
+    /// The distance from the top of the row to the baseline that its boxes are aligned to
+    ascent: f32,
+    /// The distance from the baseline of the row to its bottom
+    descent: f32,
+}
+
+impl RowMetrics {
+    /// Compute the size of the row containing the specified boxes
+    fn of<'a>(boxes: impl Iterator<Item = &'a RubyBox>) -> Self {
+        boxes.fold(RowMetrics::default(), |row, rbox| RowMetrics {
+            ascent: f32_max(row.ascent, rbox.ascent()),
+            descent: f32_max(row.descent, rbox.outer_size().height - rbox.ascent()),
+        })
+    }
+
+    /// The height of the row
+    fn height(self) -> f32 {
+        self.ascent + self.descent
+    }
+}
+
+/// Computes the layout of a ruby container (a [`Display::Ruby`](crate::Display::Ruby) node) and its children.
+///
+/// The children of the container are grouped into pairs of a ruby base and an optional ruby annotation (a child for
+/// which [`BlockItemStyle::is_ruby_text`] returns `true`), which annotates the base that it follows. The pairs are placed
+/// side by side in the inline direction, and each pair is as wide as the wider of its base and annotation. The narrower
+/// of the two is distributed within the pair according to the container's [`BlockContainerStyle::ruby_align`].
+///
+/// Ruby bases and annotations are shrink-to-fit sized, and are typically leaf nodes whose size is determined by a
+/// measure function. Annotations are placed in a row above the bases, and the boxes in each row are aligned by their
+/// first baselines. The baselines of the container are those of its row of bases.
+pub fn compute_ruby_layout(tree: &mut impl LayoutBlockContainer, node_id: NodeId, inputs: LayoutInput) -> LayoutOutput {
+    let LayoutInput { known_dimensions, parent_size, run_mode, sizing_mode, .. } = inputs;
+    let style = tree.get_block_container_style(node_id);
+
+    let padding = style.padding().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
+    let border = style.border().resolve_or_zero(parent_size.width, |val, basis| tree.calc(val, basis));
+    let padding_border = padding + border;
+    let padding_border_size = padding_border.sum_axes();
+    let box_sizing_adjustment =
+        if style.box_sizing() == BoxSizing::ContentBox { padding_border_size } else { Size::ZERO };
+    let aspect_ratio = PreferredAspectRatio::from_style(&style, padding_border_size);
+    let (style_size, min_size, max_size) = transfer_sizes(
+        aspect_ratio,
+        style.size().maybe_resolve(parent_size, |val, basis| tree.calc(val, basis)).maybe_add(box_sizing_adjustment),
+        style
+            .min_size()
+            .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+            .maybe_add(box_sizing_adjustment),
+        style
+            .max_size()
+            .maybe_resolve(parent_size, |val, basis| tree.calc(val, basis))
+            .maybe_add(box_sizing_adjustment),
+    );
+    let clamped_style_size =
+        if sizing_mode == SizingMode::InherentSize { style_size.maybe_clamp(min_size, max_size) } else { Size::NONE };
+    let known_dimensions = known_dimensions.or(clamped_style_size).maybe_max(padding_border_size);
+
+    let direction = style.direction();
+    let ruby_align = style.ruby_align();
+    let contain = style.contain();
+    #[cfg(feature = "content_size")]
+    let is_scroll_container = style.overflow().x.is_scroll_container() || style.overflow().y.is_scroll_container();
+    drop(style);
+
+    // Short-circuit layout if the container's size is fully determined by its styles
+    if run_mode == RunMode::ComputeSize {
+        if let Size { width: Some(width), height: Some(height) } = known_dimensions {
+            return LayoutOutput::from_outer_size(Size { width, height });
+        }
+    }
+
+    debug_log!("RUBY");
+    let inner_size = known_dimensions.maybe_sub(padding_border_size);
+
+    // 1. Group the children into pairs and measure their content widths
+    let mut pairs = generate_pairs(tree, node_id, inner_size);
+    for pair in pairs.iter_mut() {
+        for rbox in pair.base.iter_mut().chain(pair.annotation.iter_mut()) {
+            rbox.content_width = tree.measure_child_size(
+                rbox.node_id,
+                Size::NONE,
+                inner_size,
+                Size::MAX_CONTENT,
+                SizingMode::InherentSize,
+                AbsoluteAxis::Horizontal,
+                Line::FALSE,
+            );
+            pair.width = f32_max(pair.width, rbox.content_width + rbox.margin.horizontal_axis_sum());
+        }
+    }
+
+    // 2. Size the bases and annotations. Unless `ruby-align` places it at the start or the center of its pair, the
+    // narrower box of a pair is stretched to the width of the pair so that its content can be justified across it.
+    let stretches = matches!(ruby_align, RubyAlign::SpaceBetween | RubyAlign::SpaceAround);
+    for pair in pairs.iter_mut() {
+        for rbox in pair.base.iter_mut().chain(pair.annotation.iter_mut()) {
+            let width = if stretches && rbox.has_auto_width {
+                (pair.width - rbox.margin.horizontal_axis_sum()).maybe_clamp(rbox.min_width, rbox.max_width)
+            } else {
+                rbox.content_width
+            };
+            rbox.output = tree.compute_child_layout(
+                rbox.node_id,
+                LayoutInput {
+                    run_mode,
+                    sizing_mode: SizingMode::InherentSize,
+                    axis: RequestedAxis::Both,
+                    known_dimensions: Size { width: Some(width), height: None },
+                    known_dimensions_are_definite: Size { width: true, height: true },
+                    parent_size: inner_size,
+                    available_space: Size::MAX_CONTENT,
+                    vertical_margins_are_collapsible: Line::FALSE,
+                },
+            );
+        }
+    }
+
+    // 3. Determine the size and baselines of the container
+    let annotation_row = RowMetrics::of(pairs.iter().filter_map(|pair| pair.annotation.as_ref()));
+    let base_row = RowMetrics::of(pairs.iter().filter_map(|pair| pair.base.as_ref()));
+    let content_size = Size {
+        width: pairs.iter().map(|pair| pair.width).sum::<f32>(),
+        height: annotation_row.height() + base_row.height(),
+    };
+    let size = known_dimensions
+        .unwrap_or((content_size + padding_border_size).maybe_clamp(min_size, max_size))
+        .zip_map(padding_border_size, f32_max);
+
+    let annotation_row_top = padding_border.top;
+    let base_row_top = annotation_row_top + annotation_row.height();
+    let has_bases = pairs.iter().any(|pair| pair.base.is_some());
+    let baseline = (has_bases && !contain.suppresses_baseline()).then_some(base_row_top + base_row.ascent);
+    let baselines = Point { x: Baselines::NONE, y: Baselines { first: baseline, last: baseline } };
+
+    if run_mode == RunMode::ComputeSize {
+        return LayoutOutput::from_sizes_and_baselines(size, Rect::ZERO, baselines);
+    }
+
+    // 4. Position the bases and annotations within their pairs
+    #[cfg_attr(not(feature = "content_size"), allow(unused_mut))]
+    let mut scrollable_overflow_rect = Rect::ZERO;
+    let mut pair_offset = 0.0;
+    for pair in pairs.iter() {
+        let rows = [
+            (pair.base.as_ref(), base_row_top, base_row),
+            (pair.annotation.as_ref(), annotation_row_top, annotation_row),
+        ];
+        for (rbox, row_top, row) in rows {
+            let Some(rbox) = rbox else { continue };
+            let outer_size = rbox.outer_size();
+            let free_space = f32_max(0.0, pair.width - outer_size.width);
+            let align_offset = match ruby_align {
+                RubyAlign::Start => 0.0,
+                RubyAlign::Center | RubyAlign::SpaceBetween | RubyAlign::SpaceAround => free_space / 2.0,
+            };
+            let inline_offset = pair_offset + align_offset;
+            let static_position = Point {
+                x: match direction {
+                    Direction::Ltr => padding_border.left + inline_offset + rbox.margin.left,
+                    Direction::Rtl => {
+                        size.width - padding_border.right - inline_offset - rbox.margin.right - rbox.output.size.width
+                    }
+                },
+                y: row_top + row.ascent - rbox.ascent() + rbox.margin.top,
+            };
+
+            // Relatively positioned boxes are offset from their position in the pair
+            let inset = rbox.inset.zip_size(inner_size, |p, s| p.maybe_resolve(s, |val, basis| tree.calc(val, basis)));
+            let inset_offset = Point {
+                x: if direction.is_rtl() {
+                    inset.right.map(|x| -x).or(inset.left).unwrap_or(0.0)
+                } else {
+                    inset.left.or(inset.right.map(|x| -x)).unwrap_or(0.0)
+                },
+                y: inset.top.or(inset.bottom.map(|x| -x)).unwrap_or(0.0),
+            };
+            let location = static_position + inset_offset;
+
+            tree.set_unrounded_layout(
+                rbox.node_id,
+                &Layout {
+                    order: rbox.order,
+                    size: rbox.output.size,
+                    #[cfg(feature = "content_size")]
+                    scrollable_overflow_rect: rbox.output.scrollable_overflow_rect,
+                    scrollbar_size: rbox.output.scrollbar_size,
+                    replaced_content_rect: rbox.output.replaced_content_rect,
+                    marker_rect: rbox.output.marker_rect,
+                    location,
+                    padding: rbox.padding,
+                    border: rbox.border,
+                    margin: rbox.margin,
+                },
+            );
+
+            #[cfg(feature = "content_size")]
+            {
+                let contribution_location = if direction.is_rtl() {
+                    Point {
+                        x: size.width - (location.x + rbox.output.size.width) - border.right,
+                        y: location.y - border.top,
+                    }
+                } else {
+                    Point { x: location.x - border.left, y: location.y - border.top }
+                };
+                scrollable_overflow_rect = scrollable_overflow_rect.union(compute_scrollable_overflow_contribution(
+                    contribution_location,
+                    rbox.output.size,
+                    rbox.output.scrollable_overflow_rect,
+                    rbox.overflow,
+                    rbox.contain,
+                    resolve_transform(
+                        rbox.transform,
+                        rbox.transform_origin,
+                        rbox.output.size,
+                        direction.is_rtl(),
+                        |val, basis| tree.calc(val, basis),
+                    ),
+                    is_scroll_container,
+                ));
+            }
+        }
+        pair_offset += pair.width;
+    }
+
+    // 5. Perform hidden layout on hidden children
+    for order in 0..tree.child_count(node_id) {
+        let child = tree.get_child_id(node_id, order);
+        if tree.get_block_child_style(child).box_generation_mode() == BoxGenerationMode::None {
+            tree.set_unrounded_layout(child, &Layout::with_order(order as u32));
+            tree.perform_child_layout(
+                child,
+                Size::NONE,
+                Size::NONE,
+                Size::MAX_CONTENT,
+                SizingMode::InherentSize,
+                Line::FALSE,
+            );
+        }
+    }
+
+    LayoutOutput::from_sizes_and_baselines(size, scrollable_overflow_rect, baselines)
+}
+
+/// Group the children of a ruby container into pairs of a ruby base and its ruby annotation
+fn generate_pairs(tree: &impl LayoutBlockContainer, node: NodeId, inner_size: Size<Option<f32>>) -> Vec<RubyPair> {
+    let mut pairs: Vec<RubyPair> = Vec::new();
+    for (order, child_node_id) in tree.child_ids(node).enumerate() {
+        let child_style = tree.get_block_child_style(child_node_id);
+        if child_style.box_generation_mode() == BoxGenerationMode::None {
+            continue;
+        }
+
+        let padding = child_style.padding().resolve_or_zero(inner_size, |val, basis| tree.calc(val, basis));
+        let border = child_style.border().resolve_or_zero(inner_size, |val, basis| tree.calc(val, basis));
+        let pb_sum = (padding + border).sum_axes();
+        let box_sizing_adjustment = if child_style.box_sizing() == BoxSizing::ContentBox { pb_sum.width } else { 0.0 };
+        let min_width =
+            child_style.min_size().width.maybe_resolve(inner_size.width, |val, basis| tree.calc(val, basis));
+        let max_width =
+            child_style.max_size().width.maybe_resolve(inner_size.width, |val, basis| tree.calc(val, basis));
+
+        let rbox = RubyBox {
+            node_id: child_node_id,
+            order: order as u32,
+            margin: child_style.margin().resolve_or_zero(inner_size.width, |val, basis| tree.calc(val, basis)),
+            padding,
+            border,
+            inset: child_style.inset(),
+            has_auto_width: child_style.size().width.is_auto(),
+            min_width: min_width.maybe_add(box_sizing_adjustment),
+            max_width: max_width.maybe_add(box_sizing_adjustment),
+            #[cfg(feature = "content_size")]
+            overflow: child_style.overflow(),
+            #[cfg(feature = "content_size")]
+            contain: child_style.contain(),
+            #[cfg(feature = "content_size")]
+            transform: child_style.transform(),
+            #[cfg(feature = "content_size")]
+            transform_origin: child_style.transform_origin(),
+            content_width: 0.0,
+            output: LayoutOutput::HIDDEN,
+        };
+
+        // An annotation annotates the preceding base, unless that base is already annotated. Annotations without such a
+        // base are paired with an empty base.
+        if child_style.is_ruby_text() {
+            match pairs.last_mut() {
+                Some(pair) if pair.annotation.is_none() => pair.annotation = Some(rbox),
+                _ => pairs.push(RubyPair { annotation: Some(rbox), ..Default::default() }),
+            }
+        } else {
+            pairs.push(RubyPair { base: Some(rbox), ..Default::default() });
+        }
+    }
+    pairs
+}
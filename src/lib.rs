@@ -86,6 +86,8 @@ extern crate serde;
 
 pub mod compute;
 pub mod geometry;
+#[cfg(feature = "taffy_tree")]
+pub mod lint;
 pub mod prelude;
 pub mod style;
 pub mod style_helpers;
@@ -106,11 +108,16 @@ pub use crate::compute::compute_flexbox_layout;
 #[cfg(feature = "grid")]
 #[doc(inline)]
 pub use crate::compute::compute_grid_layout;
+#[cfg(feature = "block_layout")]
+#[doc(inline)]
+pub use crate::compute::compute_ruby_layout;
 #[cfg(feature = "detailed_layout_info")]
 pub use crate::compute::detailed_info::*;
 #[doc(inline)]
 pub use crate::compute::{
-    compute_cached_layout, compute_hidden_layout, compute_leaf_layout, compute_root_layout, round_layout,
+    compute_cached_layout, compute_hidden_layout, compute_intrinsic_sizes, compute_leaf_layout,
+    compute_leaf_layout_with_baselines, compute_leaf_layout_with_natural_aspect_ratio, compute_replaced_leaf_layout,
+    compute_root_layout, round_layout,
 };
 #[doc(inline)]
 pub use crate::style::Style;
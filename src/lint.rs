@@ -0,0 +1,249 @@
+//! Reports style values which Taffy accepts but which are invalid or have no effect on layout.
+//!
+//! Taffy never fails to lay out a tree because of its styles: invalid values are clamped or ignored, and properties
+//! which don't apply to a node (such as `flex_grow` on the child of a grid container) are silently unused. The
+//! functions in this module report such values so that they can be surfaced to whoever wrote the styles.
+//!
+//! ```
+//! # use taffy::prelude::*;
+//! # use taffy::lint::{lint_tree, LintKind};
+//! let mut tree: TaffyTree<()> = TaffyTree::new();
+//! let child = tree.new_leaf(Style { flex_grow: 1.0, ..Default::default() }).unwrap();
+//! let root = tree.new_with_children(Style { display: Display::Grid, ..Default::default() }, &[child]).unwrap();
+//!
+//! let warnings = lint_tree(&tree, root).unwrap();
+//! assert_eq!(warnings.len(), 1);
+//! assert_eq!((warnings[0].node, warnings[0].property), (child, "flex_grow"));
+//! assert_eq!(warnings[0].kind, LintKind::Ineffective);
+//! ```
+use crate::geometry::{Rect, Size};
+use crate::style::{
+    BoxGenerationMode, CheapCloneStr, CompactLength, CoreStyle, Display, Position, Style, StyleValidationError,
+};
+use crate::sys::Vec;
+use crate::tree::{NodeId, TaffyResult, TaffyTree};
+
+#[cfg(feature = "grid")]
+use crate::geometry::Line;
+#[cfg(feature = "grid")]
+use crate::style::GridAutoFlow;
+#[cfg(feature = "flexbox")]
+use crate::style::{FlexDirection, FlexWrap};
+
+/// Why a style property was reported by the linter
+#[derive(Debug, Clone, PartialEq)]
+pub enum LintKind {
+    /// The value is invalid (see [`Style::validate`])
+    Invalid(StyleValidationError),
+    /// The value is `NaN` or infinite
+    NonFinite,
+    /// The minimum size is greater than the maximum size in the same axis, so the maximum size has no effect
+    MinExceedsMax,
+    /// The property has no effect given the `display` of the node or of its parent
+    Ineffective,
+}
+
+/// A style property of a node which is invalid or has no effect on layout
+#[derive(Debug, Clone, PartialEq)]
+pub struct LintWarning {
+    /// The node whose style contains the property
+    pub node: NodeId,
+    /// The name of the [`Style`] field
+    pub property: &'static str,
+    /// Why the property was reported
+    pub kind: LintKind,
+}
+
+impl core::fmt::Display for LintWarning {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let Self { node, property, kind } = self;
+        match kind {
+            LintKind::Invalid(error) => write!(f, "{node:?}: {error}"),
+            LintKind::NonFinite => write!(f, "{node:?}: {property} is not finite"),
+            LintKind::MinExceedsMax => write!(f, "{node:?}: {property} is greater than the corresponding max_size"),
+            LintKind::Ineffective => write!(f, "{node:?}: {property} has no effect"),
+        }
+    }
+}
+
+/// Lint the styles of `root` and all of its descendants, in depth-first order.
+///
+/// The descendants of `Display::None` nodes aren't laid out, so they aren't linted.
+pub fn lint_tree<NodeContext>(tree: &TaffyTree<NodeContext>, root: NodeId) -> TaffyResult<Vec<LintWarning>> {
+    let mut warnings = Vec::new();
+    // The children of `Display::Contents` nodes are laid out by their closest ancestor which generates a box, so
+    // that ancestor's display is passed down through them
+    let mut stack = Vec::from([(root, None)]);
+    while let Some((node, parent_display)) = stack.pop() {
+        let style = tree.style(node)?;
+        warnings.extend(lint_style(node, style, parent_display));
+
+        let children_parent_display = match style.box_generation_mode() {
+            BoxGenerationMode::None => continue,
+            BoxGenerationMode::Contents => parent_display,
+            BoxGenerationMode::Normal => Some(style.display),
+        };
+        stack.extend(tree.children(node)?.into_iter().rev().map(|child| (child, children_parent_display)));
+    }
+    Ok(warnings)
+}
+
+/// Lint the style of a single node, given the `display` of the node which lays it out (or `None` for a root node).
+///
+/// This allows styles stored in trees other than [`TaffyTree`] to be linted. `node` is only used to label the
+/// returned warnings.
+pub fn lint_style<S: CheapCloneStr>(
+    node: NodeId,
+    style: &Style<S>,
+    parent_display: Option<Display>,
+) -> Vec<LintWarning> {
+    let mut warnings = Vec::new();
+    let mut warn = |property: &'static str, kind: LintKind| warnings.push(LintWarning { node, property, kind });
+
+    if let Err(errors) = style.validate() {
+        for error in errors {
+            warn(error.property(), LintKind::Invalid(error));
+        }
+    }
+
+    // Non-finite lengths are clamped or treated as `auto`
+    let is_non_finite = |value: CompactLength| value.is_length_or_percentage() && !value.value().is_finite();
+    let size_is_non_finite = |size: Size<CompactLength>| is_non_finite(size.width) || is_non_finite(size.height);
+    let rect_is_non_finite =
+        |rect: Rect<CompactLength>| [rect.left, rect.right, rect.top, rect.bottom].into_iter().any(is_non_finite);
+    let sizes = [
+        ("size", style.size.map(|value| value.0)),
+        ("min_size", style.min_size.map(|value| value.0)),
+        ("max_size", style.max_size.map(|value| value.0)),
+        #[cfg(any(feature = "flexbox", feature = "grid"))]
+        ("gap", style.gap.map(|value| value.0)),
+    ];
+    for (property, size) in sizes {
+        if size_is_non_finite(size) {
+            warn(property, LintKind::NonFinite);
+        }
+    }
+    let rects = [
+        ("inset", style.inset.map(|value| value.0)),
+        ("margin", style.margin.map(|value| value.0)),
+        ("padding", style.padding.map(|value| value.0)),
+        ("border", style.border.map(|value| value.0)),
+    ];
+    for (property, rect) in rects {
+        if rect_is_non_finite(rect) {
+            warn(property, LintKind::NonFinite);
+        }
+    }
+    if !style.scrollbar_width.is_finite() {
+        warn("scrollbar_width", LintKind::NonFinite);
+    }
+    match style.aspect_ratio.ratio {
+        Some(ratio) if !ratio.is_finite() => warn("aspect_ratio", LintKind::NonFinite),
+        // A degenerate ratio is treated as if no ratio had been specified
+        Some(ratio) if ratio <= 0.0 => warn("aspect_ratio", LintKind::Ineffective),
+        _ => {}
+    }
+    #[cfg(feature = "flexbox")]
+    {
+        if is_non_finite(style.flex_basis.0) {
+            warn("flex_basis", LintKind::NonFinite);
+        }
+        if !style.flex_grow.is_finite() {
+            warn("flex_grow", LintKind::NonFinite);
+        }
+        if !style.flex_shrink.is_finite() {
+            warn("flex_shrink", LintKind::NonFinite);
+        }
+    }
+
+    // The min size wins when it is greater than the max size. Only sizes with the same unit can be compared.
+    let min_exceeds_max = |min: CompactLength, max: CompactLength| {
+        min.is_length_or_percentage() && min.tag() == max.tag() && min.value() > max.value()
+    };
+    if min_exceeds_max(style.min_size.width.0, style.max_size.width.0)
+        || min_exceeds_max(style.min_size.height.0, style.max_size.height.0)
+    {
+        warn("min_size", LintKind::MinExceedsMax);
+    }
+
+    // Properties which only apply to the children of particular kinds of container. Nodes which don't generate a
+    // box aren't laid out by their parent, and aren't containers themselves.
+    let generates_box = !matches!(style.display, Display::None | Display::Contents);
+    let parent_inside = parent_display.filter(|_| generates_box).map(Display::inside);
+    let is_in_flow = style.position != Position::Absolute;
+
+    #[cfg(feature = "flexbox")]
+    {
+        let is_flex_item = is_in_flow && parent_inside == Some(Display::Flex);
+        if !is_flex_item {
+            if style.flex_grow != 0.0 {
+                warn("flex_grow", LintKind::Ineffective);
+            }
+            if style.flex_shrink != 1.0 {
+                warn("flex_shrink", LintKind::Ineffective);
+            }
+            if !style.flex_basis.is_auto() {
+                warn("flex_basis", LintKind::Ineffective);
+            }
+        }
+    }
+    #[cfg(all(feature = "flexbox", any(feature = "grid", feature = "block_layout")))]
+    if parent_inside == Some(Display::Flex) && style.justify_self.is_some() {
+        warn("justify_self", LintKind::Ineffective);
+    }
+    #[cfg(any(feature = "flexbox", feature = "grid"))]
+    {
+        let is_aligned_by_parent = match parent_inside {
+            #[cfg(feature = "flexbox")]
+            Some(Display::Flex) => true,
+            #[cfg(feature = "grid")]
+            Some(Display::Grid) => true,
+            _ => false,
+        };
+        if !is_aligned_by_parent && style.align_self.is_some() {
+            warn("align_self", LintKind::Ineffective);
+        }
+    }
+    #[cfg(feature = "grid")]
+    {
+        // Grid placement also applies to absolutely positioned children of a grid container
+        if parent_inside != Some(Display::Grid) {
+            if style.grid_row != Line::default() {
+                warn("grid_row", LintKind::Ineffective);
+            }
+            if style.grid_column != Line::default() {
+                warn("grid_column", LintKind::Ineffective);
+            }
+        }
+    }
+
+    // Properties which only apply to particular kinds of container
+    let inside = generates_box.then(|| style.display.inside());
+    #[cfg(feature = "flexbox")]
+    if inside != Some(Display::Flex) {
+        if style.flex_direction != FlexDirection::default() {
+            warn("flex_direction", LintKind::Ineffective);
+        }
+        if style.flex_wrap != FlexWrap::default() {
+            warn("flex_wrap", LintKind::Ineffective);
+        }
+    }
+    #[cfg(feature = "grid")]
+    if inside != Some(Display::Grid) {
+        let grid_properties = [
+            ("grid_template_rows", !style.grid_template_rows.is_empty()),
+            ("grid_template_columns", !style.grid_template_columns.is_empty()),
+            ("grid_auto_rows", !style.grid_auto_rows.is_empty()),
+            ("grid_auto_columns", !style.grid_auto_columns.is_empty()),
+            ("grid_auto_flow", style.grid_auto_flow != GridAutoFlow::default()),
+            ("grid_template_areas", style.grid_template_areas.is_some()),
+        ];
+        for (property, is_set) in grid_properties {
+            if is_set {
+                warn(property, LintKind::Ineffective);
+            }
+        }
+    }
+
+    warnings
+}
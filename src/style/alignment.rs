@@ -50,8 +50,13 @@ pub enum AlignItemsKeyword {
     SelfEnd,
     /// Items are packed along the center of the cross axis.
     Center,
-    /// Items are aligned such as their baselines align.
+    /// Items are aligned such as their (first) baselines align.
     Baseline,
+    /// Items are aligned such as their last baselines align.
+    ///
+    /// The aligned items are packed toward the end of the axis, and items without a
+    /// baseline are aligned using a baseline synthesized from their border box.
+    LastBaseline,
     /// Stretch to fill the container.
     Stretch,
 }
@@ -161,6 +166,8 @@ impl AlignItems {
     pub const CENTER: Self = Self { keyword: AlignItemsKeyword::Center, safety: AlignmentSafety::Unsafe };
     /// Items are aligned such as their baselines align.
     pub const BASELINE: Self = Self { keyword: AlignItemsKeyword::Baseline, safety: AlignmentSafety::Unsafe };
+    /// Items are aligned such as their last baselines align.
+    pub const LAST_BASELINE: Self = Self { keyword: AlignItemsKeyword::LastBaseline, safety: AlignmentSafety::Unsafe };
     /// Stretch to fill the container.
     pub const STRETCH: Self = Self { keyword: AlignItemsKeyword::Stretch, safety: AlignmentSafety::Unsafe };
     /// Like [`AlignItems::START`], but falls back to [`AlignItems::START`] when the
@@ -275,6 +282,14 @@ impl FromCss for AlignItems {
             "self-end" => Ok(Self::SELF_END),
             "center" => Ok(Self::CENTER),
             "baseline" => Ok(Self::BASELINE),
+            "first" => {
+                input.expect_ident_matching("baseline")?;
+                Ok(Self::BASELINE)
+            },
+            "last" => {
+                input.expect_ident_matching("baseline")?;
+                Ok(Self::LAST_BASELINE)
+            },
             "stretch" => Ok(Self::STRETCH),
             _ => Err(input.new_unexpected_token_error(Token::Ident(first))),
         }
@@ -438,6 +453,7 @@ const ALIGN_ITEMS_NAMES: &[&str] = &[
     "SelfEnd",
     "Center",
     "Baseline",
+    "LastBaseline",
     "Stretch",
     "SafeStart",
     "SafeEnd",
@@ -460,6 +476,7 @@ impl serde::Serialize for AlignItems {
             (AlignItemsKeyword::SelfEnd, AlignmentSafety::Unsafe) => "SelfEnd",
             (AlignItemsKeyword::Center, AlignmentSafety::Unsafe) => "Center",
             (AlignItemsKeyword::Baseline, _) => "Baseline",
+            (AlignItemsKeyword::LastBaseline, _) => "LastBaseline",
             (AlignItemsKeyword::Stretch, _) => "Stretch",
             (AlignItemsKeyword::Start, AlignmentSafety::Safe) => "SafeStart",
             (AlignItemsKeyword::End, AlignmentSafety::Safe) => "SafeEnd",
@@ -492,6 +509,7 @@ impl<'de> serde::Deserialize<'de> for AlignItems {
                     "SelfEnd" => AlignItems::SELF_END,
                     "Center" => AlignItems::CENTER,
                     "Baseline" => AlignItems::BASELINE,
+                    "LastBaseline" => AlignItems::LAST_BASELINE,
                     "Stretch" => AlignItems::STRETCH,
                     "SafeStart" => AlignItems::SAFE_START,
                     "SafeEnd" => AlignItems::SAFE_END,
@@ -707,6 +725,9 @@ mod tests {
         assert_eq!("self-start".parse::<AlignItems>().unwrap(), AlignItems::SELF_START);
         assert_eq!("self-end".parse::<AlignItems>().unwrap(), AlignItems::SELF_END);
         assert_eq!("baseline".parse::<AlignItems>().unwrap(), AlignItems::BASELINE);
+        assert_eq!("first baseline".parse::<AlignItems>().unwrap(), AlignItems::BASELINE);
+        assert_eq!("last baseline".parse::<AlignItems>().unwrap(), AlignItems::LAST_BASELINE);
+        assert!("last".parse::<AlignItems>().is_err());
         assert_eq!("stretch".parse::<AlignItems>().unwrap(), AlignItems::STRETCH);
     }
 
@@ -800,6 +821,7 @@ mod tests {
             (AlignItems::FLEX_END, "\"FlexEnd\""),
             (AlignItems::CENTER, "\"Center\""),
             (AlignItems::BASELINE, "\"Baseline\""),
+            (AlignItems::LAST_BASELINE, "\"LastBaseline\""),
             (AlignItems::STRETCH, "\"Stretch\""),
             (AlignItems::SAFE_START, "\"SafeStart\""),
             (AlignItems::SAFE_END, "\"SafeEnd\""),
@@ -0,0 +1,270 @@
+//! Style types for CSS Anchor Positioning
+//!
+//! <https://drafts.csswg.org/css-anchor-position-1/>
+#[cfg(feature = "parse")]
+use crate::util::parse::{from_str_from_css, CssParseResult, FromCss, Parser, Token};
+
+/// Which edge of the anchor box an `anchor()` value refers to
+///
+/// Physical sides (`Top`, `Right`, `Bottom` and `Left`) are only valid in an inset of the same axis. In the other
+/// axis the value behaves as `auto`.
+///
+/// <https://drafts.csswg.org/css-anchor-position-1/#typedef-anchor-side>
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AnchorSide {
+    /// The edge of the anchor box on the same side as the inset property
+    Inside,
+    /// The edge of the anchor box on the opposite side to the inset property
+    Outside,
+    /// The top edge of the anchor box
+    Top,
+    /// The right edge of the anchor box
+    Right,
+    /// The bottom edge of the anchor box
+    Bottom,
+    /// The left edge of the anchor box
+    Left,
+    /// The start edge of the anchor box (relative to the direction of the containing block in the horizontal axis)
+    Start,
+    /// The end edge of the anchor box (relative to the direction of the containing block in the horizontal axis)
+    End,
+    /// Halfway between the start and end edges of the anchor box
+    Center,
+    /// A position between the start (`0.0`) and end (`1.0`) edges of the anchor box
+    ///
+    /// **NOTE: percentages are represented as a f32 value in the range [0.0, 1.0] NOT the range [0.0, 100.0]**
+    Percent(f32),
+}
+
+impl AnchorSide {
+    /// The index of a keyword side in its `CompactLength` representation
+    #[inline(always)]
+    pub(crate) const fn keyword_index(self) -> u8 {
+        match self {
+            Self::Inside => 0,
+            Self::Outside => 1,
+            Self::Top => 2,
+            Self::Right => 3,
+            Self::Bottom => 4,
+            Self::Left => 5,
+            Self::Start => 6,
+            Self::End => 7,
+            Self::Center => 8,
+            Self::Percent(_) => u8::MAX,
+        }
+    }
+
+    /// Convert the index of a keyword side back into an [`AnchorSide`]
+    #[inline(always)]
+    pub(crate) fn from_keyword_index(index: u8) -> Self {
+        match index {
+            0 => Self::Inside,
+            1 => Self::Outside,
+            2 => Self::Top,
+            3 => Self::Right,
+            4 => Self::Bottom,
+            5 => Self::Left,
+            6 => Self::Start,
+            7 => Self::End,
+            8 => Self::Center,
+            _ => unreachable!("AnchorSide keyword index out of range"),
+        }
+    }
+
+    /// The side that refers to the same edge of the anchor once the positioned node has been flipped in the
+    /// axis of the inset containing this value
+    #[inline(always)]
+    pub(crate) fn flipped(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+            Self::Start => Self::End,
+            Self::End => Self::Start,
+            Self::Percent(percent) => Self::Percent(1.0 - percent),
+            // Inside and outside are relative to the inset property, which is itself swapped when flipping
+            Self::Inside | Self::Outside | Self::Center => self,
+        }
+    }
+}
+
+#[cfg(feature = "parse")]
+impl FromCss for AnchorSide {
+    fn from_css<'i>(parser: &mut Parser<'i, '_>) -> CssParseResult<'i, Self> {
+        match parser.next()?.clone() {
+            Token::Percentage { unit_value, .. } => Ok(Self::Percent(unit_value)),
+            Token::Ident(ident) => cssparser::match_ignore_ascii_case! { &*ident,
+                "inside" => Ok(Self::Inside),
+                "outside" => Ok(Self::Outside),
+                "top" => Ok(Self::Top),
+                "right" => Ok(Self::Right),
+                "bottom" => Ok(Self::Bottom),
+                "left" => Ok(Self::Left),
+                "start" => Ok(Self::Start),
+                "end" => Ok(Self::End),
+                "center" => Ok(Self::Center),
+                _ => Err(parser.new_unexpected_token_error(Token::Ident(ident))),
+            },
+            token => Err(parser.new_unexpected_token_error(token)),
+        }
+    }
+}
+#[cfg(feature = "parse")]
+from_str_from_css!(AnchorSide);
+
+/// Which dimension of the anchor box an `anchor-size()` value refers to
+///
+/// <https://drafts.csswg.org/css-anchor-position-1/#typedef-anchor-size>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(u8)]
+pub enum AnchorSize {
+    /// The size of the anchor box in the axis of the property containing this value
+    #[default]
+    SameAxis = 0,
+    /// The width of the anchor box
+    Width = 1,
+    /// The height of the anchor box
+    Height = 2,
+    /// The size of the anchor box in the block axis (its height)
+    Block = 3,
+    /// The size of the anchor box in the inline axis (its width)
+    Inline = 4,
+}
+
+impl AnchorSize {
+    /// Convert the `CompactLength` representation of an [`AnchorSize`] back into an [`AnchorSize`]
+    #[inline(always)]
+    pub(crate) fn from_index(index: u8) -> Self {
+        match index {
+            0 => Self::SameAxis,
+            1 => Self::Width,
+            2 => Self::Height,
+            3 => Self::Block,
+            4 => Self::Inline,
+            _ => unreachable!("AnchorSize index out of range"),
+        }
+    }
+}
+
+#[cfg(feature = "parse")]
+crate::util::parse::impl_parse_for_keyword_enum!(AnchorSize,
+    "width" => Width,
+    "height" => Height,
+    "block" => Block,
+    "inline" => Inline,
+    "self-block" => Block,
+    "self-inline" => Inline,
+);
+
+/// An alternative position for an anchor-positioned node that is tried if the node overflows its containing block
+///
+/// <https://drafts.csswg.org/css-anchor-position-1/#typedef-position-try-fallbacks-try-tactic>
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PositionTryFallback {
+    /// Swap the top and bottom insets and margins (`flip-block` in CSS)
+    FlipBlock,
+    /// Swap the left and right insets and margins (`flip-inline` in CSS)
+    FlipInline,
+    /// Swap both the top and bottom and the left and right insets and margins (`flip-block flip-inline` in CSS)
+    FlipBlockInline,
+}
+
+impl PositionTryFallback {
+    /// Whether this fallback swaps the top and bottom sides
+    #[inline(always)]
+    pub(crate) fn flips_vertical(self) -> bool {
+        matches!(self, Self::FlipBlock | Self::FlipBlockInline)
+    }
+
+    /// Whether this fallback swaps the left and right sides
+    #[inline(always)]
+    pub(crate) fn flips_horizontal(self) -> bool {
+        matches!(self, Self::FlipInline | Self::FlipBlockInline)
+    }
+}
+
+#[cfg(feature = "parse")]
+impl FromCss for PositionTryFallback {
+    fn from_css<'i>(parser: &mut Parser<'i, '_>) -> CssParseResult<'i, Self> {
+        let ident = parser.expect_ident()?.clone();
+        let (flip_block, flip_inline) = cssparser::match_ignore_ascii_case! { &*ident,
+            "flip-block" => (true, parser.try_parse(|parser| parser.expect_ident_matching("flip-inline")).is_ok()),
+            "flip-inline" => (parser.try_parse(|parser| parser.expect_ident_matching("flip-block")).is_ok(), true),
+            _ => return Err(parser.new_unexpected_token_error(Token::Ident(ident))),
+        };
+        Ok(if flip_block && flip_inline {
+            Self::FlipBlockInline
+        } else if flip_block {
+            Self::FlipBlock
+        } else {
+            Self::FlipInline
+        })
+    }
+}
+#[cfg(feature = "parse")]
+from_str_from_css!(PositionTryFallback);
+
+/// The ordered list of [`PositionTryFallback`]s that are tried (in order) when an anchor-positioned node overflows
+/// its containing block (`position-try-fallbacks` in CSS)
+///
+/// Each of the three fallbacks may appear at most once: trying the same position twice would have no effect.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PositionTryFallbacks {
+    /// The fallbacks. Only the first `len` entries are used.
+    fallbacks: [PositionTryFallback; 3],
+    /// The number of fallbacks in the list
+    len: u8,
+}
+
+impl PositionTryFallbacks {
+    /// No fallbacks (the default)
+    pub const NONE: Self = Self { fallbacks: [PositionTryFallback::FlipBlock; 3], len: 0 };
+
+    /// Create a list of fallbacks. Repeated fallbacks are ignored.
+    pub fn new(fallbacks: &[PositionTryFallback]) -> Self {
+        let mut list = Self::NONE;
+        for &fallback in fallbacks {
+            if !list.iter().any(|existing| existing == fallback) {
+                list.fallbacks[list.len as usize] = fallback;
+                list.len += 1;
+            }
+        }
+        list
+    }
+
+    /// Whether the list is empty
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Iterate over the fallbacks in order
+    #[inline(always)]
+    pub fn iter(&self) -> impl Iterator<Item = PositionTryFallback> + '_ {
+        self.fallbacks[..self.len as usize].iter().copied()
+    }
+}
+
+impl Default for PositionTryFallbacks {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
+
+#[cfg(feature = "parse")]
+impl FromCss for PositionTryFallbacks {
+    fn from_css<'i>(parser: &mut Parser<'i, '_>) -> CssParseResult<'i, Self> {
+        if parser.try_parse(|parser| parser.expect_ident_matching("none")).is_ok() {
+            return Ok(Self::NONE);
+        }
+        let fallbacks = parser.parse_comma_separated(PositionTryFallback::from_css)?;
+        Ok(Self::new(&fallbacks))
+    }
+}
+#[cfg(feature = "parse")]
+from_str_from_css!(PositionTryFallbacks);
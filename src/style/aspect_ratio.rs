@@ -0,0 +1,146 @@
+//! Style type for the CSS `aspect-ratio` property
+//!
+//! <https://drafts.csswg.org/css-sizing-4/#aspect-ratio>
+#[cfg(feature = "parse")]
+use crate::util::parse::{from_str_from_css, CssParseResult, FromCss, Parser, Token};
+
+/// The preferred aspect ratio of a node (the `aspect-ratio` property in CSS), which is used to compute its size in
+/// one axis from its size in the other
+///
+/// `auto` uses the natural aspect ratio of a replaced node's content (such as an image), if it has one. A ratio on its
+/// own is always used, and applies to the node's `box_sizing` box. If both are given then the ratio is only used if
+/// there is no natural aspect ratio, and like the natural aspect ratio it applies to the node's content box.
+///
+/// Ratios are the width divided by the height. A ratio that is zero, negative or not finite is treated as if it were
+/// not given.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/aspect-ratio>
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AspectRatio {
+    /// Whether the natural aspect ratio of a replaced node's content is preferred
+    pub auto: bool,
+    /// The ratio of width to height
+    pub ratio: Option<f32>,
+}
+
+impl AspectRatio {
+    /// `aspect-ratio: auto` (the default): only replaced nodes with a natural aspect ratio have a preferred aspect
+    /// ratio
+    pub const AUTO: Self = Self { auto: true, ratio: None };
+
+    /// `aspect-ratio: <ratio>`: the node's `box_sizing` box has the given ratio of width to height
+    pub const fn ratio(ratio: f32) -> Self {
+        Self { auto: false, ratio: Some(ratio) }
+    }
+
+    /// `aspect-ratio: auto <ratio>`: the node's content box has its natural aspect ratio, or the given ratio of width
+    /// to height if it has none
+    pub const fn auto_or_ratio(ratio: f32) -> Self {
+        Self { auto: true, ratio: Some(ratio) }
+    }
+
+    /// Resolves the ratio of width to height that is used for a node whose content has the given natural aspect
+    /// ratio, along with whether it applies to the node's content box (rather than its `box_sizing` box)
+    pub(crate) fn resolve(self, natural_ratio: Option<f32>) -> Option<(f32, bool)> {
+        /// Whether a ratio can be used to transfer sizes between axes
+        fn is_valid(ratio: f32) -> bool {
+            ratio.is_finite() && ratio > 0.0
+        }
+
+        let natural_ratio = natural_ratio.filter(|&ratio| self.auto && is_valid(ratio));
+        let ratio = self.ratio.filter(|&ratio| is_valid(ratio));
+        match (natural_ratio, ratio) {
+            (Some(natural_ratio), _) => Some((natural_ratio, true)),
+            (None, Some(ratio)) => Some((ratio, self.auto)),
+            (None, None) => None,
+        }
+    }
+}
+
+impl Default for AspectRatio {
+    fn default() -> Self {
+        Self::AUTO
+    }
+}
+
+impl From<f32> for AspectRatio {
+    fn from(ratio: f32) -> Self {
+        Self::ratio(ratio)
+    }
+}
+
+impl From<Option<f32>> for AspectRatio {
+    fn from(ratio: Option<f32>) -> Self {
+        match ratio {
+            Some(ratio) => Self::ratio(ratio),
+            None => Self::AUTO,
+        }
+    }
+}
+
+/// Parses a `<ratio>` (a width, optionally followed by a `/` and a height)
+#[cfg(feature = "parse")]
+fn parse_ratio<'i>(parser: &mut Parser<'i, '_>) -> CssParseResult<'i, f32> {
+    /// Parses a non-negative number
+    fn parse_non_negative<'i>(parser: &mut Parser<'i, '_>) -> CssParseResult<'i, f32> {
+        let token = parser.next()?.clone();
+        match token {
+            Token::Number { value, .. } if value.is_sign_positive() => Ok(value),
+            token => Err(parser.new_unexpected_token_error(token))?,
+        }
+    }
+
+    let width = parse_non_negative(parser)?;
+    let height =
+        if parser.try_parse(|parser| parser.expect_delim('/')).is_ok() { parse_non_negative(parser)? } else { 1.0 };
+    Ok(width / height)
+}
+
+#[cfg(feature = "parse")]
+impl FromCss for AspectRatio {
+    fn from_css<'i>(parser: &mut Parser<'i, '_>) -> CssParseResult<'i, Self> {
+        // `auto` and the ratio may be given in either order
+        let auto = parser.try_parse(|parser| parser.expect_ident_matching("auto")).is_ok();
+        let ratio = parser.try_parse(parse_ratio).ok();
+        let auto = auto || (ratio.is_some() && parser.try_parse(|parser| parser.expect_ident_matching("auto")).is_ok());
+        match (auto, ratio) {
+            (false, None) => {
+                let token = parser.next()?.clone();
+                Err(parser.new_unexpected_token_error(token))
+            }
+            (auto, ratio) => Ok(Self { auto, ratio }),
+        }
+    }
+}
+#[cfg(feature = "parse")]
+from_str_from_css!(AspectRatio);
+
+#[cfg(test)]
+mod tests {
+    use super::AspectRatio;
+
+    #[test]
+    #[cfg(feature = "parse")]
+    fn parse_aspect_ratio() {
+        assert_eq!("auto".parse::<AspectRatio>().unwrap(), AspectRatio::AUTO);
+        assert_eq!("2".parse::<AspectRatio>().unwrap(), AspectRatio::ratio(2.0));
+        assert_eq!("16 / 8".parse::<AspectRatio>().unwrap(), AspectRatio::ratio(2.0));
+        assert_eq!("auto 4/2".parse::<AspectRatio>().unwrap(), AspectRatio::auto_or_ratio(2.0));
+        assert_eq!("4/2 auto".parse::<AspectRatio>().unwrap(), AspectRatio::auto_or_ratio(2.0));
+        assert!("".parse::<AspectRatio>().is_err());
+        assert!("auto auto".parse::<AspectRatio>().is_err());
+        assert!("-1".parse::<AspectRatio>().is_err());
+    }
+
+    #[test]
+    fn natural_ratio_is_preferred_with_auto() {
+        assert_eq!(AspectRatio::AUTO.resolve(None), None);
+        assert_eq!(AspectRatio::AUTO.resolve(Some(2.0)), Some((2.0, true)));
+        assert_eq!(AspectRatio::ratio(3.0).resolve(Some(2.0)), Some((3.0, false)));
+        assert_eq!(AspectRatio::auto_or_ratio(3.0).resolve(Some(2.0)), Some((2.0, true)));
+        assert_eq!(AspectRatio::auto_or_ratio(3.0).resolve(None), Some((3.0, true)));
+        assert_eq!(AspectRatio::ratio(0.0).resolve(None), None);
+        assert_eq!(AspectRatio::auto_or_ratio(f32::INFINITY).resolve(Some(2.0)), Some((2.0, true)));
+    }
+}
@@ -1,5 +1,5 @@
 //! Style types for Block layout
-use crate::style::AlignContent;
+use crate::style::{AlignContent, AlignItems, AlignSelf};
 use crate::{CoreStyle, Style};
 
 /// The set of styles required for a Block layout container
@@ -15,6 +15,30 @@ pub trait BlockContainerStyle: CoreStyle {
     fn align_content(&self) -> Option<AlignContent> {
         Style::<Self::CustomIdent>::DEFAULT.align_content
     }
+
+    /// How block-level children of this block container are aligned in the inline axis
+    #[inline(always)]
+    fn justify_items(&self) -> Option<AlignItems> {
+        Style::<Self::CustomIdent>::DEFAULT.justify_items
+    }
+
+    /// Whether this block container is a list item, which generates a marker box
+    #[inline(always)]
+    fn is_list_item(&self) -> bool {
+        false
+    }
+
+    /// Where the marker box of this block container is placed, if it is a list item
+    #[inline(always)]
+    fn list_style_position(&self) -> ListStylePosition {
+        Style::<Self::CustomIdent>::DEFAULT.list_style_position
+    }
+
+    /// How the ruby bases and annotations of this ruby container are distributed within the width of their pair
+    #[inline(always)]
+    fn ruby_align(&self) -> RubyAlign {
+        Style::<Self::CustomIdent>::DEFAULT.ruby_align
+    }
 }
 
 /// The set of styles required for a Block layout item (child of a Block container)
@@ -25,6 +49,28 @@ pub trait BlockItemStyle: CoreStyle {
         false
     }
 
+    /// Whether the item is an inline-block box. When placed in a line box, such boxes are aligned by their last
+    /// baseline rather than their first, and by their bottom margin edge if they have no baseline or are scroll
+    /// containers.
+    #[inline(always)]
+    fn is_inline_block(&self) -> bool {
+        false
+    }
+
+    /// Whether the item is a ruby annotation. In a ruby container, an annotation is paired with the preceding ruby base
+    /// and placed above it.
+    #[inline(always)]
+    fn is_ruby_text(&self) -> bool {
+        false
+    }
+
+    /// How this node should be aligned in the inline axis of its block container.
+    /// Falls back to the parent's [`super::JustifyItems`] if not set
+    #[inline(always)]
+    fn justify_self(&self) -> Option<AlignSelf> {
+        Style::<Self::CustomIdent>::DEFAULT.justify_self
+    }
+
     /// Whether the item is a floated
     #[cfg(feature = "float_layout")]
     #[inline(always)]
@@ -55,6 +101,62 @@ pub enum TextAlign {
     LegacyCenter,
 }
 
+/// Where the marker box of a list item is placed
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/list-style-position>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ListStylePosition {
+    /// The marker is placed outside of the list item's principal box, before the start of its first line box, and
+    /// aligned with the first line's baseline. It does not affect the layout of the list item's contents.
+    #[default]
+    Outside,
+    /// The marker is placed as an inline-level box at the start of the list item's first line box
+    Inside,
+}
+
+/// How the content of a ruby base or annotation is distributed when it is narrower than the other half of its pair
+///
+/// Taffy lays out each ruby base and annotation as a single box, so it does not distribute the free space of a pair
+/// within the content of its narrower box. For [`SpaceBetween`](RubyAlign::SpaceBetween) and
+/// [`SpaceAround`](RubyAlign::SpaceAround), the narrower box is instead stretched to the width of the pair (if its
+/// `width` is `auto`), and the justification of its content (e.g. the spacing between characters) is left to the
+/// renderer. A box that can't be stretched that far (because of its `width` or `max_width`) is centered within the
+/// pair, as a box without justification opportunities is in browsers.
+///
+/// Only horizontal ruby is supported: the pairs are always laid out in a horizontal row, with the annotations above
+/// their bases, and there is no vertical writing mode in which they are placed beside their bases.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/ruby-align>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RubyAlign {
+    /// The box is placed at the inline-start edge of the pair
+    Start,
+    /// The box is centered within the pair
+    Center,
+    /// The box is stretched to the width of the pair, and its content is justified across it
+    SpaceBetween,
+    /// The box is stretched to the width of the pair, and its content is justified across it with half-sized spaces
+    /// at either end
+    #[default]
+    SpaceAround,
+}
+
+#[cfg(feature = "parse")]
+crate::util::parse::impl_parse_for_keyword_enum!(RubyAlign,
+    "start" => Start,
+    "center" => Center,
+    "space-between" => SpaceBetween,
+    "space-around" => SpaceAround,
+);
+
+#[cfg(feature = "parse")]
+crate::util::parse::impl_parse_for_keyword_enum!(ListStylePosition,
+    "outside" => Outside,
+    "inside" => Inside,
+);
+
 #[cfg(feature = "parse")]
 crate::util::parse::impl_parse_for_keyword_enum!(TextAlign,
     "auto" => Auto,
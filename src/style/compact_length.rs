@@ -1,6 +1,6 @@
 //! A tagged-pointer abstraction that allows size styles in Taffy to be represented
 //! in just 64 bits. Wrapped by types in the `super::dimension` and `super::grid` modules.
-use super::LengthPercentage;
+use super::{AnchorSide, AnchorSize, LengthPercentage};
 use crate::style_helpers::{
     FromFr, FromLength, FromPercent, TaffyAuto, TaffyFitContent, TaffyMaxContent, TaffyMinContent, TaffyZero,
 };
@@ -235,6 +235,12 @@ impl CompactLength {
     pub const STRETCH_TAG: usize = 0b00101111;
     /// The tag indicating a content keyword value
     pub const CONTENT_TAG: usize = 0b00110111;
+    /// The tag indicating an `anchor()` value with a keyword side. The value is the [`AnchorSide`] discriminant.
+    pub const ANCHOR_TAG: usize = 0b00111111;
+    /// The tag indicating an `anchor()` value with a percentage side
+    pub const ANCHOR_PERCENT_TAG: usize = 0b01000111;
+    /// The tag indicating an `anchor-size()` value. The value is the [`AnchorSize`] discriminant.
+    pub const ANCHOR_SIZE_TAG: usize = 0b01001111;
 }
 
 impl CompactLength {
@@ -350,6 +356,25 @@ impl CompactLength {
         Self(CompactLengthInner::from_tag(Self::CONTENT_TAG))
     }
 
+    /// A position relative to an edge of the node's anchor box (`anchor()` in CSS)
+    ///
+    /// Only valid in the inset of an absolutely positioned node. In any other context it behaves as [`auto`](Self::auto).
+    #[inline(always)]
+    pub const fn anchor(side: AnchorSide) -> Self {
+        match side {
+            AnchorSide::Percent(percent) => Self(CompactLengthInner::from_val(percent, Self::ANCHOR_PERCENT_TAG)),
+            side => Self(CompactLengthInner::from_val(side.keyword_index() as f32, Self::ANCHOR_TAG)),
+        }
+    }
+
+    /// The size of the node's anchor box in the given axis (`anchor-size()` in CSS)
+    ///
+    /// Only valid in the size of an absolutely positioned node. In any other context it behaves as [`auto`](Self::auto).
+    #[inline(always)]
+    pub const fn anchor_size(size: AnchorSize) -> Self {
+        Self(CompactLengthInner::from_val(size as u8 as f32, Self::ANCHOR_SIZE_TAG))
+    }
+
     /// Get the primary tag
     #[inline(always)]
     pub fn tag(self) -> usize {
@@ -401,6 +426,18 @@ impl CompactLength {
         self.tag() == Self::CONTENT_TAG
     }
 
+    /// Returns true if the value is an `anchor()` value
+    #[inline(always)]
+    pub fn is_anchor(self) -> bool {
+        matches!(self.tag(), Self::ANCHOR_TAG | Self::ANCHOR_PERCENT_TAG)
+    }
+
+    /// Returns true if the value is an `anchor-size()` value
+    #[inline(always)]
+    pub fn is_anchor_size(self) -> bool {
+        self.tag() == Self::ANCHOR_SIZE_TAG
+    }
+
     /// Returns true if the value is min-content
     #[inline(always)]
     pub fn is_min_content(self) -> bool {
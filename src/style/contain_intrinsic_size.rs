@@ -0,0 +1,97 @@
+//! Style type for the CSS `contain-intrinsic-width` and `contain-intrinsic-height` properties
+//!
+//! <https://drafts.csswg.org/css-sizing-4/#intrinsic-size-override>
+#[cfg(feature = "parse")]
+use crate::util::parse::{from_str_from_css, CssParseResult, FromCss, Parser, Token};
+
+/// The size of the content box that a node with size containment is given in one axis, in place of the size of its
+/// (ignored) content
+///
+/// This only has an effect in the axes in which the node has size containment (see [`Contain::SIZE`] and
+/// [`Contain::INLINE_SIZE`]). Such a node is sized as if it were empty, with a content box of this size.
+///
+/// [`Contain::SIZE`]: crate::Contain::SIZE
+/// [`Contain::INLINE_SIZE`]: crate::Contain::INLINE_SIZE
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/contain-intrinsic-size>
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ContainIntrinsicSize {
+    /// Whether the node's last remembered size is used in place of `length` if it has one (the `auto` keyword)
+    ///
+    /// The last remembered size is the size of the node's content box the last time it was laid out while this was
+    /// set. It is recorded by [`TaffyTree`](crate::TaffyTree), and other trees can provide it by implementing
+    /// [`LayoutPartialTree::last_remembered_size`](crate::LayoutPartialTree::last_remembered_size).
+    pub auto: bool,
+    /// The size of the content box in pixels, or `None` for the size of an empty content box (zero)
+    pub length: Option<f32>,
+}
+
+impl ContainIntrinsicSize {
+    /// The node is sized as if its content box were empty (`none` in CSS)
+    pub const NONE: Self = Self { auto: false, length: None };
+
+    /// The node's content box is given a fixed size
+    pub const fn length(length: f32) -> Self {
+        Self { auto: false, length: Some(length) }
+    }
+
+    /// The node's content box is given its last remembered size, or else the given size (`auto <length>` in CSS)
+    pub const fn auto(length: f32) -> Self {
+        Self { auto: true, length: Some(length) }
+    }
+
+    /// The size of the node's content box given its last remembered size
+    pub fn resolve(self, last_remembered_size: Option<f32>) -> f32 {
+        last_remembered_size.filter(|_| self.auto).or(self.length).unwrap_or(0.0)
+    }
+}
+
+/// Parses `none`, a length in pixels, `auto none` or `auto <length>`
+#[cfg(feature = "parse")]
+impl FromCss for ContainIntrinsicSize {
+    fn from_css<'i>(parser: &mut Parser<'i, '_>) -> CssParseResult<'i, Self> {
+        let auto = parser.try_parse(|parser| parser.expect_ident_matching("auto")).is_ok();
+        if parser.try_parse(|parser| parser.expect_ident_matching("none")).is_ok() {
+            return Ok(Self { auto, length: None });
+        }
+        let length = match parser.next()?.clone() {
+            Token::Dimension { value, ref unit, .. } if unit.eq_ignore_ascii_case("px") && value >= 0.0 => value,
+            Token::Number { value: 0.0, .. } => 0.0,
+            token => Err(parser.new_unexpected_token_error(token))?,
+        };
+        Ok(Self { auto, length: Some(length) })
+    }
+}
+#[cfg(feature = "parse")]
+from_str_from_css!(ContainIntrinsicSize);
+
+#[cfg(test)]
+mod tests {
+    use super::ContainIntrinsicSize;
+
+    #[test]
+    fn last_remembered_size_is_only_used_by_auto() {
+        assert_eq!(ContainIntrinsicSize::NONE.resolve(Some(10.0)), 0.0);
+        assert_eq!(ContainIntrinsicSize::length(20.0).resolve(Some(10.0)), 20.0);
+        assert_eq!(ContainIntrinsicSize::auto(20.0).resolve(Some(10.0)), 10.0);
+        assert_eq!(ContainIntrinsicSize::auto(20.0).resolve(None), 20.0);
+        assert_eq!(ContainIntrinsicSize { auto: true, length: None }.resolve(None), 0.0);
+    }
+
+    #[test]
+    #[cfg(feature = "parse")]
+    fn parse() {
+        use core::str::FromStr;
+        assert_eq!(ContainIntrinsicSize::from_str("none").unwrap(), ContainIntrinsicSize::NONE);
+        assert_eq!(ContainIntrinsicSize::from_str("300px").unwrap(), ContainIntrinsicSize::length(300.0));
+        assert_eq!(ContainIntrinsicSize::from_str("auto 300px").unwrap(), ContainIntrinsicSize::auto(300.0));
+        assert_eq!(
+            ContainIntrinsicSize::from_str("auto none").unwrap(),
+            ContainIntrinsicSize { auto: true, length: None }
+        );
+        assert!(ContainIntrinsicSize::from_str("auto").is_err());
+        assert!(ContainIntrinsicSize::from_str("-10px").is_err());
+        assert!(ContainIntrinsicSize::from_str("300px auto").is_err());
+    }
+}
@@ -0,0 +1,76 @@
+//! Style types for CSS Container Queries
+//!
+//! <https://drafts.csswg.org/css-conditional-5/#container-queries>
+use crate::geometry::Size;
+
+/// Whether a node is a query container, and which of its dimensions can be queried by its descendants
+///
+/// A query container has its size resolved before its descendants are laid out, so that styles which depend on that
+/// size (see [`ContainerCondition`]) can be selected for its descendants before they are laid out.
+///
+/// Unlike in CSS, this does not apply size containment to the node. Use [`Contain::SIZE`] or
+/// [`Contain::INLINE_SIZE`] for that.
+///
+/// [`Contain::SIZE`]: crate::Contain::SIZE
+/// [`Contain::INLINE_SIZE`]: crate::Contain::INLINE_SIZE
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/container-type>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ContainerType {
+    /// The node is not a query container
+    #[default]
+    Normal,
+    /// The width of the node's content box can be queried
+    InlineSize,
+    /// Both the width and the height of the node's content box can be queried
+    Size,
+}
+
+impl ContainerType {
+    /// Returns true if the node is a query container
+    #[inline(always)]
+    pub const fn is_container(self) -> bool {
+        !matches!(self, Self::Normal)
+    }
+}
+
+#[cfg(feature = "parse")]
+crate::util::parse::impl_parse_for_keyword_enum!(ContainerType,
+    "normal" => Normal,
+    "inline-size" => InlineSize,
+    "size" => Size,
+);
+
+/// A condition on the size of the content box of a node's nearest query container (an `@container` rule in CSS)
+///
+/// Each bound is inclusive, and a bound that is `None` always matches. A bound on an axis that cannot be queried (the
+/// height of a [`ContainerType::InlineSize`] container, or an axis whose size is not known at the time the container
+/// is laid out) never matches.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ContainerCondition {
+    /// The minimum width and height of the container (`min-width` and `min-height` in CSS)
+    pub min_size: Size<Option<f32>>,
+    /// The maximum width and height of the container (`max-width` and `max-height` in CSS)
+    pub max_size: Size<Option<f32>>,
+}
+
+impl ContainerCondition {
+    /// A condition that matches containers of any size
+    pub const ANY: Self = Self { min_size: Size::NONE, max_size: Size::NONE };
+
+    /// Returns true if a container whose content box has the given size matches the condition
+    pub fn matches(&self, container_size: Size<Option<f32>>) -> bool {
+        /// Whether `size` lies within the bounds of a single axis
+        fn matches_axis(size: Option<f32>, min: Option<f32>, max: Option<f32>) -> bool {
+            match size {
+                Some(size) => min.map_or(true, |min| size >= min) && max.map_or(true, |max| size <= max),
+                None => min.is_none() && max.is_none(),
+            }
+        }
+
+        matches_axis(container_size.width, self.min_size.width, self.max_size.width)
+            && matches_axis(container_size.height, self.min_size.height, self.max_size.height)
+    }
+}
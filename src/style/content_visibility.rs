@@ -0,0 +1,54 @@
+//! Style type for the CSS `content-visibility` property
+//!
+//! <https://drafts.csswg.org/css-contain-2/#content-visibility>
+use super::Contain;
+
+/// Whether a node lays out its contents, or skips them and is sized as if it were empty
+///
+/// Skipping a node's contents is performed by [`TaffyTree`](crate::TaffyTree): the contents of a skipped node are not
+/// laid out at all, and keep both the layouts and the cached results of the last time they were laid out, so that
+/// they can be laid out again cheaply when they stop being skipped. Other trees can skip contents by laying out
+/// such a node with [`compute_leaf_layout`](crate::compute_leaf_layout) instead of its usual algorithm.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/content-visibility>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ContentVisibility {
+    /// The node's contents are laid out as normal
+    #[default]
+    Visible,
+    /// The node has layout and paint containment, and its contents are skipped (with size containment applied) unless
+    /// the node is relevant to the user. [`TaffyTree::set_relevant_to_user`](crate::TaffyTree::set_relevant_to_user)
+    /// marks a node as relevant, for example when it intersects the viewport.
+    Auto,
+    /// The node has size, layout and paint containment, and its contents are always skipped
+    Hidden,
+}
+
+impl ContentVisibility {
+    /// The containment that is always applied to a node with this `content_visibility`
+    pub const fn implied_containment(self) -> Contain {
+        match self {
+            Self::Visible => Contain::NONE,
+            Self::Auto => Contain::CONTENT,
+            Self::Hidden => Contain::STRICT,
+        }
+    }
+
+    /// Whether the contents of a node with this `content_visibility` are skipped, given whether it is relevant to the
+    /// user
+    pub const fn skips_contents(self, is_relevant_to_user: bool) -> bool {
+        match self {
+            Self::Visible => false,
+            Self::Auto => !is_relevant_to_user,
+            Self::Hidden => true,
+        }
+    }
+}
+
+#[cfg(feature = "parse")]
+crate::util::parse::impl_parse_for_keyword_enum!(ContentVisibility,
+    "visible" => Visible,
+    "auto" => Auto,
+    "hidden" => Hidden,
+);
@@ -1,5 +1,5 @@
 //! Style types for representing lengths / sizes
-use super::CompactLength;
+use super::{AnchorSide, AnchorSize, CompactLength};
 use crate::geometry::Rect;
 use crate::style_helpers::{FromLength, FromPercent, TaffyAuto, TaffyZero};
 #[cfg(feature = "parse")]
@@ -177,6 +177,9 @@ impl FromCss for LengthPercentageAuto {
             Token::Percentage { unit_value, .. } => Ok(Self::percent(unit_value)),
             Token::Dimension { unit, value, .. } if unit == "px" => Ok(Self::length(value)),
             Token::Ident(ident) if ident == "auto" => Ok(Self::auto()),
+            Token::Function(ref name) if name.as_ref() == "anchor" => {
+                parser.parse_nested_block(|parser| Ok(Self::anchor(AnchorSide::from_css(parser)?)))
+            }
             token => Err(parser.new_unexpected_token_error(token))?,
         }
     }
@@ -207,6 +210,15 @@ impl LengthPercentageAuto {
         Self(CompactLength::auto())
     }
 
+    /// A position relative to an edge of the node's anchor box (see [`CoreStyle::position_anchor`](crate::CoreStyle::position_anchor)).
+    ///
+    /// Only valid in the inset of an absolutely positioned node. In any other context, and if the node
+    /// has no anchor, it behaves as [`auto`](Self::auto).
+    #[inline(always)]
+    pub const fn anchor(side: AnchorSide) -> Self {
+        Self(CompactLength::anchor(side))
+    }
+
     /// A `calc()` value. The value passed here is treated as an opaque handle to
     /// the actual calc representation and may be a pointer, index, etc.
     ///
@@ -233,13 +245,15 @@ impl LengthPercentageAuto {
     /// Returns:
     ///   - Some(length) for Length variants
     ///   - Some(resolved) using the provided context for Percent variants
-    ///   - None for Auto variants
+    ///   - None for Auto and Anchor variants
     #[inline(always)]
     pub fn resolve_to_option(self, context: f32, calc_resolver: impl Fn(*const (), f32) -> f32) -> Option<f32> {
         match self.0.tag() {
             CompactLength::LENGTH_TAG => Some(self.0.value()),
             CompactLength::PERCENT_TAG => Some(context * self.0.value()),
             CompactLength::AUTO_TAG => None,
+            // Anchor positions are resolved by the absolute positioning code. Elsewhere they behave as auto.
+            CompactLength::ANCHOR_TAG | CompactLength::ANCHOR_PERCENT_TAG => None,
             #[cfg(feature = "calc")]
             _ if self.0.is_calc() => Some(calc_resolver(self.0.calc_value(), context)),
             _ => unreachable!("LengthPercentageAuto values cannot be constructed with other tags"),
@@ -262,6 +276,12 @@ impl LengthPercentageAuto {
             CompactLength::LENGTH_TAG => ExpandedLengthPercentageAuto::Length(self.0.value()),
             CompactLength::PERCENT_TAG => ExpandedLengthPercentageAuto::Percent(self.0.value()),
             CompactLength::AUTO_TAG => ExpandedLengthPercentageAuto::Auto,
+            CompactLength::ANCHOR_TAG => {
+                ExpandedLengthPercentageAuto::Anchor(AnchorSide::from_keyword_index(self.0.value() as u8))
+            }
+            CompactLength::ANCHOR_PERCENT_TAG => {
+                ExpandedLengthPercentageAuto::Anchor(AnchorSide::Percent(self.0.value()))
+            }
             #[cfg(feature = "calc")]
             _ if self.0.is_calc() => ExpandedLengthPercentageAuto::Calc(self.0.calc_value()),
             _ => unreachable!("LengthPercentageAuto contains a value with an invalid tag"),
@@ -281,6 +301,8 @@ pub enum ExpandedLengthPercentageAuto {
     Percent(f32),
     /// The automatic keyword (see [`LengthPercentageAuto::auto`])
     Auto,
+    /// An `anchor()` value (see [`LengthPercentageAuto::anchor`])
+    Anchor(AnchorSide),
     /// A `calc()` value (see [`LengthPercentageAuto::calc`]). The pointer is an opaque handle to
     /// the calc representation, exactly as passed to the constructor.
     #[cfg(feature = "calc")]
@@ -299,6 +321,7 @@ impl From<ExpandedLengthPercentageAuto> for LengthPercentageAuto {
             ExpandedLengthPercentageAuto::Length(val) => Self::length(val),
             ExpandedLengthPercentageAuto::Percent(val) => Self::percent(val),
             ExpandedLengthPercentageAuto::Auto => Self::auto(),
+            ExpandedLengthPercentageAuto::Anchor(side) => Self::anchor(side),
             #[cfg(feature = "calc")]
             ExpandedLengthPercentageAuto::Calc(ptr) => Self::calc(ptr),
         }
@@ -313,7 +336,14 @@ impl<'de> serde::Deserialize<'de> for LengthPercentageAuto {
     {
         let inner = CompactLength::deserialize(deserializer)?;
         // Note: validation intentionally excludes the CALC_TAG as deserializing calc() values is not supported
-        if matches!(inner.tag(), CompactLength::LENGTH_TAG | CompactLength::PERCENT_TAG | CompactLength::AUTO_TAG) {
+        if matches!(
+            inner.tag(),
+            CompactLength::LENGTH_TAG
+                | CompactLength::PERCENT_TAG
+                | CompactLength::AUTO_TAG
+                | CompactLength::ANCHOR_TAG
+                | CompactLength::ANCHOR_PERCENT_TAG
+        ) {
             Ok(Self(inner))
         } else {
             Err(serde::de::Error::custom("Invalid tag"))
@@ -350,7 +380,12 @@ impl From<LengthPercentage> for Dimension {
 }
 impl From<LengthPercentageAuto> for Dimension {
     fn from(input: LengthPercentageAuto) -> Self {
-        Self(input.0)
+        // `anchor()` is not a valid size. Map it to `auto`.
+        if input.0.is_anchor() {
+            Self::auto()
+        } else {
+            Self(input.0)
+        }
     }
 }
 
@@ -378,6 +413,10 @@ impl FromCss for Dimension {
                     token => Err(parser.new_unexpected_token_error(token))?,
                 }
             }),
+            Token::Function(ref name) if name.as_ref() == "anchor-size" => parser.parse_nested_block(|parser| {
+                let size = parser.try_parse(AnchorSize::from_css).unwrap_or_default();
+                Ok(Self::anchor_size(size))
+            }),
             token => Err(parser.new_unexpected_token_error(token))?,
         }
     }
@@ -463,6 +502,15 @@ impl Dimension {
         Self(CompactLength::fit_content_percent(limit))
     }
 
+    /// The size of the node's anchor box (see [`CoreStyle::position_anchor`](crate::CoreStyle::position_anchor)).
+    ///
+    /// Only valid in the size of an absolutely positioned node. In any other context, and if the node
+    /// has no anchor, it behaves as [`auto`](Self::auto).
+    #[inline(always)]
+    pub const fn anchor_size(size: AnchorSize) -> Self {
+        Self(CompactLength::anchor_size(size))
+    }
+
     /// A `calc()` value. The value passed here is treated as an opaque handle to
     /// the actual calc representation and may be a pointer, index, etc.
     ///
@@ -545,6 +593,9 @@ impl Dimension {
             CompactLength::FIT_CONTENT_KEYWORD_TAG => ExpandedDimension::FitContent,
             CompactLength::STRETCH_TAG => ExpandedDimension::Stretch,
             CompactLength::CONTENT_TAG => ExpandedDimension::Content,
+            CompactLength::ANCHOR_SIZE_TAG => {
+                ExpandedDimension::AnchorSize(AnchorSize::from_index(self.0.value() as u8))
+            }
             #[cfg(feature = "calc")]
             _ if self.0.is_calc() => ExpandedDimension::Calc(self.0.calc_value()),
             _ => unreachable!("Dimension contains a value with an invalid tag"),
@@ -578,6 +629,8 @@ pub enum ExpandedDimension {
     Stretch,
     /// The `content` keyword (see [`Dimension::content`])
     Content,
+    /// An `anchor-size()` value (see [`Dimension::anchor_size`])
+    AnchorSize(AnchorSize),
     /// A `calc()` value (see [`Dimension::calc`]). The pointer is an opaque handle to the calc
     /// representation, exactly as passed to the constructor.
     #[cfg(feature = "calc")]
@@ -603,6 +656,7 @@ impl From<ExpandedDimension> for Dimension {
             ExpandedDimension::FitContent => Self::fit_content(),
             ExpandedDimension::Stretch => Self::stretch(),
             ExpandedDimension::Content => Self::content(),
+            ExpandedDimension::AnchorSize(size) => Self::anchor_size(size),
             #[cfg(feature = "calc")]
             ExpandedDimension::Calc(ptr) => Self::calc(ptr),
         }
@@ -629,6 +683,7 @@ impl<'de> serde::Deserialize<'de> for Dimension {
                 | CompactLength::FIT_CONTENT_PERCENT_TAG
                 | CompactLength::STRETCH_TAG
                 | CompactLength::CONTENT_TAG
+                | CompactLength::ANCHOR_SIZE_TAG
         ) {
             Ok(Self(inner))
         } else {
@@ -689,12 +744,21 @@ mod expand_tests {
 
     #[test]
     fn length_percentage_auto_round_trips() {
-        let cases =
-            [LengthPercentageAuto::length(12.0), LengthPercentageAuto::percent(0.5), LengthPercentageAuto::auto()];
+        let cases = [
+            LengthPercentageAuto::length(12.0),
+            LengthPercentageAuto::percent(0.5),
+            LengthPercentageAuto::auto(),
+            LengthPercentageAuto::anchor(AnchorSide::Bottom),
+            LengthPercentageAuto::anchor(AnchorSide::Percent(0.25)),
+        ];
         for value in cases {
             assert_eq!(LengthPercentageAuto::from(value.expand()), value);
         }
         assert_eq!(LengthPercentageAuto::auto().expand(), ExpandedLengthPercentageAuto::Auto);
+        assert_eq!(
+            LengthPercentageAuto::anchor(AnchorSide::Center).expand(),
+            ExpandedLengthPercentageAuto::Anchor(AnchorSide::Center)
+        );
     }
 
     #[test]
@@ -710,6 +774,8 @@ mod expand_tests {
             Dimension::fit_content(),
             Dimension::stretch(),
             Dimension::content(),
+            Dimension::anchor_size(AnchorSize::SameAxis),
+            Dimension::anchor_size(AnchorSize::Height),
         ];
         for value in cases {
             assert_eq!(Dimension::from(value.expand()), value);
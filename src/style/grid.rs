@@ -6,7 +6,7 @@ use super::{
 use crate::compute::grid::{GridCoordinate, GridLine, OriginZeroLine, MAX_GRID_TRACKS};
 use crate::geometry::{AbsoluteAxis, AbstractAxis, Line, MinMax, Size};
 use crate::style_helpers::*;
-use crate::sys::{DefaultCheapStr, Vec};
+use crate::sys::{DefaultCheapStr, String, Vec};
 use core::cmp::{max, min};
 use core::fmt::Debug;
 
@@ -48,6 +48,331 @@ pub struct GridTemplateArea<CustomIdent: CheapCloneStr> {
     pub column_end: u16,
 }
 
+/// An error describing why a `grid-template-areas` template is invalid
+///
+/// Returned by [`GridTemplateAreas::from_rows`] (and the `FromStr` implementation) for templates that fail to
+/// parse, and by [`GridTemplateAreas::validate`] for templates that were constructed directly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridTemplateAreasError {
+    /// The template doesn't contain any rows
+    Empty,
+    /// The row at index `row` doesn't contain any cells
+    EmptyRow {
+        /// The (zero-based) index of the row
+        row: u16,
+    },
+    /// The row at index `row` contains a token that is neither an area name nor a run of `.` characters
+    InvalidToken {
+        /// The (zero-based) index of the row
+        row: u16,
+        /// The invalid token
+        token: String,
+    },
+    /// The row at index `row` has a different number of cells to the first row
+    RowLengthMismatch {
+        /// The (zero-based) index of the row
+        row: u16,
+        /// The number of cells in the first row
+        expected: u16,
+        /// The number of cells in the row
+        found: u16,
+    },
+    /// The cells of the named area don't fill a rectangle
+    NonRectangularArea {
+        /// The name of the area
+        name: String,
+    },
+    /// The area name is used for more than one disjoint region of the template
+    DuplicateAreaName {
+        /// The name of the area
+        name: String,
+    },
+    /// The named area is empty or extends outside of the `row_count` x `column_count` template
+    AreaOutOfBounds {
+        /// The name of the area
+        name: String,
+    },
+    /// Two named areas share one or more cells
+    OverlappingAreas {
+        /// The name of the first area
+        first: String,
+        /// The name of the second area
+        second: String,
+    },
+    /// An explicitly named `<area>-start` or `<area>-end` line doesn't coincide with the implicitly named line
+    /// generated by the area, so placing an item by the area name won't place it in the area.
+    ConflictingLineName {
+        /// The name of the line
+        line_name: String,
+        /// The axis of the line (`Horizontal` for column lines, `Vertical` for row lines)
+        axis: AbsoluteAxis,
+        /// The grid line which has been explicitly given the name
+        explicit_line: u16,
+        /// The grid line which the area implicitly gives the name
+        area_line: u16,
+    },
+    /// The input isn't a sequence of one or more CSS strings
+    #[cfg(feature = "parse")]
+    Syntax(ParseError),
+}
+
+impl core::fmt::Display for GridTemplateAreasError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "grid-template-areas must contain at least one row"),
+            Self::EmptyRow { row } => write!(f, "row {row} of grid-template-areas contains no cells"),
+            Self::InvalidToken { row, token } => {
+                write!(f, "row {row} of grid-template-areas contains the invalid token '{token}'")
+            }
+            Self::RowLengthMismatch { row, expected, found } => {
+                write!(f, "row {row} of grid-template-areas has {found} cells, but the first row has {expected}")
+            }
+            Self::NonRectangularArea { name } => write!(f, "grid area '{name}' is not rectangular"),
+            Self::DuplicateAreaName { name } => write!(f, "grid area '{name}' is defined more than once"),
+            Self::AreaOutOfBounds { name } => {
+                write!(f, "grid area '{name}' is empty or lies outside of the area template")
+            }
+            Self::OverlappingAreas { first, second } => write!(f, "grid areas '{first}' and '{second}' overlap"),
+            Self::ConflictingLineName { line_name, axis, explicit_line, area_line } => write!(
+                f,
+                "{axis:?} line {explicit_line} is named '{line_name}', but the grid area names line {area_line}"
+            ),
+            #[cfg(feature = "parse")]
+            Self::Syntax(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GridTemplateAreasError {}
+
+impl<S: CheapCloneStr> GridTemplateAreas<S> {
+    /// Create an area template from the rows of a `grid-template-areas` value (the contents of each CSS string).
+    ///
+    /// Each row is a whitespace-separated list of cells, where each cell is either an area name or a run of one or
+    /// more `.` characters (an unnamed cell). Every row must have the same number of cells, and the cells of each
+    /// area name must form a single filled rectangle.
+    pub fn from_rows<R: AsRef<str>>(rows: impl IntoIterator<Item = R>) -> Result<Self, GridTemplateAreasError> {
+        // The name of each cell (`None` for unnamed cells) in row-major order
+        let mut cells: Vec<Option<String>> = Vec::new();
+        let mut row_count: u16 = 0;
+        let mut column_count: u16 = 0;
+
+        for (row, row_str) in rows.into_iter().enumerate() {
+            let row = row.min(u16::MAX as usize) as u16;
+            let mut row_length: u16 = 0;
+            for token in area_row_tokens(row_str.as_ref()) {
+                match token {
+                    AreaRowToken::Named(name) => cells.push(Some(String::from(name))),
+                    AreaRowToken::Unnamed => cells.push(None),
+                    AreaRowToken::Invalid(token) => {
+                        return Err(GridTemplateAreasError::InvalidToken { row, token: String::from(token) })
+                    }
+                }
+                row_length = row_length.saturating_add(1);
+            }
+
+            if row_length == 0 {
+                return Err(GridTemplateAreasError::EmptyRow { row });
+            }
+            if row == 0 {
+                column_count = row_length;
+            } else if row_length != column_count {
+                return Err(GridTemplateAreasError::RowLengthMismatch {
+                    row,
+                    expected: column_count,
+                    found: row_length,
+                });
+            }
+            row_count = row_count.saturating_add(1);
+        }
+
+        if row_count == 0 {
+            return Err(GridTemplateAreasError::Empty);
+        }
+
+        let columns = column_count as usize;
+        let mut areas = crate::util::sys::GridTrackVec::new();
+        for (first_cell, name) in cells.iter().enumerate() {
+            let Some(name) = name else { continue };
+            // Each area is generated from the first cell with its name
+            if cells[..first_cell].iter().any(|cell| cell.as_ref() == Some(name)) {
+                continue;
+            }
+
+            let named_cells = || cells.iter().enumerate().filter(move |(_, cell)| cell.as_ref() == Some(name));
+            let (mut row_start, mut row_end) = (usize::MAX, 0);
+            let (mut column_start, mut column_end) = (usize::MAX, 0);
+            for (index, _) in named_cells() {
+                row_start = min(row_start, index / columns);
+                row_end = max(row_end, index / columns + 1);
+                column_start = min(column_start, index % columns);
+                column_end = max(column_end, index % columns + 1);
+            }
+
+            let cell_count = named_cells().count();
+            if cell_count != (row_end - row_start) * (column_end - column_start) {
+                return Err(if area_cells_are_connected(&cells, columns, first_cell) {
+                    GridTemplateAreasError::NonRectangularArea { name: name.clone() }
+                } else {
+                    GridTemplateAreasError::DuplicateAreaName { name: name.clone() }
+                });
+            }
+
+            // Areas are stored in grid coordinates, in which the first line is line 1
+            areas.push(GridTemplateArea {
+                name: S::from(name.as_str()),
+                row_start: row_start as u16 + 1,
+                row_end: row_end as u16 + 1,
+                column_start: column_start as u16 + 1,
+                column_end: column_end as u16 + 1,
+            });
+        }
+
+        Ok(Self { areas, row_count, column_count })
+    }
+
+    /// Check that the template is well formed: that each area lies within the `row_count` x `column_count`
+    /// template, and that no area name is used twice and no two areas overlap.
+    ///
+    /// Templates created by [`GridTemplateAreas::from_rows`] are always well formed, but templates that are
+    /// constructed directly are laid out as specified even if they are invalid.
+    pub fn validate(&self) -> Result<(), Vec<GridTemplateAreasError>> {
+        let mut errors = Vec::new();
+        for (index, area) in self.areas.iter().enumerate() {
+            let name = || String::from(area.name.as_ref());
+            let in_bounds = |start: u16, end: u16, count: u16| start >= 1 && start < end && end <= count + 1;
+            if !in_bounds(area.row_start, area.row_end, self.row_count)
+                || !in_bounds(area.column_start, area.column_end, self.column_count)
+            {
+                errors.push(GridTemplateAreasError::AreaOutOfBounds { name: name() });
+            }
+
+            for earlier in &self.areas[..index] {
+                if earlier.name == area.name {
+                    errors.push(GridTemplateAreasError::DuplicateAreaName { name: name() });
+                } else if earlier.row_start < area.row_end
+                    && area.row_start < earlier.row_end
+                    && earlier.column_start < area.column_end
+                    && area.column_start < earlier.column_end
+                {
+                    errors.push(GridTemplateAreasError::OverlappingAreas {
+                        first: String::from(earlier.name.as_ref()),
+                        second: name(),
+                    });
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// A token in a row of a `grid-template-areas` template
+enum AreaRowToken<'a> {
+    /// A named cell
+    Named(&'a str),
+    /// An unnamed cell (a run of `.` characters)
+    Unnamed,
+    /// Any other run of characters, which makes the template invalid
+    Invalid(&'a str),
+}
+
+/// Split a row of a `grid-template-areas` template into tokens.
+/// See: <https://www.w3.org/TR/css-grid-2/#valdef-grid-template-areas-string>
+fn area_row_tokens(row: &str) -> impl Iterator<Item = AreaRowToken<'_>> {
+    /// The kind of token that a character is a part of
+    #[derive(PartialEq)]
+    enum CharKind {
+        /// An ident code point
+        Ident,
+        /// A `.` character
+        Dot,
+        /// Whitespace, which separates tokens
+        Whitespace,
+        /// Anything else
+        Other,
+    }
+    fn char_kind(c: char) -> CharKind {
+        match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' => CharKind::Ident,
+            '.' => CharKind::Dot,
+            ' ' | '\t' | '\n' | '\r' | '\u{c}' => CharKind::Whitespace,
+            c if !c.is_ascii() => CharKind::Ident,
+            _ => CharKind::Other,
+        }
+    }
+
+    let mut rest = row;
+    core::iter::from_fn(move || loop {
+        let first = rest.chars().next()?;
+        let kind = char_kind(first);
+        let length = rest.find(|c| char_kind(c) != kind).unwrap_or(rest.len());
+        let (token, remainder) = rest.split_at(length);
+        rest = remainder;
+        match kind {
+            CharKind::Ident => return Some(AreaRowToken::Named(token)),
+            CharKind::Dot => return Some(AreaRowToken::Unnamed),
+            CharKind::Other => return Some(AreaRowToken::Invalid(token)),
+            CharKind::Whitespace => continue,
+        }
+    })
+}
+
+/// Whether all of the cells with the same name as the cell at index `start` can be reached from it by moving
+/// between horizontally or vertically adjacent cells of that name.
+fn area_cells_are_connected(cells: &[Option<String>], columns: usize, start: usize) -> bool {
+    let name = &cells[start];
+    let mut visited: Vec<bool> = core::iter::repeat(false).take(cells.len()).collect();
+    let mut stack = Vec::from([start]);
+    visited[start] = true;
+    while let Some(index) = stack.pop() {
+        let (row, column) = (index / columns, index % columns);
+        let neighbours = [
+            (column > 0).then(|| index - 1),
+            (column + 1 < columns).then(|| index + 1),
+            (row > 0).then(|| index - columns),
+            (index + columns < cells.len()).then(|| index + columns),
+        ];
+        for neighbour in neighbours.into_iter().flatten() {
+            if !visited[neighbour] && &cells[neighbour] == name {
+                visited[neighbour] = true;
+                stack.push(neighbour);
+            }
+        }
+    }
+    cells.iter().zip(visited).all(|(cell, visited)| visited || cell != name)
+}
+
+/// The rows of a `grid-template-areas` template: a sequence of one or more CSS strings
+#[cfg(feature = "parse")]
+struct GridTemplateAreaRows(Vec<String>);
+
+#[cfg(feature = "parse")]
+impl FromCss for GridTemplateAreaRows {
+    fn from_css<'i>(parser: &mut Parser<'i, '_>) -> CssParseResult<'i, Self> {
+        let mut rows = Vec::new();
+        rows.push(String::from(parser.expect_string()?.as_ref()));
+        while !parser.is_exhausted() {
+            rows.push(String::from(parser.expect_string()?.as_ref()));
+        }
+        Ok(Self(rows))
+    }
+}
+
+#[cfg(feature = "parse")]
+impl<S: CheapCloneStr> core::str::FromStr for GridTemplateAreas<S> {
+    type Err = GridTemplateAreasError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let rows: GridTemplateAreaRows = parse_css_str_entirely(input).map_err(GridTemplateAreasError::Syntax)?;
+        Self::from_rows(rows.0)
+    }
+}
+
 /// Defines a named grid line
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -764,7 +1089,12 @@ impl From<LengthPercentage> for MaxTrackSizingFunction {
 }
 impl From<LengthPercentageAuto> for MaxTrackSizingFunction {
     fn from(input: LengthPercentageAuto) -> Self {
-        Self(input.0)
+        // `anchor()` is not a valid track sizing function. Map it to `auto`.
+        if input.0.is_anchor() {
+            Self::auto()
+        } else {
+            Self(input.0)
+        }
     }
 }
 impl From<Dimension> for MaxTrackSizingFunction {
@@ -772,9 +1102,10 @@ impl From<Dimension> for MaxTrackSizingFunction {
         // Dimension supports values that are not valid max track sizing functions.
         // Map those to `auto`.
         match input.0.tag() {
-            CompactLength::FIT_CONTENT_KEYWORD_TAG | CompactLength::STRETCH_TAG | CompactLength::CONTENT_TAG => {
-                Self::auto()
-            }
+            CompactLength::FIT_CONTENT_KEYWORD_TAG
+            | CompactLength::STRETCH_TAG
+            | CompactLength::CONTENT_TAG
+            | CompactLength::ANCHOR_SIZE_TAG => Self::auto(),
             _ => Self(input.0),
         }
     }
@@ -1163,7 +1494,12 @@ impl From<LengthPercentage> for MinTrackSizingFunction {
 }
 impl From<LengthPercentageAuto> for MinTrackSizingFunction {
     fn from(input: LengthPercentageAuto) -> Self {
-        Self(input.0)
+        // `anchor()` is not a valid track sizing function. Map it to `auto`.
+        if input.0.is_anchor() {
+            Self::auto()
+        } else {
+            Self(input.0)
+        }
     }
 }
 impl From<Dimension> for MinTrackSizingFunction {
@@ -1175,7 +1511,8 @@ impl From<Dimension> for MinTrackSizingFunction {
             | CompactLength::FIT_CONTENT_PERCENT_TAG
             | CompactLength::FIT_CONTENT_KEYWORD_TAG
             | CompactLength::STRETCH_TAG
-            | CompactLength::CONTENT_TAG => Self::auto(),
+            | CompactLength::CONTENT_TAG
+            | CompactLength::ANCHOR_SIZE_TAG => Self::auto(),
             _ => Self(input.0),
         }
     }
@@ -1640,7 +1977,7 @@ pub enum GridTemplateComponent<S: CheapCloneStr> {
     /// A single non-repeated track
     Single(TrackSizingFunction),
     /// Automatically generate grid tracks to fit the available space using the specified definite track lengths
-    /// Only valid if every track in template (not just the repetition) has a fixed size.
+    /// When the repetition is `auto-fill` or `auto-fit`, every other track in the template must have a fixed size.
     Repeat(GridTemplateRepetition<S>),
 }
 
@@ -1852,6 +2189,18 @@ mod tests {
         };
         assert_eq!((&repetition).track_count(), u16::MAX);
     }
+
+    #[test]
+    fn grid_template_areas_parser_reports_template_errors() {
+        let template = "\"a a\" \"b .\"".parse::<GridTemplateAreas<DefaultCheapStr>>().unwrap();
+        assert_eq!((template.row_count, template.column_count, template.areas.len()), (2, 2, 2));
+
+        assert_eq!(
+            "\"a a\" \"b\"".parse::<GridTemplateAreas<DefaultCheapStr>>(),
+            Err(GridTemplateAreasError::RowLengthMismatch { row: 1, expected: 2, found: 1 })
+        );
+        assert!(matches!("a a".parse::<GridTemplateAreas<DefaultCheapStr>>(), Err(GridTemplateAreasError::Syntax(_))));
+    }
 }
 
 #[cfg(test)]
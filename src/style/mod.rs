@@ -1,8 +1,16 @@
 //! A typed representation of [CSS style properties](https://css-tricks.com/snippets/css/a-guide-to-flexbox/) in Rust. Used as input to layout computation.
 mod alignment;
+mod anchor;
+mod aspect_ratio;
 mod available_space;
 mod compact_length;
+mod contain_intrinsic_size;
+mod container_query;
+mod content_visibility;
 mod dimension;
+mod object_fit;
+mod transform;
+mod validation;
 
 #[cfg(feature = "block_layout")]
 mod block;
@@ -17,16 +25,24 @@ pub use self::alignment::{
     AlignContent, AlignContentKeyword, AlignItems, AlignItemsKeyword, AlignSelf, AlignmentSafety, JustifyContent,
     JustifyItems, JustifySelf,
 };
+pub use self::anchor::{AnchorSide, AnchorSize, PositionTryFallback, PositionTryFallbacks};
+pub use self::aspect_ratio::AspectRatio;
 pub use self::available_space::AvailableSpace;
 pub use self::compact_length::CompactLength;
+pub use self::contain_intrinsic_size::ContainIntrinsicSize;
+pub use self::container_query::{ContainerCondition, ContainerType};
+pub use self::content_visibility::ContentVisibility;
 pub use self::dimension::{
     Dimension, ExpandedDimension, ExpandedLengthPercentage, ExpandedLengthPercentageAuto, LengthPercentage,
     LengthPercentageAuto,
 };
+pub use self::object_fit::ObjectFit;
+pub use self::transform::Transform;
+pub use self::validation::StyleValidationError;
 use crate::sys::DefaultCheapStr;
 
 #[cfg(feature = "block_layout")]
-pub use self::block::{BlockContainerStyle, BlockItemStyle, TextAlign};
+pub use self::block::{BlockContainerStyle, BlockItemStyle, ListStylePosition, RubyAlign, TextAlign};
 #[cfg(feature = "flexbox")]
 pub use self::flex::{FlexDirection, FlexWrap, FlexboxContainerStyle, FlexboxItemStyle};
 #[cfg(feature = "float_layout")]
@@ -41,7 +57,7 @@ pub use self::grid::{
 #[cfg(feature = "grid")]
 pub(crate) use self::grid::{GridAreaAxis, GridAreaEnd};
 #[cfg(feature = "grid")]
-pub use self::grid::{GridTemplateArea, GridTemplateAreas, NamedGridLine, TemplateLineNames};
+pub use self::grid::{GridTemplateArea, GridTemplateAreas, GridTemplateAreasError, NamedGridLine, TemplateLineNames};
 #[cfg(feature = "grid")]
 pub(crate) use self::grid::{NonNamedGridPlacement, OriginZeroGridPlacement};
 
@@ -92,11 +108,18 @@ pub trait CoreStyle {
     fn box_generation_mode(&self) -> BoxGenerationMode {
         BoxGenerationMode::DEFAULT
     }
+    /// Whether the node generates a block-level or an inline-level box
+    #[inline(always)]
+    fn display_outside(&self) -> DisplayOutside {
+        DisplayOutside::Block
+    }
     /// Is block layout?
     ///
-    /// This should only return `true` for `display: block`, and NOT for `display: flow-root`.
-    /// Flow-root boxes establish a new block formatting context and must not be treated as
-    /// being part of their parent's block formatting context (which is what this method controls).
+    /// This should only return `true` for `display: block`, and NOT for `display: flow-root` or
+    /// `display: inline-block`. Flow-root and inline-block boxes establish a new block formatting context
+    /// and must not be treated as being part of their parent's block formatting context (which is what
+    /// this method controls). Whether a box is block-level or inline-level is controlled by
+    /// [`display_outside`](CoreStyle::display_outside) instead.
     #[inline(always)]
     fn is_block(&self) -> bool {
         false
@@ -130,6 +153,11 @@ pub trait CoreStyle {
     fn scrollbar_width(&self) -> f32 {
         0.0
     }
+    /// Whether space should be reserved for the node's scrollbar even if no scrollbar is shown
+    #[inline(always)]
+    fn scrollbar_gutter(&self) -> ScrollbarGutter {
+        Style::<Self::CustomIdent>::DEFAULT.scrollbar_gutter
+    }
 
     // Position properties
     /// What should the `position` value of this struct use as a base offset?
@@ -143,6 +171,23 @@ pub trait CoreStyle {
         Style::<Self::CustomIdent>::DEFAULT.inset
     }
 
+    // Anchor positioning properties
+    /// The name by which absolutely positioned nodes can refer to this node as their anchor
+    #[inline(always)]
+    fn anchor_name(&self) -> Option<&Self::CustomIdent> {
+        None
+    }
+    /// The name of the anchor that the `anchor()` and `anchor-size()` values in this node's inset and size refer to
+    #[inline(always)]
+    fn position_anchor(&self) -> Option<&Self::CustomIdent> {
+        None
+    }
+    /// The alternative positions that are tried if this node overflows its containing block
+    #[inline(always)]
+    fn position_try_fallbacks(&self) -> PositionTryFallbacks {
+        Style::<Self::CustomIdent>::DEFAULT.position_try_fallbacks
+    }
+
     // Size properies
     /// Sets the initial size of the item
     #[inline(always)]
@@ -162,9 +207,30 @@ pub trait CoreStyle {
     /// Sets the preferred aspect ratio for the item
     /// The ratio is calculated as width divided by height.
     #[inline(always)]
-    fn aspect_ratio(&self) -> Option<f32> {
+    fn aspect_ratio(&self) -> AspectRatio {
         Style::<Self::CustomIdent>::DEFAULT.aspect_ratio
     }
+    /// How the content of a replaced node is sized to fit within its content box
+    #[inline(always)]
+    fn object_fit(&self) -> ObjectFit {
+        Style::<Self::CustomIdent>::DEFAULT.object_fit
+    }
+    /// The position of the content of a replaced node within its content box
+    #[inline(always)]
+    fn object_position(&self) -> Point<LengthPercentage> {
+        Style::<Self::CustomIdent>::DEFAULT.object_position
+    }
+    /// The 2D transform that is applied to the node (about its `transform_origin`) when it contributes to the
+    /// scrollable overflow of its ancestors
+    #[inline(always)]
+    fn transform(&self) -> Transform {
+        Style::<Self::CustomIdent>::DEFAULT.transform
+    }
+    /// The point of the node's border box about which its `transform` is applied
+    #[inline(always)]
+    fn transform_origin(&self) -> Point<LengthPercentage> {
+        Style::<Self::CustomIdent>::DEFAULT.transform_origin
+    }
 
     // Spacing Properties
     /// How large should the margin be on each side?
@@ -183,11 +249,30 @@ pub trait CoreStyle {
         Style::<Self::CustomIdent>::DEFAULT.border
     }
 
-    /// The layout-affecting parts of the CSS `contain` property that apply to this node
+    /// The layout-affecting parts of the CSS `contain` property that apply to this node, including the containment
+    /// implied by its `content_visibility`
     #[inline(always)]
     fn contain(&self) -> Contain {
         Contain::NONE
     }
+
+    /// The size that this node's content box is given in the axes in which it has size containment
+    #[inline(always)]
+    fn contain_intrinsic_size(&self) -> Size<ContainIntrinsicSize> {
+        Style::<Self::CustomIdent>::DEFAULT.contain_intrinsic_size
+    }
+
+    /// Whether this node lays out its contents, or skips them and is sized as if it were empty
+    #[inline(always)]
+    fn content_visibility(&self) -> ContentVisibility {
+        Style::<Self::CustomIdent>::DEFAULT.content_visibility
+    }
+
+    /// Whether this node is a query container whose size can be queried by its descendants
+    #[inline(always)]
+    fn container_type(&self) -> ContainerType {
+        Style::<Self::CustomIdent>::DEFAULT.container_type
+    }
 }
 
 /// Sets the layout used for the children of this node
@@ -208,6 +293,47 @@ pub enum Display {
     /// The children will follow the CSS Grid layout algorithm
     #[cfg(feature = "grid")]
     Grid,
+    /// A [`Block`](Display::Block) box that also generates a marker box (such as a bullet or a number), which is
+    /// positioned according to its `list_style_position`. The marker is sized by
+    /// [`LayoutBlockContainer::compute_marker_layout`](crate::LayoutBlockContainer::compute_marker_layout) and its
+    /// position is output in [`Layout::marker_rect`](crate::Layout::marker_rect).
+    ///
+    /// Like other block containers, a list item without children is laid out as a leaf node and generates no marker,
+    /// so the content of a list item should be placed in child nodes.
+    #[cfg(feature = "block_layout")]
+    ListItem,
+    /// An inline-level [`FlowRoot`](Display::FlowRoot) box
+    #[cfg(feature = "block_layout")]
+    InlineBlock,
+    /// An inline-level ruby container. Its children are laid out in pairs of a ruby base (any child that isn't
+    /// [`RubyText`](Display::RubyText)) and an optional ruby annotation (a following `RubyText` child), which are
+    /// placed side by side in the inline direction. Each pair is as wide as the wider of its base and annotation, and
+    /// each annotation is placed above its base. The container's baseline is the baseline of its bases.
+    ///
+    /// The narrower box of each pair is distributed within the pair according to [`RubyAlign`], although the
+    /// justification of its content is left to the renderer. Only horizontal ruby is supported: vertical writing modes
+    /// (which place annotations beside their bases) are not.
+    #[cfg(feature = "block_layout")]
+    Ruby,
+    /// A ruby annotation, which annotates the preceding ruby base in a [`Ruby`](Display::Ruby) container. Its children
+    /// are laid out like those of a [`FlowRoot`](Display::FlowRoot) box (which it behaves as outside of a ruby
+    /// container).
+    #[cfg(feature = "block_layout")]
+    RubyText,
+    /// An inline-level [`Flex`](Display::Flex) container
+    #[cfg(feature = "flexbox")]
+    InlineFlex,
+    /// An inline-level [`Grid`](Display::Grid) container
+    #[cfg(feature = "grid")]
+    InlineGrid,
+    /// The node does not generate a box, and it's children are laid out as if they were children of the node's parent.
+    /// The node itself is given an empty layout.
+    ///
+    /// If the root node of a layout has `display: contents` then it is laid out using [`Display::DEFAULT`] instead.
+    ///
+    /// This is implemented by [`TaffyTree`](crate::TaffyTree). Custom trees must splice the children of such nodes
+    /// into the children of their parent themselves (see [`BoxGenerationMode::Contents`]).
+    Contents,
     /// The node is hidden, and it's children will also be hidden
     None,
 }
@@ -228,6 +354,37 @@ impl Display {
     /// The default Display mode
     #[cfg(all(not(feature = "flexbox"), not(feature = "grid"), not(feature = "block_layout")))]
     pub const DEFAULT: Display = Display::None;
+
+    /// The outer display type of the node, which determines how it participates in the layout of its parent
+    pub const fn outside(self) -> DisplayOutside {
+        match self {
+            #[cfg(feature = "block_layout")]
+            Display::InlineBlock | Display::Ruby => DisplayOutside::Inline,
+            #[cfg(feature = "flexbox")]
+            Display::InlineFlex => DisplayOutside::Inline,
+            #[cfg(feature = "grid")]
+            Display::InlineGrid => DisplayOutside::Inline,
+            _ => DisplayOutside::Block,
+        }
+    }
+
+    /// The block-level display with the same inner display type as this one, which determines how the node's
+    /// children are laid out. Inline-level displays map to their block-level counterpart (`InlineBlock` to `FlowRoot`,
+    /// `InlineFlex` to `Flex` and `InlineGrid` to `Grid`), `ListItem` maps to `Block`, `RubyText` maps to `FlowRoot`,
+    /// and every other display (including `Ruby`, whose children are always laid out as ruby) maps to itself.
+    pub const fn inside(self) -> Display {
+        match self {
+            #[cfg(feature = "block_layout")]
+            Display::ListItem => Display::Block,
+            #[cfg(feature = "block_layout")]
+            Display::InlineBlock | Display::RubyText => Display::FlowRoot,
+            #[cfg(feature = "flexbox")]
+            Display::InlineFlex => Display::Flex,
+            #[cfg(feature = "grid")]
+            Display::InlineGrid => Display::Grid,
+            display => display,
+        }
+    }
 }
 
 impl Default for Display {
@@ -239,6 +396,7 @@ impl Default for Display {
 #[cfg(feature = "parse")]
 crate::util::parse::impl_parse_for_keyword_enum!(Display,
     "none" => None,
+    "contents" => Contents,
     #[cfg(feature = "flexbox")]
     "flex" => Flex,
     #[cfg(feature = "grid")]
@@ -247,12 +405,25 @@ crate::util::parse::impl_parse_for_keyword_enum!(Display,
     "block" => Block,
     #[cfg(feature = "block_layout")]
     "flow-root" => FlowRoot,
+    #[cfg(feature = "block_layout")]
+    "list-item" => ListItem,
+    #[cfg(feature = "block_layout")]
+    "inline-block" => InlineBlock,
+    #[cfg(feature = "block_layout")]
+    "ruby" => Ruby,
+    #[cfg(feature = "block_layout")]
+    "ruby-text" => RubyText,
+    #[cfg(feature = "flexbox")]
+    "inline-flex" => InlineFlex,
+    #[cfg(feature = "grid")]
+    "inline-grid" => InlineGrid,
 );
 
 impl core::fmt::Display for Display {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Display::None => write!(f, "NONE"),
+            Display::Contents => write!(f, "CONTENTS"),
             #[cfg(feature = "block_layout")]
             Display::Block => write!(f, "BLOCK"),
             #[cfg(feature = "block_layout")]
@@ -261,17 +432,59 @@ impl core::fmt::Display for Display {
             Display::Flex => write!(f, "FLEX"),
             #[cfg(feature = "grid")]
             Display::Grid => write!(f, "GRID"),
+            #[cfg(feature = "block_layout")]
+            Display::ListItem => write!(f, "LIST-ITEM"),
+            #[cfg(feature = "block_layout")]
+            Display::InlineBlock => write!(f, "INLINE-BLOCK"),
+            #[cfg(feature = "block_layout")]
+            Display::Ruby => write!(f, "RUBY"),
+            #[cfg(feature = "block_layout")]
+            Display::RubyText => write!(f, "RUBY-TEXT"),
+            #[cfg(feature = "flexbox")]
+            Display::InlineFlex => write!(f, "INLINE-FLEX"),
+            #[cfg(feature = "grid")]
+            Display::InlineGrid => write!(f, "INLINE-GRID"),
         }
     }
 }
 
-/// An abstracted version of the CSS `display` property where any value other than "none" is represented by "normal"
+/// The outer display type of a node, which determines how it participates in the layout of its parent
+///
+/// Inline-level boxes are only treated differently by block layout, which places them side by side in line boxes.
+/// Flex and grid layout treat them as block-level boxes (they are "blockified").
+/// See: <https://www.w3.org/TR/css-display-3/#outer-role>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DisplayOutside {
+    /// The node generates a block-level box
+    #[default]
+    Block,
+    /// The node generates an atomic inline-level box, which is shrink-to-fit sized and placed in a line box
+    Inline,
+}
+
+/// An abstracted version of the CSS `display` property where any value other than "none" or "contents" is represented
+/// by "normal"
 /// See: <https://www.w3.org/TR/css-display-3/#box-generation>
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum BoxGenerationMode {
     /// The node generates a box in the regular way
     Normal,
+    /// The node generates no box, and its children are laid out in its place as if they were children of its parent
+    ///
+    /// Only [`TaffyTree`] implements this mode. The layout algorithms (e.g. [`compute_flexbox_layout`]) lay out
+    /// exactly the children returned by [`TraversePartialTree::child_ids`] and treat a child in this mode like a
+    /// [`Normal`](Self::Normal) child, so custom trees that support it must flatten the children themselves:
+    ///  - `child_ids` (along with `child_count` and `get_child_id`) must return the children of the node's parent
+    ///    with each child in this mode replaced by its own (recursively flattened) children.
+    ///  - The node itself is never laid out, so the tree must give it a layout (an empty layout at the origin of its
+    ///    parent keeps the locations of its children relative to that parent).
+    ///
+    /// [`TaffyTree`]: crate::TaffyTree
+    /// [`compute_flexbox_layout`]: crate::compute_flexbox_layout
+    /// [`TraversePartialTree::child_ids`]: crate::TraversePartialTree::child_ids
+    Contents,
     /// The node and it's descendants generate no boxes (they are hidden)
     None,
 }
@@ -354,9 +567,10 @@ crate::util::parse::impl_parse_for_keyword_enum!(BoxSizing,
 ///
 ///   - The automatic minimum size Flexbox/CSS Grid items with non-`Visible` overflow is `0` rather than being content based
 ///   - `Overflow::Scroll` nodes have space in the layout reserved for a scrollbar (width controlled by the `scrollbar_width` property)
+///   - `Overflow::Auto` nodes have space reserved for a scrollbar only if their content overflows
 ///
 /// In Taffy, we only implement the layout related secondary effects as we are not concerned with drawing/painting. The amount of space reserved for
-/// a scrollbar is controlled by the `scrollbar_width` property. If this is `0` then `Scroll` behaves identically to `Hidden`.
+/// a scrollbar is controlled by the `scrollbar_width` property. If this is `0` then `Scroll` and `Auto` behave identically to `Hidden`.
 ///
 /// <https://developer.mozilla.org/en-US/docs/Web/CSS/overflow>
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
@@ -376,6 +590,13 @@ pub enum Overflow {
     /// for a scrollbar. The amount of space reserved is controlled by the `scrollbar_width` property.
     /// Content that overflows this node should *not* contribute to the scroll region of its parent.
     Scroll,
+    /// The automatic minimum size of this node as a flexbox/grid item should be `0`. Additionally, space should be reserved
+    /// for a scrollbar if the node's content overflows it in this axis. The node is first laid out without a scrollbar, and
+    /// then laid out again with space reserved for the scrollbar if its content overflows. The amount of space reserved is
+    /// controlled by the `scrollbar_width` property. Detecting overflow requires the `content_size` feature: without it no
+    /// space is reserved.
+    /// Content that overflows this node should *not* contribute to the scroll region of its parent.
+    Auto,
 }
 
 impl Overflow {
@@ -385,7 +606,7 @@ impl Overflow {
     pub fn is_scroll_container(self) -> bool {
         match self {
             Self::Visible | Self::Clip => false,
-            Self::Hidden | Self::Scroll => true,
+            Self::Hidden | Self::Scroll | Self::Auto => true,
         }
     }
 
@@ -406,8 +627,68 @@ crate::util::parse::impl_parse_for_keyword_enum!(Overflow,
     "hidden" => Hidden,
     "clip" => Clip,
     "scroll" => Scroll,
+    "auto" => Auto,
 );
 
+/// Whether space should be reserved for a node's (vertical) scrollbar regardless of whether the scrollbar is shown.
+///
+/// The gutter is the space between the inner border edge and the outer padding edge in which a scrollbar is drawn. By
+/// default, space is only reserved for a scrollbar when one is shown (always for `Overflow::Scroll`, and only if the
+/// content overflows for `Overflow::Auto`). Setting this property to `Stable` or `StableBothEdges` reserves the gutter
+/// for any scroll container (`Overflow::Hidden`, `Overflow::Scroll` or `Overflow::Auto` in the `y` axis), so that
+/// content does not move when a scrollbar appears or disappears. The gutter width is controlled by the `scrollbar_width`
+/// property.
+///
+/// As Taffy only supports horizontal writing modes, this only affects the inline-axis (left and right) edges of a node.
+/// It has no effect on nodes that are not scroll containers.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/scrollbar-gutter>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ScrollbarGutter {
+    /// Space is only reserved for the scrollbar when it is shown
+    #[default]
+    Auto,
+    /// Space is always reserved for the scrollbar on the inline-end edge (the right edge in LTR, and the left edge in RTL)
+    Stable,
+    /// Space is always reserved for the scrollbar on the inline-end edge, and a gutter of the same width is also reserved
+    /// on the inline-start edge so that the content stays centred
+    StableBothEdges,
+}
+
+impl ScrollbarGutter {
+    /// Returns true if the gutter is reserved whether or not a scrollbar is shown
+    #[inline(always)]
+    pub(crate) fn is_stable(self) -> bool {
+        matches!(self, Self::Stable | Self::StableBothEdges)
+    }
+}
+
+#[cfg(feature = "parse")]
+impl crate::util::parse::FromCss for ScrollbarGutter {
+    fn from_css<'i>(input: &mut crate::util::parse::Parser<'i, '_>) -> crate::util::parse::CssParseResult<'i, Self> {
+        let ident = input.expect_ident()?.clone();
+        cssparser::match_ignore_ascii_case! { &*ident,
+            "auto" => Ok(Self::Auto),
+            "stable" => {
+                if input.try_parse(|input| input.expect_ident_matching("both-edges")).is_ok() {
+                    Ok(Self::StableBothEdges)
+                } else {
+                    Ok(Self::Stable)
+                }
+            },
+            // The `both-edges` keyword may also come before `stable`
+            "both-edges" => {
+                input.expect_ident_matching("stable")?;
+                Ok(Self::StableBothEdges)
+            },
+            _ => Err(input.new_unexpected_token_error(crate::util::parse::Token::Ident(ident))),
+        }
+    }
+}
+#[cfg(feature = "parse")]
+crate::util::parse::from_str_from_css!(ScrollbarGutter);
+
 /// The layout-affecting parts of the CSS `contain` property.
 ///
 /// Containment limits the ways in which a box's contents can affect layout outside of the box
@@ -418,10 +699,13 @@ crate::util::parse::impl_parse_for_keyword_enum!(Overflow,
 ///   - [`Contain::PAINT`]: the box establishes an independent formatting context. Paint
 ///     containment's other effects (clipping, containing absolutely-positioned descendants,
 ///     stacking context) are outside of Taffy's scope.
+///   - [`Contain::SIZE`]: the box is sized as if it were empty, with a content box of the size
+///     given by its `contain_intrinsic_size` style (size containment).
+///   - [`Contain::INLINE_SIZE`]: the same as size containment, but only in the inline (horizontal)
+///     axis. The box's height still depends on its contents (inline-size containment).
 ///
 /// The `style` containment type has no effect on layout and is therefore not represented
-/// (it is accepted and ignored when parsing). Size and inline-size containment are not
-/// currently implemented.
+/// (it is accepted and ignored when parsing).
 ///
 /// <https://developer.mozilla.org/en-US/docs/Web/CSS/contain>
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
@@ -439,8 +723,16 @@ impl Contain {
     /// effects don't affect layout.
     /// <https://drafts.csswg.org/css-contain-2/#containment-paint>
     pub const PAINT: Contain = Contain(1 << 1);
+    /// Size containment: the box is sized as if it were empty in both axes
+    /// <https://drafts.csswg.org/css-contain-2/#containment-size>
+    pub const SIZE: Contain = Contain(1 << 2);
+    /// Inline-size containment: the box is sized as if it were empty in the inline (horizontal) axis
+    /// <https://drafts.csswg.org/css-contain-2/#containment-inline-size>
+    pub const INLINE_SIZE: Contain = Contain(1 << 3);
     /// The containment implied by `contain: content` (`layout paint`, ignoring style containment)
     pub const CONTENT: Contain = Contain(Contain::LAYOUT.0 | Contain::PAINT.0);
+    /// The containment implied by `contain: strict` (`size layout paint`, ignoring style containment)
+    pub const STRICT: Contain = Contain(Contain::SIZE.0 | Contain::CONTENT.0);
 
     /// The default containment (no containment)
     pub const DEFAULT: Contain = Contain::NONE;
@@ -484,6 +776,13 @@ impl Contain {
     pub const fn contains_scrollable_overflow(self) -> bool {
         self.intersects(Contain::LAYOUT.union(Contain::PAINT))
     }
+
+    /// The axes in which this containment causes the box to be sized as if it were empty (both
+    /// axes for size containment, and the horizontal axis for inline-size containment)
+    #[inline(always)]
+    pub const fn size_contained_axes(self) -> Size<bool> {
+        Size { width: self.intersects(Contain::SIZE.union(Contain::INLINE_SIZE)), height: self.contains(Contain::SIZE) }
+    }
 }
 
 impl core::ops::BitOr for Contain {
@@ -517,17 +816,21 @@ impl crate::util::parse::FromCss for Contain {
                 // Single-keyword values (only valid on their own; `parse_entirely` in the
                 // `FromStr` impl rejects trailing keywords, and a leading keyword before them
                 // is rejected by the `seen != 0` check below)
-                "none" | "content" => {
+                "none" | "content" | "strict" => {
                     if seen != 0 || !input.is_exhausted() {
                         return Err(input.new_unexpected_token_error(crate::util::parse::Token::Ident(ident)));
                     }
                     return Ok(cssparser::match_ignore_ascii_case! { &*ident,
                         "content" => Contain::CONTENT,
+                        "strict" => Contain::STRICT,
                         _ => Contain::NONE,
                     });
                 },
                 "layout" => (Contain::LAYOUT, Contain::LAYOUT.0),
                 "paint" => (Contain::PAINT, Contain::PAINT.0),
+                // `size` and `inline-size` are mutually exclusive, so they share a duplicate-detection bit
+                "size" => (Contain::SIZE, Contain::SIZE.0 | Contain::INLINE_SIZE.0),
+                "inline-size" => (Contain::INLINE_SIZE, Contain::SIZE.0 | Contain::INLINE_SIZE.0),
                 // `style` containment has no layout effect: accept and ignore it so that real
                 // CSS values round-trip
                 "style" => (Contain::NONE, STYLE_BIT),
@@ -617,8 +920,17 @@ pub struct Style<S: CheapCloneStr = DefaultCheapStr> {
     pub overflow: Point<Overflow>,
     /// How much space (in points) should be reserved for the scrollbars of `Overflow::Scroll` and `Overflow::Auto` nodes.
     pub scrollbar_width: f32,
-    /// The layout-affecting parts of the CSS `contain` property
+    /// Whether space should be reserved for the node's scrollbar even if no scrollbar is shown
+    pub scrollbar_gutter: ScrollbarGutter,
+    /// The layout-affecting parts of the CSS `contain` property. The containment implied by `content_visibility` is
+    /// applied in addition to this.
     pub contain: Contain,
+    /// The size of the node's content box in the axes in which it has size containment
+    pub contain_intrinsic_size: Size<ContainIntrinsicSize>,
+    /// Whether the node lays out its contents, or skips them and is sized as if it were empty
+    pub content_visibility: ContentVisibility,
+    /// Whether the node is a query container whose size can be queried by its descendants
+    pub container_type: ContainerType,
 
     #[cfg(feature = "float_layout")]
     /// Should the box be floated
@@ -634,6 +946,18 @@ pub struct Style<S: CheapCloneStr = DefaultCheapStr> {
     #[cfg_attr(feature = "serde", serde(default = "style_helpers::auto"))]
     pub inset: Rect<LengthPercentageAuto>,
 
+    // Anchor positioning properties
+    /// The name by which absolutely positioned nodes can refer to this node as their anchor
+    pub anchor_name: Option<S>,
+    /// The name of the anchor that the `anchor()` and `anchor-size()` values in this node's inset and size refer to
+    ///
+    /// [`TaffyTree`](crate::TaffyTree) only finds anchors among the descendants of the node's containing block (the
+    /// parent that positions it), other than the node itself and absolutely positioned siblings that follow it.
+    /// Anchors elsewhere in the tree are ignored, and the `anchor()` and `anchor-size()` values then behave as `auto`.
+    pub position_anchor: Option<S>,
+    /// The alternative positions that are tried (in order) if this node overflows its containing block
+    pub position_try_fallbacks: PositionTryFallbacks,
+
     // Size properties
     /// Sets the initial size of the item
     #[cfg_attr(feature = "serde", serde(default = "style_helpers::auto"))]
@@ -647,7 +971,18 @@ pub struct Style<S: CheapCloneStr = DefaultCheapStr> {
     /// Sets the preferred aspect ratio for the item
     ///
     /// The ratio is calculated as width divided by height.
-    pub aspect_ratio: Option<f32>,
+    pub aspect_ratio: AspectRatio,
+    /// How the content of a replaced node (such as an image) is sized to fit within the node's content box
+    pub object_fit: ObjectFit,
+    /// The position of the content of a replaced node within its content box. Percentages resolve against the
+    /// difference between the size of the content box and the size of the content, so `50%` centers the content.
+    pub object_position: Point<LengthPercentage>,
+    /// A 2D transform of the node. Transforms do not affect layout, but the transformed border box and content of the
+    /// node is what contributes to the scrollable overflow of its ancestors.
+    pub transform: Transform,
+    /// The point of the node's border box about which its `transform` is applied. Percentages resolve against the
+    /// size of the border box.
+    pub transform_origin: Point<LengthPercentage>,
 
     // Spacing Properties
     /// How large should the margin be on each side?
@@ -669,11 +1004,11 @@ pub struct Style<S: CheapCloneStr = DefaultCheapStr> {
     #[cfg(any(feature = "flexbox", feature = "grid"))]
     pub align_self: Option<AlignSelf>,
     /// How this node's children should be aligned in the inline axis
-    #[cfg(feature = "grid")]
+    #[cfg(any(feature = "grid", feature = "block_layout"))]
     pub justify_items: Option<AlignItems>,
     /// How this node should be aligned in the inline axis
     /// Falls back to the parents [`JustifyItems`] if not set
-    #[cfg(feature = "grid")]
+    #[cfg(any(feature = "grid", feature = "block_layout"))]
     pub justify_self: Option<AlignSelf>,
     /// How should content contained within this item be aligned in the cross/block axis
     #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout"))]
@@ -690,6 +1025,12 @@ pub struct Style<S: CheapCloneStr = DefaultCheapStr> {
     /// How items elements should aligned in the inline axis
     #[cfg(feature = "block_layout")]
     pub text_align: TextAlign,
+    /// Where the marker box of a [`Display::ListItem`] node is placed
+    #[cfg(feature = "block_layout")]
+    pub list_style_position: ListStylePosition,
+    /// How the bases and annotations of a [`Display::Ruby`] node are distributed within their pairs
+    #[cfg(feature = "block_layout")]
+    pub ruby_align: RubyAlign,
 
     // Flexbox container properties
     /// Which direction does the main axis flow in?
@@ -770,20 +1111,31 @@ impl<S: CheapCloneStr> Style<S> {
         direction: Direction::Ltr,
         overflow: Point { x: Overflow::Visible, y: Overflow::Visible },
         scrollbar_width: 0.0,
+        scrollbar_gutter: ScrollbarGutter::Auto,
         contain: Contain::NONE,
+        contain_intrinsic_size: Size { width: ContainIntrinsicSize::NONE, height: ContainIntrinsicSize::NONE },
+        content_visibility: ContentVisibility::Visible,
+        container_type: ContainerType::Normal,
         #[cfg(feature = "float_layout")]
         float: Float::None,
         #[cfg(feature = "float_layout")]
         clear: Clear::None,
         position: Position::Relative,
         inset: Rect::auto(),
+        anchor_name: None,
+        position_anchor: None,
+        position_try_fallbacks: PositionTryFallbacks::NONE,
         margin: Rect::zero(),
         padding: Rect::zero(),
         border: Rect::zero(),
         size: Size::auto(),
         min_size: Size::auto(),
         max_size: Size::auto(),
-        aspect_ratio: None,
+        aspect_ratio: AspectRatio::AUTO,
+        object_fit: ObjectFit::Fill,
+        object_position: Point { x: LengthPercentage::percent(0.5), y: LengthPercentage::percent(0.5) },
+        transform: Transform::IDENTITY,
+        transform_origin: Point { x: LengthPercentage::percent(0.5), y: LengthPercentage::percent(0.5) },
         #[cfg(any(feature = "flexbox", feature = "grid"))]
         gap: Size::zero(),
         // Alignment
@@ -791,9 +1143,9 @@ impl<S: CheapCloneStr> Style<S> {
         align_items: None,
         #[cfg(any(feature = "flexbox", feature = "grid"))]
         align_self: None,
-        #[cfg(feature = "grid")]
+        #[cfg(any(feature = "grid", feature = "block_layout"))]
         justify_items: None,
-        #[cfg(feature = "grid")]
+        #[cfg(any(feature = "grid", feature = "block_layout"))]
         justify_self: None,
         #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout"))]
         align_content: None,
@@ -802,6 +1154,10 @@ impl<S: CheapCloneStr> Style<S> {
         // Block
         #[cfg(feature = "block_layout")]
         text_align: TextAlign::Auto,
+        #[cfg(feature = "block_layout")]
+        list_style_position: ListStylePosition::Outside,
+        #[cfg(feature = "block_layout")]
+        ruby_align: RubyAlign::SpaceAround,
         // Flexbox
         #[cfg(feature = "flexbox")]
         flex_direction: FlexDirection::Row,
@@ -852,13 +1208,18 @@ impl<S: CheapCloneStr> CoreStyle for Style<S> {
     fn box_generation_mode(&self) -> BoxGenerationMode {
         match self.display {
             Display::None => BoxGenerationMode::None,
+            Display::Contents => BoxGenerationMode::Contents,
             _ => BoxGenerationMode::Normal,
         }
     }
     #[inline(always)]
+    fn display_outside(&self) -> DisplayOutside {
+        self.display.outside()
+    }
+    #[inline(always)]
     #[cfg(feature = "block_layout")]
     fn is_block(&self) -> bool {
-        matches!(self.display, Display::Block)
+        matches!(self.display, Display::Block | Display::ListItem)
     }
     #[inline(always)]
     fn is_compressible_replaced(&self) -> bool {
@@ -881,6 +1242,10 @@ impl<S: CheapCloneStr> CoreStyle for Style<S> {
         self.scrollbar_width
     }
     #[inline(always)]
+    fn scrollbar_gutter(&self) -> ScrollbarGutter {
+        self.scrollbar_gutter
+    }
+    #[inline(always)]
     fn position(&self) -> Position {
         self.position
     }
@@ -889,6 +1254,18 @@ impl<S: CheapCloneStr> CoreStyle for Style<S> {
         self.inset
     }
     #[inline(always)]
+    fn anchor_name(&self) -> Option<&S> {
+        self.anchor_name.as_ref()
+    }
+    #[inline(always)]
+    fn position_anchor(&self) -> Option<&S> {
+        self.position_anchor.as_ref()
+    }
+    #[inline(always)]
+    fn position_try_fallbacks(&self) -> PositionTryFallbacks {
+        self.position_try_fallbacks
+    }
+    #[inline(always)]
     fn size(&self) -> Size<Dimension> {
         self.size
     }
@@ -901,10 +1278,26 @@ impl<S: CheapCloneStr> CoreStyle for Style<S> {
         self.max_size
     }
     #[inline(always)]
-    fn aspect_ratio(&self) -> Option<f32> {
+    fn aspect_ratio(&self) -> AspectRatio {
         self.aspect_ratio
     }
     #[inline(always)]
+    fn object_fit(&self) -> ObjectFit {
+        self.object_fit
+    }
+    #[inline(always)]
+    fn object_position(&self) -> Point<LengthPercentage> {
+        self.object_position
+    }
+    #[inline(always)]
+    fn transform(&self) -> Transform {
+        self.transform
+    }
+    #[inline(always)]
+    fn transform_origin(&self) -> Point<LengthPercentage> {
+        self.transform_origin
+    }
+    #[inline(always)]
     fn margin(&self) -> Rect<LengthPercentageAuto> {
         self.margin
     }
@@ -918,7 +1311,19 @@ impl<S: CheapCloneStr> CoreStyle for Style<S> {
     }
     #[inline(always)]
     fn contain(&self) -> Contain {
-        self.contain
+        self.contain | self.content_visibility.implied_containment()
+    }
+    #[inline(always)]
+    fn contain_intrinsic_size(&self) -> Size<ContainIntrinsicSize> {
+        self.contain_intrinsic_size
+    }
+    #[inline(always)]
+    fn content_visibility(&self) -> ContentVisibility {
+        self.content_visibility
+    }
+    #[inline(always)]
+    fn container_type(&self) -> ContainerType {
+        self.container_type
     }
 }
 
@@ -930,6 +1335,10 @@ impl<T: CoreStyle> CoreStyle for &'_ T {
         (*self).box_generation_mode()
     }
     #[inline(always)]
+    fn display_outside(&self) -> DisplayOutside {
+        (*self).display_outside()
+    }
+    #[inline(always)]
     fn is_block(&self) -> bool {
         (*self).is_block()
     }
@@ -954,6 +1363,10 @@ impl<T: CoreStyle> CoreStyle for &'_ T {
         (*self).scrollbar_width()
     }
     #[inline(always)]
+    fn scrollbar_gutter(&self) -> ScrollbarGutter {
+        (*self).scrollbar_gutter()
+    }
+    #[inline(always)]
     fn position(&self) -> Position {
         (*self).position()
     }
@@ -962,6 +1375,18 @@ impl<T: CoreStyle> CoreStyle for &'_ T {
         (*self).inset()
     }
     #[inline(always)]
+    fn anchor_name(&self) -> Option<&Self::CustomIdent> {
+        (*self).anchor_name()
+    }
+    #[inline(always)]
+    fn position_anchor(&self) -> Option<&Self::CustomIdent> {
+        (*self).position_anchor()
+    }
+    #[inline(always)]
+    fn position_try_fallbacks(&self) -> PositionTryFallbacks {
+        (*self).position_try_fallbacks()
+    }
+    #[inline(always)]
     fn size(&self) -> Size<Dimension> {
         (*self).size()
     }
@@ -974,10 +1399,26 @@ impl<T: CoreStyle> CoreStyle for &'_ T {
         (*self).max_size()
     }
     #[inline(always)]
-    fn aspect_ratio(&self) -> Option<f32> {
+    fn aspect_ratio(&self) -> AspectRatio {
         (*self).aspect_ratio()
     }
     #[inline(always)]
+    fn object_fit(&self) -> ObjectFit {
+        (*self).object_fit()
+    }
+    #[inline(always)]
+    fn object_position(&self) -> Point<LengthPercentage> {
+        (*self).object_position()
+    }
+    #[inline(always)]
+    fn transform(&self) -> Transform {
+        (*self).transform()
+    }
+    #[inline(always)]
+    fn transform_origin(&self) -> Point<LengthPercentage> {
+        (*self).transform_origin()
+    }
+    #[inline(always)]
     fn margin(&self) -> Rect<LengthPercentageAuto> {
         (*self).margin()
     }
@@ -993,6 +1434,18 @@ impl<T: CoreStyle> CoreStyle for &'_ T {
     fn contain(&self) -> Contain {
         (*self).contain()
     }
+    #[inline(always)]
+    fn contain_intrinsic_size(&self) -> Size<ContainIntrinsicSize> {
+        (*self).contain_intrinsic_size()
+    }
+    #[inline(always)]
+    fn content_visibility(&self) -> ContentVisibility {
+        (*self).content_visibility()
+    }
+    #[inline(always)]
+    fn container_type(&self) -> ContainerType {
+        (*self).container_type()
+    }
 }
 
 #[cfg(feature = "block_layout")]
@@ -1002,10 +1455,30 @@ impl<S: CheapCloneStr> BlockContainerStyle for Style<S> {
         self.text_align
     }
 
+    #[inline(always)]
+    fn is_list_item(&self) -> bool {
+        self.display == Display::ListItem
+    }
+
+    #[inline(always)]
+    fn list_style_position(&self) -> ListStylePosition {
+        self.list_style_position
+    }
+
+    #[inline(always)]
+    fn ruby_align(&self) -> RubyAlign {
+        self.ruby_align
+    }
+
     #[inline(always)]
     fn align_content(&self) -> Option<AlignContent> {
         self.align_content
     }
+
+    #[inline(always)]
+    fn justify_items(&self) -> Option<AlignItems> {
+        self.justify_items
+    }
 }
 
 #[cfg(feature = "block_layout")]
@@ -1015,10 +1488,30 @@ impl<T: BlockContainerStyle> BlockContainerStyle for &'_ T {
         (*self).text_align()
     }
 
+    #[inline(always)]
+    fn is_list_item(&self) -> bool {
+        (*self).is_list_item()
+    }
+
+    #[inline(always)]
+    fn list_style_position(&self) -> ListStylePosition {
+        (*self).list_style_position()
+    }
+
+    #[inline(always)]
+    fn ruby_align(&self) -> RubyAlign {
+        (*self).ruby_align()
+    }
+
     #[inline(always)]
     fn align_content(&self) -> Option<AlignContent> {
         (*self).align_content()
     }
+
+    #[inline(always)]
+    fn justify_items(&self) -> Option<AlignItems> {
+        (*self).justify_items()
+    }
 }
 
 #[cfg(feature = "block_layout")]
@@ -1028,6 +1521,21 @@ impl<S: CheapCloneStr> BlockItemStyle for Style<S> {
         self.item_is_table
     }
 
+    #[inline(always)]
+    fn is_inline_block(&self) -> bool {
+        self.display == Display::InlineBlock
+    }
+
+    #[inline(always)]
+    fn is_ruby_text(&self) -> bool {
+        self.display == Display::RubyText
+    }
+
+    #[inline(always)]
+    fn justify_self(&self) -> Option<AlignSelf> {
+        self.justify_self
+    }
+
     #[cfg(feature = "float_layout")]
     #[inline(always)]
     fn float(&self) -> Float {
@@ -1048,6 +1556,21 @@ impl<T: BlockItemStyle> BlockItemStyle for &'_ T {
         (*self).is_table()
     }
 
+    #[inline(always)]
+    fn is_inline_block(&self) -> bool {
+        (*self).is_inline_block()
+    }
+
+    #[inline(always)]
+    fn is_ruby_text(&self) -> bool {
+        (*self).is_ruby_text()
+    }
+
+    #[inline(always)]
+    fn justify_self(&self) -> Option<AlignSelf> {
+        (*self).justify_self()
+    }
+
     #[cfg(feature = "float_layout")]
     #[inline(always)]
     fn float(&self) -> Float {
@@ -1430,7 +1953,11 @@ mod tests {
             direction: Default::default(),
             overflow: Default::default(),
             scrollbar_width: 0.0,
+            scrollbar_gutter: Default::default(),
             contain: Default::default(),
+            contain_intrinsic_size: Default::default(),
+            content_visibility: Default::default(),
+            container_type: Default::default(),
             position: Default::default(),
             #[cfg(feature = "flexbox")]
             flex_direction: Default::default(),
@@ -1442,21 +1969,28 @@ mod tests {
             align_items: Default::default(),
             #[cfg(any(feature = "flexbox", feature = "grid"))]
             align_self: Default::default(),
-            #[cfg(feature = "grid")]
+            #[cfg(any(feature = "grid", feature = "block_layout"))]
             justify_items: Default::default(),
-            #[cfg(feature = "grid")]
+            #[cfg(any(feature = "grid", feature = "block_layout"))]
             justify_self: Default::default(),
             #[cfg(any(feature = "flexbox", feature = "grid", feature = "block_layout"))]
             align_content: Default::default(),
             #[cfg(any(feature = "flexbox", feature = "grid"))]
             justify_content: Default::default(),
             inset: Rect::auto(),
+            anchor_name: None,
+            position_anchor: None,
+            position_try_fallbacks: Default::default(),
             margin: Rect::zero(),
             padding: Rect::zero(),
             border: Rect::zero(),
             gap: Size::zero(),
             #[cfg(feature = "block_layout")]
             text_align: Default::default(),
+            #[cfg(feature = "block_layout")]
+            list_style_position: Default::default(),
+            #[cfg(feature = "block_layout")]
+            ruby_align: Default::default(),
             #[cfg(feature = "flexbox")]
             flex_grow: 0.0,
             #[cfg(feature = "flexbox")]
@@ -1467,6 +2001,16 @@ mod tests {
             min_size: Size::auto(),
             max_size: Size::auto(),
             aspect_ratio: Default::default(),
+            object_fit: Default::default(),
+            object_position: Point {
+                x: super::LengthPercentage::percent(0.5),
+                y: super::LengthPercentage::percent(0.5),
+            },
+            transform: Default::default(),
+            transform_origin: Point {
+                x: super::LengthPercentage::percent(0.5),
+                y: super::LengthPercentage::percent(0.5),
+            },
             #[cfg(feature = "grid")]
             grid_template_rows: Default::default(),
             #[cfg(feature = "grid")]
@@ -1511,6 +2055,11 @@ mod tests {
         assert_eq!(parse("paint layout"), Contain::LAYOUT | Contain::PAINT);
         assert_eq!(parse("layout paint style"), Contain::LAYOUT | Contain::PAINT);
         assert_eq!(parse("Paint LAYOUT"), Contain::LAYOUT | Contain::PAINT);
+        assert_eq!(parse("strict"), Contain::SIZE | Contain::LAYOUT | Contain::PAINT);
+        assert_eq!(parse("size"), Contain::SIZE);
+        assert_eq!(parse("inline-size layout"), Contain::INLINE_SIZE | Contain::LAYOUT);
+        assert!("size inline-size".parse::<Contain>().is_err());
+        assert!("strict size".parse::<Contain>().is_err());
         assert!("paint paint".parse::<Contain>().is_err());
 
         assert!("".parse::<Contain>().is_err());
@@ -1522,6 +2071,59 @@ mod tests {
         assert!("layout content".parse::<Contain>().is_err());
     }
 
+    #[test]
+    #[cfg(feature = "parse")]
+    fn parse_scrollbar_gutter() {
+        use super::ScrollbarGutter;
+
+        fn parse(input: &str) -> ScrollbarGutter {
+            input.parse().unwrap()
+        }
+
+        assert_eq!(parse("auto"), ScrollbarGutter::Auto);
+        assert_eq!(parse("stable"), ScrollbarGutter::Stable);
+        assert_eq!(parse("stable both-edges"), ScrollbarGutter::StableBothEdges);
+        assert_eq!(parse("both-edges stable"), ScrollbarGutter::StableBothEdges);
+        assert_eq!(parse("STABLE Both-Edges"), ScrollbarGutter::StableBothEdges);
+
+        assert!("".parse::<ScrollbarGutter>().is_err());
+        assert!("both-edges".parse::<ScrollbarGutter>().is_err());
+        assert!("auto both-edges".parse::<ScrollbarGutter>().is_err());
+        assert!("stable stable".parse::<ScrollbarGutter>().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "parse")]
+    fn parse_anchor_values() {
+        use super::{
+            AnchorSide, AnchorSize, Dimension, LengthPercentageAuto, PositionTryFallback, PositionTryFallbacks,
+        };
+
+        fn parse<T: core::str::FromStr>(input: &str) -> T
+        where
+            T::Err: core::fmt::Debug,
+        {
+            input.parse().unwrap()
+        }
+
+        assert_eq!(parse::<LengthPercentageAuto>("anchor(bottom)"), LengthPercentageAuto::anchor(AnchorSide::Bottom));
+        assert_eq!(
+            parse::<LengthPercentageAuto>("anchor(25%)"),
+            LengthPercentageAuto::anchor(AnchorSide::Percent(0.25))
+        );
+        assert_eq!(parse::<Dimension>("anchor-size()"), Dimension::anchor_size(AnchorSize::SameAxis));
+        assert_eq!(parse::<Dimension>("anchor-size(height)"), Dimension::anchor_size(AnchorSize::Height));
+        assert!("anchor()".parse::<LengthPercentageAuto>().is_err());
+        assert!("anchor(width)".parse::<LengthPercentageAuto>().is_err());
+
+        assert_eq!(parse::<PositionTryFallbacks>("none"), PositionTryFallbacks::NONE);
+        assert_eq!(
+            parse::<PositionTryFallbacks>("flip-block, flip-inline flip-block"),
+            PositionTryFallbacks::new(&[PositionTryFallback::FlipBlock, PositionTryFallback::FlipBlockInline])
+        );
+        assert!("flip-block flip-block".parse::<PositionTryFallbacks>().is_err());
+    }
+
     // NOTE: Please feel free the update the sizes in this test as required. This test is here to prevent unintentional size changes
     // and to serve as accurate up-to-date documentation on the sizes.
     #[test]
@@ -1552,6 +2154,7 @@ mod tests {
         assert_type_size::<BoxSizing>(1);
         assert_type_size::<Position>(1);
         assert_type_size::<Overflow>(1);
+        assert_type_size::<ScrollbarGutter>(1);
 
         // Dimensions and aggregations of Dimensions
         assert_type_size::<f32>(4);
@@ -1564,6 +2167,7 @@ mod tests {
         assert_type_size::<Rect<LengthPercentage>>(32);
         assert_type_size::<Rect<LengthPercentageAuto>>(32);
         assert_type_size::<Rect<Dimension>>(32);
+        assert_type_size::<AspectRatio>(12);
 
         // Alignment — `AlignContent` and `AlignItems` are structs of two `#[repr(u8)]` enums
         // (position keyword + safety modifier). Niche-packing in the safety byte (only 2 of
@@ -1592,12 +2196,12 @@ mod tests {
         assert_type_size::<GridTemplateComponent<String>>(56);
         assert_type_size::<GridPlacement<String>>(32);
         assert_type_size::<Line<GridPlacement<String>>>(64);
-        assert_type_size::<Style<String>>(560);
+        assert_type_size::<Style<String>>(696);
 
         // String-type dependent (Arc<str>)
         assert_type_size::<GridTemplateComponent<Arc<str>>>(56);
         assert_type_size::<GridPlacement<Arc<str>>>(24);
         assert_type_size::<Line<GridPlacement<Arc<str>>>>(48);
-        assert_type_size::<Style<Arc<str>>>(528);
+        assert_type_size::<Style<Arc<str>>>(648);
     }
 }
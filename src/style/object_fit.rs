@@ -0,0 +1,37 @@
+//! Style types for the CSS `object-fit` and `object-position` properties
+//!
+//! <https://www.w3.org/TR/css-images-3/#the-object-fit>
+
+/// How the content of a replaced node (such as an image) is sized to fit within the node's content box
+///
+/// This does not affect the size of the node itself, only the rectangle in which its content is painted, which is
+/// returned as [`Layout::replaced_content_rect`](crate::Layout::replaced_content_rect). The content is then
+/// positioned within the content box according to the `object_position` style.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/object-fit>
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ObjectFit {
+    /// The content is stretched to fill the content box, ignoring its natural aspect ratio
+    #[default]
+    Fill,
+    /// The content is scaled to the largest size that fits within the content box while preserving its natural
+    /// aspect ratio
+    Contain,
+    /// The content is scaled to the smallest size that covers the content box while preserving its natural aspect
+    /// ratio
+    Cover,
+    /// The content is painted at its natural size
+    None,
+    /// The content is sized as for `None` or `Contain`, whichever results in the smaller size
+    ScaleDown,
+}
+
+#[cfg(feature = "parse")]
+crate::util::parse::impl_parse_for_keyword_enum!(ObjectFit,
+    "fill" => Fill,
+    "contain" => Contain,
+    "cover" => Cover,
+    "none" => None,
+    "scale-down" => ScaleDown,
+);
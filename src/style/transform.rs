@@ -0,0 +1,266 @@
+//! Style type for the 2D subset of the CSS `transform` property
+//!
+//! <https://www.w3.org/TR/css-transforms-1/#transform-property>
+use crate::geometry::{Point, Rect};
+#[cfg(feature = "parse")]
+use crate::util::parse::{from_str_from_css, CssParseResult, FromCss, Parser, Token};
+use crate::util::sys::{f32_max, f32_min};
+
+/// A 2D affine transform, which maps the point `(x, y)` to `(a * x + c * y + e, b * x + d * y + f)`
+///
+/// This is the matrix used by the CSS `matrix(a, b, c, d, e, f)` transform function. Transforms do not affect layout,
+/// but they do move a node's border box and content when they contribute to the scrollable overflow of its ancestors.
+/// A node's transform is applied about its `transform_origin`.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/transform>
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Transform {
+    /// The horizontal scale factor
+    pub a: f32,
+    /// The vertical skew factor
+    pub b: f32,
+    /// The horizontal skew factor
+    pub c: f32,
+    /// The vertical scale factor
+    pub d: f32,
+    /// The horizontal translation
+    pub e: f32,
+    /// The vertical translation
+    pub f: f32,
+}
+
+impl Transform {
+    /// The transform that leaves every point where it is (`transform: none` in CSS)
+    pub const IDENTITY: Self = Self { a: 1.0, b: 0.0, c: 0.0, d: 1.0, e: 0.0, f: 0.0 };
+
+    /// A transform that moves points by the given distances
+    pub const fn translate(x: f32, y: f32) -> Self {
+        Self { e: x, f: y, ..Self::IDENTITY }
+    }
+
+    /// A transform that scales points away from the origin by the given factors
+    pub const fn scale(x: f32, y: f32) -> Self {
+        Self { a: x, d: y, ..Self::IDENTITY }
+    }
+
+    /// A transform that rotates points about the origin by the given angle in radians. Positive angles rotate
+    /// clockwise, as the y axis points downwards.
+    #[cfg(feature = "std")]
+    pub fn rotate(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self { a: cos, b: sin, c: -sin, d: cos, e: 0.0, f: 0.0 }
+    }
+
+    /// Whether the transform leaves every point where it is
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    /// The transform that applies this transform followed by `next`
+    pub fn then(self, next: Self) -> Self {
+        Self {
+            a: next.a * self.a + next.c * self.b,
+            b: next.b * self.a + next.d * self.b,
+            c: next.a * self.c + next.c * self.d,
+            d: next.b * self.c + next.d * self.d,
+            e: next.a * self.e + next.c * self.f + next.e,
+            f: next.b * self.e + next.d * self.f + next.f,
+        }
+    }
+
+    /// This transform applied about the given origin rather than about `(0, 0)`
+    pub fn about(self, origin: Point<f32>) -> Self {
+        Self::translate(-origin.x, -origin.y).then(self).then(Self::translate(origin.x, origin.y))
+    }
+
+    /// Applies the transform to a point
+    pub fn transform_point(&self, point: Point<f32>) -> Point<f32> {
+        Point { x: self.a * point.x + self.c * point.y + self.e, y: self.b * point.x + self.d * point.y + self.f }
+    }
+
+    /// Applies the transform to a rectangle (given by the positions of its edges), and returns the smallest
+    /// axis-aligned rectangle that contains the result
+    pub fn transform_rect(&self, rect: Rect<f32>) -> Rect<f32> {
+        if self.is_identity() {
+            return rect;
+        }
+        let corners = [
+            self.transform_point(Point { x: rect.left, y: rect.top }),
+            self.transform_point(Point { x: rect.right, y: rect.top }),
+            self.transform_point(Point { x: rect.left, y: rect.bottom }),
+            self.transform_point(Point { x: rect.right, y: rect.bottom }),
+        ];
+        let first = corners[0];
+        corners.iter().skip(1).fold(
+            Rect { left: first.x, right: first.x, top: first.y, bottom: first.y },
+            |bounds, corner| Rect {
+                left: f32_min(bounds.left, corner.x),
+                right: f32_max(bounds.right, corner.x),
+                top: f32_min(bounds.top, corner.y),
+                bottom: f32_max(bounds.bottom, corner.y),
+            },
+        )
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// Parses a number (e.g. a scale factor or a `matrix()` argument)
+#[cfg(feature = "parse")]
+fn parse_number<'i>(parser: &mut Parser<'i, '_>) -> CssParseResult<'i, f32> {
+    Ok(parser.expect_number()?)
+}
+
+/// Parses a length in pixels (unitless zero is allowed)
+#[cfg(feature = "parse")]
+fn parse_px<'i>(parser: &mut Parser<'i, '_>) -> CssParseResult<'i, f32> {
+    match parser.next()?.clone() {
+        Token::Dimension { value, ref unit, .. } if unit.eq_ignore_ascii_case("px") => Ok(value),
+        Token::Number { value: 0.0, .. } => Ok(0.0),
+        token => Err(parser.new_unexpected_token_error(token))?,
+    }
+}
+
+/// Parses an angle in radians (unitless zero is allowed)
+#[cfg(all(feature = "parse", feature = "std"))]
+fn parse_angle<'i>(parser: &mut Parser<'i, '_>) -> CssParseResult<'i, f32> {
+    use core::f32::consts::PI;
+    let token = parser.next()?.clone();
+    let radians = match token {
+        Token::Dimension { value, ref unit, .. } => cssparser::match_ignore_ascii_case! { unit,
+            "deg" => Some(value.to_radians()),
+            "rad" => Some(value),
+            "grad" => Some(value * PI / 200.0),
+            "turn" => Some(value * 2.0 * PI),
+            _ => None,
+        },
+        Token::Number { value: 0.0, .. } => Some(0.0),
+        _ => None,
+    };
+    match radians {
+        Some(radians) => Ok(radians),
+        None => Err(parser.new_unexpected_token_error(token))?,
+    }
+}
+
+/// Parses the arguments of a transform function that takes one value, or two comma-separated values where the second
+/// defaults to the given function of the first
+#[cfg(feature = "parse")]
+fn parse_pair<'i>(
+    parser: &mut Parser<'i, '_>,
+    parse_value: fn(&mut Parser<'i, '_>) -> CssParseResult<'i, f32>,
+    default_second: fn(f32) -> f32,
+) -> CssParseResult<'i, (f32, f32)> {
+    let first = parse_value(parser)?;
+    let second = if parser.try_parse(|parser| parser.expect_comma()).is_ok() {
+        parse_value(parser)?
+    } else {
+        default_second(first)
+    };
+    Ok((first, second))
+}
+
+/// Parses a single 2D transform function
+#[cfg(feature = "parse")]
+fn parse_transform_function<'i>(parser: &mut Parser<'i, '_>) -> CssParseResult<'i, Transform> {
+    let function = parser.expect_function()?.clone();
+    parser.parse_nested_block(|parser| {
+        Ok(cssparser::match_ignore_ascii_case! { &function,
+            "matrix" => {
+                let mut values = [0.0; 6];
+                for (index, value) in values.iter_mut().enumerate() {
+                    if index > 0 {
+                        parser.expect_comma()?;
+                    }
+                    *value = parse_number(parser)?;
+                }
+                let [a, b, c, d, e, f] = values;
+                Transform { a, b, c, d, e, f }
+            },
+            "translate" => {
+                let (x, y) = parse_pair(parser, parse_px, |_| 0.0)?;
+                Transform::translate(x, y)
+            },
+            "translatex" => Transform::translate(parse_px(parser)?, 0.0),
+            "translatey" => Transform::translate(0.0, parse_px(parser)?),
+            "scale" => {
+                let (x, y) = parse_pair(parser, parse_number, |x| x)?;
+                Transform::scale(x, y)
+            },
+            "scalex" => Transform::scale(parse_number(parser)?, 1.0),
+            "scaley" => Transform::scale(1.0, parse_number(parser)?),
+            #[cfg(feature = "std")]
+            "rotate" => Transform::rotate(parse_angle(parser)?),
+            _ => Err(parser.new_unexpected_token_error(Token::Function(function.clone())))?,
+        })
+    })
+}
+
+/// Parses `none` or a list of 2D transform functions: `matrix()`, `translate()`, `translateX()`, `translateY()`,
+/// `scale()`, `scaleX()`, `scaleY()` and (with the `std` feature) `rotate()`. Translations must be given in pixels.
+#[cfg(feature = "parse")]
+impl FromCss for Transform {
+    fn from_css<'i>(parser: &mut Parser<'i, '_>) -> CssParseResult<'i, Self> {
+        if parser.try_parse(|parser| parser.expect_ident_matching("none")).is_ok() {
+            return Ok(Self::IDENTITY);
+        }
+
+        let mut transform = parse_transform_function(parser)?;
+        while let Ok(function_transform) = parser.try_parse(parse_transform_function) {
+            // Transform functions are applied from right to left
+            transform = function_transform.then(transform);
+        }
+        Ok(transform)
+    }
+}
+#[cfg(feature = "parse")]
+from_str_from_css!(Transform);
+
+#[cfg(test)]
+mod tests {
+    use super::Transform;
+    use crate::geometry::{Point, Rect};
+
+    #[test]
+    fn transforms_compose_in_order() {
+        let transform = Transform::scale(2.0, 3.0).then(Transform::translate(10.0, 20.0));
+        assert_eq!(transform.transform_point(Point { x: 1.0, y: 1.0 }), Point { x: 12.0, y: 23.0 });
+
+        let transform = Transform::translate(10.0, 20.0).then(Transform::scale(2.0, 3.0));
+        assert_eq!(transform.transform_point(Point { x: 1.0, y: 1.0 }), Point { x: 22.0, y: 63.0 });
+    }
+
+    #[test]
+    fn transformed_rect_is_the_bounding_box_of_its_corners() {
+        let rect = Rect { left: 0.0, right: 100.0, top: 0.0, bottom: 50.0 };
+        let flip = Transform { a: 0.0, b: 1.0, c: -1.0, d: 0.0, e: 0.0, f: 0.0 };
+        assert_eq!(flip.transform_rect(rect), Rect { left: -50.0, right: 0.0, top: 0.0, bottom: 100.0 });
+
+        let scale = Transform::scale(2.0, 2.0).about(Point { x: 50.0, y: 25.0 });
+        assert_eq!(scale.transform_rect(rect), Rect { left: -50.0, right: 150.0, top: -25.0, bottom: 75.0 });
+    }
+
+    #[test]
+    #[cfg(feature = "parse")]
+    fn parse() {
+        use core::str::FromStr;
+        assert_eq!(Transform::from_str("none").unwrap(), Transform::IDENTITY);
+        assert_eq!(
+            Transform::from_str("matrix(1, 2, 3, 4, 5, 6)").unwrap(),
+            Transform { a: 1.0, b: 2.0, c: 3.0, d: 4.0, e: 5.0, f: 6.0 }
+        );
+        assert_eq!(Transform::from_str("translate(10px)").unwrap(), Transform::translate(10.0, 0.0));
+        assert_eq!(Transform::from_str("scale(2)").unwrap(), Transform::scale(2.0, 2.0));
+        assert_eq!(
+            Transform::from_str("translateX(10px) scale(2, 3)").unwrap(),
+            Transform::scale(2.0, 3.0).then(Transform::translate(10.0, 0.0))
+        );
+        assert!(Transform::from_str("translate(10%)").is_err());
+        assert!(Transform::from_str("").is_err());
+    }
+}
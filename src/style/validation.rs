@@ -0,0 +1,309 @@
+//! Validation of [`Style`] values which can be represented but which are invalid in CSS
+use super::{CheapCloneStr, CompactLength, LengthPercentage, Style};
+use crate::geometry::Rect;
+use crate::sys::Vec;
+
+#[cfg(feature = "grid")]
+use super::{GridPlacement, GridTemplateAreas, GridTemplateAreasError, GridTemplateComponent, RepetitionCount};
+#[cfg(feature = "grid")]
+use crate::compute::grid::MAX_GRID_TRACKS;
+#[cfg(feature = "grid")]
+use crate::geometry::{AbsoluteAxis, Line};
+#[cfg(feature = "grid")]
+use crate::sys::String;
+#[cfg(feature = "grid")]
+use crate::util::sys::GridTrackVec;
+
+/// An invalid value or combination of values in a [`Style`]
+///
+/// Taffy lays out invalid styles without panicking, but the result is unlikely to be what was intended: invalid
+/// values are clamped or ignored, which is usually equivalent to ignoring the property in CSS.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StyleValidationError {
+    /// The property has a negative value, but only non-negative values are valid
+    NegativeValue {
+        /// The name of the property
+        property: &'static str,
+    },
+    /// The `grid_template_areas` property is invalid, or conflicts with the line names of the explicit grid
+    #[cfg(feature = "grid")]
+    GridTemplateAreas(GridTemplateAreasError),
+    /// The placement refers to grid line 0, which is treated as `auto`
+    #[cfg(feature = "grid")]
+    InvalidGridLine {
+        /// The name of the property
+        property: &'static str,
+    },
+    /// The track list contains a `repeat()` which generates no tracks
+    #[cfg(feature = "grid")]
+    EmptyRepetition {
+        /// The name of the property
+        property: &'static str,
+    },
+    /// The track list contains more than one `auto-fill` or `auto-fit` repetition, so the whole track list is ignored
+    #[cfg(feature = "grid")]
+    MultipleAutoRepetitions {
+        /// The name of the property
+        property: &'static str,
+    },
+    /// The track list contains an `auto-fill` or `auto-fit` repetition and a track outside of that repetition whose
+    /// min and max sizing functions are both intrinsic or flexible, so the whole track list is ignored
+    #[cfg(feature = "grid")]
+    AutoRepetitionWithoutFixedSize {
+        /// The name of the property
+        property: &'static str,
+    },
+    /// The track list contains a `repeat()` whose line names aren't either empty or one set per line
+    #[cfg(feature = "grid")]
+    RepetitionLineNameCount {
+        /// The name of the property
+        property: &'static str,
+        /// The number of tracks in the repetition
+        track_count: usize,
+        /// The number of line name sets in the repetition
+        line_name_count: usize,
+    },
+}
+
+impl core::fmt::Display for StyleValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::NegativeValue { property } => write!(f, "{property} must not be negative"),
+            #[cfg(feature = "grid")]
+            Self::GridTemplateAreas(error) => write!(f, "{error}"),
+            #[cfg(feature = "grid")]
+            Self::InvalidGridLine { property } => write!(f, "{property} refers to grid line 0"),
+            #[cfg(feature = "grid")]
+            Self::EmptyRepetition { property } => write!(f, "{property} contains a repeat() which has no tracks"),
+            #[cfg(feature = "grid")]
+            Self::MultipleAutoRepetitions { property } => {
+                write!(f, "{property} contains more than one auto-fill or auto-fit repeat()")
+            }
+            #[cfg(feature = "grid")]
+            Self::AutoRepetitionWithoutFixedSize { property } => {
+                write!(
+                    f,
+                    "{property} contains an auto-fill or auto-fit repeat() and another track without a fixed size"
+                )
+            }
+            #[cfg(feature = "grid")]
+            Self::RepetitionLineNameCount { property, track_count, line_name_count } => write!(
+                f,
+                "{property} contains a repeat() of {track_count} tracks with {line_name_count} line name sets \
+                (expected none or {})",
+                track_count + 1
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StyleValidationError {}
+
+impl StyleValidationError {
+    /// The name of the [`Style`] field which is invalid
+    pub fn property(&self) -> &'static str {
+        match self {
+            Self::NegativeValue { property } => property,
+            #[cfg(feature = "grid")]
+            Self::GridTemplateAreas(_) => "grid_template_areas",
+            #[cfg(feature = "grid")]
+            Self::InvalidGridLine { property }
+            | Self::EmptyRepetition { property }
+            | Self::MultipleAutoRepetitions { property }
+            | Self::AutoRepetitionWithoutFixedSize { property }
+            | Self::RepetitionLineNameCount { property, .. } => property,
+        }
+    }
+}
+
+impl<S: CheapCloneStr> Style<S> {
+    /// Check the style for values and combinations of values which are invalid in CSS, returning every error found.
+    // Without an allocator `Vec` is a fixed-capacity array stored inline, which makes the error large. Styles are only
+    // validated on request (never during layout), so the size of the result isn't a concern.
+    #[cfg_attr(not(any(feature = "std", feature = "alloc")), allow(clippy::result_large_err))]
+    pub fn validate(&self) -> Result<(), Vec<StyleValidationError>> {
+        let mut errors = Vec::new();
+
+        check_non_negative_rect(&mut errors, "padding", self.padding);
+        check_non_negative_rect(&mut errors, "border", self.border);
+        if self.scrollbar_width < 0.0 {
+            errors.push(StyleValidationError::NegativeValue { property: "scrollbar_width" });
+        }
+        #[cfg(any(feature = "flexbox", feature = "grid"))]
+        if is_negative(self.gap.width.0) || is_negative(self.gap.height.0) {
+            errors.push(StyleValidationError::NegativeValue { property: "gap" });
+        }
+        #[cfg(feature = "flexbox")]
+        {
+            if self.flex_grow < 0.0 {
+                errors.push(StyleValidationError::NegativeValue { property: "flex_grow" });
+            }
+            if self.flex_shrink < 0.0 {
+                errors.push(StyleValidationError::NegativeValue { property: "flex_shrink" });
+            }
+        }
+
+        #[cfg(feature = "grid")]
+        {
+            check_track_list(&mut errors, "grid_template_columns", &self.grid_template_columns);
+            check_track_list(&mut errors, "grid_template_rows", &self.grid_template_rows);
+            check_placement(&mut errors, "grid_column", &self.grid_column);
+            check_placement(&mut errors, "grid_row", &self.grid_row);
+
+            if let Some(template) = &self.grid_template_areas {
+                if let Err(area_errors) = template.validate() {
+                    errors.extend(area_errors.into_iter().map(StyleValidationError::GridTemplateAreas));
+                }
+                let axes = [
+                    (AbsoluteAxis::Horizontal, &self.grid_template_columns, &self.grid_template_column_names),
+                    (AbsoluteAxis::Vertical, &self.grid_template_rows, &self.grid_template_row_names),
+                ];
+                for (axis, tracks, line_names) in axes {
+                    let explicit_lines = explicit_line_names(tracks, line_names);
+                    check_area_line_names(&mut errors, template, axis, &explicit_lines);
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Whether a length or percentage is negative (`calc()` values are never considered negative)
+fn is_negative(value: CompactLength) -> bool {
+    value.is_length_or_percentage() && value.value() < 0.0
+}
+
+/// Report an error if any side of the rect is negative
+fn check_non_negative_rect(
+    errors: &mut Vec<StyleValidationError>,
+    property: &'static str,
+    rect: Rect<LengthPercentage>,
+) {
+    if [rect.left, rect.right, rect.top, rect.bottom].into_iter().any(|side| is_negative(side.0)) {
+        errors.push(StyleValidationError::NegativeValue { property });
+    }
+}
+
+/// Check the `repeat()`s in a `grid-template-columns` or `grid-template-rows` track list
+#[cfg(feature = "grid")]
+fn check_track_list<S: CheapCloneStr>(
+    errors: &mut Vec<StyleValidationError>,
+    property: &'static str,
+    tracks: &[GridTemplateComponent<S>],
+) {
+    let mut auto_repetition_count = 0;
+    let mut all_other_tracks_have_fixed_component = true;
+    for track in tracks {
+        let repetition = match track {
+            GridTemplateComponent::Single(track) => {
+                all_other_tracks_have_fixed_component &= track.has_fixed_component();
+                continue;
+            }
+            GridTemplateComponent::Repeat(repetition) => repetition,
+        };
+        if repetition.tracks.is_empty() || repetition.count == RepetitionCount::Count(0) {
+            errors.push(StyleValidationError::EmptyRepetition { property });
+        }
+        // The tracks of an auto-repetition itself may be intrinsically sized
+        if matches!(repetition.count, RepetitionCount::Count(_)) {
+            all_other_tracks_have_fixed_component &= repetition.tracks.iter().all(|track| track.has_fixed_component());
+        } else {
+            auto_repetition_count += 1;
+        }
+        let (track_count, line_name_count) = (repetition.tracks.len(), repetition.line_names.len());
+        if line_name_count != 0 && line_name_count != track_count + 1 {
+            errors.push(StyleValidationError::RepetitionLineNameCount { property, track_count, line_name_count });
+        }
+    }
+    if auto_repetition_count > 1 {
+        errors.push(StyleValidationError::MultipleAutoRepetitions { property });
+    } else if auto_repetition_count == 1 && !all_other_tracks_have_fixed_component {
+        errors.push(StyleValidationError::AutoRepetitionWithoutFixedSize { property });
+    }
+}
+
+/// Check that a `grid-row` or `grid-column` placement doesn't refer to line 0
+#[cfg(feature = "grid")]
+fn check_placement<S: CheapCloneStr>(
+    errors: &mut Vec<StyleValidationError>,
+    property: &'static str,
+    placement: &Line<GridPlacement<S>>,
+) {
+    let is_line_zero =
+        |placement: &GridPlacement<S>| matches!(placement, GridPlacement::Line(line) if line.as_i16() == 0);
+    if is_line_zero(&placement.start) || is_line_zero(&placement.end) {
+        errors.push(StyleValidationError::InvalidGridLine { property });
+    }
+}
+
+/// The explicitly named lines of a track list, as `(line, name)` pairs in grid coordinates.
+///
+/// Only lines whose position is known without laying out the grid are included: the lines that follow an
+/// `auto-fill` or `auto-fit` repetition are omitted.
+#[cfg(feature = "grid")]
+fn explicit_line_names<'a, S: CheapCloneStr>(
+    tracks: &'a [GridTemplateComponent<S>],
+    line_names: &'a [GridTrackVec<S>],
+) -> Vec<(u32, &'a S)> {
+    let mut lines = Vec::new();
+    let mut current_line = 0;
+    let mut tracks = tracks.iter();
+    for line_name_set in line_names {
+        current_line += 1;
+        lines.extend(line_name_set.iter().map(|name| (current_line, name)));
+
+        let Some(GridTemplateComponent::Repeat(repetition)) = tracks.next() else { continue };
+        let RepetitionCount::Count(count) = repetition.count else { break };
+        for _ in 0..count {
+            for (line, line_name_set) in (current_line..).zip(&repetition.line_names) {
+                lines.extend(line_name_set.iter().map(|name| (line, name)));
+            }
+            current_line += repetition.tracks.len() as u32;
+            if current_line > MAX_GRID_TRACKS as u32 {
+                break;
+            }
+        }
+        // The last line of each repetition is the first line of the following track
+        if count > 0 {
+            current_line = current_line.saturating_sub(1);
+        }
+    }
+    lines
+}
+
+/// Report an error for each explicitly named `<area>-start` or `<area>-end` line in the axis which isn't the line
+/// that is implicitly given that name by the area
+#[cfg(feature = "grid")]
+fn check_area_line_names<S: CheapCloneStr>(
+    errors: &mut Vec<StyleValidationError>,
+    template: &GridTemplateAreas<S>,
+    axis: AbsoluteAxis,
+    explicit_lines: &[(u32, &S)],
+) {
+    for area in &template.areas {
+        let (start, end) = match axis {
+            AbsoluteAxis::Horizontal => (area.column_start, area.column_end),
+            AbsoluteAxis::Vertical => (area.row_start, area.row_end),
+        };
+        for (suffix, area_line) in [("-start", start), ("-end", end)] {
+            for &(explicit_line, name) in explicit_lines {
+                let name = name.as_ref();
+                let is_area_line_name = name.strip_suffix(suffix) == Some(area.name.as_ref());
+                if is_area_line_name && explicit_line != area_line as u32 {
+                    errors.push(StyleValidationError::GridTemplateAreas(GridTemplateAreasError::ConflictingLineName {
+                        line_name: String::from(name),
+                        axis,
+                        explicit_line: explicit_line.min(u16::MAX as u32) as u16,
+                        area_line,
+                    }));
+                }
+            }
+        }
+    }
+}
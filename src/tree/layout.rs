@@ -162,8 +162,8 @@ impl LayoutInput {
     };
 }
 
-/// The first and last baselines of a node in the horizontal axis (i.e. baselines for horizontal text,
-/// measured as an offset from the top edge of the node's border box).
+/// The first and last baselines of a node in a single axis, measured as an offset from the start edge of the node's
+/// border box in that axis (the top edge for horizontal baselines, and the left edge for vertical baselines).
 ///
 /// A baseline is the line on which text sits. See <https://www.w3.org/TR/css-writing-modes-3/#intro-baselines>
 /// for details.
@@ -184,6 +184,32 @@ impl Baselines {
     pub const fn from_first(first: Option<f32>) -> Self {
         Self { first, last: None }
     }
+
+    /// Create a `Baselines` with both a first and a last baseline
+    pub const fn new(first: f32, last: f32) -> Self {
+        Self { first: Some(first), last: Some(last) }
+    }
+
+    /// Offsets both baselines by `offset`, converting them from the coordinate space of a child to that of its parent
+    pub(crate) fn offset_by(self, offset: f32) -> Self {
+        Self { first: self.first.map(|first| first + offset), last: self.last.map(|last| last + offset) }
+    }
+
+    /// Returns the `(first, last)` baselines of a box of the given `size` in the axis of the baselines for use in
+    /// baseline alignment. Missing baselines are synthesized from the box's end edge, and the baselines of scroll
+    /// containers (which are determined from their content as if scrolled to the initial position) are clamped to
+    /// their border box. See <https://github.com/w3c/csswg-drafts/issues/7660>
+    pub(crate) fn synthesized(self, size: f32, is_scroll_container: bool) -> (f32, f32) {
+        let resolve = |baseline: Option<f32>| {
+            let baseline = baseline.unwrap_or(size);
+            if is_scroll_container {
+                baseline.min(size).max(0.0)
+            } else {
+                baseline
+            }
+        };
+        (resolve(self.first), resolve(self.last))
+    }
 }
 
 /// A struct containing the result of laying a single node, which is returned up to the parent node
@@ -191,7 +217,7 @@ impl Baselines {
 /// A baseline is the line on which text sits. Your node likely has a baseline if it is a text node, or contains
 /// children that may be text nodes. See <https://www.w3.org/TR/css-writing-modes-3/#intro-baselines> for details.
 /// If your node does not have a baseline (or you are unsure how to compute it), then simply return `Baselines::NONE`
-/// for the baselines field
+/// in both axes of the baselines field
 #[derive(Debug, Copy, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct LayoutOutput {
@@ -201,8 +227,22 @@ pub struct LayoutOutput {
     /// The scrollable overflow rectangle of the node's content
     /// (see [`Layout::scrollable_overflow_rect`] for the coordinate conventions)
     pub scrollable_overflow_rect: Rect<f32>,
-    /// The first and last baselines of the node in the horizontal axis, if any
-    pub baselines: Baselines,
+    /// The first and last baselines of the node in each axis, if any. `baselines.y` holds the horizontal baselines
+    /// (used by horizontal text), measured from the top edge of the node's border box, and `baselines.x` holds the
+    /// vertical baselines (used by vertical text), measured from its left edge
+    pub baselines: Point<Baselines>,
+    /// The size of the node's scrollbars: the width of its vertical scrollbar and the height of its horizontal
+    /// scrollbar. This is zero in axes in which the node has no scrollbar (including `Overflow::Auto` axes whose
+    /// content does not overflow). Parent nodes use this to set the child's [`Layout::scrollbar_size`]
+    pub scrollbar_size: Size<f32>,
+    /// The rectangle in which the content of a replaced node is painted, measured from the top-left corner of the
+    /// node's border box. This is `None` for nodes that are not replaced. Parent nodes use this to set the child's
+    /// [`Layout::replaced_content_rect`]
+    pub replaced_content_rect: Option<Rect<f32>>,
+    /// The rectangle occupied by the node's list marker, measured from the top-left corner of the node's border
+    /// box. This is `None` for nodes that do not generate a marker. Parent nodes use this to set the child's
+    /// [`Layout::marker_rect`]
+    pub marker_rect: Option<Rect<f32>>,
     /// Top margin that can be collapsed with. This is used for CSS block layout and can be set to
     /// `CollapsibleMarginSet::ZERO` for other layout modes that don't support margin collapsing
     pub top_margin: CollapsibleMarginSet,
@@ -220,7 +260,10 @@ impl LayoutOutput {
         size: Size::ZERO,
         #[cfg(feature = "content_size")]
         scrollable_overflow_rect: Rect::ZERO,
-        baselines: Baselines::NONE,
+        baselines: Point { x: Baselines::NONE, y: Baselines::NONE },
+        scrollbar_size: Size::ZERO,
+        replaced_content_rect: None,
+        marker_rect: None,
         top_margin: CollapsibleMarginSet::ZERO,
         bottom_margin: CollapsibleMarginSet::ZERO,
         margins_can_collapse_through: false,
@@ -233,13 +276,16 @@ impl LayoutOutput {
     pub fn from_sizes_and_baselines(
         size: Size<f32>,
         #[cfg_attr(not(feature = "content_size"), allow(unused_variables))] scrollable_overflow_rect: Rect<f32>,
-        baselines: Baselines,
+        baselines: Point<Baselines>,
     ) -> Self {
         Self {
             size,
             #[cfg(feature = "content_size")]
             scrollable_overflow_rect,
             baselines,
+            scrollbar_size: Size::ZERO,
+            replaced_content_rect: None,
+            marker_rect: None,
             top_margin: CollapsibleMarginSet::ZERO,
             bottom_margin: CollapsibleMarginSet::ZERO,
             margins_can_collapse_through: false,
@@ -248,7 +294,7 @@ impl LayoutOutput {
 
     /// Construct a `LayoutOutput` from just the container size and scrollable overflow rectangle
     pub fn from_sizes(size: Size<f32>, scrollable_overflow_rect: Rect<f32>) -> Self {
-        Self::from_sizes_and_baselines(size, scrollable_overflow_rect, Baselines::NONE)
+        Self::from_sizes_and_baselines(size, scrollable_overflow_rect, Point { x: Baselines::NONE, y: Baselines::NONE })
     }
 
     /// Construct a `LayoutOutput` from just the container's size.
@@ -257,6 +303,58 @@ impl LayoutOutput {
     }
 }
 
+/// The intrinsic size contributions of a node in a single axis: the outer size (including margins) that it would
+/// take up under each of the intrinsic sizing constraints
+///
+/// See <https://www.w3.org/TR/css-sizing-3/#contributions>
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct IntrinsicSizes {
+    /// The min-content contribution: the smallest size the node can take up without overflowing its content
+    pub min_content: f32,
+    /// The max-content contribution: the size the node would take up given infinite available space
+    pub max_content: f32,
+    /// The fit-content contribution: the size the node would take up if it shrink-to-fit the available space. This is
+    /// the max-content contribution clamped between the min-content contribution and the available space.
+    pub fit_content: f32,
+}
+
+/// The natural dimensions of the content of a replaced node (such as an image or a video), from which the node is
+/// sized using [`compute_replaced_leaf_layout`](crate::compute_replaced_leaf_layout)
+///
+/// Any of the natural dimensions may be missing. For example, an SVG image may only have a natural aspect ratio.
+///
+/// See <https://www.w3.org/TR/css-images-3/#natural-dimensions>
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct ReplacedContent {
+    /// The natural width and height of the content
+    pub natural_size: Size<Option<f32>>,
+    /// The natural aspect ratio (width divided by height) of the content. If this is `None` but the content has both
+    /// a natural width and a natural height, then the ratio between them is used.
+    pub natural_ratio: Option<f32>,
+}
+
+impl ReplacedContent {
+    /// Content with the given natural width and height (and therefore the natural aspect ratio between them)
+    pub const fn from_natural_size(width: f32, height: f32) -> Self {
+        Self { natural_size: Size { width: Some(width), height: Some(height) }, natural_ratio: None }
+    }
+
+    /// Content with a natural aspect ratio, but no natural width or height
+    pub const fn from_natural_ratio(ratio: f32) -> Self {
+        Self { natural_size: Size { width: None, height: None }, natural_ratio: Some(ratio) }
+    }
+
+    /// The natural aspect ratio of the content, derived from its natural size if it is not given
+    pub fn resolved_natural_ratio(&self) -> Option<f32> {
+        self.natural_ratio.or(match self.natural_size {
+            Size { width: Some(width), height: Some(height) } if height > 0.0 => Some(width / height),
+            _ => None,
+        })
+    }
+}
+
 /// The final result of a layout algorithm for a single node.
 #[derive(Debug, Copy, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize))]
@@ -274,8 +372,8 @@ pub struct Layout {
     /// The scrollable overflow rectangle of the node: the axis-aligned rectangle containing the
     /// content of the node (the border boxes of its descendants plus their non-clipped overflow),
     /// corresponding to the CSS "scrollable overflow rectangle"
-    /// (<https://www.w3.org/TR/css-overflow-3/#scrollable>), except that transforms are not
-    /// accounted for.
+    /// (<https://www.w3.org/TR/css-overflow-3/#scrollable>). Descendants contribute the bounding
+    /// box of their border box and overflow after their `transform` has been applied.
     ///
     /// Coordinates are measured from the node's *scroll origin*: the corner of the padding box at
     /// the block-start/inline-start edge (the top-left corner in LTR, the top-*right* corner in
@@ -287,6 +385,16 @@ pub struct Layout {
     pub scrollable_overflow_rect: Rect<f32>,
     /// The size of the scrollbars in each dimension. If there is no scrollbar then the size will be zero.
     pub scrollbar_size: Size<f32>,
+    /// The rectangle in which the content of a replaced node (such as an image) is painted, as determined by its
+    /// natural dimensions and its `object_fit` and `object_position` styles. The rectangle is measured from the
+    /// top-left corner of the node's border box, and may extend beyond the node's content box (in which case the
+    /// content should be clipped to the content box). This is `None` for nodes that are not replaced.
+    pub replaced_content_rect: Option<Rect<f32>>,
+    /// The rectangle occupied by the `::marker` box of a `Display::ListItem` node, measured from the top-left corner
+    /// of the node's border box. Outside markers are placed in the node's inline-start margin area (so `left` is
+    /// typically negative in LTR) while inside markers sit at the start of the node's first line. This is `None` for
+    /// nodes that are not list items or that have no marker content.
+    pub marker_rect: Option<Rect<f32>>,
     /// The size of the borders of the node
     pub border: Rect<f32>,
     /// The size of the padding of the node
@@ -316,6 +424,8 @@ impl Layout {
             #[cfg(feature = "content_size")]
             scrollable_overflow_rect: Rect::ZERO,
             scrollbar_size: Size::zero(),
+            replaced_content_rect: None,
+            marker_rect: None,
             border: Rect::zero(),
             padding: Rect::zero(),
             margin: Rect::zero(),
@@ -335,6 +445,8 @@ impl Layout {
             #[cfg(feature = "content_size")]
             scrollable_overflow_rect: Rect::ZERO,
             scrollbar_size: Size::zero(),
+            replaced_content_rect: None,
+            marker_rect: None,
             border: Rect::zero(),
             padding: Rect::zero(),
             margin: Rect::zero(),
@@ -11,7 +11,8 @@ pub mod traits;
 
 pub use cache::{Cache, ClearState};
 pub use layout::{
-    Baselines, CollapsibleMarginSet, Layout, LayoutInput, LayoutOutput, RequestedAxis, RunMode, SizingMode,
+    Baselines, CollapsibleMarginSet, IntrinsicSizes, Layout, LayoutInput, LayoutOutput, ReplacedContent, RequestedAxis,
+    RunMode, SizingMode,
 };
 pub use node::NodeId;
 pub(crate) use traits::LayoutPartialTreeExt;
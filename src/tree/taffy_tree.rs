@@ -7,27 +7,40 @@ use slotmap::{DefaultKey, SlotMap};
 
 #[cfg(feature = "block_layout")]
 use crate::block::BlockContext;
-use crate::geometry::Size;
-use crate::style::{AvailableSpace, Display, Style};
+use crate::geometry::{AbsoluteAxis, Point, Rect, Size};
+use crate::style::{
+    AvailableSpace, BoxSizing, CompactLength, Contain, ContainIntrinsicSize, ContainerCondition, ContainerType,
+    ContentVisibility, CoreStyle, Dimension, Display, Position, Style, Transform,
+};
 use crate::sys::DefaultCheapStr;
 use crate::tree::{
-    Cache, ClearState, Layout, LayoutInput, LayoutOutput, LayoutPartialTree, NodeId, PrintTree, RoundTree, RunMode,
-    TraversePartialTree, TraverseTree,
+    Cache, ClearState, IntrinsicSizes, Layout, LayoutInput, LayoutOutput, LayoutPartialTree, LayoutPartialTreeExt,
+    NodeId, PrintTree, RequestedAxis, RoundTree, RunMode, TraversePartialTree, TraverseTree,
 };
 use crate::util::debug::{debug_log, debug_log_node};
-use crate::util::sys::{new_vec_with_capacity, ChildrenVec, Vec};
+use crate::util::sys::{f32_max, new_vec_with_capacity, Box, ChildrenVec, Vec};
+use crate::util::{MaybeMath, MaybeResolve, ResolveOrZero};
 
+use crate::compute::common::scrollable_overflow::resolve_transform;
 use crate::compute::{
-    compute_cached_layout, compute_hidden_layout, compute_leaf_layout, compute_root_layout, round_layout,
+    compute_cached_layout, compute_hidden_layout, compute_intrinsic_sizes, compute_leaf_layout, compute_root_layout,
+    round_layout, round_subtree_layout,
 };
 use crate::CacheTree;
+#[cfg(feature = "parallel")]
+use crate::{style::LengthPercentageAuto, tree::CollapsibleMarginSet};
+#[cfg(feature = "parallel")]
+use rayon::iter::{IntoParallelRefMutIterator, ParallelIterator};
 
-#[cfg(feature = "block_layout")]
-use crate::{compute::compute_block_layout, LayoutBlockContainer};
 #[cfg(feature = "flexbox")]
 use crate::{compute::compute_flexbox_layout, LayoutFlexboxContainer};
 #[cfg(feature = "grid")]
 use crate::{compute::compute_grid_layout, LayoutGridContainer};
+#[cfg(feature = "block_layout")]
+use crate::{
+    compute::{compute_block_layout, compute_ruby_layout},
+    LayoutBlockContainer,
+};
 
 #[cfg(all(feature = "detailed_layout_info", feature = "grid"))]
 use crate::compute::grid::DetailedGridInfo;
@@ -55,6 +68,8 @@ pub enum TaffyError {
     InvalidChildNode(NodeId),
     /// The supplied node was not found in the [`TaffyTree`](crate::TaffyTree) instance.
     InvalidInputNode(NodeId),
+    /// The supplied node has not been laid out yet, so there are no previous layout inputs to lay it out with.
+    NodeNotLaidOut(NodeId),
 }
 
 impl core::fmt::Display for TaffyError {
@@ -68,6 +83,7 @@ impl core::fmt::Display for TaffyError {
             }
             TaffyError::InvalidChildNode(child) => write!(f, "Child Node {child:?} is not in the TaffyTree instance"),
             TaffyError::InvalidInputNode(node) => write!(f, "Supplied Node {node:?} is not in the TaffyTree instance"),
+            TaffyError::NodeNotLaidOut(node) => write!(f, "Supplied Node {node:?} has not been laid out"),
         }
     }
 }
@@ -110,6 +126,21 @@ struct NodeData {
     /// The cached results of the layout computation
     pub(crate) cache: Cache,
 
+    /// The inputs that the node's final layout was last performed with
+    pub(crate) final_layout_inputs: Option<LayoutInput>,
+
+    /// Whether the node is relevant to the user, which stops its contents from being skipped if its
+    /// `content_visibility` is `Auto`
+    pub(crate) relevant_to_user: bool,
+
+    /// The size of the node's content box when it was last laid out, in the axes in which its `contain_intrinsic_size`
+    /// style uses the `auto` keyword
+    pub(crate) last_remembered_size: Size<Option<f32>>,
+
+    /// The children that the node lays out, if any of its children have `Display::Contents`. The children of such a
+    /// child are laid out in its place.
+    pub(crate) layout_children: Option<ChildrenVec<NodeId>>,
+
     /// The computation result from layout algorithm
     #[cfg(feature = "detailed_layout_info")]
     pub(crate) detailed_layout_info: DetailedLayoutInfo,
@@ -125,6 +156,10 @@ impl NodeData {
             unrounded_layout: Layout::new(),
             final_layout: Layout::new(),
             has_context: false,
+            final_layout_inputs: None,
+            relevant_to_user: false,
+            last_remembered_size: Size::NONE,
+            layout_children: None,
             #[cfg(feature = "detailed_layout_info")]
             detailed_layout_info: DetailedLayoutInfo::None,
         }
@@ -140,6 +175,17 @@ impl NodeData {
     }
 }
 
+/// The styles of a node that are selected by the size of its nearest query container
+#[derive(Debug, Clone, PartialEq)]
+struct ContainerQueryStyles {
+    /// The style that is applied if none of the conditions match
+    base: Style,
+    /// The conditional styles, in order of precedence
+    variants: Vec<(ContainerCondition, Style)>,
+    /// The index of the variant that is currently applied to the node, or `None` if the base style is applied
+    active: Option<usize>,
+}
+
 /// An entire tree of UI nodes. The entry point to Taffy's high-level API.
 ///
 /// Allows you to build a tree of UI nodes, run Taffy's layout algorithms over that tree, and then access the resultant layout.]
@@ -151,6 +197,9 @@ pub struct TaffyTree<NodeContext = ()> {
     /// Functions/closures that compute the intrinsic size of leaf nodes
     node_context_data: SecondaryMap<DefaultKey, NodeContext>,
 
+    /// The context passed to the measure function to size the `::marker` box of `Display::ListItem` nodes
+    marker_context_data: SecondaryMap<DefaultKey, NodeContext>,
+
     /// The children of each node
     ///
     /// The indexes in the outer vector correspond to the position of the parent [`NodeData`]
@@ -161,6 +210,22 @@ pub struct TaffyTree<NodeContext = ()> {
     /// The indexes in the outer vector correspond to the position of the child [`NodeData`]
     parents: SlotMap<DefaultKey, Option<NodeId>>,
 
+    /// Relayout boundaries that have been marked as dirty without their ancestors being marked as dirty
+    ///
+    /// These are laid out individually by the next call to `compute_layout` whose root contains them
+    dirty_relayout_boundaries: Vec<NodeId>,
+
+    /// The styles of the nodes whose styles depend on the size of their nearest query container
+    container_query_styles: SecondaryMap<DefaultKey, ContainerQueryStyles>,
+
+    /// The nodes with container query styles whose nearest query container is each query container. Found at the
+    /// start of each layout pass.
+    query_container_nodes: SecondaryMap<DefaultKey, Vec<NodeId>>,
+
+    /// The query containers that changed the container query styles of their descendants during the current layout
+    /// pass
+    changed_query_containers: Vec<NodeId>,
+
     /// Layout mode configuration
     config: TaffyConfig,
 }
@@ -233,11 +298,14 @@ impl<NodeContext> PrintTree for TaffyTree<NodeContext> {
 
         match (num_children, display) {
             (_, Display::None) => "NONE",
+            (_, Display::Contents) => "CONTENTS",
             (0, _) => "LEAF",
             #[cfg(feature = "block_layout")]
             (_, Display::Block) => "BLOCK",
             #[cfg(feature = "block_layout")]
             (_, Display::FlowRoot) => "FLOW-ROOT",
+            #[cfg(feature = "block_layout")]
+            (_, Display::ListItem) => "LIST-ITEM",
             #[cfg(feature = "flexbox")]
             (_, Display::Flex) => {
                 use crate::FlexDirection;
@@ -248,6 +316,16 @@ impl<NodeContext> PrintTree for TaffyTree<NodeContext> {
             }
             #[cfg(feature = "grid")]
             (_, Display::Grid) => "GRID",
+            #[cfg(feature = "block_layout")]
+            (_, Display::InlineBlock) => "INLINE-BLOCK",
+            #[cfg(feature = "block_layout")]
+            (_, Display::Ruby) => "RUBY",
+            #[cfg(feature = "block_layout")]
+            (_, Display::RubyText) => "RUBY-TEXT",
+            #[cfg(feature = "flexbox")]
+            (_, Display::InlineFlex) => "INLINE-FLEX",
+            #[cfg(feature = "grid")]
+            (_, Display::InlineGrid) => "INLINE-GRID",
         }
     }
 
@@ -272,6 +350,10 @@ where
     pub(crate) taffy: &'t mut TaffyTree<NodeContext>,
     /// The context provided for passing to measure functions if layout is run over this struct
     pub(crate) measure_function: MeasureFunction,
+    /// The final layouts of independent subtrees that have been deferred so that they can be performed in parallel.
+    /// `None` if layout is being performed sequentially.
+    #[cfg(feature = "parallel")]
+    pub(crate) deferred_layouts: Option<DeferredLayouts>,
 }
 
 impl<NodeContext, MeasureFunction> TaffyView<'_, NodeContext, MeasureFunction>
@@ -287,11 +369,36 @@ where
         inputs: LayoutInput,
         #[cfg(feature = "block_layout")] block_ctx: Option<&mut BlockContext<'_>>,
     ) -> LayoutOutput {
+        // Record the inputs to the node's final layout so that it can be laid out again by itself if it is a relayout boundary
+        if inputs.run_mode != RunMode::ComputeSize {
+            self.taffy.nodes[node_id.into()].final_layout_inputs = Some(inputs);
+        }
+
         // If RunMode is PerformHiddenLayout then this indicates that an ancestor node is `Display::None`
         // and thus that we should lay out this node using hidden layout regardless of it's own display style.
         if inputs.run_mode == RunMode::PerformHiddenLayout {
             debug_log!("HIDDEN");
-            return compute_hidden_layout(self, node_id);
+            let output = compute_hidden_layout(self, node_id);
+            self.taffy.clear_contents_layouts(node_id);
+            return output;
+        }
+
+        // The styles of the descendants of a query container are selected by the size that it is given when its final
+        // layout is performed (which is resolved before its descendants are laid out). Measuring the container doesn't
+        // change them, so its size is measured with the styles selected by the previous final layout.
+        let container_type = self.taffy.nodes[node_id.into()].style.container_type;
+        if inputs.run_mode == RunMode::PerformLayout && container_type.is_container() {
+            let container_size = self.container_query_size(node_id, inputs, container_type);
+            self.taffy.apply_container_queries(node_id, container_size);
+        }
+
+        // If this node is the root of an independent subtree then its final layout may be deferred and performed
+        // in parallel with other such subtrees once the rest of the tree has been laid out.
+        #[cfg(feature = "parallel")]
+        if let Some(deferred_layouts) = &mut self.deferred_layouts {
+            if let Some(predicted_output) = deferred_layouts.try_defer(self.taffy, node_id, inputs) {
+                return predicted_output;
+            }
         }
 
         // We run the following wrapped in "compute_cached_layout", which will check the cache for an entry matching the node and inputs and:
@@ -299,33 +406,164 @@ where
         //   - Else call the passed closure (below) to compute the result
         //
         // If there was no cache match and a new result needs to be computed then that result will be added to the cache
-        compute_cached_layout(self, node_id, inputs, |tree, node_id, inputs| {
-            let display_mode = tree.taffy.nodes[node_id.into()].style.display;
+        let output = compute_cached_layout(self, node_id, inputs, |tree, node_id, inputs| {
+            // The children of a node are laid out according to its inner display type. The root of a layout is laid out
+            // as a box even if it has `Display::Contents`.
+            let display_mode = match tree.taffy.nodes[node_id.into()].style.display.inside() {
+                Display::Contents => Display::DEFAULT,
+                display => display,
+            };
+            let skips_contents = tree.taffy.skips_contents(node_id);
+            tree.taffy.update_layout_children(node_id);
             let has_children = tree.child_count(node_id) > 0;
 
             debug_log!(display_mode);
             debug_log_node!(inputs);
 
             // Dispatch to a layout algorithm based on the node's display style and whether the node has children or not.
-            match (display_mode, has_children) {
+            // A node whose contents are skipped is laid out as a leaf, without laying out its children.
+            let output = match (display_mode, has_children && !skips_contents) {
                 (Display::None, _) => compute_hidden_layout(tree, node_id),
                 #[cfg(feature = "block_layout")]
                 (Display::Block, true) => compute_block_layout(tree, node_id, inputs, block_ctx),
                 #[cfg(feature = "block_layout")]
                 (Display::FlowRoot, true) => compute_block_layout(tree, node_id, inputs, None),
+                #[cfg(feature = "block_layout")]
+                (Display::Ruby, true) => compute_ruby_layout(tree, node_id, inputs),
                 #[cfg(feature = "flexbox")]
                 (Display::Flex, true) => compute_flexbox_layout(tree, node_id, inputs),
                 #[cfg(feature = "grid")]
                 (Display::Grid, true) => compute_grid_layout(tree, node_id, inputs),
+                (_, true) => {
+                    unreachable!("inline-level displays and the root's `Display::Contents` are replaced above")
+                }
                 (_, false) => {
                     let node_key = node_id.into();
-                    let style = &tree.taffy.nodes[node_key].style;
-                    let has_context = tree.taffy.nodes[node_key].has_context;
+                    let node = &tree.taffy.nodes[node_key];
+                    let has_context = node.has_context;
+
+                    // Leaf layout only has access to the node's style, so the size containment of a node whose
+                    // contents are skipped, and the node's last remembered size, are substituted into its style
+                    let substituted_style;
+                    let contain =
+                        if skips_contents { node.style.contain() | Contain::SIZE } else { node.style.contain() };
+                    let contained_axes = contain.size_contained_axes();
+                    let style = if skips_contents
+                        || ((contained_axes.width || contained_axes.height) && node.last_remembered_size != Size::NONE)
+                    {
+                        let contain_intrinsic_size =
+                            node.style.contain_intrinsic_size.zip_map(node.last_remembered_size, |size, remembered| {
+                                ContainIntrinsicSize { length: Some(size.resolve(remembered)), ..size }
+                            });
+                        substituted_style = Style { contain, contain_intrinsic_size, ..node.style.clone() };
+                        &substituted_style
+                    } else {
+                        &node.style
+                    };
+
+                    // The contents of a node whose contents are skipped are not measured
+                    if skips_contents {
+                        return compute_leaf_layout(
+                            inputs,
+                            style,
+                            |val, basis| tree.calc(val, basis),
+                            |_, _| Size::ZERO,
+                        );
+                    }
+
                     let node_context = has_context.then(|| tree.taffy.node_context_data.get_mut(node_key)).flatten();
                     (tree.measure_function)(inputs, node_id, node_context, style)
                 }
+            };
+
+            if inputs.run_mode != RunMode::ComputeSize && !skips_contents {
+                tree.taffy.clear_contents_layouts(node_id);
             }
-        })
+
+            output
+        });
+
+        // The size of a node whose contents are skipped is not remembered, as it does not depend on its contents
+        if inputs.run_mode == RunMode::PerformLayout && !self.taffy.skips_contents(node_id) {
+            self.record_last_remembered_size(node_id, inputs, &output);
+        }
+
+        output
+    }
+
+    /// Records the size of the content box of `node_id` (which has just been laid out) in the axes in which its
+    /// `contain_intrinsic_size` style uses the `auto` keyword, and forgets it in the other axes
+    fn record_last_remembered_size(&mut self, node_id: NodeId, inputs: LayoutInput, output: &LayoutOutput) {
+        let node = &self.taffy.nodes[node_id.into()];
+        let contain_intrinsic_size = node.style.contain_intrinsic_size;
+        let last_remembered_size = if contain_intrinsic_size.width.auto || contain_intrinsic_size.height.auto {
+            let style = &node.style;
+            let parent_width = inputs.parent_size.width;
+            let padding = style.padding.resolve_or_zero(parent_width, |val, basis| self.calc(val, basis));
+            let border = style.border.resolve_or_zero(parent_width, |val, basis| self.calc(val, basis));
+            let content_box_size =
+                (output.size - (padding + border).sum_axes() - output.scrollbar_size).map(|size| f32_max(size, 0.0));
+            Size {
+                width: contain_intrinsic_size.width.auto.then_some(content_box_size.width),
+                height: contain_intrinsic_size.height.auto.then_some(content_box_size.height),
+            }
+        } else {
+            Size::NONE
+        };
+        self.taffy.nodes[node_id.into()].last_remembered_size = last_remembered_size;
+    }
+
+    /// Runs `layout` (which must start at `root`), and runs it again if any query container changed the styles of its
+    /// descendants, as the ancestors of the container may have measured it using the previous styles
+    fn compute_layout_with_container_queries<R>(&mut self, root: NodeId, mut layout: impl FnMut(&mut Self) -> R) -> R {
+        let output = layout(self);
+        if self.taffy.clear_changed_query_container_ancestors(root) {
+            layout(self)
+        } else {
+            output
+        }
+    }
+
+    /// The size of the content box of the query container `node_id` that the container queries of its descendants are
+    /// evaluated against. Axes that cannot be queried, or whose size is not known before the container's contents
+    /// are laid out, are `None`.
+    fn container_query_size(
+        &self,
+        node_id: NodeId,
+        inputs: LayoutInput,
+        container_type: ContainerType,
+    ) -> Size<Option<f32>> {
+        let style = &self.taffy.nodes[node_id.into()].style;
+        let parent_size = inputs.parent_size;
+        let padding = style.padding.resolve_or_zero(parent_size.width, |val, basis| self.calc(val, basis));
+        let border = style.border.resolve_or_zero(parent_size.width, |val, basis| self.calc(val, basis));
+        let padding_border_sum = (padding + border).sum_axes();
+        let box_sizing_adjustment =
+            if style.box_sizing == BoxSizing::ContentBox { padding_border_sum } else { Size::ZERO };
+
+        let min_size = style
+            .min_size
+            .maybe_resolve(parent_size, |val, basis| self.calc(val, basis))
+            .maybe_add(box_sizing_adjustment);
+        let max_size = style
+            .max_size
+            .maybe_resolve(parent_size, |val, basis| self.calc(val, basis))
+            .maybe_add(box_sizing_adjustment);
+        let style_size = style
+            .size
+            .maybe_resolve(parent_size, |val, basis| self.calc(val, basis))
+            .maybe_add(box_sizing_adjustment)
+            .maybe_clamp(min_size, max_size);
+
+        let content_size = inputs
+            .known_dimensions
+            .or(style_size)
+            .maybe_sub(padding_border_sum)
+            .map(|size| size.map(|size| f32_max(size, 0.0)));
+        match container_type {
+            ContainerType::InlineSize => Size { width: content_size.width, height: None },
+            ContainerType::Normal | ContainerType::Size => content_size,
+        }
     }
 }
 
@@ -341,17 +579,17 @@ where
 
     #[inline(always)]
     fn child_ids(&self, parent_node_id: NodeId) -> Self::ChildIter<'_> {
-        self.taffy.child_ids(parent_node_id)
+        TaffyTreeChildIter(self.taffy.layout_children(parent_node_id).iter())
     }
 
     #[inline(always)]
     fn child_count(&self, parent_node_id: NodeId) -> usize {
-        self.taffy.child_count(parent_node_id)
+        self.taffy.layout_children(parent_node_id).len()
     }
 
     #[inline(always)]
     fn get_child_id(&self, parent_node_id: NodeId, child_index: usize) -> NodeId {
-        self.taffy.get_child_id(parent_node_id, child_index)
+        self.taffy.layout_children(parent_node_id)[child_index]
     }
 }
 
@@ -361,6 +599,14 @@ impl<NodeContext, MeasureFunction> TraverseTree for TaffyView<'_, NodeContext, M
 {
 }
 
+/// Resolves a `calc()` value of a style in a [`TaffyTree`]. `TaffyTree` has no way to evaluate calc expressions, so
+/// they all resolve to zero, both during layout and when [`TaffyTree::transformed_bounding_box`] resolves a
+/// `transform_origin`.
+#[inline(always)]
+fn resolve_calc_value(_val: *const (), _basis: f32) -> f32 {
+    0.0
+}
+
 // LayoutPartialTree impl for TaffyView
 impl<NodeContext, MeasureFunction> LayoutPartialTree for TaffyView<'_, NodeContext, MeasureFunction>
 where
@@ -384,8 +630,8 @@ where
     }
 
     #[inline(always)]
-    fn resolve_calc_value(&self, _val: *const (), _basis: f32) -> f32 {
-        0.0
+    fn resolve_calc_value(&self, val: *const (), basis: f32) -> f32 {
+        resolve_calc_value(val, basis)
     }
 
     #[inline(always)]
@@ -397,6 +643,34 @@ where
             None,
         )
     }
+
+    #[inline(always)]
+    fn last_remembered_size(&self, node_id: NodeId) -> Size<Option<f32>> {
+        self.taffy.nodes[node_id.into()].last_remembered_size
+    }
+
+    fn resolve_anchor(
+        &self,
+        containing_block_id: NodeId,
+        node_id: NodeId,
+        anchor_name: &DefaultCheapStr,
+    ) -> Option<Rect<f32>> {
+        let mut anchor = None;
+        let mut is_after_node = false;
+        for &child_id in self.taffy.layout_children(containing_block_id) {
+            if child_id == node_id {
+                // The positioned node (and its descendants) cannot be its own anchor
+                is_after_node = true;
+                continue;
+            }
+            // Absolutely positioned siblings that come later in tree order have not been laid out yet
+            if is_after_node && self.taffy.nodes[child_id.into()].style.position == Position::Absolute {
+                continue;
+            }
+            self.taffy.find_anchor(child_id, Point::ZERO, anchor_name, &mut anchor);
+        }
+        anchor
+    }
 }
 
 impl<NodeContext, MeasureFunction> CacheTree for TaffyView<'_, NodeContext, MeasureFunction>
@@ -449,6 +723,12 @@ where
     ) -> LayoutOutput {
         self.compute_child_layout(node_id, inputs, block_ctx)
     }
+
+    #[inline(always)]
+    fn compute_marker_layout(&mut self, node_id: NodeId, inputs: LayoutInput) -> Option<LayoutOutput> {
+        let context = self.taffy.marker_context_data.get_mut(node_id.into())?;
+        Some((self.measure_function)(inputs, node_id, Some(context), &Style::DEFAULT))
+    }
 }
 
 #[cfg(feature = "flexbox")]
@@ -525,6 +805,36 @@ where
 
 #[allow(clippy::iter_cloned_collect)] // due to no-std support, we need to use `iter_cloned` instead of `collect`
 impl<NodeContext> TaffyTree<NodeContext> {
+    /// Search the subtree rooted at `node` for nodes whose `anchor_name` is `anchor_name`, storing the border box of
+    /// the last one found (in tree order) in `anchor`. `parent_location` is the position of the node's parent relative
+    /// to the containing block that the anchor is being resolved for.
+    fn find_anchor(
+        &self,
+        node: NodeId,
+        parent_location: Point<f32>,
+        anchor_name: &DefaultCheapStr,
+        anchor: &mut Option<Rect<f32>>,
+    ) {
+        let node_data = &self.nodes[node.into()];
+        if node_data.style.display == Display::None {
+            return;
+        }
+
+        let location = parent_location + node_data.unrounded_layout.location;
+        if node_data.style.anchor_name.as_ref() == Some(anchor_name) {
+            let size = node_data.unrounded_layout.size;
+            *anchor = Some(Rect {
+                left: location.x,
+                right: location.x + size.width,
+                top: location.y,
+                bottom: location.y + size.height,
+            });
+        }
+        for child in self.child_ids(node) {
+            self.find_anchor(child, location, anchor_name, anchor);
+        }
+    }
+
     /// Creates a new [`TaffyTree`]
     ///
     /// The default capacity of a [`TaffyTree`] is 16 nodes.
@@ -543,6 +853,11 @@ impl<NodeContext> TaffyTree<NodeContext> {
             children: SlotMap::with_capacity(capacity),
             parents: SlotMap::with_capacity(capacity),
             node_context_data: SecondaryMap::with_capacity(capacity),
+            marker_context_data: SecondaryMap::new(),
+            dirty_relayout_boundaries: Vec::new(),
+            container_query_styles: SecondaryMap::new(),
+            query_container_nodes: SecondaryMap::new(),
+            changed_query_containers: Vec::new(),
             config: TaffyConfig::default(),
         }
     }
@@ -601,6 +916,9 @@ impl<NodeContext> TaffyTree<NodeContext> {
         self.nodes.clear();
         self.children.clear();
         self.parents.clear();
+        self.dirty_relayout_boundaries.clear();
+        self.container_query_styles.clear();
+        self.query_container_nodes.clear();
     }
 
     /// Remove a specific node from the tree and drop it
@@ -612,7 +930,7 @@ impl<NodeContext> TaffyTree<NodeContext> {
             if let Some(children) = self.children.get_mut(parent.into()) {
                 children.retain(|f| *f != node);
             }
-            self.mark_dirty(parent)?;
+            self.mark_dirty_recursive(parent.into());
         }
 
         // Remove "parent" references to a node when removing that node
@@ -625,6 +943,7 @@ impl<NodeContext> TaffyTree<NodeContext> {
         let _ = self.children.remove(key);
         let _ = self.parents.remove(key);
         let _ = self.nodes.remove(key);
+        let _ = self.container_query_styles.remove(key);
 
         Ok(node)
     }
@@ -666,13 +985,43 @@ impl<NodeContext> TaffyTree<NodeContext> {
         self.node_context_data.get_disjoint_mut(keys.map(|k| k.into()))
     }
 
+    /// Sets the context data describing the content of the node's `::marker` box (for example the bullet or counter
+    /// text of a list item). The marker is sized by calling the measure function with this context.
+    ///
+    /// Markers are only generated for nodes with `Display::ListItem`. Nodes without marker context have no marker.
+    #[inline]
+    pub fn set_marker_context(&mut self, node: NodeId, context: Option<NodeContext>) -> TaffyResult<()> {
+        let key = node.into();
+        if let Some(context) = context {
+            self.marker_context_data.insert(key, context);
+        } else {
+            self.marker_context_data.remove(key);
+        }
+
+        self.mark_dirty(node)?;
+
+        Ok(())
+    }
+
+    /// Gets a reference to the the context data associated with the node's `::marker` box
+    #[inline]
+    pub fn get_marker_context(&self, node: NodeId) -> Option<&NodeContext> {
+        self.marker_context_data.get(node.into())
+    }
+
+    /// Gets a mutable reference to the the context data associated with the node's `::marker` box
+    #[inline]
+    pub fn get_marker_context_mut(&mut self, node: NodeId) -> Option<&mut NodeContext> {
+        self.marker_context_data.get_mut(node.into())
+    }
+
     /// Adds a `child` node under the supplied `parent`
     pub fn add_child(&mut self, parent: NodeId, child: NodeId) -> TaffyResult<()> {
         let parent_key = parent.into();
         let child_key = child.into();
         self.parents[child_key] = Some(parent);
         self.children[parent_key].push(child);
-        self.mark_dirty(parent)?;
+        self.mark_dirty_recursive(parent.into());
 
         Ok(())
     }
@@ -688,7 +1037,7 @@ impl<NodeContext> TaffyTree<NodeContext> {
 
         self.parents[child.into()] = Some(parent);
         self.children[parent_key].insert(child_index, child);
-        self.mark_dirty(parent)?;
+        self.mark_dirty_recursive(parent.into());
 
         Ok(())
     }
@@ -715,7 +1064,7 @@ impl<NodeContext> TaffyTree<NodeContext> {
         parent_children.clear();
         children.iter().for_each(|child| parent_children.push(*child));
 
-        self.mark_dirty(parent)?;
+        self.mark_dirty_recursive(parent.into());
 
         Ok(())
     }
@@ -741,7 +1090,7 @@ impl<NodeContext> TaffyTree<NodeContext> {
         let child = self.children[parent_key].remove(child_index);
         self.parents[child.into()] = None;
 
-        self.mark_dirty(parent)?;
+        self.mark_dirty_recursive(parent.into());
 
         Ok(child)
     }
@@ -760,7 +1109,7 @@ impl<NodeContext> TaffyTree<NodeContext> {
             self.parents[child.into()] = None;
         }
 
-        self.mark_dirty(parent)?;
+        self.mark_dirty_recursive(parent.into());
         Ok(())
     }
 
@@ -784,7 +1133,7 @@ impl<NodeContext> TaffyTree<NodeContext> {
         let old_child = core::mem::replace(&mut self.children[parent_key][child_index], new_child);
         self.parents[old_child.into()] = None;
 
-        self.mark_dirty(parent)?;
+        self.mark_dirty_recursive(parent.into());
 
         Ok(old_child)
     }
@@ -822,19 +1171,222 @@ impl<NodeContext> TaffyTree<NodeContext> {
     }
 
     /// Sets the [`Style`] of the provided `node`
+    ///
+    /// If the node has container query styles (see [`TaffyTree::set_container_query_styles`]) then this sets the style
+    /// that is used when none of their conditions match.
     #[inline]
     pub fn set_style(&mut self, node: NodeId, style: Style) -> TaffyResult<()> {
+        if let Some(container_query_styles) = self.container_query_styles.get_mut(node.into()) {
+            container_query_styles.base = style.clone();
+            container_query_styles.active = None;
+        }
         self.nodes[node.into()].style = style;
         self.mark_dirty(node)?;
         Ok(())
     }
 
+    /// Marks `node` as relevant to the user (for example because it intersects the viewport) or not. The contents of a
+    /// node whose `content_visibility` is [`ContentVisibility::Auto`] are skipped unless it is relevant to the user.
+    ///
+    /// Nodes are not relevant to the user by default. Changing the relevance of a node whose contents may be skipped
+    /// marks it as dirty, but the layouts and caches of its contents are preserved while they are skipped.
+    pub fn set_relevant_to_user(&mut self, node: NodeId, relevant_to_user: bool) -> TaffyResult<()> {
+        let node_data = &mut self.nodes[node.into()];
+        if node_data.relevant_to_user != relevant_to_user {
+            node_data.relevant_to_user = relevant_to_user;
+            if node_data.style.content_visibility == ContentVisibility::Auto {
+                self.mark_dirty(node)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether `node` has been marked as relevant to the user with [`TaffyTree::set_relevant_to_user`]
+    #[inline]
+    pub fn is_relevant_to_user(&self, node: NodeId) -> TaffyResult<bool> {
+        Ok(self.nodes[node.into()].relevant_to_user)
+    }
+
+    /// Whether the contents of `node` are skipped rather than laid out, because of its `content_visibility`
+    #[inline]
+    fn skips_contents(&self, node: NodeId) -> bool {
+        let node_data = &self.nodes[node.into()];
+        node_data.style.content_visibility.skips_contents(node_data.relevant_to_user)
+    }
+
+    /// The children that `node` lays out, in which each child with `Display::Contents` is replaced by the children
+    /// that it lays out. Only valid once [`update_layout_children`](Self::update_layout_children) has been called.
+    #[inline]
+    fn layout_children(&self, node: NodeId) -> &[NodeId] {
+        let key = node.into();
+        self.nodes[key].layout_children.as_deref().unwrap_or(&self.children[key])
+    }
+
+    /// Recomputes the children that `node` lays out, splicing in the children of its `Display::Contents` children
+    fn update_layout_children(&mut self, node: NodeId) {
+        let key = node.into();
+        let has_contents_child =
+            self.children[key].iter().any(|child| self.nodes[(*child).into()].style.display == Display::Contents);
+        self.nodes[key].layout_children = has_contents_child.then(|| {
+            let mut layout_children = ChildrenVec::new();
+            self.push_layout_children(node, &mut layout_children);
+            layout_children
+        });
+    }
+
+    /// Pushes the children that `node` lays out onto `layout_children`
+    fn push_layout_children(&self, node: NodeId, layout_children: &mut ChildrenVec<NodeId>) {
+        for &child in &self.children[node.into()] {
+            if self.nodes[child.into()].style.display == Display::Contents {
+                self.push_layout_children(child, layout_children);
+            } else {
+                layout_children.push(child);
+            }
+        }
+    }
+
+    /// Gives the `Display::Contents` descendants of `node` that it lays out the children of an empty layout. As their
+    /// layouts are positioned at the origin of their parents, the locations of the children that they contain remain
+    /// relative to `node`. Rounding skips these nodes along with the rest of layout, so their (already round) final
+    /// layouts are set here too.
+    fn clear_contents_layouts(&mut self, node: NodeId) {
+        if self.nodes[node.into()].layout_children.is_none() {
+            return;
+        }
+        let mut parents = Vec::from([node]);
+        while let Some(parent) = parents.pop() {
+            for order in 0..self.children[parent.into()].len() {
+                let child = self.children[parent.into()][order];
+                if self.nodes[child.into()].style.display == Display::Contents {
+                    let layout = Layout::with_order(order as u32);
+                    self.nodes[child.into()].unrounded_layout = layout;
+                    self.nodes[child.into()].final_layout = layout;
+                    parents.push(child);
+                }
+            }
+        }
+    }
+
+    /// The nearest ancestor of the node that lays it out, skipping any `Display::Contents` ancestors
+    fn layout_parent(&self, node_key: DefaultKey) -> Option<NodeId> {
+        let mut parent = self.parents.get(node_key).copied().flatten();
+        while let Some(node) = parent.filter(|node| self.nodes[(*node).into()].style.display == Display::Contents) {
+            parent = self.parents[node.into()];
+        }
+        parent
+    }
+
     /// Gets the [`Style`] of the provided `node`
+    ///
+    /// If the node has container query styles (see [`TaffyTree::set_container_query_styles`]) then this is the style
+    /// that was selected by the size of its query container when it was last laid out.
     #[inline]
     pub fn style(&self, node: NodeId) -> TaffyResult<&Style> {
         Ok(&self.nodes[node.into()].style)
     }
 
+    /// Sets styles for `node` that depend on the size of its nearest ancestor that is a query container (a node whose
+    /// [`Style::container_type`] is not [`ContainerType::Normal`]).
+    ///
+    /// When the final layout of the query container is performed its size is resolved first, and then the style of the
+    /// first variant whose [`ContainerCondition`] matches the size of the container's content box is applied to `node`
+    /// before `node` is laid out. If none of the conditions match (or `node` has no query container) then the style
+    /// that was set with [`TaffyTree::set_style`] is used. The container is measured by its ancestors using the styles
+    /// that were selected by its previous final layout, so if this changes the style of `node` then the ancestors are
+    /// laid out again. This all happens within a single call to [`TaffyTree::compute_layout`].
+    ///
+    /// Passing an empty list of variants removes the node's container query styles.
+    pub fn set_container_query_styles(
+        &mut self,
+        node: NodeId,
+        variants: Vec<(ContainerCondition, Style)>,
+    ) -> TaffyResult<()> {
+        let key = node.into();
+        let base = match self.container_query_styles.remove(key) {
+            Some(container_query_styles) => container_query_styles.base,
+            None => self.nodes[key].style.clone(),
+        };
+        if !variants.is_empty() {
+            self.container_query_styles
+                .insert(key, ContainerQueryStyles { base: base.clone(), variants, active: None });
+        }
+        self.nodes[key].style = base;
+        self.mark_dirty(node)?;
+        Ok(())
+    }
+
+    /// Finds the nearest query container of each node that has container query styles, and forgets the query
+    /// containers that changed styles during the previous layout pass
+    fn update_query_container_nodes(&mut self) {
+        self.changed_query_containers.clear();
+        self.query_container_nodes.clear();
+        for node_key in self.container_query_styles.keys() {
+            let mut ancestor = self.parents[node_key];
+            while let Some(ancestor_id) = ancestor {
+                if self.nodes[ancestor_id.into()].style.container_type.is_container() {
+                    if let Some(entry) = self.query_container_nodes.entry(ancestor_id.into()) {
+                        entry.or_default().push(node_key.into());
+                    }
+                    break;
+                }
+                ancestor = self.parents[ancestor_id.into()];
+            }
+        }
+    }
+
+    /// Apply the container query styles of the descendants of the query container `container` for which it is the
+    /// nearest query container, given the size of the container's content box.
+    ///
+    /// The cache of each node whose style changes is cleared, along with the caches of its ancestors up to and
+    /// including `container`. This keeps the cache entries of the container consistent with the styles of its
+    /// descendants, as the styles are always selected by the inputs that the container is laid out with.
+    fn apply_container_queries(&mut self, container: NodeId, container_size: Size<Option<f32>>) {
+        let Some(nodes) = self.query_container_nodes.get(container.into()) else { return };
+        let mut has_changed = false;
+        for &node in nodes {
+            let Some(container_query_styles) = self.container_query_styles.get_mut(node.into()) else { continue };
+            let active =
+                container_query_styles.variants.iter().position(|(condition, _)| condition.matches(container_size));
+            if active == container_query_styles.active {
+                continue;
+            }
+            container_query_styles.active = active;
+            self.nodes[node.into()].style = match active {
+                Some(index) => container_query_styles.variants[index].1.clone(),
+                None => container_query_styles.base.clone(),
+            };
+
+            let mut ancestor = Some(node);
+            while let Some(ancestor_id) = ancestor.filter(|&ancestor_id| ancestor_id != container) {
+                self.nodes[ancestor_id.into()].cache.clear();
+                ancestor = self.parents[ancestor_id.into()];
+            }
+            has_changed = true;
+        }
+
+        if has_changed {
+            self.nodes[container.into()].cache.clear();
+            self.changed_query_containers.push(container);
+        }
+    }
+
+    /// Clears the caches of the ancestors (up to and including `root`) of the query containers that changed the styles
+    /// of their descendants during the current layout pass. These ancestors may have measured the containers using
+    /// the previous styles. Returns whether there were any such containers.
+    fn clear_changed_query_container_ancestors(&mut self, root: NodeId) -> bool {
+        let changed_query_containers = core::mem::take(&mut self.changed_query_containers);
+        for &container in &changed_query_containers {
+            let mut ancestor = Some(container);
+            while let Some(ancestor_id) = ancestor.filter(|&ancestor_id| ancestor_id != root) {
+                ancestor = self.parents[ancestor_id.into()];
+                if let Some(ancestor_id) = ancestor {
+                    self.nodes[ancestor_id.into()].cache.clear();
+                }
+            }
+        }
+        !changed_query_containers.is_empty()
+    }
+
     /// Return this node layout relative to its parent
     #[inline]
     pub fn layout(&self, node: NodeId) -> TaffyResult<&Layout> {
@@ -851,6 +1403,35 @@ impl<NodeContext> TaffyTree<NodeContext> {
         &self.nodes[node.into()].unrounded_layout
     }
 
+    /// Returns the smallest axis-aligned rectangle containing the node's border box after the `transform` styles of the
+    /// node and all of its ancestors have been applied
+    ///
+    /// The rectangle is measured in the same coordinate space as the location of the root of the node's tree, and is
+    /// computed from the same (rounded or unrounded) layouts as [`layout`](TaffyTree::layout). Each `transform_origin` is
+    /// resolved in the same way as during layout. Scroll offsets are not taken into account.
+    pub fn transformed_bounding_box(&self, node: NodeId) -> TaffyResult<Rect<f32>> {
+        let size = self.layout(node)?.size;
+        let mut transform = Transform::IDENTITY;
+        let mut current = Some(node);
+        while let Some(node) = current {
+            let layout = self.layout(node)?;
+            let style = &self.nodes[node.into()].style;
+            // A `Display::Contents` node has no box for its transform to apply to
+            if style.display == Display::Contents {
+                current = self.parent(node);
+                continue;
+            }
+            // Layout locations are measured from the left edge of the parent whatever its `direction`, so the transform
+            // is never mirrored (as it is for the scrollable overflow of right-to-left parents, which is measured from
+            // their right edge)
+            let node_transform =
+                resolve_transform(style.transform, style.transform_origin, layout.size, false, resolve_calc_value);
+            transform = transform.then(node_transform).then(Transform::translate(layout.location.x, layout.location.y));
+            current = self.parent(node);
+        }
+        Ok(transform.transform_rect(Rect { left: 0.0, right: size.width, top: 0.0, bottom: size.height }))
+    }
+
     /// Get the "detailed layout info" for a node.
     ///
     /// Currently this is only implemented for CSS Grid containers where it contains
@@ -862,29 +1443,137 @@ impl<NodeContext> TaffyTree<NodeContext> {
     }
 
     /// Marks the layout of this node and its ancestors as outdated
+    ///
+    /// Ancestors are marked as outdated up to the nearest relayout boundary above this node. A relayout boundary is a node
+    /// with [`Contain::LAYOUT`] and a fixed length `size` in both axes, which also isn't a flex item with a content-based
+    /// automatic minimum size. Its descendants can't affect the layout of
+    /// anything outside of it, so the next call to [`compute_layout`](TaffyTree::compute_layout) lays out the boundary by
+    /// itself (using the inputs that its parent last laid it out with) rather than laying out its ancestors.
     pub fn mark_dirty(&mut self, node: NodeId) -> TaffyResult<()> {
-        fn mark_dirty_recursive(
-            nodes: &mut SlotMap<DefaultKey, NodeData>,
-            parents: &SlotMap<DefaultKey, Option<NodeId>>,
-            node_key: DefaultKey,
-        ) {
-            match nodes[node_key].mark_dirty() {
-                ClearState::AlreadyEmpty => {
-                    // Node was already marked as dirty.
-                    // No need to visit ancestors
-                    // as they should be marked as dirty already.
-                }
-                ClearState::Cleared => {
-                    if let Some(Some(node)) = parents.get(node_key) {
-                        mark_dirty_recursive(nodes, parents, (*node).into());
+        // A change to the node itself may change its size, so its ancestors must be marked as dirty even if it is a
+        // relayout boundary (or was already marked as dirty)
+        let node_key = node.into();
+        let _ = self.nodes[node_key].mark_dirty();
+        if let Some(Some(parent)) = self.parents.get(node_key) {
+            self.mark_dirty_recursive((*parent).into());
+        }
+
+        Ok(())
+    }
+
+    /// Marks the layout of a node whose contents have changed and its ancestors as outdated, stopping at the first
+    /// relayout boundary
+    fn mark_dirty_recursive(&mut self, node_key: DefaultKey) {
+        // The layout of a node whose contents are skipped does not depend on its contents
+        if self.skips_contents(node_key.into()) {
+            return;
+        }
+
+        // A `Display::Contents` node is not laid out itself (so its cache is always empty), and its children are laid
+        // out by its parent
+        if self.nodes[node_key].style.display == Display::Contents {
+            if let Some(Some(parent)) = self.parents.get(node_key) {
+                self.mark_dirty_recursive((*parent).into());
+            }
+            return;
+        }
+
+        match self.nodes[node_key].mark_dirty() {
+            ClearState::AlreadyEmpty => {
+                // Node was already marked as dirty.
+                // No need to visit ancestors as they should be marked as dirty already
+                // (or are separated from the node by a relayout boundary).
+            }
+            ClearState::Cleared => {
+                if self.is_relayout_boundary(node_key) {
+                    let node = node_key.into();
+                    if !self.dirty_relayout_boundaries.contains(&node) {
+                        self.dirty_relayout_boundaries.push(node);
                     }
+                } else if let Some(Some(parent)) = self.parents.get(node_key) {
+                    self.mark_dirty_recursive((*parent).into());
                 }
             }
         }
+    }
 
-        mark_dirty_recursive(&mut self.nodes, &self.parents, node.into());
+    /// Whether changes to the node's descendants cannot affect the layout of anything outside of the node
+    ///
+    /// Layout containment means that the node's baselines, scrollable overflow and margins don't depend on its contents,
+    /// and a fixed size means that its size and intrinsic size contributions don't either.
+    fn is_relayout_boundary(&self, node_key: DefaultKey) -> bool {
+        let node = &self.nodes[node_key];
+        let style = &node.style;
+        if node.final_layout_inputs.is_none()
+            || style.display == Display::Contents
+            || !style.contain().contains(Contain::LAYOUT)
+        {
+            return false;
+        }
 
-        Ok(())
+        let is_fixed = |dimension: Dimension| dimension.tag() == CompactLength::LENGTH_TAG;
+        if !is_fixed(style.size.width) || !is_fixed(style.size.height) {
+            return false;
+        }
+
+        // The automatic minimum main size of a flex item is content-based unless it is a scroll container
+        #[cfg(feature = "flexbox")]
+        if let Some(parent) = self.layout_parent(node_key) {
+            let parent_style = &self.nodes[parent.into()].style;
+            if parent_style.display.inside() == Display::Flex {
+                let (min_main_size, main_overflow) = match parent_style.flex_direction.is_row() {
+                    true => (style.min_size.width, style.overflow.x),
+                    false => (style.min_size.height, style.overflow.y),
+                };
+                if (min_main_size.is_auto() && !main_overflow.is_scroll_container())
+                    || style.flex_basis.is_content()
+                    || style.flex_basis.is_sizing_keyword()
+                {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Stores the parts of the layout of a node that was laid out without its parent that its parent would otherwise
+    /// have set. The node's position depends on its siblings, so it is left as it is.
+    fn finish_subtree_layout(&mut self, node: NodeId, output: LayoutOutput) {
+        let layout = &mut self.nodes[node.into()].unrounded_layout;
+        layout.size = output.size;
+        layout.scrollbar_size = output.scrollbar_size;
+        layout.replaced_content_rect = output.replaced_content_rect;
+        layout.marker_rect = output.marker_rect;
+        #[cfg(feature = "content_size")]
+        {
+            layout.scrollable_overflow_rect = output.scrollable_overflow_rect;
+        }
+    }
+
+    /// Removes the dirty relayout boundaries within the subtree rooted at `root` from the list of dirty relayout
+    /// boundaries, and returns them ordered from the shallowest to the deepest
+    fn take_dirty_relayout_boundaries(&mut self, root: NodeId) -> Vec<NodeId> {
+        let mut boundaries = Vec::new();
+        let (nodes, parents) = (&self.nodes, &self.parents);
+        self.dirty_relayout_boundaries.retain(|&node| {
+            if !nodes.contains_key(node.into()) {
+                return false;
+            }
+            let mut depth = 0;
+            let mut ancestor = Some(node);
+            while let Some(ancestor_id) = ancestor {
+                if ancestor_id == root {
+                    boundaries.push((depth, node));
+                    return false;
+                }
+                depth += 1;
+                ancestor = parents[ancestor_id.into()];
+            }
+            true
+        });
+        boundaries.sort_by_key(|&(depth, _)| depth);
+        boundaries.into_iter().map(|(_, node)| node).collect()
     }
 
     /// Indicates whether the layout of this node needs to be recomputed
@@ -904,8 +1593,27 @@ impl<NodeContext> TaffyTree<NodeContext> {
         MeasureFunction: FnMut(LayoutInput, NodeId, Option<&mut NodeContext>, &Style) -> LayoutOutput,
     {
         let use_rounding = self.config.use_rounding;
-        let mut taffy_view = TaffyView { taffy: self, measure_function };
-        compute_root_layout(&mut taffy_view, node_id, available_space);
+        self.update_query_container_nodes();
+        let mut taffy_view = TaffyView {
+            taffy: self,
+            measure_function,
+            #[cfg(feature = "parallel")]
+            deferred_layouts: None,
+        };
+        taffy_view
+            .compute_layout_with_container_queries(node_id, |view| compute_root_layout(view, node_id, available_space));
+
+        // Relayout boundaries that were marked as dirty without their ancestors being marked as dirty are not reached
+        // by laying out the root, so lay them out individually using the inputs they were last laid out with
+        for boundary in taffy_view.taffy.take_dirty_relayout_boundaries(node_id) {
+            if let Some(inputs) = taffy_view.taffy.nodes[boundary.into()].final_layout_inputs {
+                let output = taffy_view.compute_layout_with_container_queries(boundary, |view| {
+                    LayoutPartialTree::compute_child_layout(view, boundary, inputs)
+                });
+                taffy_view.taffy.finish_subtree_layout(boundary, output);
+            }
+        }
+
         if use_rounding {
             round_layout(&mut taffy_view, node_id);
         }
@@ -919,6 +1627,162 @@ impl<NodeContext> TaffyTree<NodeContext> {
         })
     }
 
+    /// Computes the min-content, max-content and fit-content contributions (including margins) of `node` in both axes,
+    /// without laying it out. See [`compute_intrinsic_sizes`] for details.
+    ///
+    /// The sizes are computed using (and stored in) the same cache as [`TaffyTree::compute_layout_with_measure`], so
+    /// querying the sizes of a node that has already been laid out is cheap, as is laying out a node whose sizes have
+    /// been queried.
+    pub fn intrinsic_sizes_with_measure<MeasureFunction>(
+        &mut self,
+        node_id: NodeId,
+        available_space: Size<AvailableSpace>,
+        measure_function: MeasureFunction,
+    ) -> TaffyResult<Size<IntrinsicSizes>>
+    where
+        MeasureFunction: FnMut(LayoutInput, NodeId, Option<&mut NodeContext>, &Style) -> LayoutOutput,
+    {
+        let mut taffy_view = TaffyView {
+            taffy: self,
+            measure_function,
+            #[cfg(feature = "parallel")]
+            deferred_layouts: None,
+        };
+        Ok(compute_intrinsic_sizes(&mut taffy_view, node_id, available_space, RequestedAxis::Both))
+    }
+
+    /// Computes the min-content, max-content and fit-content contributions (including margins) of `node` in both axes,
+    /// without laying it out. See [`TaffyTree::intrinsic_sizes_with_measure`].
+    pub fn intrinsic_sizes(
+        &mut self,
+        node: NodeId,
+        available_space: Size<AvailableSpace>,
+    ) -> TaffyResult<Size<IntrinsicSizes>> {
+        self.intrinsic_sizes_with_measure(node, available_space, |inputs, _, _, style| {
+            compute_leaf_layout(inputs, style, |_, _| 0.0, |_, _| Size::ZERO)
+        })
+    }
+
+    /// Computes the min-content, max-content and fit-content contributions (including margins) of `node` in `axis`,
+    /// given the space available in that axis. The space available in the other axis is treated as max-content.
+    ///
+    /// See [`TaffyTree::intrinsic_sizes_with_measure`].
+    pub fn intrinsic_size_with_measure<MeasureFunction>(
+        &mut self,
+        node_id: NodeId,
+        axis: AbsoluteAxis,
+        available_space: AvailableSpace,
+        measure_function: MeasureFunction,
+    ) -> TaffyResult<IntrinsicSizes>
+    where
+        MeasureFunction: FnMut(LayoutInput, NodeId, Option<&mut NodeContext>, &Style) -> LayoutOutput,
+    {
+        let available_space = match axis {
+            AbsoluteAxis::Horizontal => Size { width: available_space, height: AvailableSpace::MaxContent },
+            AbsoluteAxis::Vertical => Size { width: AvailableSpace::MaxContent, height: available_space },
+        };
+        let mut taffy_view = TaffyView {
+            taffy: self,
+            measure_function,
+            #[cfg(feature = "parallel")]
+            deferred_layouts: None,
+        };
+        Ok(compute_intrinsic_sizes(&mut taffy_view, node_id, available_space, axis.into()).get_abs(axis))
+    }
+
+    /// Computes the min-content, max-content and fit-content contributions (including margins) of `node` in `axis`,
+    /// given the space available in that axis. See [`TaffyTree::intrinsic_size_with_measure`].
+    pub fn intrinsic_size(
+        &mut self,
+        node: NodeId,
+        axis: AbsoluteAxis,
+        available_space: AvailableSpace,
+    ) -> TaffyResult<IntrinsicSizes> {
+        self.intrinsic_size_with_measure(node, axis, available_space, |inputs, _, _, style| {
+            compute_leaf_layout(inputs, style, |_, _| 0.0, |_, _| Size::ZERO)
+        })
+    }
+
+    /// Returns the inputs that the final layout of the node was last computed with, or `None` if the node has not been
+    /// laid out yet
+    ///
+    /// These are the constraints that the node's parent laid it out with (or, for the node that layout was computed
+    /// from, the constraints derived from the available space).
+    pub fn final_layout_inputs(&self, node: NodeId) -> Option<LayoutInput> {
+        self.nodes.get(node.into()).and_then(|node_data| node_data.final_layout_inputs)
+    }
+
+    /// Updates the stored layout of the provided `node` and its children, using the same inputs that the node was last
+    /// laid out with (see [`TaffyTree::final_layout_inputs`]) instead of laying out the tree from its root
+    ///
+    /// The layout of the node's ancestors (and the node's position within its parent) is not updated. Returns whether the
+    /// size of the node's border box changed, in which case its ancestors need to be laid out again (for example by
+    /// calling [`TaffyTree::compute_layout_with_measure`] on the root) for the tree's layout to be consistent.
+    ///
+    /// Returns [`TaffyError::InvalidInputNode`] if the node is not in the tree, or [`TaffyError::NodeNotLaidOut`] if the
+    /// node has not been laid out yet.
+    pub fn compute_layout_subtree_with_measure<MeasureFunction>(
+        &mut self,
+        node_id: NodeId,
+        measure_function: MeasureFunction,
+    ) -> TaffyResult<bool>
+    where
+        MeasureFunction: FnMut(LayoutInput, NodeId, Option<&mut NodeContext>, &Style) -> LayoutOutput,
+    {
+        let Some(node_data) = self.nodes.get(node_id.into()) else {
+            return Err(TaffyError::InvalidInputNode(node_id));
+        };
+        let Some(inputs) = node_data.final_layout_inputs else {
+            return Err(TaffyError::NodeNotLaidOut(node_id));
+        };
+        let previous_size = self.nodes[node_id.into()].unrounded_layout.size;
+
+        let use_rounding = self.config.use_rounding;
+        self.update_query_container_nodes();
+        let mut taffy_view = TaffyView {
+            taffy: self,
+            measure_function,
+            #[cfg(feature = "parallel")]
+            deferred_layouts: None,
+        };
+        let output = taffy_view.compute_layout_with_container_queries(node_id, |view| {
+            LayoutPartialTree::compute_child_layout(view, node_id, inputs)
+        });
+        taffy_view.taffy.finish_subtree_layout(node_id, output);
+
+        for boundary in taffy_view.taffy.take_dirty_relayout_boundaries(node_id) {
+            if let Some(inputs) = taffy_view.taffy.nodes[boundary.into()].final_layout_inputs {
+                let output = taffy_view.compute_layout_with_container_queries(boundary, |view| {
+                    LayoutPartialTree::compute_child_layout(view, boundary, inputs)
+                });
+                taffy_view.taffy.finish_subtree_layout(boundary, output);
+            }
+        }
+
+        if use_rounding {
+            // Rounding depends on the absolute position of each node, so start from the position of the node's parent
+            let mut parent_location = Point::ZERO;
+            let mut ancestor = taffy_view.taffy.parents[node_id.into()];
+            while let Some(ancestor_id) = ancestor {
+                parent_location =
+                    parent_location + taffy_view.taffy.nodes[ancestor_id.into()].unrounded_layout.location;
+                ancestor = taffy_view.taffy.parents[ancestor_id.into()];
+            }
+            round_subtree_layout(&mut taffy_view, node_id, parent_location);
+        }
+        Ok(output.size != previous_size)
+    }
+
+    /// Updates the stored layout of the provided `node` and its children, using the same inputs that the node was last
+    /// laid out with
+    ///
+    /// See [`TaffyTree::compute_layout_subtree_with_measure`]
+    pub fn compute_layout_subtree(&mut self, node: NodeId) -> TaffyResult<bool> {
+        self.compute_layout_subtree_with_measure(node, |inputs, _, _, style| {
+            compute_leaf_layout(inputs, style, |_, _| 0.0, |_, _| Size::ZERO)
+        })
+    }
+
     /// Prints a debug representation of the tree's layout
     #[cfg(feature = "std")]
     pub fn print_tree(&mut self, root: NodeId) {
@@ -931,10 +1795,338 @@ impl<NodeContext> TaffyTree<NodeContext> {
         TaffyView {
             taffy: self,
             measure_function: |inputs, _, _, style| compute_leaf_layout(inputs, style, |_, _| 0.0, |_, _| Size::ZERO),
+            #[cfg(feature = "parallel")]
+            deferred_layouts: None,
         }
     }
 }
 
+/// A measure function that can be shared between the threads performing parallel layout
+#[cfg(feature = "parallel")]
+type SyncMeasureFunction<'f, NodeContext> =
+    &'f (dyn Fn(LayoutInput, NodeId, Option<&mut NodeContext>, &Style) -> LayoutOutput + Sync);
+
+/// A request to perform the final layout of an independent subtree that was deferred during a layout pass
+#[cfg(feature = "parallel")]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DeferredLayout {
+    /// The root node of the subtree
+    node_id: NodeId,
+    /// The inputs that the subtree's parent requested the layout with
+    inputs: LayoutInput,
+    /// The output that was returned to the parent in place of actually performing layout
+    predicted_output: LayoutOutput,
+    /// Whether the layout was requested more than once with inputs that predicted different outputs
+    has_conflict: bool,
+}
+
+/// The final layouts of independent subtrees that were deferred during a layout pass.
+///
+/// A node is the root of an independent subtree if it has layout containment and its parent has determined its size
+/// in both axes. Its contents then cannot affect anything outside of it except through its baselines (which layout
+/// containment suppresses), its scrollable overflow (which layout containment prevents from propagating) and its
+/// margins (which don't collapse with its children as it establishes an independent formatting context). This allows
+/// its output to be predicted without laying out its children.
+#[cfg(feature = "parallel")]
+pub(crate) struct DeferredLayouts {
+    /// The node that the layout pass started at. Its own layout is never deferred.
+    root: NodeId,
+    /// The deferred layouts in the order that they were requested
+    entries: Vec<DeferredLayout>,
+}
+
+#[cfg(feature = "parallel")]
+impl DeferredLayouts {
+    /// Create an empty list of deferred layouts for a layout pass starting at `root`
+    fn new(root: NodeId) -> Self {
+        Self { root, entries: Vec::new() }
+    }
+
+    /// Defers the layout of `node_id` if it is the root of an independent subtree, returning the predicted output
+    /// of the layout. Returns `None` if the layout should be performed immediately.
+    fn try_defer<NodeContext>(
+        &mut self,
+        taffy: &TaffyTree<NodeContext>,
+        node_id: NodeId,
+        inputs: LayoutInput,
+    ) -> Option<LayoutOutput> {
+        let Size { width: Some(width), height: Some(height) } = inputs.known_dimensions else { return None };
+        if node_id == self.root || inputs.run_mode != RunMode::PerformLayout || taffy.child_count(node_id) == 0 {
+            return None;
+        }
+        let node = &taffy.nodes[node_id.into()];
+        if node.style.display == Display::None
+            || !node.style.contain().contains(Contain::LAYOUT)
+            || node.cache.get(&inputs).is_some()
+        {
+            return None;
+        }
+
+        // The node's scrollbar size, replaced content rect and marker rect depend on its contents. Its parent only copies
+        // them into the node's layout, so they aren't predicted (see `TaffyTree::reattach_subtree`).
+        //
+        // Block containers report their own (uncollapsed) vertical margins. Other layout modes don't support margin collapsing.
+        let resolve_margin = |margin: LengthPercentageAuto| {
+            CollapsibleMarginSet::from_margin(margin.resolve_or_zero(inputs.parent_size.width, |_, _| 0.0))
+        };
+        let (top_margin, bottom_margin) = match node.style.display.inside() {
+            #[cfg(feature = "block_layout")]
+            Display::Block | Display::FlowRoot => {
+                (resolve_margin(node.style.margin.top), resolve_margin(node.style.margin.bottom))
+            }
+            _ => (CollapsibleMarginSet::ZERO, CollapsibleMarginSet::ZERO),
+        };
+        let predicted_output =
+            LayoutOutput { top_margin, bottom_margin, ..LayoutOutput::from_outer_size(Size { width, height }) };
+
+        match self.entries.iter_mut().find(|entry| entry.node_id == node_id) {
+            Some(entry) => {
+                entry.has_conflict |= entry.predicted_output != predicted_output;
+                entry.inputs = inputs;
+                entry.predicted_output = predicted_output;
+            }
+            None => self.entries.push(DeferredLayout { node_id, inputs, predicted_output, has_conflict: false }),
+        }
+
+        Some(predicted_output)
+    }
+}
+
+/// An independent subtree that has been detached from its [`TaffyTree`] so that it can be laid out on another thread
+#[cfg(feature = "parallel")]
+struct DetachedSubtree<NodeContext> {
+    /// The deferred layout that is to be performed on the subtree
+    layout: DeferredLayout,
+    /// A standalone tree holding the subtree's nodes while it is detached
+    tree: TaffyTree<NodeContext>,
+    /// The id of the subtree's root node within `tree`
+    root: NodeId,
+    /// The id in the original tree of each node in `tree`
+    original_ids: SecondaryMap<DefaultKey, NodeId>,
+    /// The actual output of the subtree's layout
+    output: LayoutOutput,
+}
+
+// SAFETY: the only part of a detached subtree which isn't otherwise `Send` is the `calc()` pointers that may be stored
+// in its styles. `TaffyTree` never dereferences them, and they are only exposed as raw pointers, which can only be
+// dereferenced by `unsafe` code. Sending the pointer values to another thread is therefore sound for any pointer: it is
+// the `unsafe` code that dereferences a pointer on another thread (e.g. in a measure function) that must ensure that
+// doing so is thread-safe.
+#[cfg(feature = "parallel")]
+#[allow(unsafe_code)]
+unsafe impl<NodeContext: Send> Send for DetachedSubtree<NodeContext> {}
+
+#[cfg(feature = "parallel")]
+impl<NodeContext: Send> DetachedSubtree<NodeContext> {
+    /// Performs the deferred layout of the subtree
+    fn compute_layout(&mut self, measure_function: SyncMeasureFunction<'_, NodeContext>) {
+        let Self { layout, tree, root, original_ids, output } = self;
+        let (root, inputs) = (*root, layout.inputs);
+        // The measure function is called with node ids from the original tree
+        let measure_function =
+            |inputs: LayoutInput, node_id: NodeId, node_context: Option<&mut NodeContext>, style: &Style| {
+                measure_function(inputs, original_ids[node_id.into()], node_context, style)
+            };
+        tree.update_query_container_nodes();
+        *output = tree.compute_layout_deferring_independent_subtrees(root, &measure_function, |view| {
+            LayoutPartialTree::compute_child_layout(view, root, inputs)
+        });
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<NodeContext: Send> TaffyTree<NodeContext> {
+    /// Updates the stored layout of the provided `node` and its children, laying out independent subtrees in parallel
+    ///
+    /// A node is laid out independently of the rest of the tree if it has [`Contain::LAYOUT`] and its parent determines
+    /// its size in both axes (for example, an item with a fixed `size` or a grid item spanning fixed-size tracks).
+    /// The final layout of such subtrees is deferred until the rest of the tree has been laid out, and is then
+    /// performed concurrently on the [`rayon`] thread pool. Independent subtrees nested within a deferred subtree are
+    /// in turn laid out in parallel.
+    ///
+    /// The results are identical to those of [`TaffyTree::compute_layout_with_measure`]. If a deferred subtree's
+    /// layout doesn't match what its parent was told in advance then the affected ancestors are laid out again.
+    ///
+    /// Deferred subtrees are moved to other threads along with their styles, so the measure function may be given a
+    /// style containing `calc()` pointers on a thread other than the one that created them. Code that dereferences
+    /// them there must ensure that it is thread-safe to do so.
+    pub fn compute_layout_with_measure_parallel<MeasureFunction>(
+        &mut self,
+        node_id: NodeId,
+        available_space: Size<AvailableSpace>,
+        measure_function: MeasureFunction,
+    ) -> Result<(), TaffyError>
+    where
+        MeasureFunction: Fn(LayoutInput, NodeId, Option<&mut NodeContext>, &Style) -> LayoutOutput + Sync,
+    {
+        let use_rounding = self.config.use_rounding;
+        self.update_query_container_nodes();
+        self.compute_layout_deferring_independent_subtrees(node_id, &measure_function, |view| {
+            compute_root_layout(view, node_id, available_space)
+        });
+        for boundary in self.take_dirty_relayout_boundaries(node_id) {
+            if let Some(inputs) = self.nodes[boundary.into()].final_layout_inputs {
+                let output = self.compute_layout_deferring_independent_subtrees(boundary, &measure_function, |view| {
+                    LayoutPartialTree::compute_child_layout(view, boundary, inputs)
+                });
+                self.finish_subtree_layout(boundary, output);
+            }
+        }
+        if use_rounding {
+            let mut taffy_view = TaffyView { taffy: self, measure_function, deferred_layouts: None };
+            round_layout(&mut taffy_view, node_id);
+        }
+        Ok(())
+    }
+
+    /// Updates the stored layout of the provided `node` and its children, laying out independent subtrees in parallel
+    ///
+    /// See [`TaffyTree::compute_layout_with_measure_parallel`]
+    pub fn compute_layout_parallel(
+        &mut self,
+        node: NodeId,
+        available_space: Size<AvailableSpace>,
+    ) -> Result<(), TaffyError> {
+        self.compute_layout_with_measure_parallel(node, available_space, |inputs, _, _, style| {
+            compute_leaf_layout(inputs, style, |_, _| 0.0, |_, _| Size::ZERO)
+        })
+    }
+
+    /// Runs `layout` (which must start at `root`) with the final layout of independent subtrees deferred, and then
+    /// performs the deferred layouts in parallel. Falls back to running `layout` again if any deferred layout's output
+    /// was mispredicted.
+    fn compute_layout_deferring_independent_subtrees<R>(
+        &mut self,
+        root: NodeId,
+        measure_function: SyncMeasureFunction<'_, NodeContext>,
+        layout: impl Fn(&mut TaffyView<'_, NodeContext, SyncMeasureFunction<'_, NodeContext>>) -> R,
+    ) -> R {
+        let mut taffy_view =
+            TaffyView { taffy: self, measure_function, deferred_layouts: Some(DeferredLayouts::new(root)) };
+        let result = layout(&mut taffy_view);
+        let entries = taffy_view.deferred_layouts.take().map(|deferred_layouts| deferred_layouts.entries);
+
+        let mut mispredicted = Vec::new();
+        if let Some(entries) = entries.filter(|entries| !entries.is_empty()) {
+            let mut subtrees: Vec<_> = entries.into_iter().map(|layout| self.detach_subtree(layout)).collect();
+            subtrees.par_iter_mut().for_each(|subtree| subtree.compute_layout(measure_function));
+            mispredicted = subtrees.into_iter().filter_map(|subtree| self.reattach_subtree(subtree)).collect();
+        }
+        let has_changed_query_containers = self.clear_changed_query_container_ancestors(root);
+        if mispredicted.is_empty() && !has_changed_query_containers {
+            return result;
+        }
+
+        // Ancestors of a mispredicted subtree cached results that were computed from the wrong output. Clear them and
+        // lay out sequentially. The deferred subtrees themselves now have up to date cache entries. The same applies
+        // to the ancestors of query containers that changed the styles of their descendants.
+        for node_id in mispredicted {
+            let mut ancestor = self.parents[node_id.into()];
+            while let Some(node_id) = ancestor {
+                self.nodes[node_id.into()].cache.clear();
+                if node_id == root {
+                    break;
+                }
+                ancestor = self.parents[node_id.into()];
+            }
+        }
+        let mut taffy_view = TaffyView { taffy: self, measure_function, deferred_layouts: None };
+        layout(&mut taffy_view)
+    }
+
+    /// Moves the nodes of the subtree rooted at `layout.node_id` into a standalone tree
+    fn detach_subtree(&mut self, layout: DeferredLayout) -> DetachedSubtree<NodeContext> {
+        let mut tree = TaffyTree::new();
+        tree.config = self.config;
+        let mut original_ids = SecondaryMap::new();
+        let mut local_ids = SecondaryMap::new();
+        let mut root = layout.node_id;
+
+        let mut stack = vec![(layout.node_id, None)];
+        while let Some((node_id, parent)) = stack.pop() {
+            let key = node_id.into();
+            let node_data = core::mem::replace(&mut self.nodes[key], NodeData::new(Style::DEFAULT));
+            let local_key = tree.nodes.insert(node_data);
+            let _ = tree.children.insert(new_vec_with_capacity(self.children[key].len()));
+            let _ = tree.parents.insert(parent);
+            if let Some(context) = self.node_context_data.remove(key) {
+                tree.node_context_data.insert(local_key, context);
+            }
+            if let Some(context) = self.marker_context_data.remove(key) {
+                tree.marker_context_data.insert(local_key, context);
+            }
+            if let Some(container_query_styles) = self.container_query_styles.remove(key) {
+                tree.container_query_styles.insert(local_key, container_query_styles);
+            }
+            original_ids.insert(local_key, node_id);
+            local_ids.insert(key, NodeId::from(local_key));
+
+            let local_id = NodeId::from(local_key);
+            match parent {
+                Some(parent) => tree.children[parent.into()].push(local_id),
+                None => root = local_id,
+            }
+            stack.extend(self.children[key].iter().rev().map(|&child| (child, Some(local_id))));
+        }
+        // The children that each node lays out refer to its descendants, which now have new ids
+        for (_, node_data) in tree.nodes.iter_mut() {
+            remap_layout_children(node_data, &local_ids);
+        }
+
+        DetachedSubtree { layout, tree, root, original_ids, output: LayoutOutput::HIDDEN }
+    }
+
+    /// Moves the nodes of a detached subtree back into this tree. Returns the subtree's root if its parent was given
+    /// the wrong output for it.
+    fn reattach_subtree(&mut self, subtree: DetachedSubtree<NodeContext>) -> Option<NodeId> {
+        let DetachedSubtree { layout, mut tree, original_ids, output, .. } = subtree;
+        for (local_key, mut node_data) in tree.nodes.drain() {
+            remap_layout_children(&mut node_data, &original_ids);
+            self.nodes[original_ids[local_key].into()] = node_data;
+        }
+        for (local_key, context) in tree.node_context_data.drain() {
+            self.node_context_data.insert(original_ids[local_key].into(), context);
+        }
+        for (local_key, context) in tree.marker_context_data.drain() {
+            self.marker_context_data.insert(original_ids[local_key].into(), context);
+        }
+        for (local_key, container_query_styles) in tree.container_query_styles.drain() {
+            self.container_query_styles.insert(original_ids[local_key].into(), container_query_styles);
+        }
+
+        // The parent copied the predicted scrollbar size, replaced content rect, marker rect and scrollable overflow
+        // into the node's layout, and ignores them otherwise (layout containment stops the scrollable overflow from
+        // propagating), so they aren't part of the prediction.
+        let node_layout = &mut self.nodes[layout.node_id.into()].unrounded_layout;
+        node_layout.scrollbar_size = output.scrollbar_size;
+        node_layout.replaced_content_rect = output.replaced_content_rect;
+        node_layout.marker_rect = output.marker_rect;
+        #[allow(unused_mut)]
+        let mut predicted_output = LayoutOutput {
+            scrollbar_size: output.scrollbar_size,
+            replaced_content_rect: output.replaced_content_rect,
+            marker_rect: output.marker_rect,
+            ..layout.predicted_output
+        };
+        #[cfg(feature = "content_size")]
+        {
+            node_layout.scrollable_overflow_rect = output.scrollable_overflow_rect;
+            predicted_output.scrollable_overflow_rect = output.scrollable_overflow_rect;
+        }
+
+        (layout.has_conflict || predicted_output != output).then_some(layout.node_id)
+    }
+}
+
+/// Replaces the ids of the children that a node lays out (if it splices in the children of `Display::Contents`
+/// children) with the ids that they are mapped to by `ids`
+#[cfg(feature = "parallel")]
+fn remap_layout_children(node_data: &mut NodeData, ids: &SecondaryMap<DefaultKey, NodeId>) {
+    for child in node_data.layout_children.iter_mut().flatten() {
+        *child = ids[(*child).into()];
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -1428,4 +2620,37 @@ mod tests {
 
         assert!(taffy.children(old_parent).unwrap().is_empty());
     }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn scroll_container_panels_are_laid_out_in_parallel() {
+        use crate::style::Overflow;
+
+        let mut taffy: TaffyTree<()> = TaffyTree::new();
+        let content = taffy.new_leaf(Style { size: Size::from_lengths(50.0, 500.0), ..Default::default() }).unwrap();
+        let panel_style = Style {
+            display: Display::Block,
+            contain: Contain::LAYOUT,
+            overflow: Point { x: Overflow::Visible, y: Overflow::Auto },
+            scrollbar_width: 10.0,
+            size: Size::from_lengths(100.0, 100.0),
+            ..Default::default()
+        };
+        let panel = taffy.new_with_children(panel_style, &[content]).unwrap();
+        let root = taffy.new_with_children(Style::default(), &[panel]).unwrap();
+
+        let measure_function: SyncMeasureFunction<'_, ()> =
+            &|inputs, _, _, style| compute_leaf_layout(inputs, style, |_, _| 0.0, |_, _| Size::ZERO);
+        let mut taffy_view =
+            TaffyView { taffy: &mut taffy, measure_function, deferred_layouts: Some(DeferredLayouts::new(root)) };
+        compute_root_layout(&mut taffy_view, root, Size::MAX_CONTENT);
+        let entries = taffy_view.deferred_layouts.take().unwrap().entries;
+        assert_eq!(entries.iter().map(|entry| entry.node_id).collect::<Vec<_>>(), [panel]);
+
+        // The panel's scrollbar depends on its contents, but doesn't make its parent lay it out again
+        let mut subtree = taffy.detach_subtree(entries[0]);
+        subtree.compute_layout(measure_function);
+        assert_eq!(taffy.reattach_subtree(subtree), None);
+        assert_eq!(taffy.unrounded_layout(panel).scrollbar_size, Size { width: 10.0, height: 0.0 });
+    }
 }
@@ -129,7 +129,7 @@
 use super::{Layout, LayoutInput, LayoutOutput, NodeId, RequestedAxis, RunMode, SizingMode};
 #[cfg(feature = "detailed_layout_info")]
 use crate::debug::debug_log;
-use crate::geometry::{AbsoluteAxis, Line, Size};
+use crate::geometry::{AbsoluteAxis, Line, Rect, Size};
 use crate::style::{AvailableSpace, CoreStyle};
 #[cfg(feature = "flexbox")]
 use crate::style::{FlexboxContainerStyle, FlexboxItemStyle};
@@ -152,6 +152,12 @@ pub trait TraversePartialTree {
         Self: 'a;
 
     /// Get the list of children IDs for the given node
+    ///
+    /// These are the children that the layout algorithms lay out. Children with [`BoxGenerationMode::Contents`]
+    /// are not flattened by the algorithms, so trees that support that mode should return their children in their
+    /// place instead.
+    ///
+    /// [`BoxGenerationMode::Contents`]: crate::BoxGenerationMode::Contents
     fn child_ids(&self, parent_node_id: NodeId) -> Self::ChildIter<'_>;
 
     /// Get the number of children for the given node
@@ -198,6 +204,38 @@ pub trait LayoutPartialTree: TraversePartialTree {
 
     /// Compute the specified node's size or full layout given the specified constraints
     fn compute_child_layout(&mut self, node_id: NodeId, inputs: LayoutInput) -> LayoutOutput;
+
+    /// Find the border box of the anchor named `anchor_name` for the absolutely positioned child `node_id` of
+    /// `containing_block_id`. The returned rect contains the positions of the anchor's edges relative to the
+    /// border box of `containing_block_id`.
+    ///
+    /// This is called while `containing_block_id` positions its absolutely positioned children, by which point
+    /// the layouts of its in-flow descendants have been set. The default implementation does not support anchors
+    /// and returns `None`, which causes `anchor()` and `anchor-size()` values to behave as `auto`.
+    ///
+    /// The implementation for [`TaffyTree`](crate::TaffyTree) only searches the subtree of `containing_block_id`, so
+    /// anchors outside of the containing block are never found, even where CSS would consider them acceptable.
+    #[inline(always)]
+    fn resolve_anchor(
+        &self,
+        containing_block_id: NodeId,
+        node_id: NodeId,
+        anchor_name: &Self::CustomIdent,
+    ) -> Option<Rect<f32>> {
+        let _ = (containing_block_id, node_id, anchor_name);
+        None
+    }
+
+    /// The size of the node's content box the last time it was laid out, in each axis in which its
+    /// `contain_intrinsic_size` style uses the `auto` keyword. A node with size containment is given this size in
+    /// place of the length in its `contain_intrinsic_size` style.
+    ///
+    /// The default implementation does not remember sizes and returns `Size::NONE`.
+    #[inline(always)]
+    fn last_remembered_size(&self, node_id: NodeId) -> Size<Option<f32>> {
+        let _ = node_id;
+        Size::NONE
+    }
 }
 
 /// Trait used by the `compute_cached_layout` method which allows cached layout results to be stored and retrieved.
@@ -312,6 +350,14 @@ pub trait LayoutBlockContainer: LayoutPartialTree {
         let _ = block_ctx;
         self.compute_child_layout(node_id, inputs)
     }
+
+    /// Measure the `::marker` box of the specified `Display::ListItem` node, or return `None` if it has no marker.
+    /// Only the size and first baseline of the returned output are used.
+    #[cfg(feature = "block_layout")]
+    fn compute_marker_layout(&mut self, node_id: NodeId, inputs: LayoutInput) -> Option<LayoutOutput> {
+        let _ = (node_id, inputs);
+        None
+    }
 }
 
 // --- PRIVATE TRAITS
@@ -11,7 +11,7 @@ pub(crate) type CssParseError<'i> = cssparser::ParseError<'i, Cow<'i, str>>;
 pub(crate) type CssParseResult<'i, T> = Result<T, CssParseError<'i>>;
 
 /// Error type for parsing a type from string
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ParseError(String);
 
 impl core::fmt::Display for ParseError {
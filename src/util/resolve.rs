@@ -45,6 +45,8 @@ impl MaybeResolve<Option<f32>, Option<f32>> for LengthPercentageAuto {
     fn maybe_resolve(self, context: Option<f32>, calc: impl Fn(*const (), f32) -> f32) -> Option<f32> {
         match self.0.tag() {
             CompactLength::AUTO_TAG => None,
+            // Anchor positions are resolved by the absolute positioning code. Elsewhere they behave as auto.
+            CompactLength::ANCHOR_TAG | CompactLength::ANCHOR_PERCENT_TAG => None,
             CompactLength::LENGTH_TAG => Some(self.0.value()),
             CompactLength::PERCENT_TAG => context.map(|dim| dim * self.0.value()),
             #[cfg(feature = "calc")]
@@ -63,6 +65,8 @@ impl MaybeResolve<Option<f32>, Option<f32>> for Dimension {
             CompactLength::AUTO_TAG => None,
             // The content keyword is only valid for flex-basis. In any other context it behaves as auto.
             CompactLength::CONTENT_TAG => None,
+            // Anchor sizes are resolved by the absolute positioning code. Elsewhere they behave as auto.
+            CompactLength::ANCHOR_SIZE_TAG => None,
             CompactLength::LENGTH_TAG => Some(self.0.value()),
             CompactLength::PERCENT_TAG => context.map(|dim| dim * self.0.value()),
             #[cfg(feature = "calc")]
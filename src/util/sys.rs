@@ -29,6 +29,10 @@ mod std {
 
     /// A string
     pub(crate) type String = std::string::String;
+
+    /// A pointer type for heap allocation
+    pub(crate) type Box<T> = std::boxed::Box<T>;
+
     /// The default type for representing strings in Taffy styles
     pub(crate) type DefaultCheapStr = String;
     /// A map
@@ -108,6 +112,10 @@ mod alloc {
 
     /// A string
     pub(crate) type String = alloc::string::String;
+
+    /// A pointer type for heap allocation
+    pub(crate) type Box<T> = alloc::boxed::Box<T>;
+
     /// The default type for representing strings in Taffy styles
     pub(crate) type DefaultCheapStr = String;
     /// A map
@@ -1,20 +1,41 @@
 mod hand_written {
     mod adversarial_styles;
+    mod anchor_positioning;
+    mod aspect_ratio;
     mod baseline;
+    mod block_justify_self;
     mod block_replaced;
     mod border_and_padding;
     mod caching;
+    mod container_queries;
+    mod content_visibility;
     mod detailed_grid_info;
+    mod display_contents;
     #[cfg(feature = "flexbox_balance")]
     mod flex_line_count;
     mod floats;
+    mod inline_level;
+    mod intrinsic_size;
+    mod lint;
+    mod list_item;
     mod measure;
     mod min_max_overrides;
+    mod object_fit;
+    mod overflow_auto;
+    #[cfg(feature = "parallel")]
+    mod parallel_layout;
+    mod percentage_gaps;
     mod relayout;
+    mod replaced_content;
     mod root_constraints;
     mod rounding;
+    mod ruby;
     mod safe_alignment;
     mod scroll_size;
     mod scrollable_overflow;
+    mod scrollbar_gutter;
     mod serde;
+    mod size_containment;
+    mod style_validation;
+    mod transform;
 }
@@ -0,0 +1,201 @@
+//! Absolutely positioned nodes can position and size themselves relative to an anchor node using `anchor()` insets
+//! and `anchor-size()` sizes, and can flip to the other side of their anchor if they would overflow.
+
+use taffy::geometry::Point;
+use taffy::prelude::*;
+use taffy::style::{AnchorSide, AnchorSize, Position, PositionTryFallback, PositionTryFallbacks};
+use taffy_test_helpers::new_test_tree;
+
+const DISPLAYS: [Display; 3] = [Display::Block, Display::Flex, Display::Grid];
+
+/// A 50x20 anchor named `--a` whose border box starts at (30, `top`) within its 200x200 container
+fn anchor_style(top: f32) -> Style {
+    Style {
+        anchor_name: Some("--a".into()),
+        size: Size { width: length(50.0), height: length(20.0) },
+        margin: Rect { left: length(30.0), right: zero(), top: length(top), bottom: zero() },
+        flex_shrink: 0.0,
+        ..Default::default()
+    }
+}
+
+/// Lays out a 200x200 container holding an anchor and an absolutely positioned child, and returns the layout of
+/// the absolutely positioned child
+fn layout_positioned(display: Display, anchor: Style, positioned: Style) -> Layout {
+    let mut tree = new_test_tree();
+    let anchor = tree.new_leaf(anchor).unwrap();
+    let positioned = tree.new_leaf(Style { position: Position::Absolute, ..positioned }).unwrap();
+    let container = tree
+        .new_with_children(
+            Style { display, size: Size { width: length(200.0), height: length(200.0) }, ..Default::default() },
+            &[anchor, positioned],
+        )
+        .unwrap();
+
+    tree.compute_layout(container, Size::MAX_CONTENT).unwrap();
+    *tree.layout(positioned).unwrap()
+}
+
+/// A 10px tall node placed below its anchor, left-aligned with it and as wide as it
+fn below_anchor_style() -> Style {
+    Style {
+        position_anchor: Some("--a".into()),
+        inset: Rect {
+            left: LengthPercentageAuto::anchor(AnchorSide::Left),
+            right: auto(),
+            top: LengthPercentageAuto::anchor(AnchorSide::Bottom),
+            bottom: auto(),
+        },
+        size: Size { width: Dimension::anchor_size(AnchorSize::Width), height: length(10.0) },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn anchor_insets_and_sizes_resolve_against_the_anchor() {
+    for display in DISPLAYS {
+        let layout = layout_positioned(display, anchor_style(10.0), below_anchor_style());
+        assert_eq!(layout.location, Point { x: 30.0, y: 30.0 }, "{display:?}");
+        assert_eq!(layout.size, Size { width: 50.0, height: 10.0 }, "{display:?}");
+    }
+}
+
+#[test]
+fn anchor_on_the_end_side_is_measured_from_the_end_edge() {
+    let positioned = Style {
+        position_anchor: Some("--a".into()),
+        inset: Rect {
+            left: auto(),
+            right: LengthPercentageAuto::anchor(AnchorSide::Left),
+            top: auto(),
+            bottom: LengthPercentageAuto::anchor(AnchorSide::Top),
+        },
+        size: Size { width: length(20.0), height: Dimension::anchor_size(AnchorSize::Height) },
+        ..Default::default()
+    };
+    for display in DISPLAYS {
+        let layout = layout_positioned(display, anchor_style(50.0), positioned.clone());
+        assert_eq!(layout.location, Point { x: 10.0, y: 30.0 }, "{display:?}");
+        assert_eq!(layout.size, Size { width: 20.0, height: 20.0 }, "{display:?}");
+    }
+}
+
+#[test]
+fn flip_block_fallback_is_used_when_the_base_position_overflows() {
+    let positioned = Style {
+        position_try_fallbacks: PositionTryFallbacks::new(&[PositionTryFallback::FlipBlock]),
+        size: Size { width: Dimension::anchor_size(AnchorSize::Width), height: length(30.0) },
+        ..below_anchor_style()
+    };
+    for display in DISPLAYS {
+        // The anchor spans y = 170..190, so the node does not fit below it and is flipped above it
+        let layout = layout_positioned(display, anchor_style(170.0), positioned.clone());
+        assert_eq!(layout.location, Point { x: 30.0, y: 140.0 }, "{display:?}");
+
+        // The node fits below an anchor near the top of the container, so the fallback is not used
+        let layout = layout_positioned(display, anchor_style(10.0), positioned.clone());
+        assert_eq!(layout.location, Point { x: 30.0, y: 30.0 }, "{display:?}");
+    }
+}
+
+#[test]
+fn base_position_is_used_when_no_fallback_fits() {
+    let positioned = Style {
+        position_try_fallbacks: PositionTryFallbacks::new(&[PositionTryFallback::FlipBlock]),
+        size: Size { width: Dimension::anchor_size(AnchorSize::Width), height: length(180.0) },
+        ..below_anchor_style()
+    };
+    for display in DISPLAYS {
+        let layout = layout_positioned(display, anchor_style(10.0), positioned.clone());
+        assert_eq!(layout.location, Point { x: 30.0, y: 30.0 }, "{display:?}");
+    }
+}
+
+#[test]
+fn unresolved_anchor_values_behave_as_auto() {
+    let auto_style = Style {
+        size: Size { width: auto(), height: length(10.0) },
+        inset: Rect { left: auto(), right: auto(), top: auto(), bottom: auto() },
+        ..Default::default()
+    };
+    for display in DISPLAYS {
+        let expected = layout_positioned(display, anchor_style(10.0), auto_style.clone());
+
+        let missing_anchor = Style { position_anchor: Some("--missing".into()), ..below_anchor_style() };
+        assert_eq!(layout_positioned(display, anchor_style(10.0), missing_anchor), expected, "{display:?}");
+
+        let no_position_anchor = Style { position_anchor: None, ..below_anchor_style() };
+        assert_eq!(layout_positioned(display, anchor_style(10.0), no_position_anchor), expected, "{display:?}");
+    }
+}
+
+#[test]
+fn anchor_can_be_a_nested_descendant_of_the_containing_block() {
+    for display in DISPLAYS {
+        let mut tree = new_test_tree();
+        let anchor = tree.new_leaf(anchor_style(10.0)).unwrap();
+        let wrapper = tree
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    padding: Rect { left: length(5.0), right: zero(), top: length(15.0), bottom: zero() },
+                    flex_grow: 1.0,
+                    ..Default::default()
+                },
+                &[anchor],
+            )
+            .unwrap();
+        let positioned = tree.new_leaf(Style { position: Position::Absolute, ..below_anchor_style() }).unwrap();
+        let container = tree
+            .new_with_children(
+                Style { display, size: Size { width: length(200.0), height: length(200.0) }, ..Default::default() },
+                &[wrapper, positioned],
+            )
+            .unwrap();
+
+        tree.compute_layout(container, Size::MAX_CONTENT).unwrap();
+        let layout = tree.layout(positioned).unwrap();
+        assert_eq!(layout.location, Point { x: 35.0, y: 45.0 }, "{display:?}");
+        assert_eq!(layout.size, Size { width: 50.0, height: 10.0 }, "{display:?}");
+    }
+}
+
+#[test]
+fn anchor_size_excludes_padding_and_border_of_content_box_nodes() {
+    let positioned = Style {
+        box_sizing: BoxSizing::ContentBox,
+        padding: Rect { left: length(4.0), right: length(6.0), top: zero(), bottom: zero() },
+        border: Rect { left: length(1.0), right: length(1.0), top: zero(), bottom: zero() },
+        ..below_anchor_style()
+    };
+    for display in DISPLAYS {
+        // The border box is as wide as the anchor's border box
+        let layout = layout_positioned(display, anchor_style(10.0), positioned.clone());
+        assert_eq!(layout.size, Size { width: 50.0, height: 10.0 }, "{display:?}");
+
+        let border_box = Style { box_sizing: BoxSizing::BorderBox, ..positioned.clone() };
+        let layout = layout_positioned(display, anchor_style(10.0), border_box);
+        assert_eq!(layout.size, Size { width: 50.0, height: 10.0 }, "{display:?}");
+    }
+}
+
+#[test]
+fn anchors_outside_of_the_containing_block_are_ignored() {
+    for display in DISPLAYS {
+        let mut tree = new_test_tree();
+        let anchor = tree.new_leaf(anchor_style(10.0)).unwrap();
+        let positioned = tree.new_leaf(Style { position: Position::Absolute, ..below_anchor_style() }).unwrap();
+        let containing_block = tree
+            .new_with_children(
+                Style { display, size: Size { width: length(100.0), height: length(100.0) }, ..Default::default() },
+                &[positioned],
+            )
+            .unwrap();
+        let root = tree.new_with_children(Style::default(), &[anchor, containing_block]).unwrap();
+
+        tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        let layout = tree.layout(positioned).unwrap();
+        assert_eq!(layout.location, Point { x: 0.0, y: 0.0 }, "{display:?}");
+        assert_eq!(layout.size, Size { width: 0.0, height: 10.0 }, "{display:?}");
+    }
+}
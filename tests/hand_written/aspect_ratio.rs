@@ -0,0 +1,92 @@
+//! A preferred aspect ratio applies to the box given by `box_sizing`, or to the content box when it is `auto <ratio>`,
+//! and the natural aspect ratio of a leaf's content is preferred over the given ratio when it is `auto`.
+
+use taffy::geometry::Point;
+use taffy::prelude::*;
+use taffy::tree::{Baselines, LayoutInput, RequestedAxis, RunMode, SizingMode};
+use taffy::{compute_leaf_layout_with_natural_aspect_ratio, AspectRatio};
+
+/// Lays out a 100px wide childless child of a container with the given display mode, and returns the child's size
+fn child_size(display: Display, child: Style) -> Size<f32> {
+    let mut tree: TaffyTree<()> = TaffyTree::new();
+    let child = tree.new_leaf(Style { size: Size { width: length(100.0), height: auto() }, ..child }).unwrap();
+    let container = tree
+        .new_with_children(
+            Style { display, size: Size { width: length(200.0), height: auto() }, ..Default::default() },
+            &[child],
+        )
+        .unwrap();
+    tree.compute_layout(container, Size::MAX_CONTENT).unwrap();
+    tree.layout(child).unwrap().size
+}
+
+#[test]
+fn ratio_applies_to_the_box_sizing_box_or_content_box() {
+    let padding = Rect { left: length(10.0), right: length(10.0), top: length(10.0), bottom: length(10.0) };
+    for display in [Display::Block, Display::Flex, Display::Grid] {
+        let border_box = Style { aspect_ratio: AspectRatio::ratio(2.0), padding, ..Default::default() };
+        assert_eq!(child_size(display, border_box.clone()), Size { width: 100.0, height: 50.0 }, "{display:?}");
+
+        let content_box = Style { box_sizing: BoxSizing::ContentBox, ..border_box.clone() };
+        assert_eq!(child_size(display, content_box), Size { width: 120.0, height: 70.0 }, "{display:?}");
+
+        // An `auto <ratio>` always applies to the content box, which here is 80px wide
+        let auto_ratio = Style { aspect_ratio: AspectRatio::auto_or_ratio(2.0), ..border_box };
+        assert_eq!(child_size(display, auto_ratio), Size { width: 100.0, height: 60.0 }, "{display:?}");
+    }
+}
+
+#[test]
+fn size_is_clamped_before_it_is_transferred() {
+    for display in [Display::Block, Display::Flex, Display::Grid] {
+        let style = Style {
+            aspect_ratio: AspectRatio::ratio(1.0),
+            max_size: Size { width: length(50.0), height: auto() },
+            ..Default::default()
+        };
+        assert_eq!(child_size(display, style), Size { width: 50.0, height: 50.0 }, "{display:?}");
+
+        let style = Style {
+            aspect_ratio: AspectRatio::ratio(1.0),
+            min_size: Size { width: length(150.0), height: auto() },
+            ..Default::default()
+        };
+        assert_eq!(child_size(display, style), Size { width: 150.0, height: 150.0 }, "{display:?}");
+    }
+}
+
+/// Lays out a 120px wide leaf, whose content has the given natural aspect ratio and is 10px tall
+fn leaf_size(aspect_ratio: AspectRatio, natural_aspect_ratio: Option<f32>) -> Size<f32> {
+    let style: Style =
+        Style { aspect_ratio, size: Size { width: length(120.0), height: auto() }, ..Default::default() };
+    let inputs = LayoutInput {
+        run_mode: RunMode::PerformLayout,
+        sizing_mode: SizingMode::InherentSize,
+        axis: RequestedAxis::Both,
+        known_dimensions: Size::NONE,
+        known_dimensions_are_definite: Size { width: true, height: true },
+        parent_size: Size::NONE,
+        available_space: Size::MAX_CONTENT,
+        vertical_margins_are_collapsible: Line::FALSE,
+    };
+    let output = compute_leaf_layout_with_natural_aspect_ratio(
+        inputs,
+        &style,
+        natural_aspect_ratio,
+        |_, _| 0.0,
+        |known_dimensions, _| {
+            let size = Size { width: known_dimensions.width.unwrap_or(120.0), height: 10.0 };
+            (size, Point { x: Baselines::NONE, y: Baselines::NONE })
+        },
+    );
+    output.size
+}
+
+#[test]
+fn natural_aspect_ratio_is_preferred_when_auto() {
+    assert_eq!(leaf_size(AspectRatio::AUTO, None), Size { width: 120.0, height: 10.0 });
+    assert_eq!(leaf_size(AspectRatio::AUTO, Some(2.0)), Size { width: 120.0, height: 60.0 });
+    assert_eq!(leaf_size(AspectRatio::auto_or_ratio(3.0), None), Size { width: 120.0, height: 40.0 });
+    assert_eq!(leaf_size(AspectRatio::auto_or_ratio(3.0), Some(2.0)), Size { width: 120.0, height: 60.0 });
+    assert_eq!(leaf_size(AspectRatio::ratio(3.0), Some(2.0)), Size { width: 120.0, height: 40.0 });
+}
@@ -1,14 +1,32 @@
 #[cfg(test)]
 mod baseline {
     use taffy::prelude::*;
-    use taffy::{Baselines, LayoutInput, LayoutOutput, Overflow, Point};
+    use taffy::{compute_leaf_layout_with_baselines, Baselines, LayoutInput, LayoutOutput, Overflow, Point};
 
-    /// A node context that pairs an intrinsic size with a first-baseline offset
+    /// A node context that pairs an intrinsic size with first and last baseline offsets
     /// (measured from the node's top edge in the block axis).
     #[derive(Debug, Clone, Copy)]
     struct BaselineContext {
         size: Size<f32>,
         baseline_y: Option<f32>,
+        last_baseline_y: Option<f32>,
+    }
+
+    impl BaselineContext {
+        /// A single line of content, whose first and last baselines coincide
+        fn single_line(width: f32, height: f32, baseline_y: Option<f32>) -> Self {
+            Self { size: Size { width, height }, baseline_y, last_baseline_y: baseline_y }
+        }
+
+        /// Multiple lines of content with distinct first and last baselines
+        fn multi_line(width: f32, height: f32, baseline_y: f32, last_baseline_y: f32) -> Self {
+            Self { size: Size { width, height }, baseline_y: Some(baseline_y), last_baseline_y: Some(last_baseline_y) }
+        }
+
+        /// The horizontal baselines of the content
+        fn baselines(&self) -> Point<Baselines> {
+            Point { x: Baselines::NONE, y: Baselines { first: self.baseline_y, last: self.last_baseline_y } }
+        }
     }
 
     fn baseline_measure_function(
@@ -18,7 +36,43 @@ mod baseline {
         _style: &Style,
     ) -> LayoutOutput {
         let Some(context) = context else { return LayoutOutput::DEFAULT };
-        LayoutOutput::from_sizes_and_baselines(context.size, Rect::ZERO, Baselines::from_first(context.baseline_y))
+        LayoutOutput::from_sizes_and_baselines(context.size, Rect::ZERO, context.baselines())
+    }
+
+    /// Like `baseline_measure_function`, but treats the context as the leaf's content, so the leaf's own styles
+    /// (such as its padding and border) are applied to it
+    fn leaf_baseline_measure_function(
+        inputs: LayoutInput,
+        _node_id: NodeId,
+        context: Option<&mut BaselineContext>,
+        style: &Style,
+    ) -> LayoutOutput {
+        compute_leaf_layout_with_baselines(
+            inputs,
+            style,
+            |_, _| 0.0,
+            |_, _| match context {
+                Some(context) => (context.size, context.baselines()),
+                None => (Size::ZERO, Point { x: Baselines::NONE, y: Baselines::NONE }),
+            },
+        )
+    }
+
+    /// Lays out `items` in a 100px tall flex row with the specified `align-items`
+    fn layout_flex_row(taffy: &mut TaffyTree<BaselineContext>, align_items: AlignItems, items: &[NodeId]) {
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Flex,
+                    flex_direction: FlexDirection::Row,
+                    align_items: Some(align_items),
+                    size: Size { width: length(200.0), height: length(100.0) },
+                    ..Default::default()
+                },
+                items,
+            )
+            .unwrap();
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, baseline_measure_function).unwrap();
     }
 
     /// Two flex items with different intrinsic baselines are aligned along their baselines
@@ -30,18 +84,12 @@ mod baseline {
 
         // Child A: 50x50 box with baseline 40px from the top
         let child_a = taffy
-            .new_leaf_with_context(
-                Style::default(),
-                BaselineContext { size: Size { width: 50.0, height: 50.0 }, baseline_y: Some(40.0) },
-            )
+            .new_leaf_with_context(Style::default(), BaselineContext::single_line(50.0, 50.0, Some(40.0)))
             .unwrap();
 
         // Child B: 30x30 box with baseline 20px from the top
         let child_b = taffy
-            .new_leaf_with_context(
-                Style::default(),
-                BaselineContext { size: Size { width: 30.0, height: 30.0 }, baseline_y: Some(20.0) },
-            )
+            .new_leaf_with_context(Style::default(), BaselineContext::single_line(30.0, 30.0, Some(20.0)))
             .unwrap();
 
         let root = taffy
@@ -88,7 +136,7 @@ mod baseline {
                     size: Size { width: length(30.0), height: length(30.0) },
                     ..Default::default()
                 },
-                BaselineContext { size: Size { width: 30.0, height: 30.0 }, baseline_y: None },
+                BaselineContext::single_line(30.0, 30.0, None),
             )
             .unwrap();
 
@@ -108,10 +156,7 @@ mod baseline {
 
         // Reference item: 40x80 box with baseline 10px from the top
         let item_b = taffy
-            .new_leaf_with_context(
-                Style::default(),
-                BaselineContext { size: Size { width: 40.0, height: 80.0 }, baseline_y: Some(10.0) },
-            )
+            .new_leaf_with_context(Style::default(), BaselineContext::single_line(40.0, 80.0, Some(10.0)))
             .unwrap();
 
         let root = taffy
@@ -150,7 +195,7 @@ mod baseline {
                     size: Size { width: length(30.0), height: length(30.0) },
                     ..Default::default()
                 },
-                BaselineContext { size: Size { width: 30.0, height: 30.0 }, baseline_y: None },
+                BaselineContext::single_line(30.0, 30.0, None),
             )
             .unwrap();
 
@@ -168,10 +213,7 @@ mod baseline {
 
         // Reference item: 40x80 box with baseline 10px from the top
         let item_b = taffy
-            .new_leaf_with_context(
-                Style::default(),
-                BaselineContext { size: Size { width: 40.0, height: 80.0 }, baseline_y: Some(10.0) },
-            )
+            .new_leaf_with_context(Style::default(), BaselineContext::single_line(40.0, 80.0, Some(10.0)))
             .unwrap();
 
         let root = taffy
@@ -207,7 +249,7 @@ mod baseline {
         let content = taffy
             .new_leaf_with_context(
                 Style { display: Display::Block, ..Default::default() },
-                BaselineContext { size: Size { width: 30.0, height: 60.0 }, baseline_y: Some(48.0) },
+                BaselineContext::single_line(30.0, 60.0, Some(48.0)),
             )
             .unwrap();
 
@@ -237,10 +279,7 @@ mod baseline {
 
         // Reference item: 40x80 box with baseline 10px from the top
         let item_b = taffy
-            .new_leaf_with_context(
-                Style::default(),
-                BaselineContext { size: Size { width: 40.0, height: 80.0 }, baseline_y: Some(10.0) },
-            )
+            .new_leaf_with_context(Style::default(), BaselineContext::single_line(40.0, 80.0, Some(10.0)))
             .unwrap();
 
         let root = taffy
@@ -271,16 +310,10 @@ mod baseline {
         let mut taffy: TaffyTree<BaselineContext> = TaffyTree::new();
 
         let child_a = taffy
-            .new_leaf_with_context(
-                Style::default(),
-                BaselineContext { size: Size { width: 50.0, height: 50.0 }, baseline_y: Some(40.0) },
-            )
+            .new_leaf_with_context(Style::default(), BaselineContext::single_line(50.0, 50.0, Some(40.0)))
             .unwrap();
         let child_b = taffy
-            .new_leaf_with_context(
-                Style::default(),
-                BaselineContext { size: Size { width: 30.0, height: 30.0 }, baseline_y: Some(20.0) },
-            )
+            .new_leaf_with_context(Style::default(), BaselineContext::single_line(30.0, 30.0, Some(20.0)))
             .unwrap();
 
         let root = taffy
@@ -301,4 +334,178 @@ mod baseline {
         assert_eq!(taffy.layout(child_a).unwrap().location.y, 0.0);
         assert_eq!(taffy.layout(child_b).unwrap().location.y, 0.0);
     }
+
+    /// With `align-items: last baseline`, items are aligned along their last baselines, and the
+    /// baseline-sharing group is aligned to the cross-end edge of the line.
+    #[test]
+    fn flex_last_baseline_alignment() {
+        let mut taffy: TaffyTree<BaselineContext> = TaffyTree::new();
+        let child_a =
+            taffy.new_leaf_with_context(Style::default(), BaselineContext::multi_line(50.0, 50.0, 10.0, 40.0)).unwrap();
+        let child_b =
+            taffy.new_leaf_with_context(Style::default(), BaselineContext::multi_line(30.0, 30.0, 5.0, 25.0)).unwrap();
+        layout_flex_row(&mut taffy, AlignItems::LAST_BASELINE, &[child_a, child_b]);
+
+        // Child A has the most space below its last baseline (10px), so it sits at the bottom of the
+        // 100px line and the shared last baseline is at y = 90.
+        assert_eq!(taffy.layout(child_a).unwrap().location.y, 50.0);
+        assert_eq!(taffy.layout(child_b).unwrap().location.y, 65.0);
+    }
+
+    /// Grid items with `align-self: last baseline` are aligned along their last baselines at the
+    /// end of their grid area.
+    #[test]
+    fn grid_last_baseline_alignment() {
+        let mut taffy: TaffyTree<BaselineContext> = TaffyTree::new();
+        let child_a =
+            taffy.new_leaf_with_context(Style::default(), BaselineContext::multi_line(50.0, 50.0, 10.0, 40.0)).unwrap();
+        let child_b =
+            taffy.new_leaf_with_context(Style::default(), BaselineContext::multi_line(30.0, 30.0, 5.0, 25.0)).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    grid_template_columns: vec![auto(), auto()],
+                    grid_template_rows: vec![length(100.0)],
+                    align_items: Some(AlignItems::LAST_BASELINE),
+                    ..Default::default()
+                },
+                &[child_a, child_b],
+            )
+            .unwrap();
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, baseline_measure_function).unwrap();
+
+        assert_eq!(taffy.layout(child_a).unwrap().location.y, 50.0);
+        assert_eq!(taffy.layout(child_b).unwrap().location.y, 65.0);
+    }
+
+    /// A block container's first baseline is that of its first in-flow line box, and its last
+    /// baseline is that of its last in-flow line box.
+    #[test]
+    fn block_container_propagates_first_and_last_baselines() {
+        for (align_items, expected_container_y, expected_reference_y) in
+            [(AlignItems::BASELINE, 0.0, 10.0), (AlignItems::LAST_BASELINE, 40.0, 55.0)]
+        {
+            let mut taffy: TaffyTree<BaselineContext> = TaffyTree::new();
+            let first_line = taffy
+                .new_leaf_with_context(Style::default(), BaselineContext::single_line(40.0, 20.0, Some(15.0)))
+                .unwrap();
+            let last_lines = taffy
+                .new_leaf_with_context(Style::default(), BaselineContext::multi_line(40.0, 30.0, 10.0, 25.0))
+                .unwrap();
+            // First baseline at 15, last baseline at 20 + 25 = 45 (15px above the bottom edge)
+            let container = taffy
+                .new_with_children(
+                    Style {
+                        display: Display::Block,
+                        padding: Rect { left: zero(), right: zero(), top: zero(), bottom: length(10.0) },
+                        ..Default::default()
+                    },
+                    &[first_line, last_lines],
+                )
+                .unwrap();
+            // First baseline at 5, last baseline at 30 (10px above the bottom edge)
+            let reference = taffy
+                .new_leaf_with_context(Style::default(), BaselineContext::multi_line(40.0, 40.0, 5.0, 30.0))
+                .unwrap();
+            layout_flex_row(&mut taffy, align_items, &[container, reference]);
+
+            assert_eq!(taffy.layout(container).unwrap().location.y, expected_container_y);
+            assert_eq!(taffy.layout(reference).unwrap().location.y, expected_reference_y);
+        }
+    }
+
+    /// A grid container's first baseline is taken from its first row and its last baseline from its last row.
+    #[test]
+    fn grid_container_propagates_first_and_last_baselines() {
+        for (align_items, expected_container_y, expected_reference_y) in
+            [(AlignItems::BASELINE, 0.0, 10.0), (AlignItems::LAST_BASELINE, 45.0, 60.0)]
+        {
+            let mut taffy: TaffyTree<BaselineContext> = TaffyTree::new();
+            let first_row = taffy
+                .new_leaf_with_context(Style::default(), BaselineContext::single_line(40.0, 20.0, Some(15.0)))
+                .unwrap();
+            let last_row = taffy
+                .new_leaf_with_context(Style::default(), BaselineContext::multi_line(40.0, 30.0, 10.0, 25.0))
+                .unwrap();
+            // First baseline at 15, last baseline at 20 + 25 = 45 (5px above the bottom edge)
+            let container = taffy
+                .new_with_children(Style { display: Display::Grid, ..Default::default() }, &[first_row, last_row])
+                .unwrap();
+            // First baseline at 5, last baseline at 30 (10px above the bottom edge)
+            let reference = taffy
+                .new_leaf_with_context(Style::default(), BaselineContext::multi_line(40.0, 40.0, 5.0, 30.0))
+                .unwrap();
+            layout_flex_row(&mut taffy, align_items, &[container, reference]);
+
+            assert_eq!(taffy.layout(container).unwrap().location.y, expected_container_y);
+            assert_eq!(taffy.layout(reference).unwrap().location.y, expected_reference_y);
+        }
+    }
+
+    /// Baselines returned from `compute_leaf_layout_with_baselines` measure functions are relative
+    /// to the content box, so they are offset by the leaf's padding and border.
+    #[test]
+    fn leaf_baselines_are_offset_by_padding_and_border() {
+        let mut taffy: TaffyTree<BaselineContext> = TaffyTree::new();
+        let leaf = taffy
+            .new_leaf_with_context(
+                Style {
+                    padding: Rect { left: zero(), right: zero(), top: length(8.0), bottom: zero() },
+                    border: Rect { left: zero(), right: zero(), top: length(2.0), bottom: zero() },
+                    ..Default::default()
+                },
+                BaselineContext::single_line(30.0, 20.0, Some(15.0)),
+            )
+            .unwrap();
+        let reference =
+            taffy.new_leaf_with_context(Style::default(), BaselineContext::single_line(40.0, 40.0, Some(5.0))).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style { display: Display::Flex, align_items: Some(AlignItems::BASELINE), ..Default::default() },
+                &[leaf, reference],
+            )
+            .unwrap();
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, leaf_baseline_measure_function).unwrap();
+
+        // The leaf's baseline is at 10 + 15 = 25 from its border box top edge
+        assert_eq!(taffy.layout(leaf).unwrap().location.y, 0.0);
+        assert_eq!(taffy.layout(reference).unwrap().location.y, 20.0);
+    }
+
+    /// The last baseline returned from a `compute_leaf_layout_with_baselines` measure function is
+    /// also relative to the content box.
+    #[test]
+    fn leaf_last_baselines_are_offset_by_padding_and_border() {
+        let mut taffy: TaffyTree<BaselineContext> = TaffyTree::new();
+        let leaf = taffy
+            .new_leaf_with_context(
+                Style {
+                    padding: Rect { left: zero(), right: zero(), top: length(8.0), bottom: length(6.0) },
+                    border: Rect { left: zero(), right: zero(), top: length(2.0), bottom: zero() },
+                    ..Default::default()
+                },
+                BaselineContext::multi_line(30.0, 20.0, 5.0, 15.0),
+            )
+            .unwrap();
+        let reference =
+            taffy.new_leaf_with_context(Style::default(), BaselineContext::multi_line(40.0, 40.0, 5.0, 30.0)).unwrap();
+        let root = taffy
+            .new_with_children(
+                Style {
+                    display: Display::Flex,
+                    align_items: Some(AlignItems::LAST_BASELINE),
+                    size: Size { width: length(200.0), height: length(100.0) },
+                    ..Default::default()
+                },
+                &[leaf, reference],
+            )
+            .unwrap();
+        taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, leaf_baseline_measure_function).unwrap();
+
+        // The leaf is 36px tall with its last baseline at 10 + 15 = 25 (11px above its bottom edge), so it
+        // sits at the bottom of the line and the shared last baseline is at y = 89
+        assert_eq!(taffy.layout(leaf).unwrap().location.y, 64.0);
+        assert_eq!(taffy.layout(reference).unwrap().location.y, 59.0);
+    }
 }
@@ -0,0 +1,155 @@
+//! Tests for `justify-self` and `justify-items` on block-level boxes in block layout.
+//!
+//! Spec: <https://www.w3.org/TR/css-align-3/#justify-block>
+#![cfg(feature = "block_layout")]
+use taffy::prelude::*;
+use taffy::style::Direction;
+use taffy_test_helpers::{new_test_tree, test_measure_function, TestNodeContext, WritingMode};
+
+/// Lays out `children` in a 200px wide block container and returns the x position and width of each child
+fn layout_children(
+    taffy: &mut TaffyTree<TestNodeContext>,
+    container_style: Style,
+    children: &[NodeId],
+) -> Vec<(f32, f32)> {
+    let root = taffy
+        .new_with_children(
+            Style { display: Display::Block, size: Size { width: length(200.0), height: auto() }, ..container_style },
+            children,
+        )
+        .unwrap();
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+    children
+        .iter()
+        .map(|&child| {
+            let layout = taffy.layout(child).unwrap();
+            (layout.location.x, layout.size.width)
+        })
+        .collect()
+}
+
+fn fixed_width_block(width: f32, justify_self: Option<AlignSelf>) -> Style {
+    Style { justify_self, size: Size { width: length(width), height: length(10.0) }, ..Default::default() }
+}
+
+#[test]
+fn justify_self_aligns_fixed_width_block() {
+    let mut taffy = new_test_tree();
+    let start = taffy.new_leaf(fixed_width_block(50.0, Some(AlignSelf::START))).unwrap();
+    let center = taffy.new_leaf(fixed_width_block(50.0, Some(AlignSelf::CENTER))).unwrap();
+    let end = taffy.new_leaf(fixed_width_block(50.0, Some(AlignSelf::END))).unwrap();
+    let positions = layout_children(&mut taffy, Style::default(), &[start, center, end]);
+
+    assert_eq!(positions, vec![(0.0, 50.0), (75.0, 50.0), (150.0, 50.0)]);
+}
+
+#[test]
+fn justify_items_is_used_when_justify_self_is_not_set() {
+    let mut taffy = new_test_tree();
+    let inherited = taffy.new_leaf(fixed_width_block(50.0, None)).unwrap();
+    let overridden = taffy.new_leaf(fixed_width_block(50.0, Some(AlignSelf::START))).unwrap();
+    let container_style = Style { justify_items: Some(AlignItems::END), ..Default::default() };
+    let positions = layout_children(&mut taffy, container_style, &[inherited, overridden]);
+
+    assert_eq!(positions, vec![(150.0, 50.0), (0.0, 50.0)]);
+}
+
+#[test]
+fn non_stretch_justify_self_sizes_auto_width_as_fit_content() {
+    let mut taffy = new_test_tree();
+    let text = || TestNodeContext::ahem_text("HH".to_string(), WritingMode::Horizontal);
+    let normal = taffy.new_leaf_with_context(Style::default(), text()).unwrap();
+    let stretch = taffy
+        .new_leaf_with_context(Style { justify_self: Some(AlignSelf::STRETCH), ..Default::default() }, text())
+        .unwrap();
+    let end = taffy
+        .new_leaf_with_context(Style { justify_self: Some(AlignSelf::END), ..Default::default() }, text())
+        .unwrap();
+    let positions = layout_children(&mut taffy, Style::default(), &[normal, stretch, end]);
+
+    // `normal` and `stretch` fill the container, whereas `end` shrinks to the width of its content
+    assert_eq!(positions, vec![(0.0, 200.0), (0.0, 200.0), (180.0, 20.0)]);
+}
+
+#[test]
+fn justify_self_is_relative_to_container_direction() {
+    let mut taffy = new_test_tree();
+    let start = taffy.new_leaf(fixed_width_block(50.0, Some(AlignSelf::START))).unwrap();
+    let end = taffy.new_leaf(fixed_width_block(50.0, Some(AlignSelf::END))).unwrap();
+    let self_start = taffy
+        .new_leaf(Style { direction: Direction::Ltr, ..fixed_width_block(50.0, Some(AlignSelf::SELF_START)) })
+        .unwrap();
+    let container_style = Style { direction: Direction::Rtl, ..Default::default() };
+    let positions = layout_children(&mut taffy, container_style, &[start, end, self_start]);
+
+    // In an RTL container `start` is the right edge. `self-start` is resolved against the item's own
+    // (LTR) direction, so is the left edge.
+    assert_eq!(positions, vec![(150.0, 50.0), (0.0, 50.0), (0.0, 50.0)]);
+}
+
+#[test]
+fn safe_justify_self_falls_back_to_start_on_overflow() {
+    let mut taffy = new_test_tree();
+    let unsafe_end = taffy.new_leaf(fixed_width_block(250.0, Some(AlignSelf::END))).unwrap();
+    let safe_end = taffy.new_leaf(fixed_width_block(250.0, Some(AlignSelf::SAFE_END))).unwrap();
+    let safe_fitting = taffy.new_leaf(fixed_width_block(50.0, Some(AlignSelf::SAFE_END))).unwrap();
+    let positions = layout_children(&mut taffy, Style::default(), &[unsafe_end, safe_end, safe_fitting]);
+
+    assert_eq!(positions, vec![(-50.0, 250.0), (0.0, 250.0), (150.0, 50.0)]);
+}
+
+#[test]
+fn auto_margins_take_precedence_over_justify_self() {
+    let mut taffy = new_test_tree();
+    let child = taffy
+        .new_leaf(Style {
+            margin: Rect { left: auto(), right: zero(), top: zero(), bottom: zero() },
+            ..fixed_width_block(50.0, Some(AlignSelf::START))
+        })
+        .unwrap();
+    let positions = layout_children(&mut taffy, Style::default(), &[child]);
+
+    assert_eq!(positions, vec![(150.0, 50.0)]);
+}
+
+#[test]
+fn justify_self_includes_non_auto_margins() {
+    let mut taffy = new_test_tree();
+    let child = taffy
+        .new_leaf(Style {
+            margin: Rect { left: length(10.0), right: length(30.0), top: zero(), bottom: zero() },
+            ..fixed_width_block(50.0, Some(AlignSelf::CENTER))
+        })
+        .unwrap();
+    let positions = layout_children(&mut taffy, Style::default(), &[child]);
+
+    // The margin box (90px) is centered within the container: 55px of free space on either side
+    assert_eq!(positions, vec![(65.0, 50.0)]);
+}
+
+#[cfg(feature = "float_layout")]
+#[test]
+fn justify_self_aligns_float_avoiding_block_beside_floats() {
+    use taffy::geometry::Point;
+    use taffy::style::{Float, Overflow};
+
+    let mut taffy = new_test_tree();
+    let float = taffy
+        .new_leaf(Style {
+            float: Float::Left,
+            size: Size { width: length(60.0), height: length(50.0) },
+            ..Default::default()
+        })
+        .unwrap();
+    // Boxes establishing a new block formatting context avoid floats, so are aligned within the space beside them
+    let bfc_block = |justify_self| Style {
+        overflow: Point { x: Overflow::Hidden, y: Overflow::Hidden },
+        ..fixed_width_block(40.0, Some(justify_self))
+    };
+    let end = taffy.new_leaf(bfc_block(AlignSelf::END)).unwrap();
+    let center = taffy.new_leaf(bfc_block(AlignSelf::CENTER)).unwrap();
+    let positions = layout_children(&mut taffy, Style::default(), &[float, end, center]);
+
+    assert_eq!(positions[1], (160.0, 40.0));
+    assert_eq!(positions[2], (110.0, 40.0));
+}
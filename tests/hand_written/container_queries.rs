@@ -0,0 +1,199 @@
+//! Nodes can have styles that depend on the size of their nearest query container, which are selected while the
+//! container is laid out.
+
+use taffy::prelude::*;
+use taffy::style::{ContainerCondition, ContainerType};
+use taffy_test_helpers::{new_test_tree, test_measure_function, TestNodeContext, WritingMode};
+
+/// A condition that matches containers whose content box is at least `width` wide
+fn min_width(width: f32) -> ContainerCondition {
+    ContainerCondition { min_size: Size { width: Some(width), height: None }, ..Default::default() }
+}
+
+fn height_style(height: f32) -> Style {
+    Style { size: Size { width: auto(), height: length(height) }, ..Default::default() }
+}
+
+/// Builds a root of the given width holding a query container (with 10px of padding on each side) holding `child`,
+/// and returns the root
+fn container_tree<T>(tree: &mut TaffyTree<T>, display: Display, root_width: f32, child: NodeId) -> NodeId {
+    let container = tree
+        .new_with_children(
+            Style {
+                display,
+                container_type: ContainerType::InlineSize,
+                padding: Rect { left: length(10.0), right: length(10.0), top: zero(), bottom: zero() },
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+    tree.new_with_children(
+        Style {
+            display: Display::Block,
+            size: Size { width: length(root_width), height: auto() },
+            ..Default::default()
+        },
+        &[container],
+    )
+    .unwrap()
+}
+
+#[test]
+fn styles_are_selected_by_the_width_of_the_container() {
+    for display in [Display::Block, Display::Flex, Display::Grid] {
+        let mut tree = new_test_tree();
+        let child = tree.new_leaf(height_style(10.0)).unwrap();
+        tree.set_container_query_styles(child, vec![(min_width(300.0), height_style(50.0))]).unwrap();
+        let root = container_tree(&mut tree, display, 400.0, child);
+
+        // The container's content box is 380px wide
+        tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(tree.layout(child).unwrap().size.height, 50.0, "{display:?}");
+        assert_eq!(tree.layout(root).unwrap().size.height, 50.0, "{display:?}");
+
+        // The container's content box is 230px wide
+        tree.set_style(root, Style { size: Size { width: length(250.0), height: auto() }, ..Default::default() })
+            .unwrap();
+        tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+        assert_eq!(tree.layout(child).unwrap().size.height, 10.0, "{display:?}");
+        assert_eq!(tree.layout(root).unwrap().size.height, 10.0, "{display:?}");
+        assert_eq!(tree.style(child).unwrap().size.height, length(10.0), "{display:?}");
+    }
+}
+
+#[test]
+fn first_matching_variant_is_used() {
+    let mut tree = new_test_tree();
+    let child = tree.new_leaf(height_style(10.0)).unwrap();
+    tree.set_container_query_styles(
+        child,
+        vec![
+            (min_width(500.0), height_style(100.0)),
+            (min_width(300.0), height_style(50.0)),
+            (min_width(0.0), height_style(20.0)),
+        ],
+    )
+    .unwrap();
+    let root = container_tree(&mut tree, Display::Block, 400.0, child);
+
+    tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(tree.layout(child).unwrap().size.height, 50.0);
+}
+
+#[test]
+fn nested_query_containers_are_queried_by_their_own_descendants() {
+    let mut tree = new_test_tree();
+    let child = tree.new_leaf(height_style(10.0)).unwrap();
+    tree.set_container_query_styles(child, vec![(min_width(300.0), height_style(50.0))]).unwrap();
+    let inner_container = tree
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                container_type: ContainerType::InlineSize,
+                size: Size { width: length(200.0), height: auto() },
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+    let root = container_tree(&mut tree, Display::Block, 400.0, inner_container);
+
+    // The child is queried against the 200px wide inner container rather than the 380px wide outer container
+    tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(tree.layout(child).unwrap().size.height, 10.0);
+}
+
+#[test]
+fn height_conditions_require_a_size_container_with_a_known_height() {
+    let min_height = ContainerCondition { min_size: Size { width: None, height: Some(50.0) }, ..Default::default() };
+    for (container_type, expected_height) in [(ContainerType::InlineSize, 10.0), (ContainerType::Size, 30.0)] {
+        let mut tree = new_test_tree();
+        let child = tree.new_leaf(height_style(10.0)).unwrap();
+        tree.set_container_query_styles(child, vec![(min_height, height_style(30.0))]).unwrap();
+        let container = tree
+            .new_with_children(
+                Style {
+                    display: Display::Block,
+                    container_type,
+                    size: Size { width: length(100.0), height: length(100.0) },
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+
+        tree.compute_layout(container, Size::MAX_CONTENT).unwrap();
+        assert_eq!(tree.layout(child).unwrap().size.height, expected_height, "{container_type:?}");
+    }
+}
+
+#[test]
+fn container_query_styles_can_be_replaced_and_removed() {
+    let mut tree = new_test_tree();
+    let child = tree.new_leaf(height_style(10.0)).unwrap();
+    tree.set_container_query_styles(child, vec![(min_width(300.0), height_style(50.0))]).unwrap();
+    let root = container_tree(&mut tree, Display::Block, 400.0, child);
+    tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(tree.layout(child).unwrap().size.height, 50.0);
+
+    // Setting the style of the node replaces the style that is used when no condition matches
+    tree.set_style(child, height_style(20.0)).unwrap();
+    tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(tree.layout(child).unwrap().size.height, 50.0);
+    tree.set_container_query_styles(child, vec![(min_width(1000.0), height_style(50.0))]).unwrap();
+    tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(tree.layout(child).unwrap().size.height, 20.0);
+
+    // Removing the container query styles restores the base style
+    tree.set_container_query_styles(child, vec![(min_width(0.0), height_style(40.0))]).unwrap();
+    tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(tree.layout(child).unwrap().size.height, 40.0);
+    tree.set_container_query_styles(child, Vec::new()).unwrap();
+    tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(tree.layout(child).unwrap().size.height, 20.0);
+    assert_eq!(tree.style(child).unwrap().size.height, length(20.0));
+}
+
+#[test]
+fn styles_are_selected_by_the_final_size_of_the_container() {
+    let mut tree = new_test_tree();
+    let text = TestNodeContext::ahem_text("HH".to_string(), WritingMode::Horizontal);
+    let child = tree.new_leaf_with_context(height_style(10.0), text).unwrap();
+    tree.set_container_query_styles(child, vec![(min_width(300.0), height_style(50.0))]).unwrap();
+    let sibling = tree.new_leaf(Style { size: Size::from_lengths(50.0, 0.0), ..Default::default() }).unwrap();
+    // The container is measured at its (zero) min- and max-content widths before it grows to be 350px wide
+    let container = tree
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                container_type: ContainerType::InlineSize,
+                flex_grow: 1.0,
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+    let root = tree
+        .new_with_children(
+            Style { size: Size { width: length(400.0), height: auto() }, ..Default::default() },
+            &[sibling, container],
+        )
+        .unwrap();
+
+    // The root measured the container with the child's previous style, so it is laid out again
+    tree.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+    assert_eq!(tree.layout(container).unwrap().size.width, 350.0);
+    assert_eq!(tree.style(child).unwrap().size.height, length(50.0));
+    assert_eq!(tree.layout(child).unwrap().size.height, 50.0);
+    assert_eq!(tree.layout(root).unwrap().size.height, 50.0);
+
+    // Measuring the container again doesn't select different styles, so the child's cached layout is reused
+    let layout = *tree.layout(root).unwrap();
+    let measure_count = tree.get_node_context(child).unwrap().count;
+    tree.mark_dirty(root).unwrap();
+    tree.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+    assert_eq!(*tree.layout(root).unwrap(), layout);
+    assert_eq!(tree.style(child).unwrap().size.height, length(50.0));
+    assert_eq!(tree.get_node_context(child).unwrap().count, measure_count);
+}
@@ -0,0 +1,102 @@
+//! The contents of a node with `content_visibility: hidden` (or `auto`, unless it is relevant to the user) are not laid
+//! out, and the node is sized as if it were empty.
+
+use taffy::compute_leaf_layout;
+use taffy::prelude::*;
+use taffy::style::{ContainIntrinsicSize, ContentVisibility};
+use taffy::tree::{LayoutInput, LayoutOutput};
+
+/// A tree containing a node with the given `content_visibility`, which contains a single measured 100x100 leaf
+struct SkippableTree {
+    tree: TaffyTree<()>,
+    node: NodeId,
+    leaf: NodeId,
+    measure_count: usize,
+}
+
+impl SkippableTree {
+    fn new(content_visibility: ContentVisibility) -> Self {
+        let mut tree: TaffyTree<()> = TaffyTree::new();
+        let leaf = tree.new_leaf_with_context(Style { flex_shrink: 0.0, ..Default::default() }, ()).unwrap();
+        let style = Style {
+            content_visibility,
+            contain_intrinsic_size: Size {
+                width: ContainIntrinsicSize::NONE,
+                height: ContainIntrinsicSize::auto(40.0),
+            },
+            ..Default::default()
+        };
+        let node = tree.new_with_children(style, &[leaf]).unwrap();
+        Self { tree, node, leaf, measure_count: 0 }
+    }
+
+    fn compute_layout(&mut self) {
+        let measure_count = &mut self.measure_count;
+        let measure = |inputs: LayoutInput, _, context: Option<&mut ()>, style: &Style| -> LayoutOutput {
+            if context.is_some() {
+                *measure_count += 1;
+            }
+            compute_leaf_layout(inputs, style, |_, _| 0.0, |_, _| Size { width: 100.0, height: 100.0 })
+        };
+        self.tree.compute_layout_with_measure(self.node, Size::MAX_CONTENT, measure).unwrap();
+    }
+
+    fn size(&self, node: NodeId) -> Size<f32> {
+        self.tree.layout(node).unwrap().size
+    }
+}
+
+#[test]
+fn hidden_contents_are_not_laid_out() {
+    let mut tree = SkippableTree::new(ContentVisibility::Hidden);
+    tree.compute_layout();
+    assert_eq!(tree.size(tree.node), Size { width: 0.0, height: 40.0 });
+    assert_eq!(tree.size(tree.leaf), Size::ZERO);
+    assert_eq!(tree.measure_count, 0);
+}
+
+#[test]
+fn auto_contents_are_only_laid_out_when_relevant_to_the_user() {
+    let mut tree = SkippableTree::new(ContentVisibility::Auto);
+    tree.compute_layout();
+    assert_eq!(tree.size(tree.node), Size { width: 0.0, height: 40.0 });
+    assert_eq!(tree.measure_count, 0);
+
+    tree.tree.set_relevant_to_user(tree.node, true).unwrap();
+    assert!(tree.tree.dirty(tree.node).unwrap());
+    tree.compute_layout();
+    assert_eq!(tree.size(tree.node), Size { width: 100.0, height: 100.0 });
+    assert_eq!(tree.size(tree.leaf), Size { width: 100.0, height: 100.0 });
+
+    // When the contents are skipped again the node's last remembered height is used
+    tree.tree.set_relevant_to_user(tree.node, false).unwrap();
+    tree.compute_layout();
+    assert_eq!(tree.size(tree.node), Size { width: 0.0, height: 100.0 });
+}
+
+#[test]
+fn skipped_contents_keep_their_layouts_and_caches() {
+    let mut tree = SkippableTree::new(ContentVisibility::Auto);
+    tree.tree.set_relevant_to_user(tree.node, true).unwrap();
+    tree.compute_layout();
+    let measure_count = tree.measure_count;
+    assert!(measure_count > 0);
+
+    tree.tree.set_relevant_to_user(tree.node, false).unwrap();
+    tree.compute_layout();
+    assert_eq!(tree.size(tree.leaf), Size { width: 100.0, height: 100.0 });
+
+    // Laying out the contents again reuses the cached results from before they were skipped
+    tree.tree.set_relevant_to_user(tree.node, true).unwrap();
+    tree.compute_layout();
+    assert_eq!(tree.measure_count, measure_count);
+}
+
+#[test]
+fn changes_to_skipped_contents_do_not_dirty_ancestors() {
+    let mut tree = SkippableTree::new(ContentVisibility::Hidden);
+    tree.compute_layout();
+    tree.tree.mark_dirty(tree.leaf).unwrap();
+    assert!(tree.tree.dirty(tree.leaf).unwrap());
+    assert!(!tree.tree.dirty(tree.node).unwrap());
+}
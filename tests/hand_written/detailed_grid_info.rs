@@ -2,6 +2,7 @@
 //! per-track positions, per-line names, and resolved track list serialization.
 #[cfg(all(feature = "detailed_layout_info", feature = "grid"))]
 mod detailed_grid_info {
+    use taffy::geometry::AbsoluteAxis;
     use taffy::prelude::*;
     use taffy::style::{GridTemplateArea, GridTemplateAreas, GridTemplateComponent, GridTemplateRepetition};
     use taffy::tree::DetailedLayoutInfo;
@@ -362,4 +363,148 @@ mod detailed_grid_info {
             Rect { left: 0.0, right: 100.0, top: 0.0, bottom: 50.0 }
         );
     }
+
+    #[test]
+    fn named_area_and_line_lookup() {
+        let mut tree = new_test_tree();
+        let child = tree.new_leaf(Style::default()).unwrap();
+        let root = tree
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    size: Size { width: Dimension::from_length(110.0), height: Dimension::from_length(60.0) },
+                    gap: Size { width: length(10.0), height: length(10.0) },
+                    grid_template_columns: vec![length(40.0), length(60.0)],
+                    grid_template_column_names: vec![vec![], vec!["content-start".into()], vec!["content-end".into()]],
+                    grid_template_rows: vec![length(20.0), length(30.0)],
+                    grid_template_row_names: vec![vec![], vec!["content-start".into()], vec!["content-end".into()]],
+                    grid_template_areas: Some(GridTemplateAreas::from_rows(["header header", "sidebar main"]).unwrap()),
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        tree.compute_layout(root, definite(110.0, 60.0)).unwrap();
+
+        let info = get_detailed_grid_info(&tree, root);
+        assert_eq!(info.named_area("header"), Some(Rect { left: 0.0, right: 110.0, top: 0.0, bottom: 20.0 }));
+        assert_eq!(info.named_area("sidebar"), Some(Rect { left: 0.0, right: 40.0, top: 30.0, bottom: 60.0 }));
+        assert_eq!(info.named_area("main"), Some(Rect { left: 50.0, right: 110.0, top: 30.0, bottom: 60.0 }));
+        // Areas are also implied by explicitly named `-start` and `-end` lines
+        assert_eq!(info.named_area("content"), info.named_area("main"));
+        assert_eq!(info.named_area("footer"), None);
+
+        // Lines between tracks extend across the gutter, and the outermost lines lie on the edges of the grid
+        assert_eq!(info.columns.lines_named("main-start").collect::<Vec<_>>(), [1]);
+        assert_eq!(
+            info.named_line_extent(AbsoluteAxis::Horizontal, "main-start", taffy::style::Direction::Ltr),
+            Some(Line { start: 40.0, end: 50.0 })
+        );
+        assert_eq!(
+            info.named_line_extent(AbsoluteAxis::Horizontal, "header-end", taffy::style::Direction::Ltr),
+            Some(Line { start: 110.0, end: 110.0 })
+        );
+        assert_eq!(
+            info.named_line_extent(AbsoluteAxis::Vertical, "header-start", taffy::style::Direction::Ltr),
+            Some(Line { start: 0.0, end: 0.0 })
+        );
+        assert_eq!(
+            info.named_line_extent(AbsoluteAxis::Vertical, "sidebar-start", taffy::style::Direction::Ltr),
+            Some(Line { start: 20.0, end: 30.0 })
+        );
+        assert_eq!(info.named_line_extent(AbsoluteAxis::Vertical, "main", taffy::style::Direction::Ltr), None);
+        assert_eq!(info.line_extent(AbsoluteAxis::Horizontal, 3, taffy::style::Direction::Ltr), None);
+    }
+
+    #[test]
+    fn rtl_named_area_and_line_lookup() {
+        let mut tree = new_test_tree();
+        let child = tree.new_leaf(Style::default()).unwrap();
+        let root = tree
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    direction: taffy::style::Direction::Rtl,
+                    size: Size { width: Dimension::from_length(110.0), height: Dimension::from_length(50.0) },
+                    gap: Size { width: length(10.0), height: zero() },
+                    grid_template_columns: vec![length(40.0), length(60.0)],
+                    grid_template_rows: vec![length(50.0)],
+                    grid_template_areas: Some(GridTemplateAreas::from_rows(["nav body"]).unwrap()),
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        tree.compute_layout(root, definite(110.0, 50.0)).unwrap();
+
+        let info = get_detailed_grid_info(&tree, root);
+        assert_eq!(info.named_area("nav"), Some(Rect { left: 70.0, right: 110.0, top: 0.0, bottom: 50.0 }));
+        assert_eq!(info.named_area("body"), Some(Rect { left: 0.0, right: 60.0, top: 0.0, bottom: 50.0 }));
+        assert_eq!(
+            info.named_line_extent(AbsoluteAxis::Horizontal, "nav-start", taffy::style::Direction::Rtl),
+            Some(Line { start: 110.0, end: 110.0 })
+        );
+        assert_eq!(
+            info.named_line_extent(AbsoluteAxis::Horizontal, "nav-end", taffy::style::Direction::Rtl),
+            Some(Line { start: 60.0, end: 70.0 })
+        );
+        assert_eq!(
+            info.named_line_extent(AbsoluteAxis::Horizontal, "body-end", taffy::style::Direction::Rtl),
+            Some(Line { start: 0.0, end: 0.0 })
+        );
+    }
+
+    #[test]
+    fn auto_fit_repetitions_and_collapsed_tracks() {
+        let mut tree = new_test_tree();
+        let children: Vec<NodeId> = (0..3).map(|_| tree.new_leaf(Style::default()).unwrap()).collect();
+        let root = tree
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    size: Size { width: Dimension::from_length(100.0), height: Dimension::from_length(20.0) },
+                    grid_template_columns: vec![length(10.0), repeat(RepetitionCount::AutoFit, vec![length(15.0)])],
+                    gap: Size { width: length(2.0), height: zero() },
+                    ..Default::default()
+                },
+                &children,
+            )
+            .unwrap();
+        tree.compute_layout(root, definite(100.0, 20.0)).unwrap();
+
+        // 10px + 5 * 15px + 5 * 2px gaps fit into 100px. The items are auto-placed into the first three tracks, so the
+        // last three repeated tracks are empty and collapse.
+        let info = get_detailed_grid_info(&tree, root);
+        assert_eq!(info.columns.auto_repetitions, 5);
+        assert_eq!(info.columns.collapsed_tracks, [false, false, false, true, true, true]);
+        assert_eq!(info.columns.positions[5], Line { start: 44.0, end: 44.0 });
+        assert_eq!(info.rows.auto_repetitions, 0);
+        assert_eq!(info.rows.collapsed_tracks, [false]);
+    }
+
+    #[test]
+    fn intrinsically_sized_auto_repeated_tracks_are_repeated_once() {
+        let mut tree = new_test_tree();
+        let child = tree.new_leaf(Style::default()).unwrap();
+        let root = tree
+            .new_with_children(
+                Style {
+                    display: Display::Grid,
+                    size: Size { width: Dimension::from_length(100.0), height: Dimension::from_length(20.0) },
+                    grid_template_columns: vec![repeat(
+                        RepetitionCount::AutoFill,
+                        vec![minmax(MinTrackSizingFunction::MIN_CONTENT, fr(1.0))],
+                    )],
+                    ..Default::default()
+                },
+                &[child],
+            )
+            .unwrap();
+        tree.compute_layout(root, definite(100.0, 20.0)).unwrap();
+
+        let info = get_detailed_grid_info(&tree, root);
+        assert_eq!(info.columns.auto_repetitions, 1);
+        assert_eq!(info.columns.explicit_tracks, 1);
+        assert_eq!(info.columns.positions, [Line { start: 0.0, end: 100.0 }]);
+    }
 }
@@ -0,0 +1,92 @@
+//! The children of a node with `display: contents` are laid out as if they were children of the node's parent, and
+//! the node itself is given an empty layout.
+
+use taffy::geometry::Point;
+use taffy::prelude::*;
+
+/// A 30x20 leaf that doesn't shrink
+fn leaf(tree: &mut TaffyTree<()>) -> NodeId {
+    let size = Size { width: length(30.0), height: length(20.0) };
+    tree.new_leaf(Style { size, flex_shrink: 0.0, ..Default::default() }).unwrap()
+}
+
+#[test]
+fn children_of_contents_nodes_are_laid_out_by_the_parent() {
+    for display in [Display::Flex, Display::Grid, Display::Block] {
+        let mut tree: TaffyTree<()> = TaffyTree::new();
+        let first = leaf(&mut tree);
+        let second = leaf(&mut tree);
+        let third = leaf(&mut tree);
+        let contents = tree
+            .new_with_children(
+                Style { display: Display::Contents, padding: length(10.0), ..Default::default() },
+                &[second, third],
+            )
+            .unwrap();
+        let style = Style { display, grid_template_columns: vec![length(30.0); 3], ..Default::default() };
+        let root = tree.new_with_children(style, &[first, contents]).unwrap();
+        tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        let locations: Vec<_> = [first, second, third].map(|node| tree.layout(node).unwrap().location).into();
+        let expected = match display {
+            Display::Block => [0.0, 20.0, 40.0].map(|y| Point { x: 0.0, y }),
+            _ => [0.0, 30.0, 60.0].map(|x| Point { x, y: 0.0 }),
+        };
+        assert_eq!(locations, expected, "{display:?}");
+
+        let contents_layout = tree.layout(contents).unwrap();
+        assert_eq!(contents_layout.location, Point::ZERO, "{display:?}");
+        assert_eq!(contents_layout.size, Size::ZERO, "{display:?}");
+        assert_eq!(contents_layout.order, 1, "{display:?}");
+    }
+}
+
+#[test]
+fn nested_contents_nodes_are_spliced() {
+    let mut tree: TaffyTree<()> = TaffyTree::new();
+    let first = leaf(&mut tree);
+    let second = leaf(&mut tree);
+    let contents_style = Style { display: Display::Contents, ..Default::default() };
+    let inner = tree.new_with_children(contents_style.clone(), &[second]).unwrap();
+    let outer = tree.new_with_children(contents_style, &[first, inner]).unwrap();
+    let root = tree.new_with_children(Style::default(), &[outer]).unwrap();
+    tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(tree.layout(root).unwrap().size, Size { width: 60.0, height: 20.0 });
+    assert_eq!(tree.layout(second).unwrap().location, Point { x: 30.0, y: 0.0 });
+    assert_eq!(tree.layout(inner).unwrap().size, Size::ZERO);
+    // The tree's own children are unchanged
+    assert_eq!(tree.children(root).unwrap(), vec![outer]);
+}
+
+#[test]
+fn changes_within_contents_nodes_dirty_the_parent() {
+    let mut tree: TaffyTree<()> = TaffyTree::new();
+    let first = leaf(&mut tree);
+    let contents =
+        tree.new_with_children(Style { display: Display::Contents, ..Default::default() }, &[first]).unwrap();
+    let root = tree.new_with_children(Style::default(), &[contents]).unwrap();
+    tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(tree.layout(root).unwrap().size, Size { width: 30.0, height: 20.0 });
+
+    let second = leaf(&mut tree);
+    tree.add_child(contents, second).unwrap();
+    assert!(tree.dirty(root).unwrap());
+    tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(tree.layout(root).unwrap().size, Size { width: 60.0, height: 20.0 });
+
+    // Changing a node to or from `display: contents` changes the children that its parent lays out
+    tree.set_style(contents, Style::default()).unwrap();
+    tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(tree.layout(contents).unwrap().size, Size { width: 60.0, height: 20.0 });
+    assert_eq!(tree.layout(second).unwrap().location, Point { x: 30.0, y: 0.0 });
+}
+
+#[test]
+fn contents_root_is_laid_out_as_a_box() {
+    let mut tree: TaffyTree<()> = TaffyTree::new();
+    let first = leaf(&mut tree);
+    let root = tree.new_with_children(Style { display: Display::Contents, ..Default::default() }, &[first]).unwrap();
+    tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(tree.layout(root).unwrap().size, Size { width: 30.0, height: 20.0 });
+}
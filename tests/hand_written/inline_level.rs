@@ -0,0 +1,118 @@
+//! Inline-level boxes (`inline-block`, `inline-flex` and `inline-grid`) in a block container are shrink-to-fit sized
+//! and placed side by side in line boxes, in which they are aligned by their baselines.
+
+use taffy::geometry::Point;
+use taffy::prelude::*;
+
+/// A box with the given display that contains a single leaf of the given size
+fn inline_box(tree: &mut TaffyTree<()>, display: Display, width: f32, height: f32) -> NodeId {
+    let leaf =
+        tree.new_leaf(Style { size: Size { width: length(width), height: length(height) }, ..Default::default() });
+    tree.new_with_children(Style { display, ..Default::default() }, &[leaf.unwrap()]).unwrap()
+}
+
+fn location(tree: &TaffyTree<()>, node: NodeId) -> Point<f32> {
+    tree.layout(node).unwrap().location
+}
+
+#[test]
+fn inline_level_boxes_are_shrink_to_fit_and_placed_in_lines() {
+    for display in [Display::InlineBlock, Display::InlineFlex, Display::InlineGrid] {
+        let mut tree: TaffyTree<()> = TaffyTree::new();
+        let first = inline_box(&mut tree, display, 30.0, 20.0);
+        let second = inline_box(&mut tree, display, 40.0, 20.0);
+        let third = inline_box(&mut tree, display, 50.0, 20.0);
+        let style = Style {
+            display: Display::Block,
+            size: Size { width: length(100.0), height: auto() },
+            ..Default::default()
+        };
+        let root = tree.new_with_children(style, &[first, second, third]).unwrap();
+        tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+        assert_eq!(tree.layout(first).unwrap().size, Size { width: 30.0, height: 20.0 }, "{display:?}");
+        assert_eq!(location(&tree, first), Point { x: 0.0, y: 0.0 }, "{display:?}");
+        assert_eq!(location(&tree, second), Point { x: 30.0, y: 0.0 }, "{display:?}");
+        // The third box doesn't fit in the first line
+        assert_eq!(location(&tree, third), Point { x: 0.0, y: 20.0 }, "{display:?}");
+        assert_eq!(tree.layout(root).unwrap().size, Size { width: 100.0, height: 40.0 }, "{display:?}");
+    }
+}
+
+#[test]
+fn inline_level_boxes_contribute_to_the_intrinsic_width_of_their_line() {
+    let mut tree: TaffyTree<()> = TaffyTree::new();
+    let first = inline_box(&mut tree, Display::InlineFlex, 30.0, 20.0);
+    let second = inline_box(&mut tree, Display::InlineBlock, 40.0, 20.0);
+    let root =
+        tree.new_with_children(Style { display: Display::Block, ..Default::default() }, &[first, second]).unwrap();
+
+    tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(tree.layout(root).unwrap().size, Size { width: 70.0, height: 20.0 });
+
+    tree.compute_layout(root, Size::MIN_CONTENT).unwrap();
+    assert_eq!(tree.layout(root).unwrap().size, Size { width: 40.0, height: 40.0 });
+}
+
+#[test]
+fn inline_level_boxes_are_aligned_by_their_baselines() {
+    let mut tree: TaffyTree<()> = TaffyTree::new();
+    // Boxes without a baseline are aligned by their bottom margin edge
+    let short = inline_box(&mut tree, Display::InlineBlock, 30.0, 20.0);
+    let tall = inline_box(&mut tree, Display::InlineBlock, 30.0, 40.0);
+    tree.set_style(
+        tall,
+        Style {
+            display: Display::InlineBlock,
+            margin: Rect { left: zero(), right: zero(), top: zero(), bottom: length(10.0) },
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let root = tree.new_with_children(Style { display: Display::Block, ..Default::default() }, &[short, tall]).unwrap();
+    tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(location(&tree, short), Point { x: 0.0, y: 30.0 });
+    assert_eq!(location(&tree, tall), Point { x: 30.0, y: 0.0 });
+    assert_eq!(tree.layout(root).unwrap().size, Size { width: 60.0, height: 50.0 });
+}
+
+#[test]
+fn line_boxes_are_stacked_between_block_level_siblings() {
+    let mut tree: TaffyTree<()> = TaffyTree::new();
+    let block_style = Style {
+        display: Display::Block,
+        size: Size { width: auto(), height: length(10.0) },
+        margin: Rect { left: zero(), right: zero(), top: length(5.0), bottom: length(5.0) },
+        ..Default::default()
+    };
+    let before = tree.new_leaf(block_style.clone()).unwrap();
+    let inline = inline_box(&mut tree, Display::InlineGrid, 30.0, 20.0);
+    let after = tree.new_leaf(block_style).unwrap();
+    let style =
+        Style { display: Display::FlowRoot, size: Size { width: length(100.0), height: auto() }, ..Default::default() };
+    let root = tree.new_with_children(style, &[before, inline, after]).unwrap();
+    tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+
+    // Block-level boxes are stretched, while the inline-level box is not
+    assert_eq!(tree.layout(before).unwrap().size.width, 100.0);
+    assert_eq!(tree.layout(inline).unwrap().size.width, 30.0);
+    // The margins of the block-level boxes don't collapse through the line box
+    assert_eq!(location(&tree, inline), Point { x: 0.0, y: 20.0 });
+    assert_eq!(location(&tree, after), Point { x: 0.0, y: 45.0 });
+    assert_eq!(tree.layout(root).unwrap().size.height, 60.0);
+}
+
+#[test]
+fn inline_level_boxes_are_blockified_in_flex_containers() {
+    let mut tree: TaffyTree<()> = TaffyTree::new();
+    let child = inline_box(&mut tree, Display::InlineBlock, 30.0, 20.0);
+    let style = Style {
+        flex_direction: FlexDirection::Column,
+        size: Size { width: length(100.0), height: auto() },
+        ..Default::default()
+    };
+    let root = tree.new_with_children(style, &[child]).unwrap();
+    tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(tree.layout(child).unwrap().size, Size { width: 100.0, height: 20.0 });
+}
@@ -0,0 +1,145 @@
+//! The min-content, max-content and fit-content contributions of a node can be queried without laying it out.
+
+use taffy::geometry::AbsoluteAxis;
+use taffy::prelude::*;
+use taffy::IntrinsicSizes;
+use taffy_test_helpers::{new_test_tree, test_measure_function, TestNodeContext, WritingMode};
+
+/// Text whose longest word is 40px wide, and which is 90px wide on a single line
+const TEXT: &str = "HHHH\u{200B}HH\u{200B}HHH";
+
+fn text() -> TestNodeContext {
+    TestNodeContext::ahem_text(TEXT.to_string(), WritingMode::Horizontal)
+}
+
+/// Builds a block node with 5px of padding and 10px of horizontal margins on each side and 5px of vertical margins
+/// on each side, containing `TEXT`, and returns the block node and the text node
+fn text_block(tree: &mut TaffyTree<TestNodeContext>) -> (NodeId, NodeId) {
+    let text = tree.new_leaf_with_context(Style::default(), text()).unwrap();
+    let block = tree
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                padding: Rect { left: length(5.0), right: length(5.0), top: length(5.0), bottom: length(5.0) },
+                margin: Rect { left: length(10.0), right: length(10.0), top: length(5.0), bottom: length(5.0) },
+                ..Default::default()
+            },
+            &[text],
+        )
+        .unwrap();
+    (block, text)
+}
+
+#[test]
+fn width_contributions_include_padding_and_margins() {
+    let mut tree = new_test_tree();
+    let (block, _) = text_block(&mut tree);
+
+    for (available_space, fit_content) in [
+        (AvailableSpace::MaxContent, 120.0),
+        (AvailableSpace::MinContent, 70.0),
+        (AvailableSpace::Definite(100.0), 100.0),
+        (AvailableSpace::Definite(50.0), 70.0),
+        (AvailableSpace::Definite(200.0), 120.0),
+    ] {
+        let sizes = tree
+            .intrinsic_size_with_measure(block, AbsoluteAxis::Horizontal, available_space, test_measure_function)
+            .unwrap();
+        assert_eq!(sizes, IntrinsicSizes { min_content: 70.0, max_content: 120.0, fit_content }, "{available_space:?}");
+    }
+}
+
+#[test]
+fn height_contributions_use_the_fit_content_width() {
+    let mut tree = new_test_tree();
+    let (block, _) = text_block(&mut tree);
+
+    // The text is laid out in a 60px wide content box, where it takes up two lines
+    let sizes = tree
+        .intrinsic_sizes_with_measure(
+            block,
+            Size { width: AvailableSpace::Definite(100.0), height: AvailableSpace::MaxContent },
+            test_measure_function,
+        )
+        .unwrap();
+    assert_eq!(sizes.width.fit_content, 100.0);
+    assert_eq!(sizes.height, IntrinsicSizes { min_content: 40.0, max_content: 40.0, fit_content: 40.0 });
+
+    // With max-content available space the text takes up a single line
+    let sizes = tree
+        .intrinsic_size_with_measure(block, AbsoluteAxis::Vertical, AvailableSpace::MaxContent, test_measure_function)
+        .unwrap();
+    assert_eq!(sizes, IntrinsicSizes { min_content: 30.0, max_content: 30.0, fit_content: 30.0 });
+}
+
+#[test]
+fn flex_row_contributions_sum_the_contributions_of_its_items() {
+    let mut tree = new_test_tree();
+    let first = tree.new_leaf_with_context(Style::default(), text()).unwrap();
+    let second = tree.new_leaf_with_context(Style::default(), text()).unwrap();
+    let flex = tree
+        .new_with_children(
+            Style { display: Display::Flex, gap: Size { width: length(10.0), height: zero() }, ..Default::default() },
+            &[first, second],
+        )
+        .unwrap();
+
+    let sizes = tree
+        .intrinsic_size_with_measure(
+            flex,
+            AbsoluteAxis::Horizontal,
+            AvailableSpace::Definite(150.0),
+            test_measure_function,
+        )
+        .unwrap();
+    assert_eq!(sizes, IntrinsicSizes { min_content: 90.0, max_content: 190.0, fit_content: 150.0 });
+}
+
+#[test]
+fn intrinsic_sizes_are_cached_and_do_not_set_layouts() {
+    let mut tree = new_test_tree();
+    let (block, text) = text_block(&mut tree);
+
+    let sizes = tree.intrinsic_sizes_with_measure(block, Size::MAX_CONTENT, test_measure_function).unwrap();
+    let measure_count = tree.get_node_context(text).unwrap().count;
+    assert_eq!(tree.intrinsic_sizes_with_measure(block, Size::MAX_CONTENT, test_measure_function).unwrap(), sizes);
+    assert_eq!(tree.get_node_context(text).unwrap().count, measure_count);
+    assert_eq!(tree.layout(block).unwrap().size, Size::ZERO);
+    assert_eq!(tree.layout(text).unwrap().size, Size::ZERO);
+}
+
+#[test]
+fn single_axis_contributions_only_measure_that_axis() {
+    let measure_count = |query: fn(&mut TaffyTree<TestNodeContext>, NodeId)| {
+        let mut tree = new_test_tree();
+        let (block, text) = text_block(&mut tree);
+        query(&mut tree, block);
+        tree.get_node_context(text).unwrap().count
+    };
+    let both_axes = measure_count(|tree, block| {
+        tree.intrinsic_sizes_with_measure(block, Size::MAX_CONTENT, test_measure_function).unwrap();
+    });
+    let horizontal = measure_count(|tree, block| {
+        tree.intrinsic_size_with_measure(
+            block,
+            AbsoluteAxis::Horizontal,
+            AvailableSpace::MaxContent,
+            test_measure_function,
+        )
+        .unwrap();
+    });
+    let vertical = measure_count(|tree, block| {
+        tree.intrinsic_size_with_measure(
+            block,
+            AbsoluteAxis::Vertical,
+            AvailableSpace::MaxContent,
+            test_measure_function,
+        )
+        .unwrap();
+    });
+
+    // The width contributions don't depend on the height, and the height contributions only depend on the
+    // max-content width
+    assert!(horizontal < both_axes, "{horizontal} < {both_axes}");
+    assert!(vertical < both_axes, "{vertical} < {both_axes}");
+}
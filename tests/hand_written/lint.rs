@@ -0,0 +1,94 @@
+//! `taffy::lint` reports style properties which are invalid or which have no effect given the `display` of the node
+//! and of its parent.
+
+use taffy::lint::{lint_tree, LintKind, LintWarning};
+use taffy::prelude::*;
+use taffy::StyleValidationError;
+
+fn warning(node: NodeId, property: &'static str, kind: LintKind) -> LintWarning {
+    LintWarning { node, property, kind }
+}
+
+#[test]
+fn item_properties_are_reported_for_the_wrong_kind_of_parent() {
+    let mut tree: TaffyTree<()> = TaffyTree::new();
+    let grid_child = tree.new_leaf(Style { flex_grow: 1.0, ..Default::default() }).unwrap();
+    let grid = tree.new_with_children(Style { display: Display::Grid, ..Default::default() }, &[grid_child]).unwrap();
+    let flex_child = tree.new_leaf(Style { grid_row: line(1), flex_grow: 1.0, ..Default::default() }).unwrap();
+    let flex = tree.new_with_children(Style { display: Display::Flex, ..Default::default() }, &[flex_child]).unwrap();
+    let root = tree.new_with_children(Style { display: Display::Block, ..Default::default() }, &[grid, flex]).unwrap();
+
+    assert_eq!(
+        lint_tree(&tree, root).unwrap(),
+        vec![
+            warning(grid_child, "flex_grow", LintKind::Ineffective),
+            warning(flex_child, "grid_row", LintKind::Ineffective)
+        ]
+    );
+}
+
+#[test]
+fn absolutely_positioned_children_are_not_flex_items() {
+    let mut tree: TaffyTree<()> = TaffyTree::new();
+    let child = tree
+        .new_leaf(Style {
+            position: Position::Absolute,
+            flex_shrink: 0.0,
+            align_self: Some(AlignSelf::END),
+            ..Default::default()
+        })
+        .unwrap();
+    let root = tree.new_with_children(Style { display: Display::Flex, ..Default::default() }, &[child]).unwrap();
+
+    // Alignment still applies to absolutely positioned children
+    assert_eq!(lint_tree(&tree, root).unwrap(), vec![warning(child, "flex_shrink", LintKind::Ineffective)]);
+}
+
+#[test]
+fn display_contents_passes_the_display_of_its_parent_to_its_children() {
+    let mut tree: TaffyTree<()> = TaffyTree::new();
+    let child = tree.new_leaf(Style { grid_column: span(2), ..Default::default() }).unwrap();
+    let contents =
+        tree.new_with_children(Style { display: Display::Contents, ..Default::default() }, &[child]).unwrap();
+    let root = tree.new_with_children(Style { display: Display::Grid, ..Default::default() }, &[contents]).unwrap();
+    assert_eq!(lint_tree(&tree, root).unwrap(), vec![]);
+
+    // The descendants of `Display::None` nodes are not linted
+    tree.set_style(root, Style { display: Display::None, ..Default::default() }).unwrap();
+    assert_eq!(lint_tree(&tree, root).unwrap(), vec![]);
+}
+
+#[test]
+fn container_properties_are_reported_for_the_wrong_kind_of_container() {
+    let mut tree: TaffyTree<()> = TaffyTree::new();
+    let style = Style {
+        display: Display::InlineFlex,
+        flex_direction: FlexDirection::Column,
+        grid_template_columns: vec![length(10.0)],
+        ..Default::default()
+    };
+    let root = tree.new_leaf(style).unwrap();
+    assert_eq!(lint_tree(&tree, root).unwrap(), vec![warning(root, "grid_template_columns", LintKind::Ineffective)]);
+}
+
+#[test]
+fn invalid_and_non_finite_values_are_reported() {
+    let mut tree: TaffyTree<()> = TaffyTree::new();
+    let style = Style {
+        display: Display::Block,
+        size: Size { width: length(f32::NAN), height: auto() },
+        min_size: Size { width: auto(), height: length(50.0) },
+        max_size: Size { width: auto(), height: length(20.0) },
+        padding: Rect { left: length(-5.0), right: zero(), top: zero(), bottom: zero() },
+        ..Default::default()
+    };
+    let root = tree.new_leaf(style).unwrap();
+    assert_eq!(
+        lint_tree(&tree, root).unwrap(),
+        vec![
+            warning(root, "padding", LintKind::Invalid(StyleValidationError::NegativeValue { property: "padding" })),
+            warning(root, "size", LintKind::NonFinite),
+            warning(root, "min_size", LintKind::MinExceedsMax),
+        ]
+    );
+}
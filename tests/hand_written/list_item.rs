@@ -0,0 +1,133 @@
+//! `Display::ListItem` nodes generate a marker box, which is sized by the measure function (using the context set with
+//! `TaffyTree::set_marker_context`) and whose position is output in `Layout::marker_rect`.
+
+use taffy::geometry::Point;
+use taffy::prelude::*;
+use taffy::{compute_leaf_layout_with_baselines, Baselines, Direction, LayoutInput, LayoutOutput, ListStylePosition};
+
+/// The size of a piece of content and the offset of its baseline from its top edge
+#[derive(Debug, Clone, Copy)]
+struct Content {
+    size: Size<f32>,
+    baseline: Option<f32>,
+}
+
+fn content(width: f32, height: f32, baseline: f32) -> Content {
+    Content { size: Size { width, height }, baseline: Some(baseline) }
+}
+
+fn measure_function(
+    inputs: LayoutInput,
+    _node_id: NodeId,
+    context: Option<&mut Content>,
+    style: &Style,
+) -> LayoutOutput {
+    compute_leaf_layout_with_baselines(
+        inputs,
+        style,
+        |_, _| 0.0,
+        |_, _| {
+            let Some(content) = context else {
+                return (Size::ZERO, Point { x: Baselines::NONE, y: Baselines::NONE });
+            };
+            (
+                content.size,
+                Point { x: Baselines::NONE, y: Baselines { first: content.baseline, last: content.baseline } },
+            )
+        },
+    )
+}
+
+/// Creates a list item with a 20x10 marker (with its baseline 8px from its top) that contains the given children
+fn list_item(tree: &mut TaffyTree<Content>, style: Style, children: &[NodeId]) -> NodeId {
+    let node = tree.new_with_children(Style { display: Display::ListItem, ..style }, children).unwrap();
+    tree.set_marker_context(node, Some(content(20.0, 10.0, 8.0))).unwrap();
+    node
+}
+
+fn marker_rect(tree: &TaffyTree<Content>, node: NodeId) -> Option<Rect<f32>> {
+    tree.layout(node).unwrap().marker_rect
+}
+
+#[test]
+fn outside_marker_is_aligned_with_the_first_baseline() {
+    let mut tree: TaffyTree<Content> = TaffyTree::new();
+    let text = tree.new_leaf_with_context(Style::default(), content(100.0, 20.0, 16.0)).unwrap();
+    let style = Style {
+        padding: Rect { left: length(30.0), right: zero(), top: zero(), bottom: zero() },
+        ..Default::default()
+    };
+    let item = list_item(&mut tree, style, &[text]);
+    tree.compute_layout_with_measure(item, Size::MAX_CONTENT, measure_function).unwrap();
+
+    // The marker is placed before the start of the content box and doesn't affect the layout of the content
+    assert_eq!(marker_rect(&tree, item), Some(Rect { left: 10.0, right: 30.0, top: 8.0, bottom: 18.0 }));
+    assert_eq!(tree.layout(text).unwrap().location, Point { x: 30.0, y: 0.0 });
+    assert_eq!(tree.layout(item).unwrap().size, Size { width: 130.0, height: 20.0 });
+}
+
+#[test]
+fn outside_marker_is_placed_at_the_inline_start_in_rtl() {
+    let mut tree: TaffyTree<Content> = TaffyTree::new();
+    let text = tree.new_leaf_with_context(Style::default(), content(100.0, 20.0, 16.0)).unwrap();
+    let style = Style {
+        direction: Direction::Rtl,
+        size: Size { width: length(200.0), height: auto() },
+        padding: Rect { left: zero(), right: length(30.0), top: zero(), bottom: zero() },
+        ..Default::default()
+    };
+    let item = list_item(&mut tree, style, &[text]);
+    tree.compute_layout_with_measure(item, Size::MAX_CONTENT, measure_function).unwrap();
+
+    assert_eq!(marker_rect(&tree, item), Some(Rect { left: 170.0, right: 190.0, top: 8.0, bottom: 18.0 }));
+}
+
+#[test]
+fn inside_marker_is_placed_at_the_start_of_the_first_line() {
+    let mut tree: TaffyTree<Content> = TaffyTree::new();
+    let text = tree.new_leaf_with_context(Style::default(), content(50.0, 20.0, 16.0)).unwrap();
+    let inline =
+        tree.new_with_children(Style { display: Display::InlineBlock, ..Default::default() }, &[text]).unwrap();
+    let style = Style { list_style_position: ListStylePosition::Inside, ..Default::default() };
+    let item = list_item(&mut tree, style, &[inline]);
+    tree.compute_layout_with_measure(item, Size::MAX_CONTENT, measure_function).unwrap();
+
+    // The marker and the inline-block are aligned by their baselines
+    assert_eq!(marker_rect(&tree, item), Some(Rect { left: 0.0, right: 20.0, top: 8.0, bottom: 18.0 }));
+    assert_eq!(tree.layout(inline).unwrap().location, Point { x: 20.0, y: 0.0 });
+    // The marker contributes to the intrinsic width of the list item
+    assert_eq!(tree.layout(item).unwrap().size, Size { width: 70.0, height: 20.0 });
+}
+
+#[test]
+fn inside_marker_before_block_level_content_is_placed_in_its_own_line() {
+    let mut tree: TaffyTree<Content> = TaffyTree::new();
+    let text = tree.new_leaf_with_context(Style::default(), content(50.0, 20.0, 16.0)).unwrap();
+    let style = Style { list_style_position: ListStylePosition::Inside, ..Default::default() };
+    let item = list_item(&mut tree, style, &[text]);
+    tree.compute_layout_with_measure(item, Size::MAX_CONTENT, measure_function).unwrap();
+
+    assert_eq!(marker_rect(&tree, item), Some(Rect { left: 0.0, right: 20.0, top: 0.0, bottom: 10.0 }));
+    assert_eq!(tree.layout(text).unwrap().location, Point { x: 0.0, y: 10.0 });
+    assert_eq!(tree.layout(item).unwrap().size, Size { width: 50.0, height: 30.0 });
+}
+
+#[test]
+fn nodes_without_marker_context_have_no_marker() {
+    let mut tree: TaffyTree<Content> = TaffyTree::new();
+    let text = tree.new_leaf_with_context(Style::default(), content(50.0, 20.0, 16.0)).unwrap();
+    let item = tree.new_with_children(Style { display: Display::ListItem, ..Default::default() }, &[text]).unwrap();
+    tree.compute_layout_with_measure(item, Size::MAX_CONTENT, measure_function).unwrap();
+    assert_eq!(marker_rect(&tree, item), None);
+
+    // Setting the marker context marks the node dirty
+    tree.set_marker_context(item, Some(content(20.0, 10.0, 8.0))).unwrap();
+    assert!(tree.dirty(item).unwrap());
+    tree.compute_layout_with_measure(item, Size::MAX_CONTENT, measure_function).unwrap();
+    assert_eq!(marker_rect(&tree, item), Some(Rect { left: -20.0, right: 0.0, top: 8.0, bottom: 18.0 }));
+
+    // Only list items generate markers
+    tree.set_style(item, Style { display: Display::Block, ..Default::default() }).unwrap();
+    tree.compute_layout_with_measure(item, Size::MAX_CONTENT, measure_function).unwrap();
+    assert_eq!(marker_rect(&tree, item), None);
+}
@@ -0,0 +1,83 @@
+//! The content of a replaced leaf is painted within its content box at the size given by its `object_fit` style and
+//! the position given by its `object_position` style.
+
+use taffy::geometry::Point;
+use taffy::prelude::*;
+use taffy::tree::{LayoutInput, LayoutOutput};
+use taffy::{compute_leaf_layout, compute_replaced_leaf_layout, ObjectFit, ReplacedContent};
+
+fn measure(inputs: LayoutInput, _: NodeId, content: Option<&mut ReplacedContent>, style: &Style) -> LayoutOutput {
+    match content {
+        Some(content) => compute_replaced_leaf_layout(inputs, style, |_, _| 0.0, *content),
+        None => compute_leaf_layout(inputs, style, |_, _| 0.0, |_, _| Size::ZERO),
+    }
+}
+
+/// Lays out a 100x100 replaced node with the given style and content, and returns the rectangle its content is painted
+/// in along with the rectangle of its container
+fn content_rects(style: Style, content: ReplacedContent) -> (Option<Rect<f32>>, Option<Rect<f32>>) {
+    let mut tree: TaffyTree<ReplacedContent> = TaffyTree::new();
+    let style = Style {
+        item_is_replaced: true,
+        size: Size { width: length(100.0), height: length(100.0) },
+        flex_shrink: 0.0,
+        ..style
+    };
+    let image = tree.new_leaf_with_context(style, content).unwrap();
+    let container = tree.new_with_children(Style::default(), &[image]).unwrap();
+    tree.compute_layout_with_measure(container, Size::MAX_CONTENT, measure).unwrap();
+    (tree.layout(image).unwrap().replaced_content_rect, tree.layout(container).unwrap().replaced_content_rect)
+}
+
+fn rect(left: f32, right: f32, top: f32, bottom: f32) -> Option<Rect<f32>> {
+    Some(Rect { left, right, top, bottom })
+}
+
+const WIDE_IMAGE: ReplacedContent = ReplacedContent::from_natural_size(200.0, 100.0);
+
+#[test]
+fn object_fit_sizes_the_content_within_the_content_box() {
+    let rect_for = |object_fit| content_rects(Style { object_fit, ..Default::default() }, WIDE_IMAGE).0;
+    assert_eq!(rect_for(ObjectFit::Fill), rect(0.0, 100.0, 0.0, 100.0));
+    assert_eq!(rect_for(ObjectFit::Contain), rect(0.0, 100.0, 25.0, 75.0));
+    assert_eq!(rect_for(ObjectFit::Cover), rect(-50.0, 150.0, 0.0, 100.0));
+    assert_eq!(rect_for(ObjectFit::None), rect(-50.0, 150.0, 0.0, 100.0));
+    assert_eq!(rect_for(ObjectFit::ScaleDown), rect(0.0, 100.0, 25.0, 75.0));
+
+    // Content that is smaller than the content box is not scaled up by `scale-down`
+    let small_image = ReplacedContent::from_natural_size(40.0, 20.0);
+    let style = Style { object_fit: ObjectFit::ScaleDown, ..Default::default() };
+    assert_eq!(content_rects(style, small_image).0, rect(30.0, 70.0, 40.0, 60.0));
+}
+
+#[test]
+fn object_position_places_the_content_within_the_content_box() {
+    let style = Style {
+        object_fit: ObjectFit::Contain,
+        object_position: Point { x: percent(0.0), y: percent(1.0) },
+        ..Default::default()
+    };
+    assert_eq!(content_rects(style, WIDE_IMAGE).0, rect(0.0, 100.0, 50.0, 100.0));
+
+    let style = Style {
+        object_fit: ObjectFit::Cover,
+        object_position: Point { x: length(10.0), y: length(0.0) },
+        ..Default::default()
+    };
+    assert_eq!(content_rects(style, WIDE_IMAGE).0, rect(10.0, 210.0, 0.0, 100.0));
+
+    // The rectangle is measured from the border box, and the content is placed within the content box
+    let style = Style {
+        object_fit: ObjectFit::Contain,
+        padding: Rect { left: length(10.0), right: length(10.0), top: length(10.0), bottom: length(30.0) },
+        ..Default::default()
+    };
+    assert_eq!(content_rects(style, WIDE_IMAGE).0, rect(10.0, 90.0, 20.0, 60.0));
+}
+
+#[test]
+fn only_replaced_nodes_have_a_content_rect() {
+    let (image, container) = content_rects(Style::default(), WIDE_IMAGE);
+    assert!(image.is_some());
+    assert_eq!(container, None);
+}
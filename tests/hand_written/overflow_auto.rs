@@ -0,0 +1,140 @@
+//! `Overflow::Auto` nodes only reserve space for a scrollbar if their content overflows them,
+//! and report the space that was reserved in `Layout::scrollbar_size`.
+#![cfg(feature = "content_size")]
+
+use taffy::geometry::Point;
+use taffy::prelude::*;
+use taffy::style::{Direction, Display, Overflow};
+use taffy_test_helpers::new_test_tree;
+
+const SCROLLBAR_WIDTH: f32 = 10.0;
+
+/// Lays out a 100x100 container with a single child of the given size and returns the layouts of the
+/// container and of the child
+fn layout_container(
+    display: Display,
+    direction: Direction,
+    overflow: Point<Overflow>,
+    child_size: Size<Dimension>,
+) -> (Layout, Layout) {
+    let mut tree = new_test_tree();
+    let child = tree.new_leaf(Style { flex_shrink: 0.0, size: child_size, ..Default::default() }).unwrap();
+    let node = tree
+        .new_with_children(
+            Style {
+                display,
+                direction,
+                flex_direction: FlexDirection::Column,
+                overflow,
+                scrollbar_width: SCROLLBAR_WIDTH,
+                size: Size { width: length(100.0), height: length(100.0) },
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+
+    tree.compute_layout(node, Size::MAX_CONTENT).unwrap();
+    (*tree.layout(node).unwrap(), *tree.layout(child).unwrap())
+}
+
+const AUTO_Y: Point<Overflow> = Point { x: Overflow::Visible, y: Overflow::Auto };
+const AUTO_BOTH: Point<Overflow> = Point { x: Overflow::Auto, y: Overflow::Auto };
+
+#[test]
+fn no_scrollbar_is_reserved_if_content_fits() {
+    for display in [Display::Block, Display::Flex, Display::Grid] {
+        let child_size = Size { width: auto(), height: length(50.0) };
+        let (container, child) = layout_container(display, Direction::Ltr, AUTO_Y, child_size);
+
+        assert_eq!(container.scrollbar_size, Size::ZERO, "{display:?}");
+        assert_eq!(child.size.width, 100.0, "{display:?}");
+    }
+}
+
+#[test]
+fn scrollbar_is_reserved_if_content_overflows() {
+    for display in [Display::Block, Display::Flex, Display::Grid] {
+        let child_size = Size { width: auto(), height: length(200.0) };
+        let (container, child) = layout_container(display, Direction::Ltr, AUTO_Y, child_size);
+
+        assert_eq!(container.scrollbar_size, Size { width: SCROLLBAR_WIDTH, height: 0.0 }, "{display:?}");
+        assert_eq!(child.size.width, 100.0 - SCROLLBAR_WIDTH, "{display:?}");
+        assert_eq!(child.location.x, 0.0, "{display:?}");
+    }
+}
+
+#[test]
+fn scrollbar_is_placed_on_the_left_in_rtl() {
+    for display in [Display::Block, Display::Flex, Display::Grid] {
+        let child_size = Size { width: auto(), height: length(200.0) };
+        let (container, child) = layout_container(display, Direction::Rtl, AUTO_Y, child_size);
+
+        assert_eq!(container.scrollbar_size, Size { width: SCROLLBAR_WIDTH, height: 0.0 }, "{display:?}");
+        assert_eq!(child.size.width, 100.0 - SCROLLBAR_WIDTH, "{display:?}");
+        assert_eq!(child.location.x, SCROLLBAR_WIDTH, "{display:?}");
+    }
+}
+
+#[test]
+fn scrollbar_in_one_axis_can_cause_overflow_in_the_other() {
+    for display in [Display::Block, Display::Flex, Display::Grid] {
+        // The child fits horizontally until the vertical scrollbar takes up 10px of the container's width
+        let child_size = Size { width: length(95.0), height: length(200.0) };
+        let (container, _) = layout_container(display, Direction::Ltr, AUTO_BOTH, child_size);
+
+        assert_eq!(container.scrollbar_size, Size { width: SCROLLBAR_WIDTH, height: SCROLLBAR_WIDTH }, "{display:?}");
+    }
+}
+
+#[test]
+fn scroll_always_reserves_a_scrollbar() {
+    for display in [Display::Block, Display::Flex, Display::Grid] {
+        let overflow = Point { x: Overflow::Visible, y: Overflow::Scroll };
+        let child_size = Size { width: auto(), height: length(50.0) };
+        let (container, child) = layout_container(display, Direction::Ltr, overflow, child_size);
+
+        assert_eq!(container.scrollbar_size, Size { width: SCROLLBAR_WIDTH, height: 0.0 }, "{display:?}");
+        assert_eq!(child.size.width, 100.0 - SCROLLBAR_WIDTH, "{display:?}");
+    }
+}
+
+#[test]
+fn auto_leaf_does_not_reserve_a_scrollbar() {
+    let mut tree = new_test_tree();
+    let node = tree
+        .new_leaf(Style {
+            overflow: AUTO_BOTH,
+            scrollbar_width: SCROLLBAR_WIDTH,
+            size: Size { width: length(100.0), height: length(100.0) },
+            ..Default::default()
+        })
+        .unwrap();
+
+    tree.compute_layout(node, Size::MAX_CONTENT).unwrap();
+    assert_eq!(tree.layout(node).unwrap().scrollbar_size, Size::ZERO);
+}
+
+#[test]
+fn auto_scrollbar_of_nested_item_is_reported() {
+    let mut tree = new_test_tree();
+    let content =
+        tree.new_leaf(Style { size: Size { width: auto(), height: length(200.0) }, ..Default::default() }).unwrap();
+    let scroller = tree
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                overflow: AUTO_Y,
+                scrollbar_width: SCROLLBAR_WIDTH,
+                size: Size { width: length(100.0), height: length(100.0) },
+                ..Default::default()
+            },
+            &[content],
+        )
+        .unwrap();
+    let root = tree.new_with_children(Style { display: Display::Flex, ..Default::default() }, &[scroller]).unwrap();
+
+    tree.compute_layout(root, Size::MAX_CONTENT).unwrap();
+    assert_eq!(tree.layout(scroller).unwrap().scrollbar_size, Size { width: SCROLLBAR_WIDTH, height: 0.0 });
+    assert_eq!(tree.layout(content).unwrap().size.width, 100.0 - SCROLLBAR_WIDTH);
+}
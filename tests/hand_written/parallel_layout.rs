@@ -0,0 +1,223 @@
+//! Tests for laying out independent subtrees in parallel (the `parallel` feature)
+use std::sync::Mutex;
+use taffy::prelude::*;
+use taffy::style::{Contain, Overflow};
+use taffy::Point;
+use taffy_test_helpers::{new_test_tree, test_measure_function, TestNodeContext, WritingMode};
+
+const TEXT: &str = "HHHH\u{200B}HHHHHH\u{200B}HH\u{200B}HHHHHHHHHHH\u{200B}HHH";
+
+fn panel_style(width: f32, height: f32) -> Style {
+    Style {
+        display: Display::Flex,
+        flex_direction: FlexDirection::Column,
+        flex_shrink: 0.0,
+        contain: Contain::LAYOUT,
+        size: Size { width: length(width), height: length(height) },
+        padding: Rect::length(5.0),
+        gap: Size { width: zero(), height: length(4.0) },
+        ..Default::default()
+    }
+}
+
+/// Builds a panel containing a header, some text, and a nested independent panel
+fn build_panel(taffy: &mut TaffyTree<TestNodeContext>, width: f32, height: f32) -> NodeId {
+    let header = taffy
+        .new_leaf(Style { size: Size { width: percent(1.0), height: length(20.0) }, ..Default::default() })
+        .unwrap();
+    let text = taffy
+        .new_leaf_with_context(Style::default(), TestNodeContext::ahem_text(TEXT.to_string(), WritingMode::Horizontal))
+        .unwrap();
+    let nested_text = taffy
+        .new_leaf_with_context(
+            Style { margin: Rect { top: length(3.0), ..Rect::zero() }, ..Default::default() },
+            TestNodeContext::ahem_text(TEXT.to_string(), WritingMode::Horizontal),
+        )
+        .unwrap();
+    let nested_panel = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                contain: Contain::LAYOUT,
+                overflow: Point { x: Overflow::Visible, y: Overflow::Scroll },
+                scrollbar_width: 6.0,
+                size: Size { width: length(width / 2.0), height: length(30.0) },
+                margin: Rect { left: auto(), right: length(0.0), top: length(2.0), bottom: length(0.0) },
+                ..Default::default()
+            },
+            &[nested_text],
+        )
+        .unwrap();
+    taffy.new_with_children(panel_style(width, height), &[header, text, nested_panel]).unwrap()
+}
+
+/// Builds a dashboard made up of a grid of independent panels, plus a flex row and a block column of panels
+fn build_dashboard(taffy: &mut TaffyTree<TestNodeContext>) -> NodeId {
+    let grid_panels: Vec<NodeId> = (0..6).map(|_| build_panel(taffy, 200.0, 150.0)).collect();
+    let grid = taffy
+        .new_with_children(
+            Style {
+                display: Display::Grid,
+                grid_template_columns: vec![length(200.0), length(200.0), length(200.0)],
+                grid_template_rows: vec![length(150.0), length(150.0)],
+                gap: Size { width: length(10.0), height: length(10.0) },
+                ..Default::default()
+            },
+            &grid_panels,
+        )
+        .unwrap();
+
+    let row_panels: Vec<NodeId> = (0..3).map(|i| build_panel(taffy, 100.0 + 20.0 * i as f32, 120.0)).collect();
+    let row = taffy
+        .new_with_children(
+            Style { display: Display::Flex, align_items: Some(AlignItems::BASELINE), ..Default::default() },
+            &row_panels,
+        )
+        .unwrap();
+
+    let column_panels: Vec<NodeId> = (0..3)
+        .map(|_| {
+            let panel = build_panel(taffy, 300.0, 100.0);
+            let mut style = taffy.style(panel).unwrap().clone();
+            style.display = Display::Block;
+            style.margin = Rect { top: length(12.0), bottom: length(8.0), ..Rect::zero() };
+            taffy.set_style(panel, style).unwrap();
+            panel
+        })
+        .collect();
+    let column =
+        taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &column_panels).unwrap();
+
+    taffy
+        .new_with_children(
+            Style {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                size: Size { width: length(640.0), height: auto() },
+                ..Default::default()
+            },
+            &[grid, row, column],
+        )
+        .unwrap()
+}
+
+fn collect_layouts(taffy: &TaffyTree<TestNodeContext>, node: NodeId, layouts: &mut Vec<Layout>) {
+    layouts.push(*taffy.layout(node).unwrap());
+    for child in taffy.children(node).unwrap() {
+        collect_layouts(taffy, child, layouts);
+    }
+}
+
+fn layouts(taffy: &TaffyTree<TestNodeContext>, root: NodeId) -> Vec<Layout> {
+    let mut layouts = Vec::new();
+    collect_layouts(taffy, root, &mut layouts);
+    layouts
+}
+
+#[test]
+fn parallel_layout_matches_sequential_layout() {
+    let mut sequential = new_test_tree();
+    let sequential_root = build_dashboard(&mut sequential);
+    sequential.compute_layout_with_measure(sequential_root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+    let mut parallel = new_test_tree();
+    let parallel_root = build_dashboard(&mut parallel);
+    parallel.compute_layout_with_measure_parallel(parallel_root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+    assert_eq!(layouts(&parallel, parallel_root), layouts(&sequential, sequential_root));
+}
+
+#[test]
+fn parallel_relayout_matches_sequential_relayout() {
+    let mut sequential = new_test_tree();
+    let sequential_root = build_dashboard(&mut sequential);
+    let mut parallel = new_test_tree();
+    let parallel_root = build_dashboard(&mut parallel);
+
+    let measure = test_measure_function;
+    sequential.compute_layout_with_measure(sequential_root, Size::MAX_CONTENT, measure).unwrap();
+    parallel.compute_layout_with_measure_parallel(parallel_root, Size::MAX_CONTENT, measure).unwrap();
+
+    // Resize one of the grid's panels, which changes its contents but not the grid's tracks
+    for (taffy, root) in [(&mut sequential, sequential_root), (&mut parallel, parallel_root)] {
+        let grid = taffy.child_at_index(root, 0).unwrap();
+        let panel = taffy.child_at_index(grid, 4).unwrap();
+        let mut style = taffy.style(panel).unwrap().clone();
+        style.size.width = length(120.0);
+        taffy.set_style(panel, style).unwrap();
+    }
+    sequential.compute_layout_with_measure(sequential_root, Size::MAX_CONTENT, measure).unwrap();
+    parallel.compute_layout_with_measure_parallel(parallel_root, Size::MAX_CONTENT, measure).unwrap();
+
+    assert_eq!(layouts(&parallel, parallel_root), layouts(&sequential, sequential_root));
+}
+
+#[test]
+fn parallel_layout_measures_with_original_node_ids() {
+    let mut taffy = new_test_tree();
+    let root = build_dashboard(&mut taffy);
+    let measured_nodes = Mutex::new(Vec::new());
+    taffy
+        .compute_layout_with_measure_parallel(root, Size::MAX_CONTENT, |inputs, node_id, context, style| {
+            if context.is_some() {
+                measured_nodes.lock().unwrap().push(node_id);
+            }
+            test_measure_function(inputs, node_id, context, style)
+        })
+        .unwrap();
+
+    let measured_nodes = measured_nodes.into_inner().unwrap();
+    assert!(!measured_nodes.is_empty());
+    for node_id in measured_nodes {
+        assert!(taffy.get_node_context(node_id).is_some());
+        assert_eq!(taffy.child_count(node_id), 0);
+    }
+}
+
+#[test]
+fn parallel_layout_preserves_node_contexts() {
+    let mut taffy = new_test_tree();
+    let root = build_dashboard(&mut taffy);
+    taffy.compute_layout_parallel(root, Size::MAX_CONTENT).unwrap();
+
+    let grid = taffy.child_at_index(root, 0).unwrap();
+    let panel = taffy.child_at_index(grid, 0).unwrap();
+    let text = taffy.child_at_index(panel, 1).unwrap();
+    assert!(taffy.get_node_context(text).is_some());
+    assert!(taffy.get_node_context(panel).is_none());
+    assert!(!taffy.dirty(panel).unwrap());
+}
+
+/// Builds a fixed-size root containing an independent panel whose children are wrapped in a `display: contents` node
+fn build_panel_with_contents(taffy: &mut TaffyTree<TestNodeContext>) -> NodeId {
+    let leaf_style = Style { size: Size { width: length(50.0), height: length(30.0) }, ..Default::default() };
+    let first = taffy.new_leaf(leaf_style.clone()).unwrap();
+    let second = taffy.new_leaf(leaf_style).unwrap();
+    let contents =
+        taffy.new_with_children(Style { display: Display::Contents, ..Default::default() }, &[first, second]);
+    let panel = taffy.new_with_children(panel_style(200.0, 150.0), &[contents.unwrap()]).unwrap();
+    taffy
+        .new_with_children(
+            Style { size: Size { width: length(400.0), height: length(400.0) }, ..Default::default() },
+            &[panel],
+        )
+        .unwrap()
+}
+
+#[test]
+fn parallel_layout_splices_display_contents_children() {
+    let mut sequential = new_test_tree();
+    let sequential_root = build_panel_with_contents(&mut sequential);
+    sequential.compute_layout_with_measure(sequential_root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+    let mut parallel = new_test_tree();
+    let parallel_root = build_panel_with_contents(&mut parallel);
+    parallel.compute_layout_with_measure_parallel(parallel_root, Size::MAX_CONTENT, test_measure_function).unwrap();
+    assert_eq!(layouts(&parallel, parallel_root), layouts(&sequential, sequential_root));
+
+    // Laying out again uses the children spliced in by the previous layout
+    let panel = parallel.child_at_index(parallel_root, 0).unwrap();
+    parallel.mark_dirty(panel).unwrap();
+    parallel.compute_layout_with_measure_parallel(parallel_root, Size::MAX_CONTENT, test_measure_function).unwrap();
+    assert_eq!(layouts(&parallel, parallel_root), layouts(&sequential, sequential_root));
+}
@@ -0,0 +1,106 @@
+//! Percentage gaps contribute zero to the intrinsic size of a container whose size is indefinite, and are then
+//! resolved against the size that was determined for it when laying out its children.
+
+use taffy::geometry::Point;
+use taffy::prelude::*;
+
+/// Lays out `container` (holding `count` 20x10 children) with the given available space, and returns the locations
+/// of its children along with its size
+fn layout_children(
+    container: Style,
+    count: usize,
+    available_space: Size<AvailableSpace>,
+) -> (Vec<Point<f32>>, Size<f32>) {
+    let mut tree: TaffyTree<()> = TaffyTree::new();
+    let children: Vec<NodeId> = (0..count)
+        .map(|_| {
+            tree.new_leaf(Style {
+                size: Size { width: length(20.0), height: length(10.0) },
+                flex_shrink: 0.0,
+                ..Default::default()
+            })
+            .unwrap()
+        })
+        .collect();
+    let container = tree.new_with_children(container, &children).unwrap();
+    tree.compute_layout(container, available_space).unwrap();
+
+    let locations = children.iter().map(|&child| tree.layout(child).unwrap().location).collect();
+    (locations, tree.layout(container).unwrap().size)
+}
+
+fn definite_width(width: f32) -> Size<AvailableSpace> {
+    Size { width: AvailableSpace::Definite(width), height: AvailableSpace::MaxContent }
+}
+
+#[test]
+fn percentage_gaps_do_not_contribute_to_intrinsic_size() {
+    let flex = Style { gap: Size { width: percent(0.5), height: zero() }, ..Default::default() };
+    let (locations, size) = layout_children(flex, 3, Size::MAX_CONTENT);
+    assert_eq!(size, Size { width: 60.0, height: 10.0 });
+    assert_eq!(locations.iter().map(|location| location.x).collect::<Vec<_>>(), [0.0, 50.0, 100.0]);
+
+    let grid = Style {
+        display: Display::Grid,
+        grid_template_rows: vec![length(10.0), length(10.0)],
+        gap: Size { width: zero(), height: percent(0.5) },
+        ..Default::default()
+    };
+    let (locations, size) = layout_children(grid, 2, Size::MAX_CONTENT);
+    assert_eq!(size, Size { width: 20.0, height: 20.0 });
+    assert_eq!(locations.iter().map(|location| location.y).collect::<Vec<_>>(), [0.0, 20.0]);
+}
+
+#[test]
+fn flex_wrap_lines_are_collected_with_the_resolved_gap() {
+    let flex =
+        Style { flex_wrap: FlexWrap::Wrap, gap: Size { width: percent(0.1), height: zero() }, ..Default::default() };
+    let (locations, size) = layout_children(flex.clone(), 5, definite_width(100.0));
+    assert_eq!(size, Size { width: 100.0, height: 20.0 });
+    assert_eq!(
+        locations,
+        [
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 30.0, y: 0.0 },
+            Point { x: 60.0, y: 0.0 },
+            Point { x: 0.0, y: 10.0 },
+            Point { x: 30.0, y: 10.0 },
+        ]
+    );
+
+    // The same layout is produced when the container's width is known up front
+    let sized_flex = Style { size: Size { width: length(100.0), height: auto() }, ..flex };
+    assert_eq!(layout_children(sized_flex, 5, definite_width(100.0)), (locations, size));
+}
+
+#[test]
+fn grid_column_gaps_resolve_against_the_determined_width() {
+    let grid = Style {
+        display: Display::Grid,
+        grid_template_columns: vec![minmax(length(0.0), fr(1.0)), minmax(length(0.0), fr(1.0))],
+        gap: Size { width: percent(0.1), height: zero() },
+        ..Default::default()
+    };
+    // The container is sized to fit the 20px wide items, and the columns then shrink to make room for the 4px gap
+    let (locations, size) = layout_children(grid.clone(), 2, definite_width(200.0));
+    assert_eq!(size, Size { width: 40.0, height: 10.0 });
+    assert_eq!(locations, [Point { x: 0.0, y: 0.0 }, Point { x: 22.0, y: 0.0 }]);
+
+    // The same layout is produced when the container's width is known up front
+    let sized_grid = Style { size: Size { width: length(40.0), height: auto() }, ..grid };
+    assert_eq!(layout_children(sized_grid, 2, definite_width(200.0)), (locations, size));
+}
+
+#[test]
+fn grid_row_gaps_resolve_regardless_of_available_space() {
+    let grid = Style {
+        display: Display::Grid,
+        grid_template_rows: vec![length(10.0), length(10.0)],
+        gap: Size { width: zero(), height: percent(0.5) },
+        ..Default::default()
+    };
+    let definite_height = Size { width: AvailableSpace::MaxContent, height: AvailableSpace::Definite(500.0) };
+    let expected = (vec![Point { x: 0.0, y: 0.0 }, Point { x: 0.0, y: 20.0 }], Size { width: 20.0, height: 20.0 });
+    assert_eq!(layout_children(grid.clone(), 2, Size::MAX_CONTENT), expected);
+    assert_eq!(layout_children(grid, 2, definite_height), expected);
+}
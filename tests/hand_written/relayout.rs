@@ -1,5 +1,7 @@
 use taffy::prelude::*;
-use taffy_test_helpers::new_test_tree;
+use taffy::style::{Contain, Overflow};
+use taffy::Point;
+use taffy_test_helpers::{new_test_tree, test_measure_function, TestNodeContext, WritingMode};
 
 #[test]
 fn relayout() {
@@ -421,3 +423,273 @@ fn relayout_is_stable_with_rounding() {
         assert_eq!(initial_inner_layout.size.height, inner_layout.size.height);
     }
 }
+
+/// Builds a root containing a measured sibling and a fixed-size panel with layout containment, which is a relayout
+/// boundary. Returns `(root, sibling, panel, panel_text)`.
+fn build_tree_with_relayout_boundary(
+    taffy: &mut TaffyTree<TestNodeContext>,
+    panel_style: Style,
+) -> (NodeId, NodeId, NodeId, NodeId) {
+    let sibling = taffy
+        .new_leaf_with_context(
+            Style::default(),
+            TestNodeContext::ahem_text("HH HH".to_string(), WritingMode::Horizontal),
+        )
+        .unwrap();
+    let panel_text = taffy
+        .new_leaf_with_context(Style::default(), TestNodeContext::ahem_text("HHH".to_string(), WritingMode::Horizontal))
+        .unwrap();
+    let wrapper =
+        taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[panel_text]).unwrap();
+    let panel = taffy.new_with_children(panel_style, &[wrapper]).unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Flex,
+                flex_direction: FlexDirection::Column,
+                size: Size { width: length(400.), height: auto() },
+                ..Default::default()
+            },
+            &[sibling, panel],
+        )
+        .unwrap();
+    (root, sibling, panel, panel_text)
+}
+
+fn relayout_boundary_style() -> Style {
+    Style {
+        display: Display::Block,
+        contain: Contain::LAYOUT,
+        overflow: Point { x: Overflow::Visible, y: Overflow::Scroll },
+        size: Size { width: length(200.), height: length(100.) },
+        ..Default::default()
+    }
+}
+
+fn measure_count(taffy: &TaffyTree<TestNodeContext>, node: NodeId) -> usize {
+    taffy.get_node_context(node).unwrap().count
+}
+
+#[test]
+fn mark_dirty_stops_at_relayout_boundary() {
+    let mut taffy = new_test_tree();
+    let (root, sibling, panel, panel_text) = build_tree_with_relayout_boundary(&mut taffy, relayout_boundary_style());
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+    taffy.mark_dirty(panel_text).unwrap();
+    assert!(taffy.dirty(panel_text).unwrap());
+    assert!(taffy.dirty(panel).unwrap());
+    assert!(!taffy.dirty(root).unwrap());
+
+    let sibling_measure_count = measure_count(&taffy, sibling);
+    let panel_text_measure_count = measure_count(&taffy, panel_text);
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+    assert!(!taffy.dirty(panel).unwrap());
+    assert_eq!(measure_count(&taffy, sibling), sibling_measure_count);
+    assert!(measure_count(&taffy, panel_text) > panel_text_measure_count);
+}
+
+#[test]
+fn relayout_boundary_layout_matches_full_layout() {
+    let mut taffy = new_test_tree();
+    let (root, _, panel, panel_text) = build_tree_with_relayout_boundary(&mut taffy, relayout_boundary_style());
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+    // Change the panel's contents and add a new child to it
+    let text = TestNodeContext::ahem_text("HHHHHHHHHHHHHHH HHHHHHHHHHHHHHH".to_string(), WritingMode::Horizontal);
+    taffy.set_node_context(panel_text, Some(text.clone())).unwrap();
+    let extra = taffy.new_leaf(Style { size: Size { width: length(50.), height: length(150.) }, ..Default::default() });
+    taffy.add_child(panel, extra.unwrap()).unwrap();
+    assert!(!taffy.dirty(root).unwrap());
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+    // Lay out an identical tree from scratch
+    let mut expected_taffy = new_test_tree();
+    let (expected_root, _, expected_panel, expected_panel_text) =
+        build_tree_with_relayout_boundary(&mut expected_taffy, relayout_boundary_style());
+    expected_taffy.set_node_context(expected_panel_text, Some(text)).unwrap();
+    let expected_extra = expected_taffy
+        .new_leaf(Style { size: Size { width: length(50.), height: length(150.) }, ..Default::default() });
+    expected_taffy.add_child(expected_panel, expected_extra.unwrap()).unwrap();
+    expected_taffy.compute_layout_with_measure(expected_root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+    assert_eq!(taffy.layout(panel_text).unwrap(), expected_taffy.layout(expected_panel_text).unwrap());
+    assert_eq!(taffy.layout(panel).unwrap(), expected_taffy.layout(expected_panel).unwrap());
+    assert_eq!(taffy.layout(root).unwrap(), expected_taffy.layout(expected_root).unwrap());
+}
+
+#[test]
+fn changing_relayout_boundary_style_marks_ancestors_dirty() {
+    let mut taffy = new_test_tree();
+    let (root, _, panel, panel_text) = build_tree_with_relayout_boundary(&mut taffy, relayout_boundary_style());
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+    // Dirtying the contents first must not stop the boundary's own change from reaching its ancestors
+    taffy.mark_dirty(panel_text).unwrap();
+    let mut style = taffy.style(panel).unwrap().clone();
+    style.size.height = length(140.);
+    taffy.set_style(panel, style).unwrap();
+    assert!(taffy.dirty(root).unwrap());
+
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+    assert_eq!(taffy.layout(root).unwrap().size.height, taffy.layout(panel).unwrap().location.y + 140.);
+}
+
+#[test]
+fn contained_node_without_fixed_size_is_not_relayout_boundary() {
+    let mut taffy = new_test_tree();
+    let panel_style = Style { size: Size { width: length(200.), height: auto() }, ..relayout_boundary_style() };
+    let (root, _, _, panel_text) = build_tree_with_relayout_boundary(&mut taffy, panel_style);
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+    taffy.mark_dirty(panel_text).unwrap();
+    assert!(taffy.dirty(root).unwrap());
+}
+
+#[test]
+fn flex_item_with_content_based_minimum_size_is_not_relayout_boundary() {
+    let mut taffy = new_test_tree();
+    // The panel is a flex item in a column, so its automatic minimum height depends on its contents
+    let panel_style =
+        Style { overflow: Point { x: Overflow::Scroll, y: Overflow::Visible }, ..relayout_boundary_style() };
+    let (root, _, _, panel_text) = build_tree_with_relayout_boundary(&mut taffy, panel_style);
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+    taffy.mark_dirty(panel_text).unwrap();
+    assert!(taffy.dirty(root).unwrap());
+}
+
+/// Builds a root containing a measured sibling with a fractional size and a scrollable panel whose contents are
+/// measured. Returns `(root, sibling, panel, panel_text)`.
+fn build_tree_with_scroll_panel(
+    taffy: &mut TaffyTree<TestNodeContext>,
+    panel_size: Size<Dimension>,
+) -> (NodeId, NodeId, NodeId, NodeId) {
+    let sibling = taffy
+        .new_leaf_with_context(
+            Style { size: Size { width: auto(), height: length(10.3) }, ..Default::default() },
+            TestNodeContext::ahem_text("HH HH".to_string(), WritingMode::Horizontal),
+        )
+        .unwrap();
+    let panel_text = taffy
+        .new_leaf_with_context(Style::default(), TestNodeContext::ahem_text("HHH".to_string(), WritingMode::Horizontal))
+        .unwrap();
+    let panel = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                overflow: Point { x: Overflow::Visible, y: Overflow::Scroll },
+                padding: Rect::length(2.6),
+                size: panel_size,
+                ..Default::default()
+            },
+            &[panel_text],
+        )
+        .unwrap();
+    let root = taffy
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                padding: Rect::length(0.4),
+                size: Size { width: length(400.), height: auto() },
+                ..Default::default()
+            },
+            &[sibling, panel],
+        )
+        .unwrap();
+    (root, sibling, panel, panel_text)
+}
+
+#[test]
+fn compute_layout_subtree_matches_full_layout() {
+    let panel_size = Size { width: length(200.), height: length(100.) };
+    let mut taffy = new_test_tree();
+    let (root, sibling, panel, panel_text) = build_tree_with_scroll_panel(&mut taffy, panel_size);
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+    let text = TestNodeContext::ahem_text("HHHHHHHHHHHHHHH HHHHHHHHHHHHHHH".to_string(), WritingMode::Horizontal);
+    taffy.set_node_context(panel_text, Some(text.clone())).unwrap();
+    let sibling_measure_count = measure_count(&taffy, sibling);
+    let size_changed = taffy.compute_layout_subtree_with_measure(panel, test_measure_function).unwrap();
+    assert!(!size_changed);
+    assert_eq!(measure_count(&taffy, sibling), sibling_measure_count);
+
+    let mut expected_taffy = new_test_tree();
+    let (expected_root, _, expected_panel, expected_panel_text) =
+        build_tree_with_scroll_panel(&mut expected_taffy, panel_size);
+    expected_taffy.set_node_context(expected_panel_text, Some(text)).unwrap();
+    expected_taffy.compute_layout_with_measure(expected_root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+    assert_eq!(taffy.layout(panel).unwrap(), expected_taffy.layout(expected_panel).unwrap());
+    assert_eq!(taffy.layout(panel_text).unwrap(), expected_taffy.layout(expected_panel_text).unwrap());
+    assert_eq!(taffy.unrounded_layout(panel_text), expected_taffy.unrounded_layout(expected_panel_text));
+}
+
+#[test]
+fn compute_layout_subtree_reports_size_change() {
+    let mut taffy = new_test_tree();
+    let (root, _, panel, panel_text) = build_tree_with_scroll_panel(&mut taffy, Size::auto());
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+    // The panel's height depends on its contents, as its block container parent only determines its width
+    taffy.set_style(panel_text, Style { margin: Rect::length(1.0), ..Default::default() }).unwrap();
+    let size_changed = taffy.compute_layout_subtree_with_measure(panel, test_measure_function).unwrap();
+    assert!(size_changed);
+    assert_eq!(taffy.unrounded_layout(panel).size.height, 10. + 2. * (2.6 + 1.0));
+
+    // Laying out the root again updates the ancestors with the new size
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+    assert!(!taffy.compute_layout_subtree_with_measure(panel, test_measure_function).unwrap());
+}
+
+#[test]
+fn compute_layout_subtree_requires_previous_layout() {
+    let mut taffy: TaffyTree<()> = TaffyTree::new();
+    let node = taffy.new_leaf(Style::default()).unwrap();
+    assert_eq!(taffy.final_layout_inputs(node), None);
+    assert_eq!(taffy.compute_layout_subtree(node), Err(taffy::TaffyError::NodeNotLaidOut(node)));
+
+    taffy.compute_layout(node, Size::MAX_CONTENT).unwrap();
+    assert!(taffy.final_layout_inputs(node).is_some());
+    assert_eq!(taffy.compute_layout_subtree(node), Ok(false));
+    // A removed node is not in the tree
+    taffy.remove(node).unwrap();
+    assert_eq!(taffy.final_layout_inputs(node), None);
+    assert_eq!(taffy.compute_layout_subtree(node), Err(taffy::TaffyError::InvalidInputNode(node)));
+}
+
+#[test]
+fn relayout_boundary_scrollbar_matches_full_layout() {
+    fn build_tree(taffy: &mut TaffyTree<TestNodeContext>) -> (NodeId, NodeId, NodeId) {
+        let content = taffy.new_leaf(Style { size: Size::from_lengths(50., 10.), ..Default::default() }).unwrap();
+        let panel_style = Style {
+            overflow: Point { x: Overflow::Visible, y: Overflow::Auto },
+            scrollbar_width: 10.,
+            size: Size::from_lengths(100., 100.),
+            ..relayout_boundary_style()
+        };
+        let panel = taffy.new_with_children(panel_style, &[content]).unwrap();
+        let root = taffy.new_with_children(Style { display: Display::Block, ..Default::default() }, &[panel]).unwrap();
+        (root, panel, content)
+    }
+
+    let mut taffy = new_test_tree();
+    let (root, panel, content) = build_tree(&mut taffy);
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+    assert_eq!(taffy.layout(panel).unwrap().scrollbar_size, Size::zero());
+
+    // The content now overflows the panel, which makes it show a vertical scrollbar
+    let grown_style = Style { size: Size::from_lengths(50., 500.), ..Default::default() };
+    taffy.set_style(content, grown_style.clone()).unwrap();
+    assert!(!taffy.dirty(root).unwrap());
+    taffy.compute_layout_with_measure(root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+    let mut expected_taffy = new_test_tree();
+    let (expected_root, expected_panel, expected_content) = build_tree(&mut expected_taffy);
+    expected_taffy.set_style(expected_content, grown_style).unwrap();
+    expected_taffy.compute_layout_with_measure(expected_root, Size::MAX_CONTENT, test_measure_function).unwrap();
+
+    assert_eq!(taffy.layout(panel).unwrap().scrollbar_size, Size { width: 10., height: 0. });
+    assert_eq!(taffy.layout(panel).unwrap(), expected_taffy.layout(expected_panel).unwrap());
+    assert_eq!(taffy.layout(content).unwrap(), expected_taffy.layout(expected_content).unwrap());
+}
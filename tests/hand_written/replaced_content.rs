@@ -0,0 +1,121 @@
+//! Replaced leaves (such as images) are sized from the natural dimensions of their content using the CSS sizing rules
+//! for replaced elements.
+
+use taffy::geometry::Point;
+use taffy::prelude::*;
+use taffy::tree::{LayoutInput, LayoutOutput};
+use taffy::{compute_leaf_layout, compute_replaced_leaf_layout, AspectRatio, Overflow, ReplacedContent};
+
+fn measure(inputs: LayoutInput, _: NodeId, content: Option<&mut ReplacedContent>, style: &Style) -> LayoutOutput {
+    match content {
+        Some(content) => compute_replaced_leaf_layout(inputs, style, |_, _| 0.0, *content),
+        None => compute_leaf_layout(inputs, style, |_, _| 0.0, |_, _| Size::ZERO),
+    }
+}
+
+/// Lays out a replaced node with the given style and content as a child of a block container with the given width,
+/// and returns its size
+fn replaced_size(style: Style, content: ReplacedContent, container_width: AvailableSpace) -> Size<f32> {
+    let mut tree: TaffyTree<ReplacedContent> = TaffyTree::new();
+    let image = tree.new_leaf_with_context(Style { item_is_replaced: true, ..style }, content).unwrap();
+    let container = tree.new_with_children(Style { display: Display::Block, ..Default::default() }, &[image]).unwrap();
+    tree.compute_layout_with_measure(
+        container,
+        Size { width: container_width, height: AvailableSpace::MaxContent },
+        measure,
+    )
+    .unwrap();
+    tree.layout(image).unwrap().size
+}
+
+fn size(width: f32, height: f32) -> Size<f32> {
+    Size { width, height }
+}
+
+const IMAGE: ReplacedContent = ReplacedContent::from_natural_size(200.0, 100.0);
+const WIDE: AvailableSpace = AvailableSpace::Definite(500.0);
+
+#[test]
+fn auto_sizes_come_from_the_natural_size_and_ratio() {
+    assert_eq!(replaced_size(Style::default(), IMAGE, WIDE), size(200.0, 100.0));
+
+    let width = Style { size: Size { width: length(100.0), height: auto() }, ..Default::default() };
+    assert_eq!(replaced_size(width, IMAGE, WIDE), size(100.0, 50.0));
+
+    let height = Style { size: Size { width: auto(), height: length(200.0) }, ..Default::default() };
+    assert_eq!(replaced_size(height, IMAGE, WIDE), size(400.0, 200.0));
+
+    // A non-`auto` aspect ratio overrides the natural aspect ratio
+    let square = Style { aspect_ratio: AspectRatio::ratio(1.0), ..Default::default() };
+    assert_eq!(replaced_size(square, IMAGE, WIDE), size(200.0, 200.0));
+}
+
+#[test]
+fn missing_natural_dimensions_fall_back_to_300x150() {
+    assert_eq!(replaced_size(Style::default(), ReplacedContent::default(), WIDE), size(300.0, 150.0));
+
+    let natural_width =
+        ReplacedContent { natural_size: Size { width: Some(200.0), height: None }, natural_ratio: None };
+    assert_eq!(replaced_size(Style::default(), natural_width, WIDE), size(200.0, 150.0));
+
+    // Content with only a natural aspect ratio fills the available width, if it is definite
+    let ratio = ReplacedContent::from_natural_ratio(2.0);
+    assert_eq!(replaced_size(Style::default(), ratio, WIDE), size(500.0, 250.0));
+    assert_eq!(replaced_size(Style::default(), ratio, AvailableSpace::MaxContent), size(300.0, 150.0));
+}
+
+#[test]
+fn min_and_max_sizes_preserve_the_aspect_ratio() {
+    let max_width = Style { max_size: Size { width: length(100.0), height: auto() }, ..Default::default() };
+    assert_eq!(replaced_size(max_width, IMAGE, WIDE), size(100.0, 50.0));
+
+    // Both maximums are violated, and the height must shrink the most
+    let max_size = Style { max_size: Size { width: length(100.0), height: length(30.0) }, ..Default::default() };
+    assert_eq!(replaced_size(max_size, IMAGE, WIDE), size(60.0, 30.0));
+
+    // The ratio can not be preserved when it conflicts with a maximum in the other axis
+    let min_width_max_height = Style {
+        min_size: Size { width: length(300.0), height: auto() },
+        max_size: Size { width: auto(), height: length(120.0) },
+        ..Default::default()
+    };
+    assert_eq!(replaced_size(min_width_max_height, IMAGE, WIDE), size(300.0, 120.0));
+
+    // A definite size is clamped before it is transferred to the other axis
+    let width = Style {
+        size: Size { width: length(300.0), height: auto() },
+        max_size: Size { width: length(100.0), height: auto() },
+        ..Default::default()
+    };
+    assert_eq!(replaced_size(width, IMAGE, WIDE), size(100.0, 50.0));
+}
+
+#[test]
+fn natural_sizes_are_content_box_sizes() {
+    let padding = Rect { left: length(10.0), right: length(10.0), top: length(10.0), bottom: length(10.0) };
+    let style = Style { padding, ..Default::default() };
+    assert_eq!(replaced_size(style.clone(), IMAGE, WIDE), size(220.0, 120.0));
+
+    let width = Style { size: Size { width: length(120.0), height: auto() }, ..style };
+    assert_eq!(replaced_size(width, IMAGE, WIDE), size(120.0, 70.0));
+}
+
+#[test]
+fn scrollbar_gutters_are_added_to_natural_sizes() {
+    let scroll = Style {
+        overflow: Point { x: Overflow::Scroll, y: Overflow::Scroll },
+        scrollbar_width: 15.0,
+        ..Default::default()
+    };
+    assert_eq!(replaced_size(scroll.clone(), IMAGE, WIDE), size(215.0, 115.0));
+
+    // The same as a measured leaf with the same content size
+    let mut tree: TaffyTree<ReplacedContent> = TaffyTree::new();
+    let leaf = tree.new_leaf(scroll).unwrap();
+    let container = tree.new_with_children(Style { display: Display::Block, ..Default::default() }, &[leaf]).unwrap();
+    tree.compute_layout_with_measure(container, Size::MAX_CONTENT, |inputs, _, _, style| {
+        compute_leaf_layout(inputs, style, |_, _| 0.0, |_, _| Size { width: 200.0, height: 100.0 })
+    })
+    .unwrap();
+    assert_eq!(tree.layout(leaf).unwrap().size, size(215.0, 115.0));
+}
@@ -0,0 +1,134 @@
+//! `Display::Ruby` containers lay out their children in pairs of a ruby base and a `Display::RubyText` annotation,
+//! which is placed above the base.
+
+use taffy::geometry::Point;
+use taffy::prelude::*;
+use taffy::{compute_leaf_layout_with_baselines, Baselines, Direction, LayoutInput, LayoutOutput, RubyAlign};
+
+/// The size of a run of text and the offset of its baseline from its top edge
+#[derive(Debug, Clone, Copy)]
+struct Text {
+    size: Size<f32>,
+    baseline: f32,
+}
+
+fn measure_function(inputs: LayoutInput, _node_id: NodeId, context: Option<&mut Text>, style: &Style) -> LayoutOutput {
+    compute_leaf_layout_with_baselines(
+        inputs,
+        style,
+        |_, _| 0.0,
+        |_, _| match context {
+            Some(text) => {
+                let baselines = Baselines { first: Some(text.baseline), last: Some(text.baseline) };
+                (text.size, Point { x: Baselines::NONE, y: baselines })
+            }
+            None => (Size::ZERO, Point { x: Baselines::NONE, y: Baselines::NONE }),
+        },
+    )
+}
+
+/// A ruby base with a 16px tall font
+fn base(tree: &mut TaffyTree<Text>, width: f32) -> NodeId {
+    let text = Text { size: Size { width, height: 20.0 }, baseline: 16.0 };
+    tree.new_leaf_with_context(Style { display: Display::Block, ..Default::default() }, text).unwrap()
+}
+
+/// A ruby annotation with an 8px tall font
+fn annotation(tree: &mut TaffyTree<Text>, width: f32) -> NodeId {
+    let text = Text { size: Size { width, height: 10.0 }, baseline: 8.0 };
+    tree.new_leaf_with_context(Style { display: Display::RubyText, ..Default::default() }, text).unwrap()
+}
+
+fn ruby(tree: &mut TaffyTree<Text>, style: Style, children: &[NodeId]) -> NodeId {
+    tree.new_with_children(Style { display: Display::Ruby, ..style }, children).unwrap()
+}
+
+fn layout(tree: &TaffyTree<Text>, node: NodeId) -> (Point<f32>, Size<f32>) {
+    let layout = tree.layout(node).unwrap();
+    (layout.location, layout.size)
+}
+
+#[test]
+fn annotation_is_placed_above_its_base() {
+    let mut tree: TaffyTree<Text> = TaffyTree::new();
+    let rb = base(&mut tree, 60.0);
+    let rt = annotation(&mut tree, 20.0);
+    let container = ruby(&mut tree, Style { ruby_align: RubyAlign::Center, ..Default::default() }, &[rb, rt]);
+    tree.compute_layout_with_measure(container, Size::MAX_CONTENT, measure_function).unwrap();
+
+    assert_eq!(layout(&tree, rt), (Point { x: 20.0, y: 0.0 }, Size { width: 20.0, height: 10.0 }));
+    assert_eq!(layout(&tree, rb), (Point { x: 0.0, y: 10.0 }, Size { width: 60.0, height: 20.0 }));
+    assert_eq!(tree.layout(container).unwrap().size, Size { width: 60.0, height: 30.0 });
+}
+
+#[test]
+fn pair_is_as_wide_as_its_wider_half() {
+    for (ruby_align, base_x) in [(RubyAlign::Start, 0.0), (RubyAlign::Center, 10.0)] {
+        let mut tree: TaffyTree<Text> = TaffyTree::new();
+        let rb = base(&mut tree, 20.0);
+        let rt = annotation(&mut tree, 40.0);
+        let container = ruby(&mut tree, Style { ruby_align, ..Default::default() }, &[rb, rt]);
+        tree.compute_layout_with_measure(container, Size::MAX_CONTENT, measure_function).unwrap();
+
+        assert_eq!(layout(&tree, rt), (Point { x: 0.0, y: 0.0 }, Size { width: 40.0, height: 10.0 }), "{ruby_align:?}");
+        assert_eq!(layout(&tree, rb).0, Point { x: base_x, y: 10.0 }, "{ruby_align:?}");
+        assert_eq!(tree.layout(container).unwrap().size, Size { width: 40.0, height: 30.0 }, "{ruby_align:?}");
+    }
+}
+
+#[test]
+fn space_distribution_stretches_the_narrower_half() {
+    for ruby_align in [RubyAlign::SpaceAround, RubyAlign::SpaceBetween] {
+        let mut tree: TaffyTree<Text> = TaffyTree::new();
+        let rb = base(&mut tree, 60.0);
+        let rt = annotation(&mut tree, 20.0);
+        let container = ruby(&mut tree, Style { ruby_align, ..Default::default() }, &[rb, rt]);
+        tree.compute_layout_with_measure(container, Size::MAX_CONTENT, measure_function).unwrap();
+
+        assert_eq!(layout(&tree, rt), (Point { x: 0.0, y: 0.0 }, Size { width: 60.0, height: 10.0 }), "{ruby_align:?}");
+    }
+}
+
+#[test]
+fn pairs_are_placed_side_by_side() {
+    for direction in [Direction::Ltr, Direction::Rtl] {
+        let mut tree: TaffyTree<Text> = TaffyTree::new();
+        let first_base = base(&mut tree, 30.0);
+        let first_annotation = annotation(&mut tree, 30.0);
+        // A base without an annotation
+        let second_base = base(&mut tree, 20.0);
+        let third_base = base(&mut tree, 40.0);
+        let third_annotation = annotation(&mut tree, 40.0);
+        let style = Style { direction, padding: length(5.0), ..Default::default() };
+        let children = [first_base, first_annotation, second_base, third_base, third_annotation];
+        let container = ruby(&mut tree, style, &children);
+        tree.compute_layout_with_measure(container, Size::MAX_CONTENT, measure_function).unwrap();
+
+        let x = |ltr_x: f32, width: f32| if direction == Direction::Ltr { ltr_x } else { 100.0 - ltr_x - width };
+        assert_eq!(layout(&tree, first_base).0, Point { x: x(5.0, 30.0), y: 15.0 }, "{direction:?}");
+        assert_eq!(layout(&tree, first_annotation).0, Point { x: x(5.0, 30.0), y: 5.0 }, "{direction:?}");
+        assert_eq!(layout(&tree, second_base).0, Point { x: x(35.0, 20.0), y: 15.0 }, "{direction:?}");
+        assert_eq!(layout(&tree, third_base).0, Point { x: x(55.0, 40.0), y: 15.0 }, "{direction:?}");
+        assert_eq!(layout(&tree, third_annotation).0, Point { x: x(55.0, 40.0), y: 5.0 }, "{direction:?}");
+        assert_eq!(tree.layout(container).unwrap().size, Size { width: 100.0, height: 40.0 }, "{direction:?}");
+    }
+}
+
+#[test]
+fn ruby_is_aligned_by_the_baseline_of_its_bases_in_a_line() {
+    let mut tree: TaffyTree<Text> = TaffyTree::new();
+    let rb = base(&mut tree, 60.0);
+    let rt = annotation(&mut tree, 20.0);
+    let container = ruby(&mut tree, Style::default(), &[rb, rt]);
+    let text = base(&mut tree, 30.0);
+    let inline =
+        tree.new_with_children(Style { display: Display::InlineBlock, ..Default::default() }, &[text]).unwrap();
+    let root =
+        tree.new_with_children(Style { display: Display::Block, ..Default::default() }, &[container, inline]).unwrap();
+    tree.compute_layout_with_measure(root, Size::MAX_CONTENT, measure_function).unwrap();
+
+    // The baseline of the ruby container is 26px from its top (below its 10px tall row of annotations)
+    assert_eq!(layout(&tree, container), (Point { x: 0.0, y: 0.0 }, Size { width: 60.0, height: 30.0 }));
+    assert_eq!(layout(&tree, inline).0, Point { x: 60.0, y: 10.0 });
+    assert_eq!(tree.layout(root).unwrap().size, Size { width: 90.0, height: 30.0 });
+}
@@ -0,0 +1,138 @@
+//! A stable `scrollbar_gutter` reserves space for a scroll container's scrollbar on its inline-end edge
+//! (and with `both-edges` also on its inline-start edge) whether or not a scrollbar is shown.
+
+use taffy::geometry::Point;
+use taffy::prelude::*;
+use taffy::style::{Direction, Display, Overflow, Position, ScrollbarGutter};
+use taffy_test_helpers::new_test_tree;
+
+const SCROLLBAR_WIDTH: f32 = 10.0;
+const DISPLAYS: [Display; 3] = [Display::Block, Display::Flex, Display::Grid];
+
+/// Lays out a 100x100 container with the given child and returns the layouts of the container and of the child
+fn layout_container(
+    display: Display,
+    direction: Direction,
+    overflow: Overflow,
+    scrollbar_gutter: ScrollbarGutter,
+    child_style: Style,
+) -> (Layout, Layout) {
+    let mut tree = new_test_tree();
+    let child = tree.new_leaf(Style { flex_shrink: 0.0, ..child_style }).unwrap();
+    let node = tree
+        .new_with_children(
+            Style {
+                display,
+                direction,
+                flex_direction: FlexDirection::Column,
+                overflow: Point { x: Overflow::Visible, y: overflow },
+                scrollbar_gutter,
+                scrollbar_width: SCROLLBAR_WIDTH,
+                size: Size { width: length(100.0), height: length(100.0) },
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+
+    tree.compute_layout(node, Size::MAX_CONTENT).unwrap();
+    (*tree.layout(node).unwrap(), *tree.layout(child).unwrap())
+}
+
+fn auto_width_child() -> Style {
+    Style { size: Size { width: auto(), height: length(50.0) }, ..Default::default() }
+}
+
+#[test]
+fn stable_gutter_is_reserved_on_the_inline_end_edge() {
+    for display in DISPLAYS {
+        for (direction, expected_x) in [(Direction::Ltr, 0.0), (Direction::Rtl, SCROLLBAR_WIDTH)] {
+            let (container, child) =
+                layout_container(display, direction, Overflow::Hidden, ScrollbarGutter::Stable, auto_width_child());
+
+            assert_eq!(container.scrollbar_size, Size { width: SCROLLBAR_WIDTH, height: 0.0 }, "{display:?}");
+            assert_eq!(child.size.width, 100.0 - SCROLLBAR_WIDTH, "{display:?} {direction:?}");
+            assert_eq!(child.location.x, expected_x, "{display:?} {direction:?}");
+        }
+    }
+}
+
+#[test]
+fn stable_both_edges_gutter_is_reserved_on_both_inline_edges() {
+    for display in DISPLAYS {
+        for direction in [Direction::Ltr, Direction::Rtl] {
+            let (container, child) = layout_container(
+                display,
+                direction,
+                Overflow::Hidden,
+                ScrollbarGutter::StableBothEdges,
+                auto_width_child(),
+            );
+
+            assert_eq!(container.scrollbar_size, Size { width: SCROLLBAR_WIDTH, height: 0.0 }, "{display:?}");
+            assert_eq!(child.size.width, 100.0 - 2.0 * SCROLLBAR_WIDTH, "{display:?} {direction:?}");
+            assert_eq!(child.location.x, SCROLLBAR_WIDTH, "{display:?} {direction:?}");
+        }
+    }
+}
+
+#[test]
+fn stable_gutter_has_no_effect_on_non_scroll_containers() {
+    for display in DISPLAYS {
+        let (container, child) =
+            layout_container(display, Direction::Ltr, Overflow::Visible, ScrollbarGutter::Stable, auto_width_child());
+
+        assert_eq!(container.scrollbar_size, Size::ZERO, "{display:?}");
+        assert_eq!(child.size.width, 100.0, "{display:?}");
+    }
+}
+
+#[test]
+#[cfg(feature = "content_size")]
+fn stable_gutter_does_not_change_when_auto_scrollbar_appears() {
+    for display in DISPLAYS {
+        for child_height in [50.0, 200.0] {
+            let child_style =
+                Style { size: Size { width: auto(), height: length(child_height) }, ..Default::default() };
+            let (container, child) =
+                layout_container(display, Direction::Ltr, Overflow::Auto, ScrollbarGutter::Stable, child_style);
+
+            assert_eq!(container.scrollbar_size, Size { width: SCROLLBAR_WIDTH, height: 0.0 }, "{display:?}");
+            assert_eq!(child.size.width, 100.0 - SCROLLBAR_WIDTH, "{display:?} {child_height}");
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "content_size")]
+fn scroll_width_accounts_for_both_gutters() {
+    for display in DISPLAYS {
+        for direction in [Direction::Ltr, Direction::Rtl] {
+            let child_style = Style { size: Size { width: length(200.0), height: length(50.0) }, ..Default::default() };
+            let (container, _) =
+                layout_container(display, direction, Overflow::Hidden, ScrollbarGutter::StableBothEdges, child_style);
+
+            // The 200px child overflows the 80px wide space between the gutters by 120px
+            assert_eq!(container.scroll_width(), 120.0, "{display:?} {direction:?}");
+        }
+    }
+}
+
+#[test]
+fn absolutely_positioned_children_are_inset_from_the_gutters() {
+    for display in DISPLAYS {
+        for direction in [Direction::Ltr, Direction::Rtl] {
+            let child_style = Style {
+                position: Position::Absolute,
+                inset: Rect { left: length(0.0), right: length(0.0), top: length(0.0), bottom: auto() },
+                size: Size { width: auto(), height: length(50.0) },
+                ..Default::default()
+            };
+            let (_, child) =
+                layout_container(display, direction, Overflow::Hidden, ScrollbarGutter::StableBothEdges, child_style);
+
+            assert_eq!(child.size.width, 100.0 - 2.0 * SCROLLBAR_WIDTH, "{display:?} {direction:?}");
+            assert_eq!(child.location.x, SCROLLBAR_WIDTH, "{display:?} {direction:?}");
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! A node with size containment is sized as if it were empty in the contained axes, with a content box of the size
+//! given by its `contain_intrinsic_size` style (or its last remembered size, for the `auto` keyword).
+
+use taffy::compute_leaf_layout;
+use taffy::prelude::*;
+use taffy::style::{Contain, ContainIntrinsicSize};
+use taffy::tree::{LayoutInput, LayoutOutput};
+
+/// Lays out a node with the given display and style, containing a single 100x100 child, and returns the layouts of
+/// the node and the child
+fn contained_layouts(display: Display, style: Style) -> (Layout, Layout) {
+    let mut tree: TaffyTree<()> = TaffyTree::new();
+    let child = tree.new_leaf(Style {
+        size: Size { width: length(100.0), height: length(100.0) },
+        flex_shrink: 0.0,
+        ..Default::default()
+    });
+    let node = tree.new_with_children(Style { display, ..style }, &[child.unwrap()]).unwrap();
+    tree.compute_layout(node, Size::MAX_CONTENT).unwrap();
+    (*tree.layout(node).unwrap(), *tree.layout(tree.child_at_index(node, 0).unwrap()).unwrap())
+}
+
+#[test]
+fn size_contained_containers_are_sized_as_if_empty() {
+    for display in [Display::Block, Display::Flex, Display::Grid] {
+        let (node, child) = contained_layouts(display, Style { contain: Contain::SIZE, ..Default::default() });
+        assert_eq!(node.size, Size::ZERO, "{display:?}");
+        // The contents are still laid out
+        assert_eq!(child.size, Size { width: 100.0, height: 100.0 }, "{display:?}");
+
+        let style = Style {
+            contain: Contain::SIZE,
+            contain_intrinsic_size: Size {
+                width: ContainIntrinsicSize::length(50.0),
+                height: ContainIntrinsicSize::length(30.0),
+            },
+            padding: Rect { left: length(5.0), right: length(5.0), top: zero(), bottom: zero() },
+            max_size: Size { width: auto(), height: length(20.0) },
+            ..Default::default()
+        };
+        let (node, _) = contained_layouts(display, style);
+        assert_eq!(node.size, Size { width: 60.0, height: 20.0 }, "{display:?}");
+    }
+}
+
+#[test]
+fn inline_size_containment_only_applies_to_the_width() {
+    for display in [Display::Block, Display::Flex, Display::Grid] {
+        let style = Style {
+            contain: Contain::INLINE_SIZE,
+            contain_intrinsic_size: Size {
+                width: ContainIntrinsicSize::length(50.0),
+                height: ContainIntrinsicSize::length(30.0),
+            },
+            ..Default::default()
+        };
+        let (node, _) = contained_layouts(display, style);
+        assert_eq!(node.size, Size { width: 50.0, height: 100.0 }, "{display:?}");
+    }
+}
+
+#[test]
+fn size_contained_leaves_ignore_their_measured_content() {
+    let mut tree: TaffyTree<()> = TaffyTree::new();
+    let style = Style {
+        contain: Contain::SIZE,
+        contain_intrinsic_size: Size { width: ContainIntrinsicSize::length(50.0), height: ContainIntrinsicSize::NONE },
+        ..Default::default()
+    };
+    let leaf = tree.new_leaf(style).unwrap();
+    let measure = |inputs: LayoutInput, _, _: Option<&mut ()>, style: &Style| -> LayoutOutput {
+        compute_leaf_layout(inputs, style, |_, _| 0.0, |_, _| Size { width: 100.0, height: 100.0 })
+    };
+    tree.compute_layout_with_measure(leaf, Size::MAX_CONTENT, measure).unwrap();
+    assert_eq!(tree.layout(leaf).unwrap().size, Size { width: 50.0, height: 0.0 });
+}
+
+#[test]
+fn auto_contain_intrinsic_size_uses_the_last_remembered_size() {
+    let mut tree: TaffyTree<()> = TaffyTree::new();
+    let child =
+        tree.new_leaf(Style { size: Size { width: length(100.0), height: length(80.0) }, ..Default::default() });
+    let auto_size = Size { width: ContainIntrinsicSize::auto(300.0), height: ContainIntrinsicSize::auto(300.0) };
+    let style = Style {
+        display: Display::Flex,
+        padding: Rect { left: length(10.0), right: length(10.0), top: length(10.0), bottom: length(10.0) },
+        contain_intrinsic_size: auto_size,
+        ..Default::default()
+    };
+    let node = tree.new_with_children(style.clone(), &[child.unwrap()]).unwrap();
+
+    // Without size containment the node is sized by its content, and that size is remembered
+    tree.compute_layout(node, Size::MAX_CONTENT).unwrap();
+    assert_eq!(tree.layout(node).unwrap().size, Size { width: 120.0, height: 100.0 });
+
+    // With size containment the node is given its remembered size rather than the length in its style
+    tree.set_style(node, Style { contain: Contain::SIZE, ..style.clone() }).unwrap();
+    tree.compute_layout(node, Size::MAX_CONTENT).unwrap();
+    assert_eq!(tree.layout(node).unwrap().size, Size { width: 120.0, height: 100.0 });
+
+    // Without the `auto` keyword the size is not remembered
+    let fixed_size = Size { width: ContainIntrinsicSize::length(300.0), height: ContainIntrinsicSize::length(300.0) };
+    tree.set_style(node, Style { contain: Contain::SIZE, contain_intrinsic_size: fixed_size, ..style.clone() })
+        .unwrap();
+    tree.compute_layout(node, Size::MAX_CONTENT).unwrap();
+    assert_eq!(tree.layout(node).unwrap().size, Size { width: 320.0, height: 320.0 });
+    tree.set_style(node, Style { contain: Contain::SIZE, ..style }).unwrap();
+    tree.compute_layout(node, Size::MAX_CONTENT).unwrap();
+    assert_eq!(tree.layout(node).unwrap().size, Size { width: 320.0, height: 320.0 });
+}
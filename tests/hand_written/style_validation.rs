@@ -0,0 +1,108 @@
+//! `Style::validate` reports style values which Taffy tolerates but which are invalid in CSS, including
+//! `grid-template-areas` templates which don't define rectangular areas.
+
+use taffy::geometry::AbsoluteAxis;
+use taffy::prelude::*;
+use taffy::{GridTemplateArea, GridTemplateAreas, GridTemplateAreasError, StyleValidationError};
+
+fn areas(rows: &[&str]) -> Result<GridTemplateAreas<String>, GridTemplateAreasError> {
+    GridTemplateAreas::from_rows(rows)
+}
+
+fn area(name: &str, row_start: u16, row_end: u16, column_start: u16, column_end: u16) -> GridTemplateArea<String> {
+    GridTemplateArea { name: name.into(), row_start, row_end, column_start, column_end }
+}
+
+#[test]
+fn area_template_rows_are_parsed_into_areas() {
+    let template = areas(&["head head .", "nav main main", "nav ... ..."]).unwrap();
+    assert_eq!(template.row_count, 3);
+    assert_eq!(template.column_count, 3);
+    assert_eq!(template.areas, vec![area("head", 1, 2, 1, 3), area("nav", 2, 4, 1, 2), area("main", 2, 3, 2, 4)]);
+    assert_eq!(template.validate(), Ok(()));
+
+    // Runs of names and dots are separate cells even without whitespace between them
+    assert_eq!(areas(&["a.b"]).unwrap().column_count, 3);
+}
+
+#[test]
+fn invalid_area_templates_are_reported() {
+    assert_eq!(areas(&[]), Err(GridTemplateAreasError::Empty));
+    assert_eq!(areas(&["a b", "  "]), Err(GridTemplateAreasError::EmptyRow { row: 1 }));
+    assert_eq!(areas(&["a #b"]), Err(GridTemplateAreasError::InvalidToken { row: 0, token: "#".into() }));
+    assert_eq!(
+        areas(&["a b", "a b c"]),
+        Err(GridTemplateAreasError::RowLengthMismatch { row: 1, expected: 2, found: 3 })
+    );
+    assert_eq!(areas(&["a a", "a ."]), Err(GridTemplateAreasError::NonRectangularArea { name: "a".into() }));
+    assert_eq!(areas(&["a b a"]), Err(GridTemplateAreasError::DuplicateAreaName { name: "a".into() }));
+}
+
+#[test]
+fn directly_constructed_area_templates_are_validated() {
+    let template = GridTemplateAreas {
+        areas: vec![area("a", 1, 2, 1, 3), area("b", 1, 3, 2, 3), area("a", 2, 3, 1, 2), area("c", 3, 4, 1, 2)],
+        row_count: 2,
+        column_count: 2,
+    };
+    assert_eq!(
+        template.validate(),
+        Err(vec![
+            GridTemplateAreasError::OverlappingAreas { first: "a".into(), second: "b".into() },
+            GridTemplateAreasError::DuplicateAreaName { name: "a".into() },
+            GridTemplateAreasError::AreaOutOfBounds { name: "c".into() },
+        ])
+    );
+}
+
+#[test]
+fn explicit_line_names_conflicting_with_areas_are_reported() {
+    let style: Style<String> = Style {
+        display: Display::Grid,
+        grid_template_columns: vec![length(10.0), repeat(2, vec![length(20.0)])],
+        grid_template_column_names: vec![vec![], vec!["main-start".into()], vec!["main-end".into()]],
+        grid_template_rows: vec![length(10.0)],
+        grid_template_row_names: vec![vec!["side-end".into()], vec!["main-end".into()]],
+        grid_template_areas: Some(areas(&["side main main"]).unwrap()),
+        ..Default::default()
+    };
+    // The lines named `main-start` and `main-end` match the area (the column line named `main-end` follows the
+    // repeated tracks), but the row line named `side-end` is the first line rather than the last
+    assert_eq!(
+        style.validate(),
+        Err(vec![StyleValidationError::GridTemplateAreas(GridTemplateAreasError::ConflictingLineName {
+            line_name: "side-end".into(),
+            axis: AbsoluteAxis::Vertical,
+            explicit_line: 1,
+            area_line: 2,
+        })])
+    );
+}
+
+#[test]
+fn invalid_style_values_are_reported() {
+    assert_eq!(Style::<String>::DEFAULT.validate(), Ok(()));
+
+    let style: Style<String> = Style {
+        padding: Rect { left: length(-1.0), right: zero(), top: zero(), bottom: zero() },
+        flex_grow: -1.0,
+        grid_template_columns: vec![
+            repeat(RepetitionCount::AutoFill, vec![length(10.0)]),
+            repeat(RepetitionCount::Count(0), vec![auto()]),
+        ],
+        grid_template_rows: vec![repeat(RepetitionCount::AutoFit, vec![length(10.0)]), auto()],
+        grid_row: Line { start: line(0), end: auto() },
+        ..Default::default()
+    };
+    assert_eq!(
+        style.validate(),
+        Err(vec![
+            StyleValidationError::NegativeValue { property: "padding" },
+            StyleValidationError::NegativeValue { property: "flex_grow" },
+            StyleValidationError::EmptyRepetition { property: "grid_template_columns" },
+            StyleValidationError::AutoRepetitionWithoutFixedSize { property: "grid_template_columns" },
+            StyleValidationError::AutoRepetitionWithoutFixedSize { property: "grid_template_rows" },
+            StyleValidationError::InvalidGridLine { property: "grid_row" },
+        ])
+    );
+}
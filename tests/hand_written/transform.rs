@@ -0,0 +1,183 @@
+//! A node's transform does not affect layout, but the transformed border box (and content) of the node is what
+//! contributes to the scrollable overflow of its ancestors.
+
+use taffy::geometry::Point;
+use taffy::prelude::*;
+use taffy::style::{Direction, Overflow, Transform};
+use taffy_test_helpers::new_test_tree;
+
+/// Lays out a 50x50 child with the given transform and origin inside a 200x200 scroll container, and returns the
+/// layouts of the container and the child
+fn scroll_container_layouts(
+    display: Display,
+    direction: Direction,
+    transform: Transform,
+    transform_origin: Point<LengthPercentage>,
+) -> (Layout, Layout) {
+    let mut tree = new_test_tree();
+    let child = tree
+        .new_leaf(Style {
+            size: Size { width: length(50.0), height: length(50.0) },
+            transform,
+            transform_origin,
+            ..Default::default()
+        })
+        .unwrap();
+    let node = tree
+        .new_with_children(
+            Style {
+                display,
+                direction,
+                size: Size { width: length(200.0), height: length(200.0) },
+                overflow: Point { x: Overflow::Scroll, y: Overflow::Scroll },
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+    tree.compute_layout(node, Size::MAX_CONTENT).unwrap();
+    (*tree.layout(node).unwrap(), *tree.layout(child).unwrap())
+}
+
+const CENTER: Point<LengthPercentage> = Point { x: LengthPercentage::percent(0.5), y: LengthPercentage::percent(0.5) };
+const TOP_LEFT: Point<LengthPercentage> = Point { x: LengthPercentage::ZERO, y: LengthPercentage::ZERO };
+
+#[test]
+fn transforms_do_not_affect_layout() {
+    for display in [Display::Block, Display::Flex, Display::Grid] {
+        let transform = Transform::translate(300.0, 0.0);
+        let (_, child) = scroll_container_layouts(display, Direction::Ltr, transform, CENTER);
+        assert_eq!(child.location, Point::ZERO, "{display:?}");
+        assert_eq!(child.size, Size { width: 50.0, height: 50.0 }, "{display:?}");
+    }
+}
+
+#[test]
+fn translated_boxes_extend_the_scrollable_overflow() {
+    for display in [Display::Block, Display::Flex, Display::Grid] {
+        let transform = Transform::translate(300.0, 0.0);
+        let (container, _) = scroll_container_layouts(display, Direction::Ltr, transform, CENTER);
+        assert_eq!(container.scrollable_overflow_rect.right, 350.0, "{display:?}");
+        assert_eq!(container.scrollable_overflow_rect.bottom, 50.0, "{display:?}");
+
+        // Overflow in RTL containers extends to the left
+        let transform = Transform::translate(-300.0, 0.0);
+        let (container, _) = scroll_container_layouts(display, Direction::Rtl, transform, CENTER);
+        assert_eq!(container.scrollable_overflow_rect.right, 350.0, "{display:?}");
+    }
+}
+
+#[test]
+fn transforms_are_applied_about_the_transform_origin() {
+    for display in [Display::Block, Display::Flex, Display::Grid] {
+        let transform = Transform::scale(10.0, 10.0);
+
+        // Scaled about its center, the child covers -225..275 in both axes
+        let (container, _) = scroll_container_layouts(display, Direction::Ltr, transform, CENTER);
+        assert_eq!(container.scrollable_overflow_rect.right, 275.0, "{display:?}");
+        assert_eq!(container.scrollable_overflow_rect.bottom, 275.0, "{display:?}");
+
+        let (container, _) = scroll_container_layouts(display, Direction::Ltr, transform, TOP_LEFT);
+        assert_eq!(container.scrollable_overflow_rect.right, 500.0, "{display:?}");
+        assert_eq!(container.scrollable_overflow_rect.bottom, 500.0, "{display:?}");
+    }
+}
+
+#[test]
+fn transformed_bounding_box_includes_ancestor_transforms() {
+    let mut tree: TaffyTree<()> = TaffyTree::new();
+    let child = tree
+        .new_leaf(Style {
+            size: Size { width: length(100.0), height: length(50.0) },
+            margin: Rect { left: length(10.0), right: zero(), top: zero(), bottom: zero() },
+            // A quarter turn clockwise
+            transform: Transform { a: 0.0, b: 1.0, c: -1.0, d: 0.0, e: 0.0, f: 0.0 },
+            ..Default::default()
+        })
+        .unwrap();
+    let parent = tree
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                size: Size { width: length(200.0), height: length(200.0) },
+                transform: Transform::translate(5.0, 5.0),
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+    tree.compute_layout(parent, Size::MAX_CONTENT).unwrap();
+
+    assert_eq!(
+        tree.transformed_bounding_box(parent).unwrap(),
+        Rect { left: 5.0, right: 205.0, top: 5.0, bottom: 205.0 }
+    );
+    // The child is rotated about its center (at 60, 25) and then translated along with its parent
+    assert_eq!(
+        tree.transformed_bounding_box(child).unwrap(),
+        Rect { left: 40.0, right: 90.0, top: -20.0, bottom: 80.0 }
+    );
+}
+
+/// Lays out the child of [`scroll_container_layouts`] in a block scroll container, and returns the scrollable overflow of
+/// the container and the transformed bounding box of the child
+fn scrollable_overflow_and_transformed_bounding_box(
+    direction: Direction,
+    transform: Transform,
+    transform_origin: Point<LengthPercentage>,
+) -> (Rect<f32>, Rect<f32>) {
+    let mut tree = new_test_tree();
+    let child = tree
+        .new_leaf(Style {
+            size: Size { width: length(50.0), height: length(50.0) },
+            transform,
+            transform_origin,
+            ..Default::default()
+        })
+        .unwrap();
+    let node = tree
+        .new_with_children(
+            Style {
+                display: Display::Block,
+                direction,
+                size: Size { width: length(200.0), height: length(200.0) },
+                overflow: Point { x: Overflow::Scroll, y: Overflow::Scroll },
+                ..Default::default()
+            },
+            &[child],
+        )
+        .unwrap();
+    tree.compute_layout(node, Size::MAX_CONTENT).unwrap();
+    (tree.layout(node).unwrap().scrollable_overflow_rect, tree.transformed_bounding_box(child).unwrap())
+}
+
+#[test]
+fn transformed_bounding_box_in_rtl_parent() {
+    // The child is placed at the right edge of its parent (150..200), and is not mirrored
+    let (_, bounding_box) =
+        scrollable_overflow_and_transformed_bounding_box(Direction::Rtl, Transform::scale(2.0, 1.0), TOP_LEFT);
+    assert_eq!(bounding_box, Rect { left: 150.0, right: 250.0, top: 0.0, bottom: 50.0 });
+
+    // The scrollable overflow of the parent is measured from its right edge, and agrees with the bounding box
+    let (overflow, bounding_box) =
+        scrollable_overflow_and_transformed_bounding_box(Direction::Rtl, Transform::translate(-300.0, 0.0), CENTER);
+    assert_eq!(bounding_box, Rect { left: -150.0, right: -100.0, top: 0.0, bottom: 50.0 });
+    assert_eq!(overflow.right, 200.0 - bounding_box.left);
+}
+
+#[cfg(feature = "calc")]
+#[test]
+fn transformed_bounding_box_resolves_calc_origins_like_layout() {
+    #[allow(dead_code)]
+    #[repr(align(8))]
+    struct Aligned(u64);
+    static CALC: Aligned = Aligned(0);
+    let calc = LengthPercentage::calc(&CALC as *const Aligned as *const ());
+
+    // `TaffyTree` resolves calc values to zero, so the child is scaled about its top-left corner
+    let origin = Point { x: calc, y: calc };
+    let (overflow, bounding_box) =
+        scrollable_overflow_and_transformed_bounding_box(Direction::Ltr, Transform::scale(10.0, 10.0), origin);
+    assert_eq!(bounding_box, Rect { left: 0.0, right: 500.0, top: 0.0, bottom: 500.0 });
+    assert_eq!(overflow, bounding_box);
+}
@@ -351,7 +351,14 @@ fn build_style<S: CheapCloneStr>(xnode: roxmltree::Node) -> taffy::Style<S> {
             y: parse_or_default(xnode.attribute("overflow-y")),
         },
         scrollbar_width: parse_or_default(xnode.attribute("scrollbar-width")),
+        scrollbar_gutter: parse_or_default(xnode.attribute("scrollbar-gutter")),
         contain: parse_or_default(xnode.attribute("contain")),
+        contain_intrinsic_size: Size {
+            width: parse_or_default(xnode.attribute("contain-intrinsic-width")),
+            height: parse_or_default(xnode.attribute("contain-intrinsic-height")),
+        },
+        content_visibility: parse_or_default(xnode.attribute("content-visibility")),
+        container_type: parse_or_default(xnode.attribute("container-type")),
         float: parse_or_default(xnode.attribute("float")),
         clear: parse_or_default(xnode.attribute("clear")),
         position: parse_or_default(xnode.attribute("position")),
@@ -374,6 +381,9 @@ fn build_style<S: CheapCloneStr>(xnode: roxmltree::Node) -> taffy::Style<S> {
             bottom: parse_or(xnode.attribute("bottom"), LengthPercentageAuto::auto()),
             right: parse_or(xnode.attribute("right"), LengthPercentageAuto::auto()),
         },
+        anchor_name: xnode.attribute("anchor-name").map(S::from),
+        position_anchor: xnode.attribute("position-anchor").map(S::from),
+        position_try_fallbacks: parse_or_default(xnode.attribute("position-try-fallbacks")),
         margin: Rect {
             top: parse_or(xnode.attribute("margin-top"), LengthPercentageAuto::ZERO),
             left: parse_or(xnode.attribute("margin-left"), LengthPercentageAuto::ZERO),
@@ -397,7 +407,17 @@ fn build_style<S: CheapCloneStr>(xnode: roxmltree::Node) -> taffy::Style<S> {
             height: parse_or(xnode.attribute("row-gap"), LengthPercentage::ZERO),
         },
 
-        aspect_ratio: maybe_parse(xnode.attribute("aspect-ratio")),
+        aspect_ratio: parse_or_default(xnode.attribute("aspect-ratio")),
+        object_fit: parse_or_default(xnode.attribute("object-fit")),
+        object_position: Point {
+            x: parse_or(xnode.attribute("object-position-x"), LengthPercentage::percent(0.5)),
+            y: parse_or(xnode.attribute("object-position-y"), LengthPercentage::percent(0.5)),
+        },
+        transform: parse_or_default(xnode.attribute("transform")),
+        transform_origin: Point {
+            x: parse_or(xnode.attribute("transform-origin-x"), LengthPercentage::percent(0.5)),
+            y: parse_or(xnode.attribute("transform-origin-y"), LengthPercentage::percent(0.5)),
+        },
         align_items: maybe_parse(xnode.attribute("align-items")),
         align_self: maybe_parse(xnode.attribute("align-self")),
         justify_items: maybe_parse(xnode.attribute("justify-items")),
@@ -406,6 +426,8 @@ fn build_style<S: CheapCloneStr>(xnode: roxmltree::Node) -> taffy::Style<S> {
         justify_content: maybe_parse(xnode.attribute("justify-content")),
 
         text_align: parse_or_default(xnode.attribute("text-align")),
+        list_style_position: parse_or_default(xnode.attribute("list-style-position")),
+        ruby_align: parse_or_default(xnode.attribute("ruby-align")),
         flex_direction: parse_or_default(xnode.attribute("flex-direction")),
         flex_wrap: parse_or_default(xnode.attribute("flex-wrap")),
         #[cfg(feature = "flexbox_balance")]